@@ -8,7 +8,11 @@ use bevy_ecs::{
 use naia_bevy_shared::{
     Channel, EntityAndGlobalEntityConverter, EntityDoesNotExistError, GlobalEntity, Message, Tick,
 };
-use naia_client::{shared::SocketConfig, transport::Socket, Client as NaiaClient, NaiaClientError};
+use naia_client::{
+    shared::{BandwidthBreakdown, NetworkStats, SocketConfig},
+    transport::Socket,
+    Client as NaiaClient, EntityAuthStatus, NaiaClientError, NetworkClock, TickBufferMessages,
+};
 
 // Client
 #[derive(SystemParam)]
@@ -25,12 +29,12 @@ impl<'w> Client<'w> {
         self.client.auth(auth);
     }
 
-    pub fn connect<S: Into<Box<dyn Socket>>>(&mut self, socket: S) {
-        self.client.connect(socket);
+    pub fn connect<S: Into<Box<dyn Socket>>>(&mut self, socket: S) -> Result<(), NaiaClientError> {
+        self.client.connect(socket)
     }
 
-    pub fn disconnect(&mut self) {
-        self.client.disconnect();
+    pub fn disconnect(&mut self) -> Result<(), NaiaClientError> {
+        self.client.disconnect()
     }
 
     pub fn is_connected(&self) -> bool {
@@ -61,18 +65,41 @@ impl<'w> Client<'w> {
         self.client.jitter()
     }
 
+    pub fn network_stats(&self) -> Option<NetworkStats> {
+        self.client.network_stats()
+    }
+
+    pub fn bandwidth_breakdown(&mut self) -> Option<BandwidthBreakdown> {
+        self.client.bandwidth_breakdown()
+    }
+
+    pub fn prometheus_metrics(&self) -> String {
+        self.client.prometheus_metrics()
+    }
+
     // Config
     pub fn socket_config(&self) -> &SocketConfig {
         self.client.socket_config()
     }
 
     //// Messages ////
-    pub fn send_message<C: Channel, M: Message>(&mut self, message: &M) {
-        self.client.send_message::<C, M>(message);
+    pub fn send_message<C: Channel, M: Message>(
+        &mut self,
+        message: &M,
+    ) -> Result<(), NaiaClientError> {
+        self.client.send_message::<C, M>(message)
+    }
+
+    pub fn send_tick_buffer_message<C: Channel, M: Message>(
+        &mut self,
+        tick: &Tick,
+        message: &M,
+    ) -> Result<(), NaiaClientError> {
+        self.client.send_tick_buffer_message::<C, M>(tick, message)
     }
 
-    pub fn send_tick_buffer_message<C: Channel, M: Message>(&mut self, tick: &Tick, message: &M) {
-        self.client.send_tick_buffer_message::<C, M>(tick, message);
+    pub fn receive_tick_buffer_messages(&mut self, tick: &Tick) -> TickBufferMessages {
+        self.client.receive_tick_buffer_messages(tick)
     }
 
     //// Ticks ////
@@ -85,6 +112,10 @@ impl<'w> Client<'w> {
         self.client.server_tick()
     }
 
+    pub fn network_clock(&self) -> Option<NetworkClock> {
+        self.client.network_clock()
+    }
+
     // Interpolation
 
     pub fn client_interpolation(&self) -> Option<f32> {
@@ -104,6 +135,17 @@ impl<'w> Client<'w> {
     pub fn disable_replication(&mut self, entity: &Entity) {
         self.client.disable_replication(entity);
     }
+
+    // Authority Delegation
+
+    pub fn entity_auth_status(&self, entity: &Entity) -> Option<EntityAuthStatus> {
+        self.client.entity_auth_status(entity)
+    }
+
+    /// Authority is given back with `CommandsExt::release_authority()`
+    pub fn request_authority(&mut self, entity: &Entity) -> Result<(), NaiaClientError> {
+        self.client.request_authority(entity)
+    }
 }
 
 impl<'w> EntityAndGlobalEntityConverter<Entity> for Client<'w> {
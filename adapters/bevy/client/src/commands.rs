@@ -1,10 +1,11 @@
 use bevy_ecs::{
     entity::Entity,
     system::{Command as BevyCommand, EntityCommands},
-    world::World,
+    world::{Mut, World},
 };
 
 use naia_bevy_shared::{HostOwned, WorldMutType, WorldProxyMut};
+use naia_client::Client as NaiaClient;
 
 use crate::Client;
 
@@ -14,6 +15,7 @@ pub trait CommandsExt<'w, 's, 'a> {
     fn disable_replication(&'a mut self, client: &mut Client)
         -> &'a mut EntityCommands<'w, 's, 'a>;
     fn duplicate(&'a mut self) -> EntityCommands<'w, 's, 'a>;
+    fn release_authority(&'a mut self) -> &'a mut EntityCommands<'w, 's, 'a>;
 }
 
 impl<'w, 's, 'a> CommandsExt<'w, 's, 'a> for EntityCommands<'w, 's, 'a> {
@@ -40,6 +42,12 @@ impl<'w, 's, 'a> CommandsExt<'w, 's, 'a> for EntityCommands<'w, 's, 'a> {
         commands.add(command);
         commands.entity(new_entity)
     }
+
+    fn release_authority(&'a mut self) -> &'a mut EntityCommands<'w, 's, 'a> {
+        let entity = self.id();
+        self.commands().add(ReleaseAuthority { entity });
+        return self;
+    }
 }
 
 //// DuplicateComponents Command ////
@@ -67,3 +75,17 @@ impl BevyCommand for DuplicateComponents {
         );
     }
 }
+
+//// ReleaseAuthority Command ////
+
+pub(crate) struct ReleaseAuthority {
+    entity: Entity,
+}
+
+impl BevyCommand for ReleaseAuthority {
+    fn write(self, world: &mut World) {
+        world.resource_scope(|world, mut client: Mut<NaiaClient<Entity>>| {
+            client.release_authority(world.proxy_mut(), &self.entity);
+        });
+    }
+}
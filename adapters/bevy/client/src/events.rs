@@ -62,22 +62,34 @@ pub struct ClientTickEvent(pub Tick);
 // ServerTickEvent
 pub struct ServerTickEvent(pub Tick);
 
+// ServerClockJumpEvent
+pub struct ServerClockJumpEvent(pub i32);
+
 // SpawnEntityEvent
-pub struct SpawnEntityEvent(pub Entity);
+pub struct SpawnEntityEvent(pub Tick, pub Entity);
 
 // DespawnEntityEvent
-pub struct DespawnEntityEvent(pub Entity);
+pub struct DespawnEntityEvent(pub Tick, pub Entity);
+
+// EntityAuthGrantedEvent
+pub struct EntityAuthGrantedEvent(pub Entity);
+
+// EntityAuthDeniedEvent
+pub struct EntityAuthDeniedEvent(pub Entity);
+
+// EntityAuthRevokedEvent
+pub struct EntityAuthRevokedEvent(pub Entity);
 
 // InsertComponentEvent
 pub struct InsertComponentEvents {
-    inner: HashMap<ComponentKind, Vec<Entity>>,
+    inner: HashMap<ComponentKind, Vec<(Tick, Entity)>>,
 }
 
 impl InsertComponentEvents {
-    pub fn new(inner: HashMap<ComponentKind, Vec<Entity>>) -> Self {
+    pub fn new(inner: HashMap<ComponentKind, Vec<(Tick, Entity)>>) -> Self {
         Self { inner }
     }
-    pub fn read<C: Replicate>(&self) -> Vec<Entity> {
+    pub fn read<C: Replicate>(&self) -> Vec<(Tick, Entity)> {
         let component_kind = ComponentKind::of::<C>();
         if let Some(components) = self.inner.get(&component_kind) {
             return components.clone();
@@ -109,26 +121,26 @@ impl UpdateComponentEvents {
 
 // RemoveComponentEvents
 pub struct RemoveComponentEvents {
-    inner: HashMap<ComponentKind, Vec<(Entity, Box<dyn Replicate>)>>,
+    inner: HashMap<ComponentKind, Vec<(Tick, Entity, Box<dyn Replicate>)>>,
 }
 
 impl RemoveComponentEvents {
-    pub fn new(inner: HashMap<ComponentKind, Vec<(Entity, Box<dyn Replicate>)>>) -> Self {
+    pub fn new(inner: HashMap<ComponentKind, Vec<(Tick, Entity, Box<dyn Replicate>)>>) -> Self {
         Self { inner }
     }
 
-    pub fn read<C: Replicate>(&self) -> Vec<(Entity, C)> {
+    pub fn read<C: Replicate>(&self) -> Vec<(Tick, Entity, C)> {
         let mut output = Vec::new();
 
         let component_kind = ComponentKind::of::<C>();
         if let Some(components) = self.inner.get(&component_kind) {
-            for (entity, boxed_component) in components {
+            for (tick, entity, boxed_component) in components {
                 let boxed_any = boxed_component.copy_to_box().to_boxed_any();
                 let component: C = Box::<dyn Any + 'static>::downcast::<C>(boxed_any)
                     .ok()
                     .map(|boxed_c| *boxed_c)
                     .unwrap();
-                output.push((*entity, component));
+                output.push((*tick, *entity, component));
             }
         }
 
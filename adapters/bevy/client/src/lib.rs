@@ -1,5 +1,8 @@
 pub use naia_bevy_shared::{sequence_greater_than, Random, ReceiveEvents, Replicate, Tick};
-pub use naia_client::{transport, ClientConfig, CommandHistory};
+pub use naia_client::{
+    shared::{BandwidthBreakdown, BandwidthShare, NetworkStats, PrometheusExporter},
+    transport, ClientConfig, CommandHistory, EntityAuthStatus, NetworkClock, TickBufferMessages,
+};
 
 pub mod events;
 
@@ -8,8 +8,9 @@ use naia_client::{Client, ClientConfig};
 
 use super::{
     events::{
-        ClientTickEvent, ConnectEvent, DespawnEntityEvent, DisconnectEvent, ErrorEvent,
-        InsertComponentEvents, MessageEvents, RejectEvent, RemoveComponentEvents, ServerTickEvent,
+        ClientTickEvent, ConnectEvent, DespawnEntityEvent, DisconnectEvent, EntityAuthDeniedEvent,
+        EntityAuthGrantedEvent, EntityAuthRevokedEvent, ErrorEvent, InsertComponentEvents,
+        MessageEvents, RejectEvent, RemoveComponentEvents, ServerClockJumpEvent, ServerTickEvent,
         SpawnEntityEvent, UpdateComponentEvents,
     },
     systems::before_receive_events,
@@ -64,9 +65,13 @@ impl PluginType for Plugin {
             .add_event::<ErrorEvent>()
             .add_event::<ClientTickEvent>()
             .add_event::<ServerTickEvent>()
+            .add_event::<ServerClockJumpEvent>()
             .add_event::<MessageEvents>()
             .add_event::<SpawnEntityEvent>()
             .add_event::<DespawnEntityEvent>()
+            .add_event::<EntityAuthGrantedEvent>()
+            .add_event::<EntityAuthDeniedEvent>()
+            .add_event::<EntityAuthRevokedEvent>()
             .add_event::<InsertComponentEvents>()
             .add_event::<UpdateComponentEvents>()
             .add_event::<RemoveComponentEvents>()
@@ -13,35 +13,42 @@ use crate::ServerOwned;
 
 mod naia_events {
     pub use naia_client::{
-        ClientTickEvent, ConnectEvent, DespawnEntityEvent, DisconnectEvent, ErrorEvent,
-        RejectEvent, ServerTickEvent, SpawnEntityEvent,
+        ClientTickEvent, ConnectEvent, DespawnEntityEvent, DisconnectEvent, EntityAuthDeniedEvent,
+        EntityAuthGrantedEvent, EntityAuthRevokedEvent, ErrorEvent, RejectEvent,
+        ServerClockJumpEvent, ServerTickEvent, SpawnEntityEvent,
     };
 }
 
 mod bevy_events {
     pub use crate::events::{
-        ClientTickEvent, ConnectEvent, DespawnEntityEvent, DisconnectEvent, ErrorEvent,
-        InsertComponentEvents, MessageEvents, RejectEvent, RemoveComponentEvents, ServerTickEvent,
+        ClientTickEvent, ConnectEvent, DespawnEntityEvent, DisconnectEvent, EntityAuthDeniedEvent,
+        EntityAuthGrantedEvent, EntityAuthRevokedEvent, ErrorEvent, InsertComponentEvents,
+        MessageEvents, RejectEvent, RemoveComponentEvents, ServerClockJumpEvent, ServerTickEvent,
         SpawnEntityEvent, UpdateComponentEvents,
     };
 }
 
 pub fn before_receive_events(world: &mut World) {
     world.resource_scope(|world, mut client: Mut<Client<Entity>>| {
-
         // Host Component Updates
-        let mut host_component_event_reader = world
-            .get_resource_mut::<Events<HostSyncEvent>>()
-            .unwrap();
-        let host_component_events: Vec<HostSyncEvent> = host_component_event_reader.drain().collect();
+        let mut host_component_event_reader =
+            world.get_resource_mut::<Events<HostSyncEvent>>().unwrap();
+        let host_component_events: Vec<HostSyncEvent> =
+            host_component_event_reader.drain().collect();
         for event in host_component_events {
             match event {
                 HostSyncEvent::Insert(entity, component_kind) => {
                     let mut world_proxy = world.proxy_mut();
-                    let Some(mut component_mut) = world_proxy.component_mut_of_kind(&entity, &component_kind) else {
+                    let Some(mut component_mut) =
+                        world_proxy.component_mut_of_kind(&entity, &component_kind)
+                    else {
                         continue;
                     };
-                    client.insert_component_worldless(&entity, DerefMut::deref_mut(&mut component_mut));
+                    // a Component which is already tracked needs no inserting
+                    let _ = client.insert_component_worldless(
+                        &entity,
+                        DerefMut::deref_mut(&mut component_mut),
+                    );
                 }
                 HostSyncEvent::Remove(entity, component_kind) => {
                     client.remove_component_worldless(&entity, &component_kind);
@@ -55,7 +62,6 @@ pub fn before_receive_events(world: &mut World) {
         // Receive Events
         let mut events = client.receive(world.proxy_mut());
         if !events.is_empty() {
-
             if events.has::<naia_events::ConnectEvent>() {
                 // Connect Event
                 let mut connect_event_writer = world
@@ -116,6 +122,16 @@ pub fn before_receive_events(world: &mut World) {
                 }
             }
 
+            // Server Clock Jump Event
+            if events.has::<naia_events::ServerClockJumpEvent>() {
+                let mut clock_jump_event_writer = world
+                    .get_resource_mut::<Events<bevy_events::ServerClockJumpEvent>>()
+                    .unwrap();
+                for millis in events.read::<naia_events::ServerClockJumpEvent>() {
+                    clock_jump_event_writer.send(bevy_events::ServerClockJumpEvent(millis));
+                }
+            }
+
             // Message Event
             if events.has_messages() {
                 let mut message_event_writer = world
@@ -131,9 +147,9 @@ pub fn before_receive_events(world: &mut World) {
                     .unwrap();
 
                 let mut spawned_entities = Vec::new();
-                for entity in events.read::<naia_events::SpawnEntityEvent>() {
+                for (tick, entity) in events.read::<naia_events::SpawnEntityEvent>() {
                     spawned_entities.push(entity);
-                    spawn_entity_event_writer.send(bevy_events::SpawnEntityEvent(entity));
+                    spawn_entity_event_writer.send(bevy_events::SpawnEntityEvent(tick, entity));
                 }
                 for entity in spawned_entities {
                     world.entity_mut(entity).insert(ServerOwned);
@@ -145,8 +161,38 @@ pub fn before_receive_events(world: &mut World) {
                 let mut despawn_entity_event_writer = world
                     .get_resource_mut::<Events<bevy_events::DespawnEntityEvent>>()
                     .unwrap();
-                for entity in events.read::<naia_events::DespawnEntityEvent>() {
-                    despawn_entity_event_writer.send(bevy_events::DespawnEntityEvent(entity));
+                for (tick, entity) in events.read::<naia_events::DespawnEntityEvent>() {
+                    despawn_entity_event_writer.send(bevy_events::DespawnEntityEvent(tick, entity));
+                }
+            }
+
+            // Entity Auth Granted Event
+            if events.has::<naia_events::EntityAuthGrantedEvent>() {
+                let mut auth_granted_event_writer = world
+                    .get_resource_mut::<Events<bevy_events::EntityAuthGrantedEvent>>()
+                    .unwrap();
+                for entity in events.read::<naia_events::EntityAuthGrantedEvent>() {
+                    auth_granted_event_writer.send(bevy_events::EntityAuthGrantedEvent(entity));
+                }
+            }
+
+            // Entity Auth Denied Event
+            if events.has::<naia_events::EntityAuthDeniedEvent>() {
+                let mut auth_denied_event_writer = world
+                    .get_resource_mut::<Events<bevy_events::EntityAuthDeniedEvent>>()
+                    .unwrap();
+                for entity in events.read::<naia_events::EntityAuthDeniedEvent>() {
+                    auth_denied_event_writer.send(bevy_events::EntityAuthDeniedEvent(entity));
+                }
+            }
+
+            // Entity Auth Revoked Event
+            if events.has::<naia_events::EntityAuthRevokedEvent>() {
+                let mut auth_revoked_event_writer = world
+                    .get_resource_mut::<Events<bevy_events::EntityAuthRevokedEvent>>()
+                    .unwrap();
+                for entity in events.read::<naia_events::EntityAuthRevokedEvent>() {
+                    auth_revoked_event_writer.send(bevy_events::EntityAuthRevokedEvent(entity));
                 }
             }
 
@@ -156,7 +202,8 @@ pub fn before_receive_events(world: &mut World) {
                 let mut insert_component_event_writer = world
                     .get_resource_mut::<Events<bevy_events::InsertComponentEvents>>()
                     .unwrap();
-                insert_component_event_writer.send(bevy_events::InsertComponentEvents::new(inserts));
+                insert_component_event_writer
+                    .send(bevy_events::InsertComponentEvents::new(inserts));
             }
 
             // Update Component Event
@@ -176,7 +223,8 @@ pub fn before_receive_events(world: &mut World) {
                     .get_resource_mut::<Events<bevy_events::RemoveComponentEvents>>()
                     .unwrap();
 
-                remove_component_event_writer.send(bevy_events::RemoveComponentEvents::new(removes));
+                remove_component_event_writer
+                    .send(bevy_events::RemoveComponentEvents::new(removes));
             }
         }
     });
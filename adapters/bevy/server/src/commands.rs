@@ -1,6 +1,13 @@
-use bevy_ecs::system::EntityCommands;
+use std::marker::PhantomData;
 
-use naia_bevy_shared::HostOwned;
+use bevy_ecs::{
+    entity::Entity,
+    system::{Command as BevyCommand, Commands, EntityCommands},
+    world::{Mut, World},
+};
+
+use naia_bevy_shared::{HostOwned, Replicate, WorldProxyMut};
+use naia_server::Server as NaiaServer;
 
 use crate::Server;
 
@@ -27,3 +34,54 @@ impl<'w, 's, 'a> CommandsExt<'w, 's, 'a> for EntityCommands<'w, 's, 'a> {
         return self;
     }
 }
+
+// Bevy Commands Extension for Resources
+pub trait ResourceCommandsExt {
+    /// Replicates a Resource to every User, or updates it if it exists.
+    /// As Commands can not return errors, the Command panics if the Resource
+    /// type was not registered with `Protocol::add_resource()`.
+    fn replicate_resource<R: Replicate>(&mut self, resource: R);
+    fn remove_replicated_resource<R: Replicate>(&mut self);
+}
+
+impl<'w, 's> ResourceCommandsExt for Commands<'w, 's> {
+    fn replicate_resource<R: Replicate>(&mut self, resource: R) {
+        self.add(InsertResource { resource });
+    }
+
+    fn remove_replicated_resource<R: Replicate>(&mut self) {
+        self.add(RemoveResource::<R> {
+            phantom_r: PhantomData,
+        });
+    }
+}
+
+//// InsertResource Command ////
+
+pub(crate) struct InsertResource<R: Replicate> {
+    resource: R,
+}
+
+impl<R: Replicate> BevyCommand for InsertResource<R> {
+    fn write(self, world: &mut World) {
+        world.resource_scope(|world, mut server: Mut<NaiaServer<Entity>>| {
+            server
+                .insert_resource(world.proxy_mut(), self.resource)
+                .expect("Resources must be registered with `Protocol::add_resource()`");
+        });
+    }
+}
+
+//// RemoveResource Command ////
+
+pub(crate) struct RemoveResource<R: Replicate> {
+    phantom_r: PhantomData<R>,
+}
+
+impl<R: Replicate> BevyCommand for RemoveResource<R> {
+    fn write(self, world: &mut World) {
+        world.resource_scope(|world, mut server: Mut<NaiaServer<Entity>>| {
+            server.remove_resource::<_, R>(world.proxy_mut());
+        });
+    }
+}
@@ -93,6 +93,12 @@ pub struct SpawnEntityEvent(pub UserKey, pub Entity);
 // DespawnEntityEvent
 pub struct DespawnEntityEvent(pub UserKey, pub Entity);
 
+// EntityAuthRequestEvent
+pub struct EntityAuthRequestEvent(pub UserKey, pub Entity);
+
+// EntityAuthReleaseEvent
+pub struct EntityAuthReleaseEvent(pub UserKey, pub Entity);
+
 // InsertComponentEvent
 pub struct InsertComponentEvents {
     inner: HashMap<ComponentKind, Vec<(UserKey, Entity)>>,
@@ -1,5 +1,8 @@
 pub use naia_bevy_shared::{Random, ReceiveEvents, Tick};
-pub use naia_server::{transport, RoomKey, ServerConfig, UserKey};
+pub use naia_server::{
+    shared::{BandwidthBreakdown, BandwidthShare, NetworkStats, PrometheusExporter},
+    transport, RoomKey, ServerConfig, TickMode, UserKey,
+};
 
 pub mod events;
 
@@ -9,7 +12,7 @@ mod plugin;
 mod server;
 mod systems;
 
-pub use commands::CommandsExt;
+pub use commands::{CommandsExt, ResourceCommandsExt};
 pub use components::{ClientOwned, ServerOwned};
 pub use plugin::Plugin;
 pub use server::Server;
@@ -8,9 +8,9 @@ use naia_server::{Server, ServerConfig};
 
 use super::{
     events::{
-        AuthEvents, ConnectEvent, DespawnEntityEvent, DisconnectEvent, ErrorEvent,
-        InsertComponentEvents, MessageEvents, RemoveComponentEvents, SpawnEntityEvent, TickEvent,
-        UpdateComponentEvents,
+        AuthEvents, ConnectEvent, DespawnEntityEvent, DisconnectEvent, EntityAuthReleaseEvent,
+        EntityAuthRequestEvent, ErrorEvent, InsertComponentEvents, MessageEvents,
+        RemoveComponentEvents, SpawnEntityEvent, TickEvent, UpdateComponentEvents,
     },
     systems::before_receive_events,
 };
@@ -66,6 +66,8 @@ impl PluginType for Plugin {
             .add_event::<AuthEvents>()
             .add_event::<SpawnEntityEvent>()
             .add_event::<DespawnEntityEvent>()
+            .add_event::<EntityAuthRequestEvent>()
+            .add_event::<EntityAuthReleaseEvent>()
             .add_event::<InsertComponentEvents>()
             .add_event::<UpdateComponentEvents>()
             .add_event::<RemoveComponentEvents>()
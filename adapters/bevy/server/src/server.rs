@@ -6,12 +6,15 @@ use bevy_ecs::{
 };
 
 use naia_server::{
-    shared::SocketConfig, transport::Socket, RoomKey, RoomMut, RoomRef, Server as NaiaServer,
-    TickBufferMessages, UserKey, UserMut, UserRef, UserScopeMut,
+    shared::{BandwidthBreakdown, NetworkStats, SocketConfig},
+    transport::Socket,
+    NaiaServerError, RoomKey, RoomMut, RoomRef, Server as NaiaServer, TickBufferMessages, UserKey,
+    UserMut, UserRef, UserScopeMut,
 };
 
 use naia_bevy_shared::{
-    Channel, EntityAndGlobalEntityConverter, EntityDoesNotExistError, GlobalEntity, Message, Tick,
+    Channel, EntityAndGlobalEntityConverter, EntityDoesNotExistError, GlobalEntity, Message,
+    Replicate, Tick,
 };
 
 // Server
@@ -48,13 +51,39 @@ impl<'w> Server<'w> {
     }
 
     //// Messages ////
-    pub fn send_message<C: Channel, M: Message>(&mut self, user_key: &UserKey, message: &M) {
+    pub fn send_message<C: Channel, M: Message>(
+        &mut self,
+        user_key: &UserKey,
+        message: &M,
+    ) -> Result<(), NaiaServerError> {
         self.server.send_message::<C, M>(user_key, message)
     }
 
     /// Sends a message to all connected users using a given channel
-    pub fn broadcast_message<C: Channel, M: Message>(&mut self, message: &M) {
-        self.server.broadcast_message::<C, M>(message);
+    pub fn broadcast_message<C: Channel, M: Message>(
+        &mut self,
+        message: &M,
+    ) -> Result<(), NaiaServerError> {
+        self.server.broadcast_message::<C, M>(message)
+    }
+
+    pub fn send_tick_buffer_message<C: Channel, M: Message>(
+        &mut self,
+        user_key: &UserKey,
+        tick: &Tick,
+        message: &M,
+    ) -> Result<(), NaiaServerError> {
+        self.server
+            .send_tick_buffer_message::<C, M>(user_key, tick, message)
+    }
+
+    pub fn broadcast_tick_buffer_message<C: Channel, M: Message>(
+        &mut self,
+        tick: &Tick,
+        message: &M,
+    ) -> Result<(), NaiaServerError> {
+        self.server
+            .broadcast_tick_buffer_message::<C, M>(tick, message)
     }
 
     pub fn receive_tick_buffer_messages(&mut self, tick: &Tick) -> TickBufferMessages {
@@ -93,6 +122,22 @@ impl<'w> Server<'w> {
         self.server.rtt(user_key)
     }
 
+    pub fn network_stats(&self, user_key: &UserKey) -> Option<NetworkStats> {
+        self.server.network_stats(user_key)
+    }
+
+    pub fn bandwidth_breakdown(&mut self, user_key: &UserKey) -> Option<BandwidthBreakdown> {
+        self.server.bandwidth_breakdown(user_key)
+    }
+
+    pub fn total_network_stats(&self) -> NetworkStats {
+        self.server.total_network_stats()
+    }
+
+    pub fn prometheus_metrics(&self) -> String {
+        self.server.prometheus_metrics()
+    }
+
     pub fn user_scope(&mut self, user_key: &UserKey) -> UserScopeMut<Entity> {
         self.server.user_scope(user_key)
     }
@@ -133,6 +178,18 @@ impl<'w> Server<'w> {
         self.server.average_tick_duration()
     }
 
+    pub fn advance_tick(&mut self) -> Result<Tick, NaiaServerError> {
+        self.server.advance_tick()
+    }
+
+    pub fn tick_interval(&self) -> Duration {
+        self.server.tick_interval()
+    }
+
+    pub fn set_tick_interval(&mut self, tick_interval: Duration) {
+        self.server.set_tick_interval(tick_interval);
+    }
+
     // Entity Replication
 
     pub fn enable_replication(&mut self, entity: &Entity) {
@@ -142,6 +199,52 @@ impl<'w> Server<'w> {
     pub fn disable_replication(&mut self, entity: &Entity) {
         self.server.disable_replication(entity);
     }
+
+    // Authority Delegation
+
+    pub fn enable_delegation(&mut self, entity: &Entity) -> Result<(), NaiaServerError> {
+        self.server.enable_delegation(entity)
+    }
+
+    pub fn disable_delegation(&mut self, entity: &Entity) {
+        self.server.disable_delegation(entity);
+    }
+
+    pub fn entity_is_delegated(&self, entity: &Entity) -> bool {
+        self.server.entity_is_delegated(entity)
+    }
+
+    pub fn entity_authority(&self, entity: &Entity) -> Option<UserKey> {
+        self.server.entity_authority(entity)
+    }
+
+    pub fn grant_authority(
+        &mut self,
+        entity: &Entity,
+        user_key: &UserKey,
+    ) -> Result<(), NaiaServerError> {
+        self.server.grant_authority(entity, user_key)
+    }
+
+    pub fn deny_authority(&mut self, entity: &Entity, user_key: &UserKey) {
+        self.server.deny_authority(entity, user_key);
+    }
+
+    pub fn revoke_authority(&mut self, entity: &Entity) {
+        self.server.revoke_authority(entity);
+    }
+
+    // Resources
+
+    pub fn has_resource<R: Replicate>(&self) -> bool {
+        self.server.has_resource::<R>()
+    }
+
+    /// Gets the Entity which holds a replicated Resource. The Resource itself is
+    /// read & mutated as the Bevy Resource of the same type
+    pub fn resource_entity<R: Replicate>(&self) -> Option<Entity> {
+        self.server.resource_entity::<R>()
+    }
 }
 
 impl<'w> EntityAndGlobalEntityConverter<Entity> for Server<'w> {
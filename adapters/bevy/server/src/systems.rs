@@ -13,16 +13,17 @@ use crate::ClientOwned;
 
 mod naia_events {
     pub use naia_server::{
-        ConnectEvent, DespawnEntityEvent, DisconnectEvent, ErrorEvent, InsertComponentEvent,
-        RemoveComponentEvent, SpawnEntityEvent, TickEvent, UpdateComponentEvent,
+        ConnectEvent, DespawnEntityEvent, DisconnectEvent, EntityAuthReleaseEvent,
+        EntityAuthRequestEvent, ErrorEvent, InsertComponentEvent, RemoveComponentEvent,
+        SpawnEntityEvent, TickEvent, UpdateComponentEvent,
     };
 }
 
 mod bevy_events {
     pub use crate::events::{
-        AuthEvents, ConnectEvent, DespawnEntityEvent, DisconnectEvent, ErrorEvent,
-        InsertComponentEvents, MessageEvents, RemoveComponentEvents, SpawnEntityEvent, TickEvent,
-        UpdateComponentEvents,
+        AuthEvents, ConnectEvent, DespawnEntityEvent, DisconnectEvent, EntityAuthReleaseEvent,
+        EntityAuthRequestEvent, ErrorEvent, InsertComponentEvents, MessageEvents,
+        RemoveComponentEvents, SpawnEntityEvent, TickEvent, UpdateComponentEvents,
     };
 }
 
@@ -44,7 +45,8 @@ pub fn before_receive_events(world: &mut World) {
                     let Some(mut component_mut) = world_proxy.component_mut_of_kind(&entity, &component_kind) else {
                         continue;
                     };
-                    server.insert_component_worldless(&entity, DerefMut::deref_mut(&mut component_mut));
+                    // a Component which is already tracked needs no inserting
+                    let _ = server.insert_component_worldless(&entity, DerefMut::deref_mut(&mut component_mut));
                 }
                 HostSyncEvent::Remove(entity, component_kind) => {
                     server.remove_component_worldless(&entity, &component_kind);
@@ -145,6 +147,26 @@ pub fn before_receive_events(world: &mut World) {
                 }
             }
 
+            // Entity Auth Request Event
+            if events.has::<naia_events::EntityAuthRequestEvent>() {
+                let mut auth_request_event_writer = world
+                    .get_resource_mut::<Events<bevy_events::EntityAuthRequestEvent>>()
+                    .unwrap();
+                for (user_key, entity) in events.read::<naia_events::EntityAuthRequestEvent>() {
+                    auth_request_event_writer.send(bevy_events::EntityAuthRequestEvent(user_key, entity));
+                }
+            }
+
+            // Entity Auth Release Event
+            if events.has::<naia_events::EntityAuthReleaseEvent>() {
+                let mut auth_release_event_writer = world
+                    .get_resource_mut::<Events<bevy_events::EntityAuthReleaseEvent>>()
+                    .unwrap();
+                for (user_key, entity) in events.read::<naia_events::EntityAuthReleaseEvent>() {
+                    auth_release_event_writer.send(bevy_events::EntityAuthReleaseEvent(user_key, entity));
+                }
+            }
+
             // Insert Component Event
             if events.has_inserts() {
                 let inserts = events.take_inserts().unwrap();
@@ -53,3 +53,27 @@ impl<'a, R: Replicate> ReplicaDynMutTrait for ComponentDynMut<'a, R> {
         self.0.deref_mut()
     }
 }
+
+// ResourceDynRef
+pub struct ResourceDynRef<'a>(pub &'a dyn Replicate);
+
+impl<'a> ReplicaDynRefTrait for ResourceDynRef<'a> {
+    fn to_dyn_ref(&self) -> &dyn Replicate {
+        self.0
+    }
+}
+
+// ResourceDynMut
+pub struct ResourceDynMut<'a>(pub BevyMut<'a, dyn Replicate>);
+
+impl<'a> ReplicaDynRefTrait for ResourceDynMut<'a> {
+    fn to_dyn_ref(&self) -> &dyn Replicate {
+        self.0.deref()
+    }
+}
+
+impl<'a> ReplicaDynMutTrait for ResourceDynMut<'a> {
+    fn to_dyn_mut(&mut self) -> &mut dyn Replicate {
+        self.0.deref_mut()
+    }
+}
@@ -2,7 +2,7 @@ pub use naia_shared::{
     sequence_greater_than, BitReader, BitWrite, BitWriter, Channel, ChannelDirection, ChannelKind,
     ChannelMode, ComponentFieldUpdate, ComponentKind, ComponentKinds, ComponentUpdate,
     ConstBitLength, DiffMask, EntityAndGlobalEntityConverter, EntityDoesNotExistError,
-    EntityProperty, GlobalEntity, LinkConditionerConfig, LocalEntity,
+    EntityProperty, EntityPropertyVec, GlobalEntity, LinkConditionerConfig, LocalEntity,
     LocalEntityAndGlobalEntityConverter, LocalEntityAndGlobalEntityConverterMut,
     MessageBevy as Message, MessageBuilder, MessageContainer, MessageKind, MessageKinds, Named,
     OwnedBitReader, Property, PropertyMutate, PropertyMutator, Random, ReliableSettings,
@@ -18,6 +18,7 @@ mod components;
 mod plugin;
 mod protocol;
 mod protocol_plugin;
+mod resource_access;
 mod system_set;
 mod world_data;
 mod world_proxy;
@@ -28,6 +29,7 @@ pub use components::HostOwned;
 pub use plugin::SharedPlugin;
 pub use protocol::Protocol;
 pub use protocol_plugin::ProtocolPlugin;
+pub use resource_access::{ResourceAccess, ResourceAccessor};
 pub use system_set::{BeforeReceiveEvents, ReceiveEvents};
 pub use world_data::WorldData;
 pub use world_proxy::{WorldMut, WorldProxy, WorldProxyMut, WorldRef};
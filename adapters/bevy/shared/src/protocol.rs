@@ -1,8 +1,10 @@
 use std::time::Duration;
 
+use bevy_ecs::prelude::Resource;
+
 use naia_shared::{
     Channel, ChannelDirection, ChannelMode, ComponentKind, CompressionConfig,
-    LinkConditionerConfig, Message, Protocol as InnerProtocol, Replicate,
+    LinkConditionerConfig, Message, Protocol as InnerProtocol, ProtocolLockedError, Replicate,
 };
 
 use crate::{ProtocolPlugin, WorldData};
@@ -31,7 +33,10 @@ impl Protocol {
     }
 
     pub fn add_plugin<P: ProtocolPlugin>(&mut self, plugin: P) -> &mut Self {
-        self.check_lock();
+        // changes to a locked Protocol are ignored
+        if self.inner.try_check_lock().is_err() {
+            return self;
+        }
         plugin.build(self);
         self
     }
@@ -89,16 +94,23 @@ impl Protocol {
         self
     }
 
-    pub fn lock(&mut self) {
-        self.inner.lock();
+    /// Registers a Component which is replicated as a singleton Resource. On
+    /// both Server & Client, it is held as the Bevy Resource of the same type,
+    /// rather than as a Component of an Entity.
+    pub fn add_resource<R: Replicate + Resource>(&mut self) -> &mut Self {
+        self.inner.add_resource::<R>();
+        let world_data = self.world_data.as_mut().expect("shouldn't happen");
+        world_data.put_kind::<R>(&ComponentKind::of::<R>());
+        world_data.put_resource_kind::<R>(&ComponentKind::of::<R>());
+        self
     }
 
-    pub fn into(self) -> InnerProtocol {
-        self.inner
+    pub fn try_lock(&mut self) -> Result<(), ProtocolLockedError> {
+        self.inner.try_lock()
     }
 
-    fn check_lock(&self) {
-        self.inner.check_lock();
+    pub fn into(self) -> InnerProtocol {
+        self.inner
     }
 
     pub fn build(&mut self) -> Self {
@@ -0,0 +1,57 @@
+use std::marker::PhantomData;
+
+use bevy_ecs::{
+    prelude::Resource,
+    world::{Mut, World},
+};
+
+use naia_shared::Replicate;
+
+/// Stores a replicated Resource as the Bevy Resource of the same type, rather
+/// than as a Component of the Entity which holds it, keeping it out of Queries
+pub trait ResourceAccess: Send + Sync {
+    fn box_clone(&self) -> Box<dyn ResourceAccess>;
+    fn resource<'w>(&self, world: &'w World) -> Option<&'w dyn Replicate>;
+    fn resource_mut<'w>(&self, world: &'w mut World) -> Option<Mut<'w, dyn Replicate>>;
+    fn insert_resource(&self, world: &mut World, boxed_resource: Box<dyn Replicate>);
+    fn remove_resource(&self, world: &mut World) -> Option<Box<dyn Replicate>>;
+}
+
+pub struct ResourceAccessor<R: Replicate + Resource> {
+    phantom_r: PhantomData<R>,
+}
+
+impl<R: Replicate + Resource> ResourceAccessor<R> {
+    pub fn create() -> Box<dyn ResourceAccess> {
+        Box::new(ResourceAccessor {
+            phantom_r: PhantomData::<R>,
+        })
+    }
+}
+
+impl<R: Replicate + Resource> ResourceAccess for ResourceAccessor<R> {
+    fn box_clone(&self) -> Box<dyn ResourceAccess> {
+        Self::create()
+    }
+
+    fn resource<'w>(&self, world: &'w World) -> Option<&'w dyn Replicate> {
+        let resource: &R = world.get_resource::<R>()?;
+        Some(resource)
+    }
+
+    fn resource_mut<'w>(&self, world: &'w mut World) -> Option<Mut<'w, dyn Replicate>> {
+        let resource_mut = world.get_resource_mut::<R>()?;
+        Some(resource_mut.map_unchanged(|resource| resource as &mut dyn Replicate))
+    }
+
+    fn insert_resource(&self, world: &mut World, boxed_resource: Box<dyn Replicate>) {
+        let boxed_any = boxed_resource.to_boxed_any();
+        let inner: R = *(boxed_any.downcast::<R>().unwrap());
+        world.insert_resource(inner);
+    }
+
+    fn remove_resource(&self, world: &mut World) -> Option<Box<dyn Replicate>> {
+        let resource = world.remove_resource::<R>()?;
+        Some(Box::new(resource))
+    }
+}
@@ -13,12 +13,17 @@ use bevy_ecs::{
 
 use naia_shared::{ComponentKind, Replicate};
 
-use super::component_access::{ComponentAccess, ComponentAccessor};
+use super::{
+    component_access::{ComponentAccess, ComponentAccessor},
+    resource_access::{ResourceAccess, ResourceAccessor},
+};
 
 #[derive(Resource)]
 pub struct WorldData {
     entities: HashSet<Entity>,
     kind_to_accessor_map: HashMap<ComponentKind, Box<dyn Any>>,
+    kind_to_resource_accessor_map: HashMap<ComponentKind, Box<dyn ResourceAccess>>,
+    entity_resource_kinds: HashMap<Entity, HashSet<ComponentKind>>,
 }
 
 unsafe impl Send for WorldData {}
@@ -29,6 +34,8 @@ impl FromWorld for WorldData {
         Self {
             entities: HashSet::default(),
             kind_to_accessor_map: HashMap::default(),
+            kind_to_resource_accessor_map: HashMap::default(),
+            entity_resource_kinds: HashMap::default(),
         }
     }
 }
@@ -38,6 +45,8 @@ impl WorldData {
         Self {
             entities: HashSet::default(),
             kind_to_accessor_map: HashMap::default(),
+            kind_to_resource_accessor_map: HashMap::default(),
+            entity_resource_kinds: HashMap::default(),
         }
     }
 
@@ -68,6 +77,7 @@ impl WorldData {
 
     pub(crate) fn despawn_entity(&mut self, entity: &Entity) {
         self.entities.remove(entity);
+        self.entity_resource_kinds.remove(entity);
     }
 
     // Components
@@ -87,4 +97,70 @@ impl WorldData {
         self.kind_to_accessor_map
             .insert(*component_kind, ComponentAccessor::<R>::create());
     }
+
+    // Resources
+
+    #[allow(clippy::borrowed_box)]
+    pub fn resource_access(
+        &self,
+        component_kind: &ComponentKind,
+    ) -> Option<&Box<dyn ResourceAccess>> {
+        self.kind_to_resource_accessor_map.get(component_kind)
+    }
+
+    pub(crate) fn put_resource_kind<R: Replicate + Resource>(
+        &mut self,
+        component_kind: &ComponentKind,
+    ) {
+        self.kind_to_resource_accessor_map
+            .insert(*component_kind, ResourceAccessor::<R>::create());
+    }
+
+    /// Gets the accessor of a Resource, if the given Entity holds it
+    #[allow(clippy::borrowed_box)]
+    pub(crate) fn entity_resource_access(
+        &self,
+        entity: &Entity,
+        component_kind: &ComponentKind,
+    ) -> Option<&Box<dyn ResourceAccess>> {
+        if !self
+            .entity_resource_kinds
+            .get(entity)
+            .is_some_and(|kinds| kinds.contains(component_kind))
+        {
+            return None;
+        }
+        self.resource_access(component_kind)
+    }
+
+    pub(crate) fn entity_resource_kinds(&self, entity: &Entity) -> Vec<ComponentKind> {
+        self.entity_resource_kinds
+            .get(entity)
+            .map(|kinds| kinds.iter().copied().collect())
+            .unwrap_or_default()
+    }
+
+    pub(crate) fn insert_entity_resource(
+        &mut self,
+        entity: &Entity,
+        component_kind: &ComponentKind,
+    ) {
+        self.entity_resource_kinds
+            .entry(*entity)
+            .or_default()
+            .insert(*component_kind);
+    }
+
+    pub(crate) fn remove_entity_resource(
+        &mut self,
+        entity: &Entity,
+        component_kind: &ComponentKind,
+    ) {
+        if let Some(kinds) = self.entity_resource_kinds.get_mut(entity) {
+            kinds.remove(component_kind);
+            if kinds.is_empty() {
+                self.entity_resource_kinds.remove(entity);
+            }
+        }
+    }
 }
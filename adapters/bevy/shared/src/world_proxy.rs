@@ -11,7 +11,8 @@ use naia_shared::{
 };
 
 use super::{
-    component_ref::{ComponentMut, ComponentRef},
+    component_ref::{ComponentMut, ComponentRef, ResourceDynMut, ResourceDynRef},
+    resource_access::ResourceAccess,
     world_data::WorldData,
 };
 
@@ -154,6 +155,10 @@ impl<'w> WorldMutType<Entity> for WorldMut<'w> {
     }
 
     fn despawn_entity(&mut self, entity: &Entity) {
+        for component_kind in world_data(self.world).entity_resource_kinds(entity) {
+            WorldMutType::<Entity>::remove_component_of_kind(self, entity, &component_kind);
+        }
+
         let mut world_data = world_data_unchecked_mut(self.world);
         world_data.despawn_entity(entity);
 
@@ -176,10 +181,20 @@ impl<'w> WorldMutType<Entity> for WorldMut<'w> {
             kinds.push(component_kind);
         }
 
+        kinds.extend(world_data(self.world).entity_resource_kinds(entity));
+
         kinds
     }
 
     fn component_mut<R: Replicate>(&mut self, entity: &Entity) -> Option<ReplicaMutWrapper<R>> {
+        if let Some(resource_access) =
+            entity_resource_access(self.world, entity, &ComponentKind::of::<R>())
+        {
+            let resource_mut = resource_access.resource_mut(self.world)?;
+            let bevy_mut = resource_mut
+                .map_unchanged(|resource| resource.to_any_mut().downcast_mut::<R>().unwrap());
+            return Some(ReplicaMutWrapper::new(ComponentMut(bevy_mut)));
+        }
         if let Some(bevy_mut) = self.world.get_mut::<R>(*entity) {
             let wrapper = ComponentMut(bevy_mut);
             let component_mut = ReplicaMutWrapper::new(wrapper);
@@ -193,6 +208,10 @@ impl<'w> WorldMutType<Entity> for WorldMut<'w> {
         entity: &Entity,
         component_kind: &ComponentKind,
     ) -> Option<ReplicaDynMutWrapper> {
+        if let Some(resource_access) = entity_resource_access(self.world, entity, component_kind) {
+            let resource_mut = resource_access.resource_mut(self.world)?;
+            return Some(ReplicaDynMutWrapper::new(ResourceDynMut(resource_mut)));
+        }
         let world_data = world_data(&self.world);
         let Some(component_access) = world_data.component_access(component_kind) else {
             return None;
@@ -210,7 +229,11 @@ impl<'w> WorldMutType<Entity> for WorldMut<'w> {
     ) -> Result<(), SerdeErr> {
         self.world
             .resource_scope(|world: &mut World, data: Mut<WorldData>| {
-                if let Some(accessor) = data.component_access(component_kind) {
+                if let Some(accessor) = data.entity_resource_access(entity, component_kind) {
+                    if let Some(mut resource) = accessor.resource_mut(world) {
+                        let _update_result = resource.read_apply_update(converter, update);
+                    }
+                } else if let Some(accessor) = data.component_access(component_kind) {
                     if let Some(mut component) = accessor.component_mut(world, entity) {
                         let _update_result = component.read_apply_update(converter, update);
                     }
@@ -228,7 +251,11 @@ impl<'w> WorldMutType<Entity> for WorldMut<'w> {
     ) -> Result<(), SerdeErr> {
         self.world
             .resource_scope(|world: &mut World, data: Mut<WorldData>| {
-                if let Some(accessor) = data.component_access(component_kind) {
+                if let Some(accessor) = data.entity_resource_access(entity, component_kind) {
+                    if let Some(mut resource) = accessor.resource_mut(world) {
+                        let _update_result = resource.read_apply_field_update(converter, update);
+                    }
+                } else if let Some(accessor) = data.component_access(component_kind) {
                     if let Some(mut component) = accessor.component_mut(world, entity) {
                         let _update_result = component.read_apply_field_update(converter, update);
                     }
@@ -256,6 +283,10 @@ impl<'w> WorldMutType<Entity> for WorldMut<'w> {
     ) {
         self.world
             .resource_scope(|world: &mut World, data: Mut<WorldData>| {
+                // a Resource is a single value, mirroring it into itself does nothing
+                if data.resource_access(component_kind).is_some() {
+                    return;
+                }
                 if let Some(accessor) = data.component_access(component_kind) {
                     accessor.mirror_components(world, mutable_entity, immutable_entity);
                 }
@@ -263,6 +294,14 @@ impl<'w> WorldMutType<Entity> for WorldMut<'w> {
     }
 
     fn insert_component<R: Replicate>(&mut self, entity: &Entity, component_ref: R) {
+        if world_data(self.world)
+            .resource_access(&ComponentKind::of::<R>())
+            .is_some()
+        {
+            WorldMutType::<Entity>::insert_boxed_component(self, entity, Box::new(component_ref));
+            return;
+        }
+
         // insert into ecs
         self.world.entity_mut(*entity).insert(component_ref);
     }
@@ -270,14 +309,30 @@ impl<'w> WorldMutType<Entity> for WorldMut<'w> {
     fn insert_boxed_component(&mut self, entity: &Entity, boxed_component: Box<dyn Replicate>) {
         let component_kind = boxed_component.kind();
         self.world
-            .resource_scope(|world: &mut World, data: Mut<WorldData>| {
-                if let Some(accessor) = data.component_access(&component_kind) {
+            .resource_scope(|world: &mut World, mut data: Mut<WorldData>| {
+                if let Some(accessor) = data.resource_access(&component_kind) {
+                    accessor.insert_resource(world, boxed_component);
+                    data.insert_entity_resource(entity, &component_kind);
+                } else if let Some(accessor) = data.component_access(&component_kind) {
                     accessor.insert_component(world, entity, boxed_component);
                 }
             });
     }
 
     fn remove_component<R: Replicate>(&mut self, entity: &Entity) -> Option<R> {
+        let component_kind = ComponentKind::of::<R>();
+        if world_data(self.world)
+            .entity_resource_access(entity, &component_kind)
+            .is_some()
+        {
+            let boxed_resource =
+                WorldMutType::<Entity>::remove_component_of_kind(self, entity, &component_kind)?;
+            return boxed_resource
+                .to_boxed_any()
+                .downcast::<R>()
+                .ok()
+                .map(|r| *r);
+        }
         return self.world.entity_mut(*entity).take::<R>();
     }
 
@@ -288,8 +343,11 @@ impl<'w> WorldMutType<Entity> for WorldMut<'w> {
     ) -> Option<Box<dyn Replicate>> {
         let mut output: Option<Box<dyn Replicate>> = None;
         self.world
-            .resource_scope(|world: &mut World, data: Mut<WorldData>| {
-                if let Some(accessor) = data.component_access(component_kind) {
+            .resource_scope(|world: &mut World, mut data: Mut<WorldData>| {
+                if let Some(accessor) = data.entity_resource_access(entity, component_kind) {
+                    output = accessor.remove_resource(world);
+                    data.remove_entity_resource(entity, component_kind);
+                } else if let Some(accessor) = data.component_access(component_kind) {
                     output = accessor.remove_component(world, entity);
                 }
             });
@@ -309,10 +367,13 @@ fn entities(world: &World) -> Vec<Entity> {
 }
 
 fn has_component<R: Replicate>(world: &World, entity: &Entity) -> bool {
-    return world.get::<R>(*entity).is_some();
+    has_component_of_kind(world, entity, &ComponentKind::of::<R>())
 }
 
 fn has_component_of_kind(world: &World, entity: &Entity, component_kind: &ComponentKind) -> bool {
+    if let Some(resource_access) = entity_resource_access(world, entity, component_kind) {
+        return resource_access.resource(world).is_some();
+    }
     return world
         .entity(*entity)
         .contains_type_id(<ComponentKind as Into<TypeId>>::into(*component_kind));
@@ -322,6 +383,12 @@ fn component<'a, R: Replicate>(
     world: &'a World,
     entity: &Entity,
 ) -> Option<ReplicaRefWrapper<'a, R>> {
+    if let Some(resource_access) = entity_resource_access(world, entity, &ComponentKind::of::<R>())
+    {
+        let resource = resource_access.resource(world)?;
+        let resource_ref = resource.to_any().downcast_ref::<R>()?;
+        return Some(ReplicaRefWrapper::new(ComponentRef(resource_ref)));
+    }
     if let Some(bevy_ref) = world.get::<R>(*entity) {
         let wrapper = ComponentRef(bevy_ref);
         let component_ref = ReplicaRefWrapper::new(wrapper);
@@ -336,12 +403,27 @@ fn component_of_kind<'a>(
     component_kind: &ComponentKind,
 ) -> Option<ReplicaDynRefWrapper<'a>> {
     let world_data = world_data(world);
+    if let Some(resource_access) = world_data.entity_resource_access(entity, component_kind) {
+        let resource = resource_access.resource(world)?;
+        return Some(ReplicaDynRefWrapper::new(ResourceDynRef(resource)));
+    }
     if let Some(component_access) = world_data.component_access(component_kind) {
         return component_access.component(world, entity);
     }
     None
 }
 
+/// Gets the accessor of a Resource, if the given Entity holds it
+fn entity_resource_access(
+    world: &World,
+    entity: &Entity,
+    component_kind: &ComponentKind,
+) -> Option<Box<dyn ResourceAccess>> {
+    world_data(world)
+        .entity_resource_access(entity, component_kind)
+        .map(|resource_access| resource_access.box_clone())
+}
+
 fn world_data(world: &World) -> &WorldData {
     return world
         .get_resource::<WorldData>()
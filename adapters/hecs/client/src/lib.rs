@@ -1,5 +1,7 @@
 pub use naia_client::{
     transport, Client, ClientConfig, ClientTickEvent, ConnectEvent, DespawnEntityEvent,
-    DisconnectEvent, ErrorEvent, InsertComponentEvent, RemoveComponentEvent, SpawnEntityEvent,
+    DisconnectEvent, EntityAuthDeniedEvent, EntityAuthGrantedEvent, EntityAuthRevokedEvent,
+    EntityAuthStatus, ErrorEvent, InsertComponentEvent, NaiaClientError, RemoveComponentEvent,
+    SpawnEntityEvent,
 };
 pub use naia_hecs_shared::{Protocol, WorldWrapper};
@@ -4,8 +4,8 @@ use hecs::World;
 
 use naia_shared::{
     Channel, ChannelDirection, ChannelMode, ComponentKind, CompressionConfig,
-    LinkConditionerConfig, Message, Protocol as InnerProtocol, ProtocolPlugin, Replicate,
-    SocketConfig,
+    LinkConditionerConfig, Message, Protocol as InnerProtocol, ProtocolLockedError, ProtocolPlugin,
+    Replicate, SocketConfig,
 };
 
 use crate::{WorldData, WorldWrapper};
@@ -96,8 +96,8 @@ impl Protocol {
         self
     }
 
-    pub fn lock(&mut self) {
-        self.inner.lock();
+    pub fn try_lock(&mut self) -> Result<(), ProtocolLockedError> {
+        self.inner.try_lock()
     }
 
     pub fn build(&mut self) -> Self {
@@ -8,21 +8,30 @@ use bevy_ecs::prelude::Resource;
 pub use naia_shared::{
     BitReader, BitWriter, Channel, ChannelKind, ChannelKinds, ComponentKind, ConnectionConfig,
     EntityAndGlobalEntityConverter, EntityConverter, EntityConverterMut, EntityDoesNotExistError,
-    EntityRef, FakeEntityConverter, GameInstant, GlobalEntity, Instant, Message, MessageContainer,
+    EntityRef, FakeEntityConverter, GameInstant, GlobalEntity, Message, MessageContainer,
     PacketType, PingIndex, Protocol, Replicate, Serde, SocketConfig, StandardHeader, Tick, Timer,
     Timestamp, WorldMutType, WorldRefType,
 };
 
+use naia_shared::{
+    BandwidthBreakdown, EntityAuthAction, EntityAuthChannel, EntityAuthMessage,
+    EntityAuthUpdateMessage, NetworkStats, PredictionKey, PrometheusWriter, ReplicaRefWrapper,
+    ReversedEntityConverter, UnsignedVariableInteger,
+};
+
 use crate::{
     connection::{
         base_time_manager::BaseTimeManager,
         connection::Connection,
         handshake_manager::{HandshakeManager, HandshakeResult},
         io::Io,
+        network_clock::NetworkClock,
+        tick_buffer_messages::TickBufferMessages,
     },
     transport::Socket,
     world::{
-        entity_mut::EntityMut, entity_owner::EntityOwner, global_world_manager::GlobalWorldManager,
+        entity_auth_status::EntityAuthStatus, entity_mut::EntityMut, entity_owner::EntityOwner,
+        global_world_manager::GlobalWorldManager,
     },
 };
 
@@ -50,12 +59,16 @@ impl<E: Copy + Eq + Hash + Send + Sync> Client<E> {
     /// Create a new Client
     pub fn new<P: Into<Protocol>>(client_config: ClientConfig, protocol: P) -> Self {
         let mut protocol: Protocol = protocol.into();
-        protocol.lock();
+        // a Protocol which is already locked can't be changed either way
+        let _ = protocol.try_lock();
+
+        let clock = &client_config.clock;
 
         let handshake_manager = HandshakeManager::new(
             client_config.send_handshake_interval,
             client_config.ping_interval,
             client_config.handshake_pings,
+            clock,
         );
 
         let compression_config = protocol.compression.clone();
@@ -68,12 +81,13 @@ impl<E: Copy + Eq + Hash + Send + Sync> Client<E> {
             io: Io::new(
                 &client_config.connection.bandwidth_measure_duration,
                 &compression_config,
+                clock,
             ),
             server_connection: None,
             handshake_manager,
             manual_disconnect: false,
             // World
-            global_world_manager: GlobalWorldManager::new(),
+            global_world_manager: GlobalWorldManager::new(clock),
             // Events
             incoming_events: Events::new(),
         }
@@ -88,14 +102,16 @@ impl<E: Copy + Eq + Hash + Send + Sync> Client<E> {
             ));
     }
 
-    /// Connect to the given server address
-    pub fn connect<S: Into<Box<dyn Socket>>>(&mut self, socket: S) {
+    /// Connect to the given server address, or return an Error if the Client
+    /// has already initiated a connection
+    pub fn connect<S: Into<Box<dyn Socket>>>(&mut self, socket: S) -> Result<(), NaiaClientError> {
         if !self.is_disconnected() {
-            panic!("Client has already initiated a connection, cannot initiate a new one. TIP: Check client.is_disconnected() before calling client.connect()");
+            return Err(NaiaClientError::AlreadyConnected);
         }
         let boxed_socket: Box<dyn Socket> = socket.into();
-        let (packet_sender, packet_receiver) = boxed_socket.connect();
+        let (packet_sender, packet_receiver) = boxed_socket.connect(&self.client_config.clock);
         self.io.load(packet_sender, packet_receiver);
+        Ok(())
     }
 
     /// Returns whether or not the client is disconnected
@@ -113,10 +129,11 @@ impl<E: Copy + Eq + Hash + Send + Sync> Client<E> {
         self.server_connection.is_some()
     }
 
-    /// Disconnect from Server
-    pub fn disconnect(&mut self) {
+    /// Disconnect from Server, or return an Error if the Client is not
+    /// connected
+    pub fn disconnect(&mut self) -> Result<(), NaiaClientError> {
         if !self.is_connected() {
-            panic!("Trying to disconnect Client which is not connected yet!")
+            return Err(NaiaClientError::NotConnected);
         }
 
         for _ in 0..10 {
@@ -128,6 +145,7 @@ impl<E: Copy + Eq + Hash + Send + Sync> Client<E> {
         }
 
         self.manual_disconnect = true;
+        Ok(())
     }
 
     /// Returns socket config
@@ -156,22 +174,67 @@ impl<E: Copy + Eq + Hash + Send + Sync> Client<E> {
                 connection.time_manager.collect_ticks();
 
             if let Some((prev_receiving_tick, current_receiving_tick)) = receiving_tick_happened {
-                // read packets on tick boundary, de-jittering
-                if connection
-                    .read_buffered_packets(&self.protocol, &mut self.global_world_manager)
-                    .is_err()
-                {
-                    // TODO: Except for cosmic radiation .. Server should never send a malformed packet .. handle this
-                    warn!("Error reading from buffered packet!");
+                // read packets on tick boundary, de-jittering. Each is processed
+                // before the next is read, so Entities it spawns can be updated
+                // by the next
+                loop {
+                    let packet_read = match connection
+                        .read_buffered_packet(&self.protocol, &mut self.global_world_manager)
+                    {
+                        Ok(packet_read) => packet_read,
+                        Err(_) => {
+                            warn!("Error reading from buffered packet!");
+                            self.incoming_events
+                                .push_error(NaiaClientError::MalformedPacket);
+                            false
+                        }
+                    };
+
+                    // receive packets, process into events
+                    connection.process_packets(
+                        &mut self.global_world_manager,
+                        &self.protocol.component_kinds,
+                        &mut world,
+                        &mut self.incoming_events,
+                    );
+
+                    if !packet_read {
+                        break;
+                    }
                 }
 
-                // receive packets, process into events
-                connection.process_packets(
-                    &mut self.global_world_manager,
-                    &self.protocol.component_kinds,
-                    &mut world,
-                    &mut self.incoming_events,
-                );
+                // process Entity authority
+                for (entity, action) in connection.take_auth_actions() {
+                    if let Err(error) = Self::receive_auth_action(
+                        connection,
+                        &self.protocol,
+                        &mut self.global_world_manager,
+                        &mut world,
+                        &mut self.incoming_events,
+                        &entity,
+                        action,
+                    ) {
+                        warn!("{}", error);
+                    }
+                }
+
+                // resolve predicted spawns
+                for (prediction_key, entity) in
+                    self.global_world_manager.take_confirmed_predictions()
+                {
+                    self.incoming_events
+                        .push_prediction_confirm(prediction_key, entity);
+                }
+                for (prediction_key, entity) in self
+                    .global_world_manager
+                    .take_expired_predictions(&self.client_config.predicted_spawn_timeout)
+                {
+                    if world.has_entity(&entity) {
+                        world.despawn_entity(&entity);
+                    }
+                    self.incoming_events
+                        .push_prediction_rollback(prediction_key, entity);
+                }
 
                 let mut index_tick = prev_receiving_tick.wrapping_add(1);
                 loop {
@@ -186,7 +249,17 @@ impl<E: Copy + Eq + Hash + Send + Sync> Client<E> {
 
             if let Some((prev_sending_tick, current_sending_tick)) = sending_tick_happened {
                 // send outgoing packets
-                let now = Instant::now();
+                let now = self.client_config.clock.now();
+
+                for entity in self.global_world_manager.granted_entities() {
+                    Self::send_auth_update(
+                        connection,
+                        &self.protocol,
+                        &self.global_world_manager,
+                        &world,
+                        &entity,
+                    );
+                }
 
                 connection.send_outgoing_packets(
                     &self.protocol,
@@ -217,22 +290,41 @@ impl<E: Copy + Eq + Hash + Send + Sync> Client<E> {
 
     // Messages
 
-    /// Queues up an Message to be sent to the Server
-    pub fn send_message<C: Channel, M: Message>(&mut self, message: &M) {
+    /// Queues up an Message to be sent to the Server, or returns an Error if
+    /// the Channel can not be used for it. Messages sent before connecting
+    /// are dropped.
+    pub fn send_message<C: Channel, M: Message>(
+        &mut self,
+        message: &M,
+    ) -> Result<(), NaiaClientError> {
+        let channel_kind = ChannelKind::of::<C>();
+        self.check_send_channel(&channel_kind, false)?;
         let cloned_message = M::clone_box(message);
-        self.send_message_inner(&ChannelKind::of::<C>(), cloned_message);
+        self.send_message_inner(&channel_kind, cloned_message);
+        Ok(())
     }
 
-    fn send_message_inner(&mut self, channel_kind: &ChannelKind, message_box: Box<dyn Message>) {
+    /// Checks that Messages can be sent to the Server on the Channel, with or
+    /// without a Tick
+    fn check_send_channel(
+        &self,
+        channel_kind: &ChannelKind,
+        tick_buffered: bool,
+    ) -> Result<(), NaiaClientError> {
         let channel_settings = self.protocol.channel_kinds.channel(channel_kind);
+
         if !channel_settings.can_send_to_server() {
-            panic!("Cannot send message to Server on this Channel");
+            return Err(NaiaClientError::ChannelNotSendable);
         }
 
-        if channel_settings.tick_buffered() {
-            panic!("Cannot call `Client.send_message()` on a Tick Buffered Channel, use `Client.send_tick_buffered_message()` instead");
+        if channel_settings.tick_buffered() != tick_buffered {
+            return Err(NaiaClientError::ChannelTickBufferMismatch);
         }
 
+        Ok(())
+    }
+
+    fn send_message_inner(&mut self, channel_kind: &ChannelKind, message_box: Box<dyn Message>) {
         if let Some(connection) = &mut self.server_connection {
             let mut converter = EntityConverterMut::new(
                 &self.global_world_manager,
@@ -248,9 +340,18 @@ impl<E: Copy + Eq + Hash + Send + Sync> Client<E> {
         }
     }
 
-    pub fn send_tick_buffer_message<C: Channel, M: Message>(&mut self, tick: &Tick, message: &M) {
+    /// Queues up a Message to be sent to the Server on a Tick Buffered
+    /// Channel, or returns an Error if the Channel can not be used for it
+    pub fn send_tick_buffer_message<C: Channel, M: Message>(
+        &mut self,
+        tick: &Tick,
+        message: &M,
+    ) -> Result<(), NaiaClientError> {
+        let channel_kind = ChannelKind::of::<C>();
+        self.check_send_channel(&channel_kind, true)?;
         let cloned_message = M::clone_box(message);
-        self.send_tick_buffer_message_inner(tick, &ChannelKind::of::<C>(), cloned_message);
+        self.send_tick_buffer_message_inner(tick, &channel_kind, cloned_message);
+        Ok(())
     }
 
     fn send_tick_buffer_message_inner(
@@ -259,16 +360,6 @@ impl<E: Copy + Eq + Hash + Send + Sync> Client<E> {
         channel_kind: &ChannelKind,
         message_box: Box<dyn Message>,
     ) {
-        let channel_settings = self.protocol.channel_kinds.channel(channel_kind);
-
-        if !channel_settings.can_send_to_server() {
-            panic!("Cannot send message to Server on this Channel");
-        }
-
-        if !channel_settings.tick_buffered() {
-            panic!("Can only use `Client.send_tick_buffer_message()` on a Channel that is configured for it.");
-        }
-
         if let Some(connection) = self.server_connection.as_mut() {
             let mut converter = EntityConverterMut::new(
                 &self.global_world_manager,
@@ -281,6 +372,17 @@ impl<E: Copy + Eq + Hash + Send + Sync> Client<E> {
         }
     }
 
+    /// Takes the Messages the Server sent on Tick Buffered Channels for the
+    /// given Server Tick. Call this for each `ServerTickEvent`, Messages for
+    /// earlier Ticks which were not taken are dropped.
+    pub fn receive_tick_buffer_messages(&mut self, tick: &Tick) -> TickBufferMessages {
+        let mut tick_buffer_messages = TickBufferMessages::new();
+        if let Some(connection) = self.server_connection.as_mut() {
+            connection.tick_buffer_messages(tick, &mut tick_buffer_messages);
+        }
+        tick_buffer_messages
+    }
+
     // Entities
 
     pub fn enable_replication(&mut self, entity: &E) {
@@ -304,6 +406,23 @@ impl<E: Copy + Eq + Hash + Send + Sync> Client<E> {
         EntityMut::new(self, world, &entity)
     }
 
+    /// Spawns a local Entity in prediction of one the Server is going to
+    /// spawn, and returns it with the key that identifies the prediction. Send
+    /// the key to the Server, which confirms the spawn with it, and the
+    /// Server's Entity then replicates onto this one. A
+    /// `PredictedSpawnConfirmedEvent` or `PredictedSpawnRolledBackEvent` is
+    /// emitted once the prediction resolves, the latter after despawning the
+    /// Entity if it is not confirmed within the configured timeout.
+    pub fn spawn_predicted_entity<W: WorldMutType<E>>(
+        &mut self,
+        mut world: W,
+    ) -> (E, PredictionKey) {
+        let entity = world.spawn_entity();
+        let prediction_key = self.global_world_manager.add_predicted_spawn(&entity);
+
+        (entity, prediction_key)
+    }
+
     /// Creates a new Entity with a specific id
     pub fn spawn_entity_at(&mut self, entity: &E) {
         self.check_client_authoritative_allowed();
@@ -326,26 +445,57 @@ impl<E: Copy + Eq + Hash + Send + Sync> Client<E> {
     /// given Entity.
     /// Panics if the Entity does not exist.
     pub fn entity<W: WorldRefType<E>>(&self, world: W, entity: &E) -> EntityRef<E, W> {
+        match self.try_entity(world, entity) {
+            Ok(entity_ref) => entity_ref,
+            Err(error) => panic!("{}", error),
+        }
+    }
+
+    /// Retrieves an EntityRef that exposes read-only operations for the
+    /// given Entity, or an Error if the Entity does not exist
+    pub fn try_entity<W: WorldRefType<E>>(
+        &self,
+        world: W,
+        entity: &E,
+    ) -> Result<EntityRef<E, W>, NaiaClientError> {
         if world.has_entity(entity) {
-            return EntityRef::new(world, entity);
+            return Ok(EntityRef::new(world, entity));
         }
-        panic!("No Entity exists for given Key!");
+        Err(NaiaClientError::EntityNotFound)
     }
 
     /// Retrieves an EntityMut that exposes read and write operations for the
     /// Entity.
     /// Panics if the Entity does not exist.
     pub fn entity_mut<W: WorldMutType<E>>(&mut self, world: W, entity: &E) -> EntityMut<E, W> {
-        self.check_client_authoritative_allowed();
+        match self.try_entity_mut(world, entity) {
+            Ok(entity_mut) => entity_mut,
+            Err(error) => panic!("{}", error),
+        }
+    }
+
+    /// Retrieves an EntityMut that exposes read and write operations for the
+    /// Entity, or an Error if the Entity does not exist or Client
+    /// Authoritative Entities are not enabled
+    pub fn try_entity_mut<W: WorldMutType<E>>(
+        &mut self,
+        world: W,
+        entity: &E,
+    ) -> Result<EntityMut<E, W>, NaiaClientError> {
+        if !self.protocol.client_authoritative_entities {
+            return Err(NaiaClientError::ClientAuthorityDisabled);
+        }
         if world.has_entity(entity) {
-            return EntityMut::new(self, world, entity);
+            return Ok(EntityMut::new(self, world, entity));
         }
-        panic!("No Entity exists for given Key!");
+        Err(NaiaClientError::EntityNotFound)
     }
 
-    /// Return a list of all Entities
+    /// Return a list of all Entities, leaving out those which hold Resources
     pub fn entities<W: WorldRefType<E>>(&self, world: &W) -> Vec<E> {
-        world.entities()
+        let mut entities = world.entities();
+        entities.retain(|entity| !self.global_world_manager.entity_is_resource(entity));
+        entities
     }
 
     pub fn entity_owner(&self, entity: &E) -> EntityOwner {
@@ -355,6 +505,126 @@ impl<E: Copy + Eq + Hash + Send + Sync> Client<E> {
         return EntityOwner::Local;
     }
 
+    // Hierarchy
+
+    /// Gets the parent of a Server Entity, if it has one
+    pub fn entity_parent(&self, entity: &E) -> Option<E> {
+        self.global_world_manager.entity_parent(entity)
+    }
+
+    /// Gets the children of a Server Entity which are in scope
+    pub fn entity_children(&self, entity: &E) -> Vec<E> {
+        self.global_world_manager.entity_children(entity)
+    }
+
+    /// Returns whether a Server Entity is dormant, i.e. has left the Client's
+    /// scope but is kept with its last known state
+    pub fn entity_is_dormant(&self, entity: &E) -> bool {
+        self.global_world_manager.entity_is_dormant(entity)
+    }
+
+    // Resources
+
+    pub fn has_resource<R: Replicate>(&self) -> bool {
+        self.resource_entity::<R>().is_some()
+    }
+
+    /// Gets the Entity which holds a Resource replicated from the Server
+    pub fn resource_entity<R: Replicate>(&self) -> Option<E> {
+        self.global_world_manager
+            .resource_entity(&ComponentKind::of::<R>())
+    }
+
+    /// Reads a Resource replicated from the Server
+    pub fn resource<'w, W: WorldRefType<E>, R: Replicate>(
+        &self,
+        world: &'w W,
+    ) -> Option<ReplicaRefWrapper<'w, R>> {
+        let entity = self.resource_entity::<R>()?;
+        world.component::<R>(&entity)
+    }
+
+    // Authority Delegation
+
+    /// Returns the status of the Client's authority over the given Entity, or
+    /// None if the Entity is not delegated
+    pub fn entity_auth_status(&self, entity: &E) -> Option<EntityAuthStatus> {
+        self.global_world_manager.entity_auth_status(entity)
+    }
+
+    /// Requests authority over a delegated Entity from the Server. The
+    /// response arrives as an `EntityAuthGrantedEvent` or
+    /// `EntityAuthDeniedEvent`.
+    /// Returns an Error if the Entity is not delegated, or the Client is not
+    /// connected.
+    pub fn request_authority(&mut self, entity: &E) -> Result<(), NaiaClientError> {
+        match self.global_world_manager.entity_auth_status(entity) {
+            Some(EntityAuthStatus::Available) | Some(EntityAuthStatus::Denied) => {}
+            Some(EntityAuthStatus::Requested) | Some(EntityAuthStatus::Granted) => return Ok(()),
+            None => return Err(NaiaClientError::EntityNotDelegated),
+        }
+        let Some(connection) = self.server_connection.as_mut() else {
+            return Err(NaiaClientError::NotConnected);
+        };
+
+        self.global_world_manager
+            .entity_set_auth_status(entity, Some(EntityAuthStatus::Requested))?;
+        Self::send_auth_message(
+            connection,
+            &self.protocol,
+            &self.global_world_manager,
+            entity,
+            EntityAuthAction::Request,
+        );
+        Ok(())
+    }
+
+    /// Gives authority over a delegated Entity back to the Server, or
+    /// withdraws a pending request for it
+    pub fn release_authority<W: WorldMutType<E>>(&mut self, mut world: W, entity: &E) {
+        let auth_status = self.global_world_manager.entity_auth_status(entity);
+        if auth_status != Some(EntityAuthStatus::Granted)
+            && auth_status != Some(EntityAuthStatus::Requested)
+        {
+            return;
+        }
+        let Some(connection) = self.server_connection.as_mut() else {
+            return;
+        };
+
+        if auth_status == Some(EntityAuthStatus::Granted) {
+            // make sure the Server has the latest state before giving it back
+            Self::send_auth_update(
+                connection,
+                &self.protocol,
+                &self.global_world_manager,
+                &world,
+                entity,
+            );
+            Self::return_authority(
+                connection,
+                &self.protocol,
+                &mut self.global_world_manager,
+                &mut world,
+                entity,
+            );
+        }
+
+        if self
+            .global_world_manager
+            .entity_set_auth_status(entity, Some(EntityAuthStatus::Available))
+            .is_ok()
+        {
+            Self::send_auth_message(
+                connection,
+                &self.protocol,
+                &self.global_world_manager,
+                entity,
+                EntityAuthAction::Release,
+            );
+        }
+    }
+
     // Connection
 
     /// Get the address currently associated with the Server
@@ -362,6 +632,30 @@ impl<E: Copy + Eq + Hash + Send + Sync> Client<E> {
         self.io.server_addr()
     }
 
+    /// Gets the NetworkStats of the connection to the Server, if connected
+    pub fn network_stats(&self) -> Option<NetworkStats> {
+        let connection = self.server_connection.as_ref()?;
+        Some(connection.network_stats(&self.protocol, &self.io))
+    }
+
+    /// Gets the bandwidth sent to the Server over the last
+    /// `bandwidth_measure_duration`, by Component, Message & Channel. Returns
+    /// None if not connected, or bandwidth is not being measured.
+    pub fn bandwidth_breakdown(&mut self) -> Option<BandwidthBreakdown> {
+        let connection = self.server_connection.as_mut()?;
+        connection.base.bandwidth_breakdown(&self.protocol)
+    }
+
+    /// Writes the NetworkStats of the connection to the Server in the
+    /// Prometheus text format, i.e. to be handed to a `PrometheusExporter`
+    pub fn prometheus_metrics(&self) -> String {
+        let mut writer = PrometheusWriter::new();
+        if let Some(stats) = self.network_stats() {
+            writer.add_stats("naia_client", &[], &stats);
+        }
+        writer.to_text()
+    }
+
     /// Gets the average Round Trip Time measured to the Server
     pub fn rtt(&self) -> f32 {
         self.server_connection
@@ -396,6 +690,16 @@ impl<E: Copy + Eq + Hash + Send + Sync> Client<E> {
         return None;
     }
 
+    /// Gets the Client's current estimate of the Server's game time & Tick,
+    /// for timing which must line up with the Server, such as countdowns.
+    /// Unlike `server_tick()`, this is not delayed for interpolation.
+    pub fn network_clock(&self) -> Option<NetworkClock> {
+        if let Some(connection) = &self.server_connection {
+            return Some(connection.time_manager.network_clock());
+        }
+        return None;
+    }
+
     // Interpolation
 
     /// Gets the interpolation tween amount for the current frame, for use by entities on the Client Tick (i.e. predicted)
@@ -473,7 +777,12 @@ impl<E: Copy + Eq + Hash + Send + Sync> Client<E> {
         } else {
             // Entity does not have this Component type yet, initialize Component
 
-            self.insert_component_worldless(entity, &mut component);
+            if self
+                .insert_component_worldless(entity, &mut component)
+                .is_err()
+            {
+                return;
+            }
 
             // actually insert component into world
             world.insert_component(entity, component);
@@ -481,9 +790,18 @@ impl<E: Copy + Eq + Hash + Send + Sync> Client<E> {
     }
 
     // This intended to be used by adapter crates, do not use this as it will not update the world
-    pub fn insert_component_worldless(&mut self, entity: &E, component: &mut dyn Replicate) {
+    pub fn insert_component_worldless(
+        &mut self,
+        entity: &E,
+        component: &mut dyn Replicate,
+    ) -> Result<(), NaiaClientError> {
         let component_kind = component.kind();
 
+        // update in world manager
+        self.global_world_manager
+            .host_insert_component(entity, component)
+            .map_err(|_| NaiaClientError::ComponentAlreadyInserted)?;
+
         // insert component into server connection
         if let Some(connection) = &mut self.server_connection {
             // insert component into server connection
@@ -495,9 +813,7 @@ impl<E: Copy + Eq + Hash + Send + Sync> Client<E> {
             }
         }
 
-        // update in world manager
-        self.global_world_manager
-            .host_insert_component(entity, component);
+        Ok(())
     }
 
     /// Removes a Component from an Entity
@@ -532,6 +848,248 @@ impl<E: Copy + Eq + Hash + Send + Sync> Client<E> {
 
     // Private methods
 
+    fn receive_auth_action<W: WorldMutType<E>>(
+        connection: &mut Connection<E>,
+        protocol: &Protocol,
+        global_world_manager: &mut GlobalWorldManager<E>,
+        world: &mut W,
+        incoming_events: &mut Events<E>,
+        entity: &E,
+        action: EntityAuthAction,
+    ) -> Result<(), NaiaClientError> {
+        let auth_status = global_world_manager.entity_auth_status(entity);
+        match action {
+            EntityAuthAction::EnableDelegation => {
+                if auth_status.is_none() {
+                    global_world_manager
+                        .entity_set_auth_status(entity, Some(EntityAuthStatus::Available))?;
+                }
+            }
+            EntityAuthAction::DisableDelegation => {
+                if auth_status == Some(EntityAuthStatus::Granted) {
+                    Self::return_authority(
+                        connection,
+                        protocol,
+                        global_world_manager,
+                        world,
+                        entity,
+                    );
+                    incoming_events.push_auth_revoke(*entity);
+                }
+                global_world_manager.entity_set_auth_status(entity, None)?;
+            }
+            EntityAuthAction::Grant => match auth_status {
+                Some(EntityAuthStatus::Granted) => {}
+                Some(EntityAuthStatus::Requested) => {
+                    Self::take_authority(global_world_manager, world, entity);
+                    global_world_manager
+                        .entity_set_auth_status(entity, Some(EntityAuthStatus::Granted))?;
+                    incoming_events.push_auth_grant(*entity);
+                }
+                _ => {
+                    // the request was withdrawn before the Server responded
+                    Self::send_auth_message(
+                        connection,
+                        protocol,
+                        global_world_manager,
+                        entity,
+                        EntityAuthAction::Release,
+                    );
+                }
+            },
+            EntityAuthAction::Deny => {
+                if auth_status == Some(EntityAuthStatus::Requested) {
+                    global_world_manager
+                        .entity_set_auth_status(entity, Some(EntityAuthStatus::Denied))?;
+                    incoming_events.push_auth_deny(*entity);
+                }
+            }
+            EntityAuthAction::Revoke => {
+                if auth_status == Some(EntityAuthStatus::Granted) {
+                    Self::return_authority(
+                        connection,
+                        protocol,
+                        global_world_manager,
+                        world,
+                        entity,
+                    );
+                    global_world_manager
+                        .entity_set_auth_status(entity, Some(EntityAuthStatus::Available))?;
+                    incoming_events.push_auth_revoke(*entity);
+                }
+            }
+            EntityAuthAction::Despawn => {
+                // a Server validator refused the state of an Entity this Client owns
+                if global_world_manager.entity_owner(entity) != Some(EntityOwner::Client)
+                    || !world.has_entity(entity)
+                {
+                    return Ok(());
+                }
+                world.despawn_entity(entity);
+                connection.base.host_world_manager.despawn_entity(entity);
+                global_world_manager.host_despawn_entity(entity);
+                incoming_events
+                    .push_despawn(connection.time_manager.client_receiving_tick, *entity);
+            }
+            EntityAuthAction::Request | EntityAuthAction::Release => {
+                warn!("Client Error: received unexpected Entity authority action from Server");
+            }
+        }
+        Ok(())
+    }
+
+    /// Converts the Entity's replicated Components into locally-owned ones, so
+    /// that changes to them can be tracked and sent to the Server
+    fn take_authority<W: WorldMutType<E>>(
+        global_world_manager: &mut GlobalWorldManager<E>,
+        world: &mut W,
+        entity: &E,
+    ) {
+        for component_kind in world.component_kinds(entity) {
+            let Some(component) = world.remove_component_of_kind(entity, &component_kind) else {
+                continue;
+            };
+            let mut host_component = component.copy_to_box();
+            if global_world_manager
+                .host_insert_component(entity, host_component.as_mut())
+                .is_err()
+            {
+                // already tracked, so keep the Component as it was
+                world.insert_boxed_component(entity, component);
+                continue;
+            }
+            world.insert_boxed_component(entity, host_component);
+        }
+    }
+
+    /// Converts the Entity's locally-owned Components back into replicated
+    /// ones, after authority over it has returned to the Server
+    fn return_authority<W: WorldMutType<E>>(
+        connection: &mut Connection<E>,
+        protocol: &Protocol,
+        global_world_manager: &mut GlobalWorldManager<E>,
+        world: &mut W,
+        entity: &E,
+    ) {
+        let Some(component_kinds) = global_world_manager.component_kinds(entity) else {
+            return;
+        };
+        for component_kind in component_kinds {
+            global_world_manager.host_remove_component(entity, &component_kind);
+
+            let Some(component) = world.remove_component_of_kind(entity, &component_kind) else {
+                continue;
+            };
+
+            let mut writer = BitWriter::new();
+            {
+                let mut converter = EntityConverterMut::new(
+                    global_world_manager,
+                    &mut connection.base.local_world_manager,
+                );
+                component.write(&protocol.component_kinds, &mut writer, &mut converter);
+            }
+            let bytes = writer.to_bytes();
+            let mut reader = BitReader::new(&bytes);
+
+            let converter =
+                EntityConverter::new(global_world_manager, &connection.base.local_world_manager);
+            let converter = ReversedEntityConverter::new(&converter);
+            let Ok(remote_component) = protocol.component_kinds.read(&mut reader, &converter)
+            else {
+                warn!("Client Error: unable to restore replicated Component");
+                continue;
+            };
+            world.insert_boxed_component(entity, remote_component);
+        }
+    }
+
+    /// Sends the full state of any changed Components of an Entity the Client
+    /// holds authority over
+    fn send_auth_update<W: WorldRefType<E>>(
+        connection: &mut Connection<E>,
+        protocol: &Protocol,
+        global_world_manager: &GlobalWorldManager<E>,
+        world: &W,
+        entity: &E,
+    ) {
+        let Some(component_kinds) = global_world_manager.component_kinds(entity) else {
+            return;
+        };
+        let mut changed_kinds = Vec::new();
+        for component_kind in component_kinds {
+            let Some(receiver) = global_world_manager.component_receiver(entity, &component_kind)
+            else {
+                continue;
+            };
+            if !receiver.diff_mask_is_clear() {
+                receiver.clear_mask();
+                changed_kinds.push(component_kind);
+            }
+        }
+        if changed_kinds.is_empty() {
+            return;
+        }
+
+        let mut writer = BitWriter::new();
+        {
+            let mut converter = EntityConverterMut::new(
+                global_world_manager,
+                &mut connection.base.local_world_manager,
+            );
+            UnsignedVariableInteger::<3>::new(changed_kinds.len() as i128).ser(&mut writer);
+            for component_kind in &changed_kinds {
+                let component = world
+                    .component_of_kind(entity, component_kind)
+                    .expect("Component does not exist in World");
+                component.write(&protocol.component_kinds, &mut writer, &mut converter);
+            }
+        }
+
+        let message = EntityAuthUpdateMessage::new(global_world_manager, entity, writer.to_bytes());
+        Self::send_auth_channel_message(
+            connection,
+            protocol,
+            global_world_manager,
+            Box::new(message),
+        );
+    }
+
+    fn send_auth_message(
+        connection: &mut Connection<E>,
+        protocol: &Protocol,
+        global_world_manager: &GlobalWorldManager<E>,
+        entity: &E,
+        action: EntityAuthAction,
+    ) {
+        let message = EntityAuthMessage::new(global_world_manager, entity, action);
+        Self::send_auth_channel_message(
+            connection,
+            protocol,
+            global_world_manager,
+            Box::new(message),
+        );
+    }
+
+    fn send_auth_channel_message(
+        connection: &mut Connection<E>,
+        protocol: &Protocol,
+        global_world_manager: &GlobalWorldManager<E>,
+        message_box: Box<dyn Message>,
+    ) {
+        let mut converter = EntityConverterMut::new(
+            global_world_manager,
+            &mut connection.base.local_world_manager,
+        );
+        let message = MessageContainer::from_write(message_box, &mut converter);
+        connection.base.message_manager.send_message(
+            &protocol.message_kinds,
+            &mut converter,
+            &ChannelKind::of::<EntityAuthChannel>(),
+            message,
+        );
+    }
+
     fn check_client_authoritative_allowed(&self) {
         if !self.protocol.client_authoritative_entities {
             panic!("Cannot perform this operation: Client Authoritative Entities are not enabled! Enable them in the Protocol, with the `enable_client_authoritative_entities() method, and note that if you do enable them, to make sure you handle all Spawn/Insert/Update events in the Server, as this may be an attack vector.")
@@ -563,12 +1121,16 @@ impl<E: Copy + Eq + Hash + Send + Sync> Client<E> {
                             self.server_connection = Some(Connection::new(
                                 &self.client_config.connection,
                                 &self.protocol.channel_kinds,
-                                time_manager,
+                                *time_manager,
                                 &self.global_world_manager,
+                                &self.client_config.clock,
                             ));
 
                             let server_addr = self.server_address_unwrapped();
                             self.incoming_events.push_connection(&server_addr);
+
+                            // packets after this are read by the Connection
+                            break;
                         }
                         Some(HandshakeResult::Rejected) => {
                             let server_addr = self.server_address_unwrapped();
@@ -607,8 +1169,12 @@ impl<E: Copy + Eq + Hash + Send + Sync> Client<E> {
                 Ok(Some(mut reader)) => {
                     connection.base.mark_heard();
 
-                    let header = StandardHeader::de(&mut reader)
-                        .expect("unable to parse header from incoming packet");
+                    let Ok(header) = StandardHeader::de(&mut reader) else {
+                        warn!("unable to parse header from incoming packet");
+                        self.incoming_events
+                            .push_error(NaiaClientError::MalformedPacket);
+                        continue;
+                    };
 
                     match header.packet_type {
                         PacketType::Data
@@ -660,15 +1226,27 @@ impl<E: Copy + Eq + Hash + Send + Sync> Client<E> {
                         }
                         PacketType::Ping => {
                             let Ok(ping_index) = BaseTimeManager::read_ping(&mut reader) else {
-                                panic!("unable to read ping index");
+                                warn!("unable to read ping index");
+                                self.incoming_events
+                                    .push_error(NaiaClientError::MalformedPacket);
+                                continue;
                             };
                             BaseTimeManager::send_pong(connection, &mut self.io, ping_index);
                         }
                         PacketType::Pong => {
+                            let prev_server_time_offset =
+                                connection.time_manager.server_time_offset();
                             if connection.time_manager.read_pong(&mut reader).is_err() {
                                 // TODO: pass this on and handle above
                                 warn!("Client Error: Cannot process pong packet from Server");
                             }
+                            let clock_jump = connection.time_manager.server_time_offset()
+                                - prev_server_time_offset;
+                            if u128::from(clock_jump.unsigned_abs())
+                                >= self.client_config.clock_jump_threshold.as_millis()
+                            {
+                                self.incoming_events.push_clock_jump(clock_jump);
+                            }
                         }
                         _ => {
                             // no other packet types matter when connection
@@ -732,11 +1310,17 @@ impl<E: Copy + Eq + Hash + Send + Sync> Client<E> {
             panic!("Client is already disconnected!");
         };
 
-        let events = connection
-            .base
-            .despawn_all_remote_entities(&mut self.global_world_manager, world);
+        let events = connection.base.despawn_all_remote_entities(
+            &mut self.global_world_manager,
+            world,
+            connection.time_manager.client_receiving_tick,
+        );
 
-        self.incoming_events.receive_world_events(events);
+        self.incoming_events.receive_world_events(
+            &mut self.global_world_manager,
+            &self.protocol.component_kinds,
+            events,
+        );
     }
 
     fn disconnect_reset_connection(&mut self) {
@@ -745,15 +1329,35 @@ impl<E: Copy + Eq + Hash + Send + Sync> Client<E> {
         self.io = Io::new(
             &self.client_config.connection.bandwidth_measure_duration,
             &self.protocol.compression,
+            &self.client_config.clock,
         );
 
         self.handshake_manager = HandshakeManager::new(
             self.client_config.send_handshake_interval,
             self.client_config.ping_interval,
             self.client_config.handshake_pings,
+            &self.client_config.clock,
         );
     }
 
+    /// Drops the connection to a replay Socket whose playback restarted, and
+    /// connects to it again
+    pub(crate) fn reconnect_replay<W: WorldMutType<E>>(
+        &mut self,
+        mut world: W,
+        socket: Box<dyn Socket>,
+    ) {
+        if self.is_connected() {
+            self.disconnect_with_events(&mut world);
+        } else {
+            self.disconnect_reset_connection();
+        }
+        self.manual_disconnect = false;
+
+        let (packet_sender, packet_receiver) = socket.connect(&self.client_config.clock);
+        self.io.load(packet_sender, packet_receiver);
+    }
+
     fn server_address_unwrapped(&self) -> SocketAddr {
         // NOTE: may panic if the connection is not yet established!
         self.io.server_addr().expect("connection not established!")
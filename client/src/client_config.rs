@@ -1,6 +1,6 @@
-use std::{default::Default, time::Duration};
+use std::{default::Default, sync::Arc, time::Duration};
 
-use naia_shared::ConnectionConfig;
+use naia_shared::{Clock, ConnectionConfig, SystemClock};
 
 /// Contains Config properties which will be used by a Server or Client
 #[derive(Clone)]
@@ -17,6 +17,15 @@ pub struct ClientConfig {
     /// taking longer. Keep in mind that the network measurements affect how likely commands
     /// are able to arrive at the server before processing.
     pub handshake_pings: u8,
+    /// The duration to wait for the Server to confirm an Entity spawned in
+    /// prediction, before it is despawned again
+    pub predicted_spawn_timeout: Duration,
+    /// The smallest correction to the Client's estimate of the Server's time
+    /// which is reported as a `ServerClockJumpEvent`
+    pub clock_jump_threshold: Duration,
+    /// The Clock all of the Client's timing reads from, such as a
+    /// `VirtualClock` for tests. Defaults to the system clock.
+    pub clock: Arc<dyn Clock>,
 }
 
 impl Default for ClientConfig {
@@ -26,6 +35,9 @@ impl Default for ClientConfig {
             send_handshake_interval: Duration::from_millis(250),
             ping_interval: Duration::from_secs(1),
             handshake_pings: 10,
+            predicted_spawn_timeout: Duration::from_secs(2),
+            clock_jump_threshold: Duration::from_millis(10),
+            clock: Arc::new(SystemClock),
         }
     }
 }
@@ -1,10 +1,10 @@
-use std::hash::Hash;
+use std::{hash::Hash, sync::Arc};
 
 use log::warn;
 
 use naia_shared::{
-    sequence_greater_than, BitReader, BitWriter, GameDuration, GameInstant, Instant, PacketType,
-    PingIndex, PingStore, Serde, SerdeErr, StandardHeader, UnsignedVariableInteger,
+    sequence_greater_than, BitReader, BitWriter, Clock, GameDuration, GameInstant, Instant,
+    PacketType, PingIndex, PingStore, Serde, SerdeErr, StandardHeader, UnsignedVariableInteger,
 };
 
 use crate::connection::{connection::Connection, io::Io};
@@ -15,19 +15,25 @@ pub struct BaseTimeManager {
     sent_pings: PingStore,
     most_recent_ping: PingIndex,
     never_been_pinged: bool,
+    clock: Arc<dyn Clock>,
 }
 
 impl BaseTimeManager {
-    pub fn new() -> Self {
-        let now = Instant::now();
+    pub fn new(clock: &Arc<dyn Clock>) -> Self {
+        let now = clock.now();
         Self {
             start_instant: now,
             sent_pings: PingStore::new(),
             most_recent_ping: 0,
             never_been_pinged: true,
+            clock: clock.clone(),
         }
     }
 
+    pub fn clock(&self) -> &Arc<dyn Clock> {
+        &self.clock
+    }
+
     // Ping & Pong
 
     pub fn send_ping(&mut self, io: &mut Io) {
@@ -141,7 +147,7 @@ impl BaseTimeManager {
     }
 
     pub fn game_time_now(&self) -> GameInstant {
-        GameInstant::new(&self.start_instant)
+        GameInstant::new(&self.start_instant, &self.clock.now())
     }
 
     pub fn game_time_since(&self, previous_instant: &GameInstant) -> GameDuration {
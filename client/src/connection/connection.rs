@@ -1,29 +1,37 @@
-use std::hash::Hash;
+use std::{hash::Hash, sync::Arc};
 
 use log::warn;
 
 use naia_shared::{
-    BaseConnection, BitReader, BitWriter, ChannelKinds, ComponentKinds, ConnectionConfig,
-    EntityConverter, EntityConverterMut, HostType, HostWorldEvents, Instant, OwnedBitReader,
-    PacketType, Protocol, Serde, SerdeErr, StandardHeader, Tick, WorldMutType, WorldRefType,
+    sequence_greater_than, BaseConnection, BitReader, BitWriter, ChannelKind, ChannelKinds, Clock,
+    ComponentKinds, ConnectionConfig, EntityAuthAction, EntityAuthChannel, EntityAuthMessage,
+    EntityConverter, EntityConverterMut, EntityHierarchyChannel, EntityParentMessage,
+    EntityScopeChannel, EntityScopeMessage, HostType, HostWorldEvents, Instant, MessageContainer,
+    MessageKind, NetworkStats, OwnedBitReader, PacketType, Protocol, Serde, SerdeErr,
+    StandardHeader, Tick, TickBufferReceiver, TickBufferSender, TickIntervalChannel,
+    TickIntervalMessage, WorldMutType, WorldRefType,
 };
 
 use crate::{
     connection::{
-        io::Io, tick_buffer_sender::TickBufferSender, tick_queue::TickQueue,
+        io::Io, tick_buffer_messages::TickBufferMessages, tick_queue::TickQueue,
         time_manager::TimeManager,
     },
     events::Events,
-    world::global_world_manager::GlobalWorldManager,
+    world::{entity_auth_status::EntityAuthStatus, global_world_manager::GlobalWorldManager},
 };
 
 pub struct Connection<E: Copy + Eq + Hash + Send + Sync> {
     pub base: BaseConnection<E>,
     pub time_manager: TimeManager,
     pub tick_buffer: TickBufferSender,
+    tick_buffer_receiver: TickBufferReceiver,
+    /// The newest Server Tick whose Tick Buffered messages were handed out
+    tick_buffer_received_tick: Tick,
     /// Small buffer when receiving updates (entity actions, entity updates) from the server
     /// to make sure we receive them in order
     jitter_buffer: TickQueue<OwnedBitReader>,
+    incoming_auth_actions: Vec<(E, EntityAuthAction)>,
 }
 
 impl<E: Copy + Eq + Hash + Send + Sync> Connection<E> {
@@ -32,8 +40,11 @@ impl<E: Copy + Eq + Hash + Send + Sync> Connection<E> {
         channel_kinds: &ChannelKinds,
         time_manager: TimeManager,
         global_world_manager: &GlobalWorldManager<E>,
+        clock: &Arc<dyn Clock>,
     ) -> Self {
-        let tick_buffer = TickBufferSender::new(channel_kinds);
+        let tick_buffer = TickBufferSender::new(HostType::Client, channel_kinds);
+        let tick_buffer_receiver = TickBufferReceiver::new(HostType::Client, channel_kinds);
+        let tick_buffer_received_tick = time_manager.client_receiving_tick;
 
         let mut connection = Connection {
             base: BaseConnection::new(
@@ -43,10 +54,14 @@ impl<E: Copy + Eq + Hash + Send + Sync> Connection<E> {
                 connection_config,
                 channel_kinds,
                 global_world_manager,
+                clock,
             ),
             time_manager,
             tick_buffer,
+            tick_buffer_receiver,
+            tick_buffer_received_tick,
             jitter_buffer: TickQueue::new(),
+            incoming_auth_actions: Vec::new(),
         };
 
         let existing_entities = global_world_manager.entities();
@@ -64,6 +79,21 @@ impl<E: Copy + Eq + Hash + Send + Sync> Connection<E> {
 
     // Incoming data
 
+    pub fn network_stats(&self, protocol: &Protocol, io: &Io) -> NetworkStats {
+        let mut stats = self.base.network_stats(protocol);
+        stats.add_packets(io.packet_counter());
+        stats.add_channel_bits(
+            &protocol.channel_kinds,
+            self.tick_buffer.channel_bits_sent(),
+        );
+        stats.add_message_bits(
+            &protocol.message_kinds,
+            self.tick_buffer.message_bits_sent(),
+        );
+        stats.messages_dropped = self.tick_buffer.messages_dropped();
+        stats
+    }
+
     pub fn process_incoming_header(&mut self, header: &StandardHeader) {
         self.base
             .process_incoming_header(header, &mut [&mut self.tick_buffer]);
@@ -79,22 +109,33 @@ impl<E: Copy + Eq + Hash + Send + Sync> Connection<E> {
         Ok(())
     }
 
-    /// Read the packets (raw bits) from the jitter buffer that correspond to the
-    /// `receiving_tick`. Reads packets, storing necessary data into an internal buffer
-    pub fn read_buffered_packets(
+    /// Read the next packet (raw bits) from the jitter buffer that corresponds to
+    /// the `receiving_tick`, storing necessary data into an internal buffer.
+    /// Returns false if no packet was due
+    pub fn read_buffered_packet(
         &mut self,
         protocol: &Protocol,
         global_world_manager: &mut GlobalWorldManager<E>,
-    ) -> Result<(), SerdeErr> {
+    ) -> Result<bool, SerdeErr> {
         let receiving_tick = self.time_manager.client_receiving_tick;
 
-        while let Some((server_tick, owned_reader)) = self.jitter_buffer.pop_item(receiving_tick) {
+        if let Some((server_tick, owned_reader)) = self.jitter_buffer.pop_item(receiving_tick) {
             let mut reader = owned_reader.borrow();
 
             // read messages
             {
                 let entity_converter =
                     EntityConverter::new(global_world_manager, &self.base.local_world_manager);
+
+                // read tick-buffered messages
+                self.tick_buffer_receiver.read_messages(
+                    protocol,
+                    &self.tick_buffer_received_tick,
+                    &server_tick,
+                    &entity_converter,
+                    &mut reader,
+                )?;
+
                 self.base.message_manager.read_messages(
                     protocol,
                     &mut self.base.remote_world_manager.entity_waitlist,
@@ -111,9 +152,10 @@ impl<E: Copy + Eq + Hash + Send + Sync> Connection<E> {
                 server_tick,
                 &mut reader,
             )?;
+            return Ok(true);
         }
 
-        Ok(())
+        Ok(false)
     }
 
     /// Receive & process messages / entity actions / entity updates and emit events for them
@@ -131,21 +173,150 @@ impl<E: Copy + Eq + Hash + Send + Sync> Connection<E> {
             &mut self.base.remote_world_manager.entity_waitlist,
         );
         for (channel_kind, messages) in messages {
+            if channel_kind == ChannelKind::of::<EntityAuthChannel>() {
+                self.receive_auth_messages(global_world_manager, messages);
+                continue;
+            }
+            if channel_kind == ChannelKind::of::<EntityHierarchyChannel>() {
+                Self::receive_hierarchy_messages(global_world_manager, messages);
+                continue;
+            }
+            if channel_kind == ChannelKind::of::<EntityScopeChannel>() {
+                Self::receive_scope_messages(global_world_manager, incoming_events, messages);
+                continue;
+            }
+            if channel_kind == ChannelKind::of::<TickIntervalChannel>() {
+                self.receive_tick_interval_messages(messages);
+                continue;
+            }
             for message in messages {
                 incoming_events.push_message(&channel_kind, message);
             }
         }
 
         // Receive World Events
-        let remote_events = self.base.remote_world_reader.take_incoming_events();
+        let mut remote_events = self.base.remote_world_reader.take_incoming_events();
+
+        // the Client is the source of state for Entities it holds authority over,
+        // so ignore any updates to them still in flight from the Server
+        remote_events.incoming_updates.retain(|(_, entity, _)| {
+            global_world_manager.entity_auth_status(entity) != Some(EntityAuthStatus::Granted)
+        });
+
         let world_events = self.base.remote_world_manager.process_world_events(
             global_world_manager,
             &mut self.base.local_world_manager,
             component_kinds,
             world,
             remote_events,
+            None,
         );
-        incoming_events.receive_world_events(world_events);
+        incoming_events.receive_world_events(global_world_manager, component_kinds, world_events);
+    }
+
+    fn receive_auth_messages(
+        &mut self,
+        global_world_manager: &GlobalWorldManager<E>,
+        messages: Vec<MessageContainer>,
+    ) {
+        for message in messages {
+            if message.kind() != MessageKind::of::<EntityAuthMessage>() {
+                warn!("Client Error: received unexpected message on Entity authority channel");
+                continue;
+            }
+            let message = message
+                .to_boxed_any()
+                .downcast::<EntityAuthMessage>()
+                .unwrap();
+            if let Some(entity) = message.entity.get(global_world_manager) {
+                self.incoming_auth_actions.push((entity, message.action));
+            }
+        }
+    }
+
+    fn receive_hierarchy_messages(
+        global_world_manager: &mut GlobalWorldManager<E>,
+        messages: Vec<MessageContainer>,
+    ) {
+        for message in messages {
+            if message.kind() != MessageKind::of::<EntityParentMessage>() {
+                warn!("Client Error: received unexpected message on Entity hierarchy channel");
+                continue;
+            }
+            let message = message
+                .to_boxed_any()
+                .downcast::<EntityParentMessage>()
+                .unwrap();
+            let Some(child) = message.child.get(global_world_manager) else {
+                continue;
+            };
+            let parent = message.parent.get(global_world_manager);
+            if !global_world_manager.entity_set_parent(&child, parent.as_ref()) {
+                warn!("Client Error: received an Entity parent which would create a cycle");
+            }
+        }
+    }
+
+    fn receive_scope_messages(
+        global_world_manager: &mut GlobalWorldManager<E>,
+        incoming_events: &mut Events<E>,
+        messages: Vec<MessageContainer>,
+    ) {
+        for message in messages {
+            if message.kind() != MessageKind::of::<EntityScopeMessage>() {
+                warn!("Client Error: received unexpected message on Entity scope channel");
+                continue;
+            }
+            let message = message
+                .to_boxed_any()
+                .downcast::<EntityScopeMessage>()
+                .unwrap();
+            let Some(entity) = message.entity.get(global_world_manager) else {
+                continue;
+            };
+            if !global_world_manager.entity_set_dormant(&entity, !message.in_scope) {
+                continue;
+            }
+            if message.in_scope {
+                incoming_events.push_scope_enter(entity);
+            } else {
+                incoming_events.push_scope_exit(entity);
+            }
+        }
+    }
+
+    fn receive_tick_interval_messages(&mut self, messages: Vec<MessageContainer>) {
+        for message in messages {
+            if message.kind() != MessageKind::of::<TickIntervalMessage>() {
+                warn!("Client Error: received unexpected message on tick interval channel");
+                continue;
+            }
+            let message = message
+                .to_boxed_any()
+                .downcast::<TickIntervalMessage>()
+                .unwrap();
+            self.time_manager
+                .recv_tick_interval(message.tick, message.interval());
+        }
+    }
+
+    /// Take the Tick Buffered messages the Server sent for the given Tick.
+    /// Messages for earlier Ticks which were not taken are dropped.
+    pub fn tick_buffer_messages(&mut self, tick: &Tick, messages: &mut TickBufferMessages) {
+        if sequence_greater_than(*tick, self.tick_buffer_received_tick) {
+            self.tick_buffer_received_tick = *tick;
+        }
+        let channel_messages = self.tick_buffer_receiver.receive_messages(tick);
+        for (channel_kind, received_messages) in channel_messages {
+            for message in received_messages {
+                messages.push_message(&channel_kind, message);
+            }
+        }
+    }
+
+    /// Take all Entity authority actions received from the Server
+    pub fn take_auth_actions(&mut self) -> Vec<(E, EntityAuthAction)> {
+        std::mem::take(&mut self.incoming_auth_actions)
     }
 
     // Outgoing data
@@ -251,6 +422,7 @@ impl<E: Copy + Eq + Hash + Send + Sync> Connection<E> {
                 now,
                 &mut writer,
                 next_packet_index,
+                &client_tick,
                 world,
                 global_world_manager,
                 &mut has_written,
@@ -259,7 +431,10 @@ impl<E: Copy + Eq + Hash + Send + Sync> Connection<E> {
             );
 
             // send packet
-            if io.send_packet(writer.to_packet()).is_err() {
+            let packet = writer.to_packet();
+            self.base
+                .record_sent_packet(packet.slice().len(), &self.tick_buffer);
+            if io.send_packet(packet).is_err() {
                 // TODO: pass this on and handle above
                 warn!("Client Error: Cannot send data packet to Server");
             }
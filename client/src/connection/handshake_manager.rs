@@ -1,10 +1,10 @@
-use std::time::Duration;
+use std::{sync::Arc, time::Duration};
 
 use log::warn;
 
 use naia_shared::{
-    BitReader, BitWriter, FakeEntityConverter, MessageContainer, MessageKinds, PacketType, Serde,
-    StandardHeader, Timer, Timestamp as stamp_time,
+    BitReader, BitWriter, Clock, FakeEntityConverter, MessageContainer, MessageKinds, PacketType,
+    Serde, StandardHeader, Timer, Timestamp as stamp_time,
 };
 
 use super::io::Io;
@@ -16,7 +16,7 @@ pub enum HandshakeState {
     AwaitingChallengeResponse,
     AwaitingValidateResponse,
     TimeSync(HandshakeTimeManager),
-    AwaitingConnectResponse(TimeManager),
+    AwaitingConnectResponse(Box<TimeManager>),
     Connected,
 }
 
@@ -41,7 +41,7 @@ impl PartialEq for HandshakeState {
 }
 
 pub enum HandshakeResult {
-    Connected(TimeManager),
+    Connected(Box<TimeManager>),
     Rejected,
 }
 
@@ -53,11 +53,17 @@ pub struct HandshakeManager {
     pre_connection_timestamp: Timestamp,
     pre_connection_digest: Option<Vec<u8>>,
     auth_message: Option<MessageContainer>,
+    clock: Arc<dyn Clock>,
 }
 
 impl HandshakeManager {
-    pub fn new(send_interval: Duration, ping_interval: Duration, handshake_pings: u8) -> Self {
-        let mut handshake_timer = Timer::new(send_interval);
+    pub fn new(
+        send_interval: Duration,
+        ping_interval: Duration,
+        handshake_pings: u8,
+        clock: &Arc<dyn Clock>,
+    ) -> Self {
+        let mut handshake_timer = Timer::new(send_interval, clock);
         handshake_timer.ring_manual();
 
         let pre_connection_timestamp = stamp_time::now();
@@ -70,6 +76,7 @@ impl HandshakeManager {
             auth_message: None,
             ping_interval,
             handshake_pings,
+            clock: clock.clone(),
         }
     }
 
@@ -164,7 +171,7 @@ impl HandshakeManager {
                         panic!("should be impossible due to check above");
                     };
                     self.connection_state =
-                        HandshakeState::AwaitingConnectResponse(time_manager.finalize());
+                        HandshakeState::AwaitingConnectResponse(Box::new(time_manager.finalize()));
                 }
                 return None;
             }
@@ -240,6 +247,7 @@ impl HandshakeManager {
         self.connection_state = HandshakeState::TimeSync(HandshakeTimeManager::new(
             self.ping_interval,
             self.handshake_pings,
+            &self.clock,
         ));
     }
 
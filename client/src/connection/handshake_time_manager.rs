@@ -1,6 +1,6 @@
-use std::time::Duration;
+use std::{sync::Arc, time::Duration};
 
-use naia_shared::{BitReader, GameInstant, Serde, SerdeErr, Tick, GAME_TIME_LIMIT};
+use naia_shared::{BitReader, Clock, GameInstant, Serde, SerdeErr, Tick, GAME_TIME_LIMIT};
 
 use crate::connection::{base_time_manager::BaseTimeManager, io::Io, time_manager::TimeManager};
 
@@ -16,8 +16,8 @@ pub struct HandshakeTimeManager {
 }
 
 impl HandshakeTimeManager {
-    pub fn new(ping_interval: Duration, handshake_pings: u8) -> Self {
-        let base = BaseTimeManager::new();
+    pub fn new(ping_interval: Duration, handshake_pings: u8, clock: &Arc<dyn Clock>) -> Self {
+        let base = BaseTimeManager::new(clock);
         let server_tick_instant = base.game_time_now();
         Self {
             base,
@@ -106,7 +106,7 @@ impl HandshakeTimeManager {
         for (time_offset_millis, rtt_millis) in pongs {
             let offset_diff = (time_offset_millis - offset_mean).abs();
             let rtt_diff = (rtt_millis - rtt_mean).abs();
-            if offset_diff < offset_stdv && rtt_diff < rtt_stdv {
+            if offset_diff <= offset_stdv && rtt_diff <= rtt_stdv {
                 pruned_pongs.push((time_offset_millis, rtt_millis));
             }
         }
@@ -1,7 +1,8 @@
-use std::{net::SocketAddr, time::Duration};
+use std::{net::SocketAddr, sync::Arc, time::Duration};
 
 use naia_shared::{
-    BandwidthMonitor, BitReader, CompressionConfig, Decoder, Encoder, OutgoingPacket,
+    BandwidthMonitor, BitReader, Clock, CompressionConfig, Decoder, Encoder, OutgoingPacket,
+    PacketCounter,
 };
 
 use crate::{
@@ -16,15 +17,19 @@ pub struct Io {
     incoming_bandwidth_monitor: Option<BandwidthMonitor>,
     outgoing_encoder: Option<Encoder>,
     incoming_decoder: Option<Decoder>,
+    packet_counter: PacketCounter,
 }
 
 impl Io {
     pub fn new(
         bandwidth_measure_duration: &Option<Duration>,
         compression_config: &Option<CompressionConfig>,
+        clock: &Arc<dyn Clock>,
     ) -> Self {
-        let outgoing_bandwidth_monitor = bandwidth_measure_duration.map(BandwidthMonitor::new);
-        let incoming_bandwidth_monitor = bandwidth_measure_duration.map(BandwidthMonitor::new);
+        let outgoing_bandwidth_monitor =
+            bandwidth_measure_duration.map(|duration| BandwidthMonitor::new(duration, clock));
+        let incoming_bandwidth_monitor =
+            bandwidth_measure_duration.map(|duration| BandwidthMonitor::new(duration, clock));
 
         let outgoing_encoder = compression_config.as_ref().and_then(|config| {
             config
@@ -46,6 +51,7 @@ impl Io {
             incoming_bandwidth_monitor,
             outgoing_encoder,
             incoming_decoder,
+            packet_counter: PacketCounter::new(),
         }
     }
 
@@ -79,6 +85,7 @@ impl Io {
         if let Some(monitor) = &mut self.outgoing_bandwidth_monitor {
             monitor.record_packet(payload.len());
         }
+        self.packet_counter.record_sent(payload.len());
 
         self.packet_sender
             .as_mut()
@@ -99,10 +106,14 @@ impl Io {
             if let Some(monitor) = &mut self.incoming_bandwidth_monitor {
                 monitor.record_packet(payload.len());
             }
+            self.packet_counter.record_received(payload.len());
 
             // Decompression
             if let Some(decoder) = &mut self.incoming_decoder {
-                payload = decoder.decode(payload);
+                let Ok(decoded_payload) = decoder.decode(payload) else {
+                    return Err(NaiaClientError::MalformedPacket);
+                };
+                payload = decoded_payload;
             }
 
             Ok(Some(BitReader::new(payload)))
@@ -113,15 +124,20 @@ impl Io {
         }
     }
 
+    /// Gets the packets & bytes sent to & received from the Server
+    pub fn packet_counter(&self) -> &PacketCounter {
+        &self.packet_counter
+    }
+
     pub fn server_addr(&self) -> Result<SocketAddr, NaiaClientError> {
         if let Some(packet_sender) = self.packet_sender.as_ref() {
             if let ServerAddr::Found(server_addr) = packet_sender.server_addr() {
                 Ok(server_addr)
             } else {
-                Err(NaiaClientError::from_message("Connection has not yet been established! Make sure you call Client.connect() before calling this."))
+                Err(NaiaClientError::NotConnected)
             }
         } else {
-            Err(NaiaClientError::from_message("Connection has not yet been established! Make sure you call Client.connect() before calling this."))
+            Err(NaiaClientError::NotConnected)
         }
     }
 
@@ -1,10 +1,10 @@
 pub mod base_time_manager;
-pub mod channel_tick_buffer_sender;
 #[allow(clippy::module_inception)]
 pub mod connection;
 pub mod handshake_manager;
 pub mod handshake_time_manager;
 pub mod io;
-pub mod tick_buffer_sender;
+pub mod network_clock;
+pub mod tick_buffer_messages;
 pub mod tick_queue;
 pub mod time_manager;
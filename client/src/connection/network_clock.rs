@@ -0,0 +1,17 @@
+use naia_shared::{GameInstant, Tick};
+
+/// The Client's estimate of the Server's clock, taken at one moment. See
+/// `Client::network_clock()`.
+#[derive(Clone)]
+pub struct NetworkClock {
+    /// The Server's current game time
+    pub server_time: GameInstant,
+    /// The Tick the Server is currently on
+    pub server_tick: Tick,
+    /// How far the Server is into `server_tick`, from 0.0 up to 1.0
+    pub tick_fraction: f32,
+    /// The standard deviation of the measured offset between the Client's &
+    /// the Server's clocks, in milliseconds. The Server's actual time is
+    /// usually within a couple of these of `server_time`.
+    pub error_millis: f32,
+}
@@ -0,0 +1,48 @@
+use std::collections::HashMap;
+
+use naia_shared::{Channel, ChannelKind, Message, MessageContainer, MessageKind};
+
+/// Messages the Server sent on Tick Buffered Channels for one Server Tick
+pub struct TickBufferMessages {
+    messages: HashMap<ChannelKind, HashMap<MessageKind, Vec<MessageContainer>>>,
+}
+
+impl Default for TickBufferMessages {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TickBufferMessages {
+    pub fn new() -> Self {
+        Self {
+            messages: HashMap::new(),
+        }
+    }
+
+    pub(crate) fn push_message(&mut self, channel_kind: &ChannelKind, message: MessageContainer) {
+        self.messages
+            .entry(*channel_kind)
+            .or_default()
+            .entry(message.kind())
+            .or_default()
+            .push(message);
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.messages.is_empty()
+    }
+
+    pub fn read<C: Channel, M: Message>(&mut self) -> Vec<M> {
+        let Some(channel_map) = self.messages.get_mut(&ChannelKind::of::<C>()) else {
+            return Vec::new();
+        };
+        let Some(messages) = channel_map.remove(&MessageKind::of::<M>()) else {
+            return Vec::new();
+        };
+        messages
+            .into_iter()
+            .map(|message| *message.to_boxed_any().downcast::<M>().unwrap())
+            .collect()
+    }
+}
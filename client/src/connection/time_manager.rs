@@ -5,7 +5,7 @@ use naia_shared::{
     Instant, SerdeErr, Tick, Timer,
 };
 
-use crate::connection::{base_time_manager::BaseTimeManager, io::Io};
+use crate::connection::{base_time_manager::BaseTimeManager, io::Io, network_clock::NetworkClock};
 
 pub struct TimeManager {
     base: BaseTimeManager,
@@ -49,6 +49,8 @@ impl TimeManager {
         offset_stdv: f32,
     ) -> Self {
         let now = base.game_time_now();
+        let ping_timer = Timer::new(ping_interval, base.clock());
+        let last_tick_check_instant = base.clock().now();
         let latency_ms = (pruned_rtt_avg / 2.0) as u32;
         let major_jitter_ms = (rtt_stdv / 2.0 * 3.0) as u32;
         let tick_duration_ms = server_tick_duration_avg.round() as u32;
@@ -81,7 +83,7 @@ impl TimeManager {
 
         Self {
             base,
-            ping_timer: Timer::new(ping_interval),
+            ping_timer,
 
             pruned_offset_avg: 0.0,
             raw_offset_avg: 0.0,
@@ -98,7 +100,7 @@ impl TimeManager {
             server_tick_duration_avg,
             server_speedup_potential,
 
-            last_tick_check_instant: Instant::now(),
+            last_tick_check_instant,
 
             client_receiving_tick,
             client_sending_tick,
@@ -165,6 +167,30 @@ impl TimeManager {
         self.base.game_time_since(previous_instant)
     }
 
+    // Network Clock
+
+    /// The estimated number of milliseconds the Server's game time is ahead
+    /// of the Client's
+    pub(crate) fn server_time_offset(&self) -> i32 {
+        -(self.pruned_offset_avg.round() as i32)
+    }
+
+    pub(crate) fn network_clock(&self) -> NetworkClock {
+        let server_time = self
+            .game_time_now()
+            .add_signed_millis(self.server_time_offset());
+        let ticks = (self.server_tick_instant.offset_from(&server_time) as f32)
+            / self.server_tick_duration_avg;
+        let whole_ticks = ticks.floor();
+
+        NetworkClock {
+            server_tick: self.server_tick.wrapping_add_signed(whole_ticks as i16),
+            tick_fraction: ticks - whole_ticks,
+            server_time,
+            error_millis: self.offset_stdv,
+        }
+    }
+
     // Tick
 
     pub(crate) fn recv_tick_instant(
@@ -214,6 +240,68 @@ impl TimeManager {
             self.instant_from_interp(self.server_receivable_tick, server_receivable_interp);
     }
 
+    /// Switches to a new Server tick interval, which took effect on the given
+    /// Server Tick, and re-targets the Ticks to it
+    pub(crate) fn recv_tick_interval(&mut self, server_tick: Tick, tick_interval: Duration) {
+        let tick_duration_ms = tick_interval.as_secs_f32() * 1000.0;
+        if tick_duration_ms <= 0.0 {
+            return;
+        }
+
+        // Ticks up until the new interval took effect ran at the old one.
+        // If a later Tick's instant has already been received, it ran at the
+        // new interval, so it stays as the reference point.
+        if sequence_greater_than(server_tick, self.server_tick) {
+            self.server_tick_instant = self.tick_to_instant(server_tick);
+            self.server_tick = server_tick;
+        }
+        self.server_tick_duration_avg = tick_duration_ms;
+
+        // Target Instants
+        let now: GameInstant = self.game_time_now();
+        let latency_ms: u32 = self.latency().round() as u32;
+        let major_jitter_ms: u32 = (self.jitter() * 3.0).round() as u32;
+        let tick_duration_ms: u32 = self.server_tick_duration_avg.round() as u32;
+
+        let client_receiving_target =
+            get_client_receiving_target(&now, latency_ms, major_jitter_ms, tick_duration_ms);
+        retarget_time(
+            &self.server_tick,
+            &self.server_tick_instant,
+            self.server_tick_duration_avg,
+            &mut self.client_receiving_tick,
+            &mut self.client_receiving_instant,
+            &client_receiving_target,
+        );
+
+        let client_sending_target = get_client_sending_target(
+            &now,
+            latency_ms,
+            major_jitter_ms,
+            tick_duration_ms,
+            self.server_speedup_potential,
+        );
+        retarget_time(
+            &self.server_tick,
+            &self.server_tick_instant,
+            self.server_tick_duration_avg,
+            &mut self.client_sending_tick,
+            &mut self.client_sending_instant,
+            &client_sending_target,
+        );
+
+        let server_receivable_target =
+            get_server_receivable_target(&now, latency_ms, major_jitter_ms, tick_duration_ms);
+        retarget_time(
+            &self.server_tick,
+            &self.server_tick_instant,
+            self.server_tick_duration_avg,
+            &mut self.server_receivable_tick,
+            &mut self.server_receivable_instant,
+            &server_receivable_target,
+        );
+    }
+
     pub(crate) fn collect_ticks(&mut self) -> (Option<(Tick, Tick)>, Option<(Tick, Tick)>) {
         // updates client_receiving_tick
         // returns (Some(start_tick, end_tick), None) if a client_receiving_tick has incremented
@@ -222,8 +310,12 @@ impl TimeManager {
         let prev_client_sending_tick = self.client_sending_tick;
 
         {
-            let time_elapsed = self.last_tick_check_instant.elapsed().as_secs_f32() * 1000.0;
-            self.last_tick_check_instant = Instant::now();
+            let now = self.base.clock().now();
+            let time_elapsed = now
+                .duration_since(&self.last_tick_check_instant)
+                .as_secs_f32()
+                * 1000.0;
+            self.last_tick_check_instant = now;
             self.accumulator += time_elapsed;
             if self.accumulator < 1.0 {
                 return (None, None);
@@ -394,6 +486,33 @@ fn adjust_time(
     }
 }
 
+/// Moves a Tick straight to its target instant. A Tick is never moved
+/// backwards, instead it waits at the start of its current Tick for
+/// `adjust_time` to slow it down towards the target.
+fn retarget_time(
+    server_tick: &Tick,
+    server_tick_instant: &GameInstant,
+    server_tick_duration_avg: f32,
+    tick: &mut Tick,
+    tick_instant: &mut GameInstant,
+    target_instant: &GameInstant,
+) {
+    let new_tick = instant_to_tick(
+        server_tick,
+        server_tick_instant,
+        server_tick_duration_avg,
+        target_instant,
+    );
+    if sequence_less_than(new_tick, *tick) {
+        let tick_diff = wrapping_diff(*server_tick, *tick);
+        let tick_diff_duration = ((tick_diff as f32) * server_tick_duration_avg).round() as i32;
+        *tick_instant = server_tick_instant.add_signed_millis(tick_diff_duration);
+    } else {
+        *tick = new_tick;
+        *tick_instant = target_instant.clone();
+    }
+}
+
 fn instant_to_tick(
     server_tick: &Tick,
     server_tick_instant: &GameInstant,
@@ -497,3 +616,141 @@ mod offset_to_speed_tests {
         assert_eq!(offset_to_speed(offset), 0.8);
     }
 }
+
+#[cfg(test)]
+mod network_clock_tests {
+    use std::{sync::Arc, time::Duration};
+
+    use naia_shared::{Clock, VirtualClock};
+
+    use crate::connection::{base_time_manager::BaseTimeManager, time_manager::TimeManager};
+
+    #[test]
+    fn estimates_server_time_and_tick() {
+        let clock = VirtualClock::new();
+        let base_clock: Arc<dyn Clock> = Arc::new(clock.clone());
+
+        let base = BaseTimeManager::new(&base_clock);
+        clock.advance(Duration::from_secs(1));
+        let server_tick_instant = base.game_time_now().sub_millis(125);
+        let mut time_manager = TimeManager::from_parts(
+            Duration::from_secs(1),
+            base,
+            10,
+            server_tick_instant,
+            50.0,
+            1.0,
+            100.0,
+            4.0,
+            3.0,
+        );
+
+        let network_clock = time_manager.network_clock();
+        assert_eq!(network_clock.server_tick, 12);
+        assert_eq!(network_clock.tick_fraction, 0.5);
+        assert_eq!(network_clock.error_millis, 3.0);
+
+        // the Client's clock runs 25ms ahead of the Server's
+        time_manager.pruned_offset_avg = 25.0;
+        let network_clock = time_manager.network_clock();
+        assert_eq!(
+            time_manager
+                .game_time_now()
+                .offset_from(&network_clock.server_time),
+            -25
+        );
+        assert_eq!(network_clock.server_tick, 12);
+        assert_eq!(network_clock.tick_fraction, 0.0);
+    }
+}
+
+#[cfg(test)]
+mod tick_interval_tests {
+    use std::{sync::Arc, time::Duration};
+
+    use naia_shared::{Clock, GameInstant, VirtualClock};
+
+    use crate::connection::{base_time_manager::BaseTimeManager, time_manager::TimeManager};
+
+    /// Server Tick 10 happened just now at a 50ms interval, with 50ms of
+    /// latency & no jitter
+    fn time_manager() -> (TimeManager, GameInstant) {
+        let clock = VirtualClock::new();
+        let base_clock: Arc<dyn Clock> = Arc::new(clock.clone());
+
+        let base = BaseTimeManager::new(&base_clock);
+        clock.advance(Duration::from_secs(1));
+        let now = base.game_time_now();
+        let time_manager = TimeManager::from_parts(
+            Duration::from_secs(1),
+            base,
+            10,
+            now.clone(),
+            50.0,
+            1.0,
+            100.0,
+            0.0,
+            0.0,
+        );
+        assert_eq!(time_manager.client_receiving_tick, 8);
+        assert_eq!(time_manager.client_sending_tick, 16);
+        assert_eq!(time_manager.server_receivable_tick, 13);
+        (time_manager, now)
+    }
+
+    #[test]
+    fn rebases_on_the_tick_the_interval_changed() {
+        let (mut time_manager, now) = time_manager();
+
+        time_manager.recv_tick_interval(12, Duration::from_millis(100));
+
+        // Ticks 10 to 12 ran at 50ms, later ones at 100ms
+        assert_eq!(time_manager.server_tick, 12);
+        assert_eq!(now.offset_from(&time_manager.tick_to_instant(12)), 100);
+        assert_eq!(now.offset_from(&time_manager.tick_to_instant(14)), 300);
+
+        // re-targeted from 50ms of latency at the new interval
+        assert_eq!(time_manager.client_receiving_tick, 10);
+        assert_eq!(
+            now.offset_from(&time_manager.client_receiving_instant),
+            -150
+        );
+        assert_eq!(time_manager.client_sending_tick, 16);
+        assert_eq!(now.offset_from(&time_manager.client_sending_instant), 550);
+        assert_eq!(time_manager.server_receivable_tick, 13);
+        assert_eq!(
+            now.offset_from(&time_manager.server_receivable_instant),
+            250
+        );
+    }
+
+    #[test]
+    fn faster_interval_does_not_move_ticks_backwards() {
+        let (mut time_manager, now) = time_manager();
+
+        time_manager.recv_tick_interval(12, Duration::from_millis(25));
+
+        // each waits at the start of its current Tick
+        assert_eq!(time_manager.client_receiving_tick, 8);
+        assert_eq!(now.offset_from(&time_manager.client_receiving_instant), 0);
+        assert_eq!(time_manager.client_sending_tick, 16);
+        assert_eq!(now.offset_from(&time_manager.client_sending_instant), 200);
+        assert_eq!(time_manager.server_receivable_tick, 13);
+        assert_eq!(
+            now.offset_from(&time_manager.server_receivable_instant),
+            125
+        );
+    }
+
+    #[test]
+    fn interval_from_before_the_latest_tick_keeps_it() {
+        let (mut time_manager, now) = time_manager();
+
+        // i.e. told on connecting of a change made before the Client joined
+        time_manager.recv_tick_interval(4, Duration::from_millis(100));
+
+        assert_eq!(time_manager.server_tick, 10);
+        assert_eq!(now.offset_from(&time_manager.tick_to_instant(10)), 0);
+        assert_eq!(now.offset_from(&time_manager.tick_to_instant(12)), 200);
+    }
+}
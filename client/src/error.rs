@@ -6,6 +6,24 @@ pub enum NaiaClientError {
     Wrapped(Box<dyn Error + Send>),
     SendError,
     RecvError,
+    /// A packet received from the Server could not be read, and was dropped
+    MalformedPacket,
+    NotConnected,
+    AlreadyConnected,
+    EntityNotFound,
+    /// Client Authoritative Entities must be enabled in the Protocol
+    ClientAuthorityDisabled,
+    /// The Channel is not configured to send Messages to the Server
+    ChannelNotSendable,
+    /// Tick Buffered Channels are sent on with
+    /// `Client::send_tick_buffer_message()`, and only those
+    ChannelTickBufferMismatch,
+    /// Authority can only be requested over Entities the Server delegates
+    EntityNotDelegated,
+    /// Only Server-owned Entities can be delegated
+    EntityNotServerOwned,
+    /// The Entity already has a Component of the same kind
+    ComponentAlreadyInserted,
 }
 
 impl NaiaClientError {
@@ -21,6 +39,36 @@ impl fmt::Display for NaiaClientError {
             NaiaClientError::Wrapped(boxed_err) => fmt::Display::fmt(boxed_err.as_ref(), f),
             NaiaClientError::SendError => write!(f, "Naia Client Error: Send Error"),
             NaiaClientError::RecvError => write!(f, "Naia Client Error: Recv Error"),
+            NaiaClientError::MalformedPacket => {
+                write!(f, "Naia Client Error: Malformed packet from Server")
+            }
+            NaiaClientError::NotConnected => write!(f, "Naia Client Error: Connection has not yet been established! Make sure you call Client.connect() before calling this."),
+            NaiaClientError::AlreadyConnected => write!(f, "Naia Client Error: Client has already initiated a connection, cannot initiate a new one. TIP: Check client.is_disconnected() before calling client.connect()"),
+            NaiaClientError::EntityNotFound => {
+                write!(f, "Naia Client Error: No Entity exists for given Key!")
+            }
+            NaiaClientError::ClientAuthorityDisabled => write!(f, "Naia Client Error: Client Authoritative Entities are not enabled! Enable them in the Protocol, with the `enable_client_authoritative_entities() method"),
+            NaiaClientError::ChannelNotSendable => {
+                write!(f, "Naia Client Error: Cannot send message to Server on this Channel")
+            }
+            NaiaClientError::ChannelTickBufferMismatch => {
+                write!(f, "Naia Client Error: Tick Buffered Channels can only be sent on with `Client.send_tick_buffer_message()`")
+            }
+            NaiaClientError::EntityNotDelegated => {
+                write!(f, "Naia Client Error: Entity is not delegated by the Server!")
+            }
+            NaiaClientError::EntityNotServerOwned => {
+                write!(
+                    f,
+                    "Naia Client Error: Only Server-owned Entities can be delegated!"
+                )
+            }
+            NaiaClientError::ComponentAlreadyInserted => {
+                write!(
+                    f,
+                    "Naia Client Error: Entity already has a Component of this kind!"
+                )
+            }
         }
     }
 }
@@ -1,11 +1,13 @@
-use std::{collections::HashMap, marker::PhantomData, mem, net::SocketAddr, vec::IntoIter};
+use std::{
+    collections::HashMap, hash::Hash, marker::PhantomData, mem, net::SocketAddr, vec::IntoIter,
+};
 
 use naia_shared::{
-    Channel, ChannelKind, ComponentKind, EntityEvent, Message, MessageContainer, MessageKind,
-    Replicate, Tick,
+    Channel, ChannelKind, ComponentKind, ComponentKinds, EntityEvent, Message, MessageContainer,
+    MessageKind, PredictionKey, Replicate, Tick,
 };
 
-use crate::NaiaClientError;
+use crate::{world::global_world_manager::GlobalWorldManager, NaiaClientError};
 
 pub struct Events<E: Copy> {
     connections: Vec<SocketAddr>,
@@ -13,13 +15,24 @@ pub struct Events<E: Copy> {
     disconnections: Vec<SocketAddr>,
     client_ticks: Vec<Tick>,
     server_ticks: Vec<Tick>,
+    clock_jumps: Vec<i32>,
     errors: Vec<NaiaClientError>,
     messages: HashMap<ChannelKind, HashMap<MessageKind, Vec<MessageContainer>>>,
-    spawns: Vec<E>,
-    despawns: Vec<E>,
-    inserts: HashMap<ComponentKind, Vec<E>>,
-    removes: HashMap<ComponentKind, Vec<(E, Box<dyn Replicate>)>>,
+    spawns: Vec<(Tick, E)>,
+    despawns: Vec<(Tick, E)>,
+    inserts: HashMap<ComponentKind, Vec<(Tick, E)>>,
+    removes: HashMap<ComponentKind, Vec<(Tick, E, Box<dyn Replicate>)>>,
     updates: HashMap<ComponentKind, Vec<(Tick, E)>>,
+    auth_grants: Vec<E>,
+    auth_denies: Vec<E>,
+    auth_revokes: Vec<E>,
+    scope_exits: Vec<E>,
+    scope_enters: Vec<E>,
+    prediction_confirms: Vec<(PredictionKey, E)>,
+    prediction_rollbacks: Vec<(PredictionKey, E)>,
+    resource_inserts: HashMap<ComponentKind, Vec<(Tick, E)>>,
+    resource_updates: HashMap<ComponentKind, Vec<(Tick, E)>>,
+    resource_removes: HashMap<ComponentKind, Vec<(Tick, Box<dyn Replicate>)>>,
     empty: bool,
 }
 
@@ -37,6 +50,7 @@ impl<E: Copy> Events<E> {
             disconnections: Vec::new(),
             client_ticks: Vec::new(),
             server_ticks: Vec::new(),
+            clock_jumps: Vec::new(),
             errors: Vec::new(),
             messages: HashMap::new(),
             spawns: Vec::new(),
@@ -44,6 +58,16 @@ impl<E: Copy> Events<E> {
             inserts: HashMap::new(),
             removes: HashMap::new(),
             updates: HashMap::new(),
+            auth_grants: Vec::new(),
+            auth_denies: Vec::new(),
+            auth_revokes: Vec::new(),
+            scope_exits: Vec::new(),
+            scope_enters: Vec::new(),
+            prediction_confirms: Vec::new(),
+            prediction_rollbacks: Vec::new(),
+            resource_inserts: HashMap::new(),
+            resource_updates: HashMap::new(),
+            resource_removes: HashMap::new(),
             empty: true,
         }
     }
@@ -74,7 +98,7 @@ impl<E: Copy> Events<E> {
     pub fn has_inserts(&self) -> bool {
         !self.inserts.is_empty()
     }
-    pub fn take_inserts(&mut self) -> Option<HashMap<ComponentKind, Vec<E>>> {
+    pub fn take_inserts(&mut self) -> Option<HashMap<ComponentKind, Vec<(Tick, E)>>> {
         if self.inserts.is_empty() {
             return None;
         } else {
@@ -98,7 +122,9 @@ impl<E: Copy> Events<E> {
     pub fn has_removes(&self) -> bool {
         !self.removes.is_empty()
     }
-    pub fn take_removes(&mut self) -> Option<HashMap<ComponentKind, Vec<(E, Box<dyn Replicate>)>>> {
+    pub fn take_removes(
+        &mut self,
+    ) -> Option<HashMap<ComponentKind, Vec<(Tick, E, Box<dyn Replicate>)>>> {
         if self.removes.is_empty() {
             return None;
         } else {
@@ -106,6 +132,24 @@ impl<E: Copy> Events<E> {
         }
     }
 
+    // These methods are exposed for adapter crates ... prefer using Events.read::<SomeEvent>() instead.
+    pub fn has_resource_events(&self) -> bool {
+        !self.resource_inserts.is_empty()
+            || !self.resource_updates.is_empty()
+            || !self.resource_removes.is_empty()
+    }
+    pub fn take_resource_inserts(&mut self) -> HashMap<ComponentKind, Vec<(Tick, E)>> {
+        mem::take(&mut self.resource_inserts)
+    }
+    pub fn take_resource_updates(&mut self) -> HashMap<ComponentKind, Vec<(Tick, E)>> {
+        mem::take(&mut self.resource_updates)
+    }
+    pub fn take_resource_removes(
+        &mut self,
+    ) -> HashMap<ComponentKind, Vec<(Tick, Box<dyn Replicate>)>> {
+        mem::take(&mut self.resource_removes)
+    }
+
     // Crate-public
 
     pub(crate) fn push_connection(&mut self, socket_addr: &SocketAddr) {
@@ -148,27 +192,32 @@ impl<E: Copy> Events<E> {
         self.empty = false;
     }
 
+    pub(crate) fn push_clock_jump(&mut self, millis: i32) {
+        self.clock_jumps.push(millis);
+        self.empty = false;
+    }
+
     pub(crate) fn push_error(&mut self, error: NaiaClientError) {
         self.errors.push(error);
         self.empty = false;
     }
 
-    pub(crate) fn push_spawn(&mut self, entity: E) {
-        self.spawns.push(entity);
+    pub(crate) fn push_spawn(&mut self, tick: Tick, entity: E) {
+        self.spawns.push((tick, entity));
         self.empty = false;
     }
 
-    pub(crate) fn push_despawn(&mut self, entity: E) {
-        self.despawns.push(entity);
+    pub(crate) fn push_despawn(&mut self, tick: Tick, entity: E) {
+        self.despawns.push((tick, entity));
         self.empty = false;
     }
 
-    pub(crate) fn push_insert(&mut self, entity: E, component_kind: ComponentKind) {
+    pub(crate) fn push_insert(&mut self, tick: Tick, entity: E, component_kind: ComponentKind) {
         if !self.inserts.contains_key(&component_kind) {
             self.inserts.insert(component_kind, Vec::new());
         }
         let list = self.inserts.get_mut(&component_kind).unwrap();
-        list.push(entity);
+        list.push((tick, entity));
         self.empty = false;
     }
 
@@ -181,32 +230,137 @@ impl<E: Copy> Events<E> {
         self.empty = false;
     }
 
-    pub(crate) fn push_remove(&mut self, entity: E, component: Box<dyn Replicate>) {
+    pub(crate) fn push_remove(&mut self, tick: Tick, entity: E, component: Box<dyn Replicate>) {
         let component_kind: ComponentKind = component.kind();
         if !self.removes.contains_key(&component_kind) {
             self.removes.insert(component_kind, Vec::new());
         }
         let list = self.removes.get_mut(&component_kind).unwrap();
-        list.push((entity, component));
+        list.push((tick, entity, component));
+        self.empty = false;
+    }
+
+    pub(crate) fn push_auth_grant(&mut self, entity: E) {
+        self.auth_grants.push(entity);
+        self.empty = false;
+    }
+
+    pub(crate) fn push_auth_deny(&mut self, entity: E) {
+        self.auth_denies.push(entity);
+        self.empty = false;
+    }
+
+    pub(crate) fn push_auth_revoke(&mut self, entity: E) {
+        self.auth_revokes.push(entity);
         self.empty = false;
     }
 
-    pub(crate) fn receive_world_events(&mut self, entity_events: Vec<EntityEvent<E>>) {
+    pub(crate) fn push_scope_exit(&mut self, entity: E) {
+        self.scope_exits.push(entity);
+        self.empty = false;
+    }
+
+    pub(crate) fn push_scope_enter(&mut self, entity: E) {
+        self.scope_enters.push(entity);
+        self.empty = false;
+    }
+
+    pub(crate) fn push_prediction_confirm(&mut self, prediction_key: PredictionKey, entity: E) {
+        self.prediction_confirms.push((prediction_key, entity));
+        self.empty = false;
+    }
+
+    pub(crate) fn push_prediction_rollback(&mut self, prediction_key: PredictionKey, entity: E) {
+        self.prediction_rollbacks.push((prediction_key, entity));
+        self.empty = false;
+    }
+
+    pub(crate) fn push_resource_insert(
+        &mut self,
+        tick: Tick,
+        entity: E,
+        component_kind: ComponentKind,
+    ) {
+        self.resource_inserts
+            .entry(component_kind)
+            .or_default()
+            .push((tick, entity));
+        self.empty = false;
+    }
+
+    pub(crate) fn push_resource_update(
+        &mut self,
+        tick: Tick,
+        entity: E,
+        component_kind: ComponentKind,
+    ) {
+        self.resource_updates
+            .entry(component_kind)
+            .or_default()
+            .push((tick, entity));
+        self.empty = false;
+    }
+
+    pub(crate) fn push_resource_remove(&mut self, tick: Tick, component: Box<dyn Replicate>) {
+        self.resource_removes
+            .entry(component.kind())
+            .or_default()
+            .push((tick, component));
+        self.empty = false;
+    }
+
+    pub(crate) fn receive_world_events(
+        &mut self,
+        global_world_manager: &mut GlobalWorldManager<E>,
+        component_kinds: &ComponentKinds,
+        entity_events: Vec<EntityEvent<E>>,
+    ) where
+        E: Eq + Hash + Send + Sync,
+    {
+        // Resources are held by Entities of their own, which are recognized by
+        // their Component, and kept out of the Entity events
+        for event in &entity_events {
+            if let EntityEvent::InsertComponent(_, entity, component_kind) = event {
+                if component_kinds.is_resource(component_kind) {
+                    global_world_manager.insert_resource_entity(component_kind, entity);
+                }
+            }
+        }
+
         for event in entity_events {
             match event {
-                EntityEvent::SpawnEntity(entity) => {
-                    self.push_spawn(entity);
+                EntityEvent::SpawnEntity(tick, entity) => {
+                    if global_world_manager.entity_is_resource(&entity) {
+                        continue;
+                    }
+                    self.push_spawn(tick, entity);
                 }
-                EntityEvent::DespawnEntity(entity) => {
-                    self.push_despawn(entity);
+                EntityEvent::DespawnEntity(tick, entity) => {
+                    if global_world_manager.entity_is_resource(&entity) {
+                        global_world_manager.remove_resource_entity(&entity);
+                        continue;
+                    }
+                    self.push_despawn(tick, entity);
                 }
-                EntityEvent::InsertComponent(entity, component_kind) => {
-                    self.push_insert(entity, component_kind);
+                EntityEvent::InsertComponent(tick, entity, component_kind) => {
+                    if global_world_manager.entity_is_resource(&entity) {
+                        self.push_resource_insert(tick, entity, component_kind);
+                        continue;
+                    }
+                    self.push_insert(tick, entity, component_kind);
                 }
-                EntityEvent::RemoveComponent(entity, component_box) => {
-                    self.push_remove(entity, component_box);
+                EntityEvent::RemoveComponent(tick, entity, component_box) => {
+                    if global_world_manager.entity_is_resource(&entity) {
+                        self.push_resource_remove(tick, component_box);
+                        continue;
+                    }
+                    self.push_remove(tick, entity, component_box);
                 }
                 EntityEvent::UpdateComponent(tick, entity, component_kind) => {
+                    if global_world_manager.entity_is_resource(&entity) {
+                        self.push_resource_update(tick, entity, component_kind);
+                        continue;
+                    }
                     self.push_update(tick, entity, component_kind);
                 }
             }
@@ -219,6 +373,7 @@ impl<E: Copy> Events<E> {
         self.disconnections.clear();
         self.client_ticks.clear();
         self.server_ticks.clear();
+        self.clock_jumps.clear();
         self.errors.clear();
         self.messages.clear();
         self.spawns.clear();
@@ -226,6 +381,16 @@ impl<E: Copy> Events<E> {
         self.inserts.clear();
         self.removes.clear();
         self.updates.clear();
+        self.auth_grants.clear();
+        self.auth_denies.clear();
+        self.auth_revokes.clear();
+        self.scope_exits.clear();
+        self.scope_enters.clear();
+        self.prediction_confirms.clear();
+        self.prediction_rollbacks.clear();
+        self.resource_inserts.clear();
+        self.resource_updates.clear();
+        self.resource_removes.clear();
         self.empty = true;
     }
 }
@@ -314,6 +479,24 @@ impl<E: Copy> Event<E> for ServerTickEvent {
     }
 }
 
+// Server Clock Jump Event
+/// The Client's estimate of the Server's time was corrected by more than
+/// `ClientConfig::clock_jump_threshold`. Yields the correction in
+/// milliseconds, positive when the estimate moved forward.
+pub struct ServerClockJumpEvent;
+impl<E: Copy> Event<E> for ServerClockJumpEvent {
+    type Iter = IntoIter<i32>;
+
+    fn iter(events: &mut Events<E>) -> Self::Iter {
+        let list = std::mem::take(&mut events.clock_jumps);
+        return IntoIterator::into_iter(list);
+    }
+
+    fn has(events: &Events<E>) -> bool {
+        !events.clock_jumps.is_empty()
+    }
+}
+
 // Error Event
 pub struct ErrorEvent;
 impl<E: Copy> Event<E> for ErrorEvent {
@@ -369,7 +552,7 @@ impl<E: Copy, C: Channel, M: Message> Event<E> for MessageEvent<C, M> {
 // Spawn Event
 pub struct SpawnEntityEvent;
 impl<E: Copy> Event<E> for SpawnEntityEvent {
-    type Iter = IntoIter<E>;
+    type Iter = IntoIter<(Tick, E)>;
 
     fn iter(events: &mut Events<E>) -> Self::Iter {
         let list = std::mem::take(&mut events.spawns);
@@ -384,7 +567,7 @@ impl<E: Copy> Event<E> for SpawnEntityEvent {
 // Despawn Event
 pub struct DespawnEntityEvent;
 impl<E: Copy> Event<E> for DespawnEntityEvent {
-    type Iter = IntoIter<E>;
+    type Iter = IntoIter<(Tick, E)>;
 
     fn iter(events: &mut Events<E>) -> Self::Iter {
         let list = std::mem::take(&mut events.despawns);
@@ -401,7 +584,7 @@ pub struct InsertComponentEvent<C: Replicate> {
     phantom_c: PhantomData<C>,
 }
 impl<E: Copy, C: Replicate> Event<E> for InsertComponentEvent<C> {
-    type Iter = IntoIter<E>;
+    type Iter = IntoIter<(Tick, E)>;
 
     fn iter(events: &mut Events<E>) -> Self::Iter {
         let component_kind: ComponentKind = ComponentKind::of::<C>();
@@ -445,17 +628,17 @@ pub struct RemoveComponentEvent<C: Replicate> {
     phantom_c: PhantomData<C>,
 }
 impl<E: Copy, C: Replicate> Event<E> for RemoveComponentEvent<C> {
-    type Iter = IntoIter<(E, C)>;
+    type Iter = IntoIter<(Tick, E, C)>;
 
     fn iter(events: &mut Events<E>) -> Self::Iter {
         let component_kind: ComponentKind = ComponentKind::of::<C>();
         if let Some(boxed_list) = events.removes.remove(&component_kind) {
-            let mut output_list: Vec<(E, C)> = Vec::new();
+            let mut output_list: Vec<(Tick, E, C)> = Vec::new();
 
-            for (entity, boxed_component) in boxed_list {
+            for (tick, entity, boxed_component) in boxed_list {
                 let boxed_any = boxed_component.to_boxed_any();
                 let component = boxed_any.downcast::<C>().unwrap();
-                output_list.push((entity, *component));
+                output_list.push((tick, entity, *component));
             }
 
             return IntoIterator::into_iter(output_list);
@@ -469,3 +652,199 @@ impl<E: Copy, C: Replicate> Event<E> for RemoveComponentEvent<C> {
         events.removes.contains_key(&component_kind)
     }
 }
+
+// Entity Auth Granted Event
+pub struct EntityAuthGrantedEvent;
+impl<E: Copy> Event<E> for EntityAuthGrantedEvent {
+    type Iter = IntoIter<E>;
+
+    fn iter(events: &mut Events<E>) -> Self::Iter {
+        let list = std::mem::take(&mut events.auth_grants);
+        return IntoIterator::into_iter(list);
+    }
+
+    fn has(events: &Events<E>) -> bool {
+        !events.auth_grants.is_empty()
+    }
+}
+
+// Entity Auth Denied Event
+pub struct EntityAuthDeniedEvent;
+impl<E: Copy> Event<E> for EntityAuthDeniedEvent {
+    type Iter = IntoIter<E>;
+
+    fn iter(events: &mut Events<E>) -> Self::Iter {
+        let list = std::mem::take(&mut events.auth_denies);
+        return IntoIterator::into_iter(list);
+    }
+
+    fn has(events: &Events<E>) -> bool {
+        !events.auth_denies.is_empty()
+    }
+}
+
+// Entity Auth Revoked Event
+pub struct EntityAuthRevokedEvent;
+impl<E: Copy> Event<E> for EntityAuthRevokedEvent {
+    type Iter = IntoIter<E>;
+
+    fn iter(events: &mut Events<E>) -> Self::Iter {
+        let list = std::mem::take(&mut events.auth_revokes);
+        return IntoIterator::into_iter(list);
+    }
+
+    fn has(events: &Events<E>) -> bool {
+        !events.auth_revokes.is_empty()
+    }
+}
+
+// Entity Left Scope Event
+/// A dormant Server Entity left the Client's scope. It is kept with its last
+/// known state, but receives no updates until it re-enters the scope.
+pub struct EntityLeftScopeEvent;
+impl<E: Copy> Event<E> for EntityLeftScopeEvent {
+    type Iter = IntoIter<E>;
+
+    fn iter(events: &mut Events<E>) -> Self::Iter {
+        let list = std::mem::take(&mut events.scope_exits);
+        return IntoIterator::into_iter(list);
+    }
+
+    fn has(events: &Events<E>) -> bool {
+        !events.scope_exits.is_empty()
+    }
+}
+
+// Entity Entered Scope Event
+/// A dormant Server Entity re-entered the Client's scope, and receives
+/// updates again
+pub struct EntityEnteredScopeEvent;
+impl<E: Copy> Event<E> for EntityEnteredScopeEvent {
+    type Iter = IntoIter<E>;
+
+    fn iter(events: &mut Events<E>) -> Self::Iter {
+        let list = std::mem::take(&mut events.scope_enters);
+        return IntoIterator::into_iter(list);
+    }
+
+    fn has(events: &Events<E>) -> bool {
+        !events.scope_enters.is_empty()
+    }
+}
+
+// Predicted Spawn Confirmed Event
+/// The Server confirmed an Entity spawned in prediction, which now is the
+/// Server Entity
+pub struct PredictedSpawnConfirmedEvent;
+impl<E: Copy> Event<E> for PredictedSpawnConfirmedEvent {
+    type Iter = IntoIter<(PredictionKey, E)>;
+
+    fn iter(events: &mut Events<E>) -> Self::Iter {
+        let list = std::mem::take(&mut events.prediction_confirms);
+        return IntoIterator::into_iter(list);
+    }
+
+    fn has(events: &Events<E>) -> bool {
+        !events.prediction_confirms.is_empty()
+    }
+}
+
+// Predicted Spawn Rolled Back Event
+/// The Server did not confirm an Entity spawned in prediction in time, and it
+/// was despawned
+pub struct PredictedSpawnRolledBackEvent;
+impl<E: Copy> Event<E> for PredictedSpawnRolledBackEvent {
+    type Iter = IntoIter<(PredictionKey, E)>;
+
+    fn iter(events: &mut Events<E>) -> Self::Iter {
+        let list = std::mem::take(&mut events.prediction_rollbacks);
+        return IntoIterator::into_iter(list);
+    }
+
+    fn has(events: &Events<E>) -> bool {
+        !events.prediction_rollbacks.is_empty()
+    }
+}
+
+// Insert Resource Event
+/// A Resource was replicated from the Server
+pub struct InsertResourceEvent<R: Replicate> {
+    phantom_r: PhantomData<R>,
+}
+impl<E: Copy, R: Replicate> Event<E> for InsertResourceEvent<R> {
+    type Iter = IntoIter<Tick>;
+
+    fn iter(events: &mut Events<E>) -> Self::Iter {
+        let component_kind: ComponentKind = ComponentKind::of::<R>();
+        let list: Vec<Tick> = events
+            .resource_inserts
+            .remove(&component_kind)
+            .unwrap_or_default()
+            .into_iter()
+            .map(|(tick, _)| tick)
+            .collect();
+        return IntoIterator::into_iter(list);
+    }
+
+    fn has(events: &Events<E>) -> bool {
+        let component_kind: ComponentKind = ComponentKind::of::<R>();
+        events.resource_inserts.contains_key(&component_kind)
+    }
+}
+
+// Update Resource Event
+pub struct UpdateResourceEvent<R: Replicate> {
+    phantom_r: PhantomData<R>,
+}
+impl<E: Copy, R: Replicate> Event<E> for UpdateResourceEvent<R> {
+    type Iter = IntoIter<Tick>;
+
+    fn iter(events: &mut Events<E>) -> Self::Iter {
+        let component_kind: ComponentKind = ComponentKind::of::<R>();
+        let list: Vec<Tick> = events
+            .resource_updates
+            .remove(&component_kind)
+            .unwrap_or_default()
+            .into_iter()
+            .map(|(tick, _)| tick)
+            .collect();
+        return IntoIterator::into_iter(list);
+    }
+
+    fn has(events: &Events<E>) -> bool {
+        let component_kind: ComponentKind = ComponentKind::of::<R>();
+        events.resource_updates.contains_key(&component_kind)
+    }
+}
+
+// Remove Resource Event
+/// A Resource was removed on the Server, or left the Client's scope along
+/// with its Room. Yields its last value.
+pub struct RemoveResourceEvent<R: Replicate> {
+    phantom_r: PhantomData<R>,
+}
+impl<E: Copy, R: Replicate> Event<E> for RemoveResourceEvent<R> {
+    type Iter = IntoIter<(Tick, R)>;
+
+    fn iter(events: &mut Events<E>) -> Self::Iter {
+        let component_kind: ComponentKind = ComponentKind::of::<R>();
+        if let Some(boxed_list) = events.resource_removes.remove(&component_kind) {
+            let mut output_list: Vec<(Tick, R)> = Vec::new();
+
+            for (tick, boxed_resource) in boxed_list {
+                let boxed_any = boxed_resource.to_boxed_any();
+                let resource = boxed_any.downcast::<R>().unwrap();
+                output_list.push((tick, *resource));
+            }
+
+            return IntoIterator::into_iter(output_list);
+        }
+
+        return IntoIterator::into_iter(Vec::new());
+    }
+
+    fn has(events: &Events<E>) -> bool {
+        let component_kind: ComponentKind = ComponentKind::of::<R>();
+        events.resource_removes.contains_key(&component_kind)
+    }
+}
@@ -0,0 +1,210 @@
+use std::{
+    collections::{HashMap, VecDeque},
+    hash::Hash,
+};
+
+use naia_shared::{sequence_greater_than, Tick};
+
+use crate::Client;
+
+/// A value which can be blended between two snapshots of itself
+pub trait Interpolate: Clone {
+    /// Returns the value `fraction` of the way from `self` to `next`. The
+    /// fraction is greater than 1.0 when extrapolating past `next`.
+    fn interpolate(&self, next: &Self, fraction: f32) -> Self;
+}
+
+impl Interpolate for f32 {
+    fn interpolate(&self, next: &Self, fraction: f32) -> Self {
+        self + (next - self) * fraction
+    }
+}
+
+impl Interpolate for f64 {
+    fn interpolate(&self, next: &Self, fraction: f32) -> Self {
+        self + (next - self) * f64::from(fraction)
+    }
+}
+
+/// Keeps a short, Tick-indexed history of a replicated value per Entity, and
+/// blends between those snapshots for render time.
+///
+/// Feed it the received state along with the Tick carried by
+/// `InsertComponentEvent` / `UpdateComponentEvent`, then read values with
+/// `render_value()` each frame.
+pub struct InterpolationBuffer<E: Copy + Eq + Hash, C: Interpolate> {
+    snapshots: HashMap<E, VecDeque<(Tick, C)>>,
+    history_length: u16,
+    max_extrapolation: f32,
+}
+
+impl<E: Copy + Eq + Hash, C: Interpolate> Default for InterpolationBuffer<E, C> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<E: Copy + Eq + Hash, C: Interpolate> InterpolationBuffer<E, C> {
+    /// Creates a new InterpolationBuffer, which keeps 8 Ticks of history and
+    /// extrapolates at most 1 Tick past the newest snapshot
+    pub fn new() -> Self {
+        Self {
+            snapshots: HashMap::new(),
+            history_length: 8,
+            max_extrapolation: 1.0,
+        }
+    }
+
+    /// Sets how many Ticks of snapshots are kept behind the newest one
+    pub fn set_history_length(&mut self, ticks: u16) {
+        self.history_length = ticks.max(1);
+    }
+
+    /// Sets how many Ticks past the newest snapshot a value is extrapolated,
+    /// before it is held in place
+    pub fn set_max_extrapolation(&mut self, ticks: f32) {
+        self.max_extrapolation = ticks.max(0.0);
+    }
+
+    /// Records the state of an Entity's value at the given Server Tick.
+    /// Snapshots older than the kept history are ignored.
+    pub fn insert(&mut self, entity: &E, tick: Tick, value: C) {
+        let history = self.snapshots.entry(*entity).or_default();
+
+        if let Some((newest_tick, _)) = history.back() {
+            if sequence_greater_than(newest_tick.wrapping_sub(self.history_length), tick) {
+                return;
+            }
+        }
+
+        // keep the history ordered, replacing any snapshot at the same Tick
+        let mut index = history.len();
+        while index > 0 {
+            let (other_tick, _) = &history[index - 1];
+            if *other_tick == tick {
+                history[index - 1].1 = value;
+                return;
+            }
+            if sequence_greater_than(tick, *other_tick) {
+                break;
+            }
+            index -= 1;
+        }
+        history.insert(index, (tick, value));
+
+        // prune snapshots which fell out of the history
+        let (newest_tick, _) = history.back().unwrap();
+        let oldest_kept_tick = newest_tick.wrapping_sub(self.history_length);
+        while let Some((oldest_tick, _)) = history.front() {
+            if !sequence_greater_than(oldest_kept_tick, *oldest_tick) {
+                break;
+            }
+            history.pop_front();
+        }
+    }
+
+    /// Forgets an Entity, i.e. after it has despawned
+    pub fn remove_entity(&mut self, entity: &E) {
+        self.snapshots.remove(entity);
+    }
+
+    /// Returns the value of an Entity `fraction` of the way from `tick` to the
+    /// next Tick. Blends across gaps in the history, and extrapolates from
+    /// the two newest snapshots up to the configured cap.
+    pub fn value(&self, entity: &E, tick: Tick, fraction: f32) -> Option<C> {
+        let history = self.snapshots.get(entity)?;
+        let (newest_tick, newest_value) = history.back()?;
+
+        // time of each snapshot relative to the newest one, in Ticks
+        let offset_of =
+            |other_tick: Tick| -> f32 { f32::from(tick_diff(other_tick, *newest_tick)) };
+        let time = f32::from(tick_diff(tick, *newest_tick)) + fraction;
+
+        if time >= 0.0 {
+            // past the newest snapshot, extrapolate
+            if history.len() < 2 || self.max_extrapolation == 0.0 {
+                return Some(newest_value.clone());
+            }
+            let (previous_tick, previous_value) = &history[history.len() - 2];
+            let span = -offset_of(*previous_tick);
+            let time = time.min(self.max_extrapolation);
+            return Some(previous_value.interpolate(newest_value, (span + time) / span));
+        }
+
+        let (oldest_tick, oldest_value) = history.front().unwrap();
+        if time <= offset_of(*oldest_tick) {
+            // before the oldest snapshot
+            return Some(oldest_value.clone());
+        }
+
+        for index in 1..history.len() {
+            let (next_tick, next_value) = &history[index];
+            let next_time = offset_of(*next_tick);
+            if time > next_time {
+                continue;
+            }
+            let (previous_tick, previous_value) = &history[index - 1];
+            let previous_time = offset_of(*previous_tick);
+            let blend = (time - previous_time) / (next_time - previous_time);
+            return Some(previous_value.interpolate(next_value, blend));
+        }
+
+        Some(newest_value.clone())
+    }
+
+    /// Returns the value of an Entity for the current frame. This renders one
+    /// Tick behind the Client's receiving Tick, so that there is a snapshot on
+    /// either side to blend between.
+    pub fn render_value(&self, client: &Client<E>, entity: &E) -> Option<C>
+    where
+        E: Send + Sync,
+    {
+        let tick = client.server_tick()?;
+        let fraction = client.server_interpolation()?;
+        self.value(entity, tick.wrapping_sub(1), fraction)
+    }
+}
+
+/// Returns how many Ticks `a` is ahead of `b`, accounting for wrap-around
+fn tick_diff(a: Tick, b: Tick) -> i16 {
+    a.wrapping_sub(b) as i16
+}
+
+#[cfg(test)]
+mod tests {
+    use super::InterpolationBuffer;
+
+    #[test]
+    fn interpolates_across_gaps() {
+        let mut buffer = InterpolationBuffer::<u32, f32>::new();
+        buffer.insert(&1, 10, 0.0);
+        buffer.insert(&1, 11, 10.0);
+        // Tick 12 was never received
+        buffer.insert(&1, 13, 30.0);
+
+        assert_eq!(buffer.value(&1, 10, 0.5), Some(5.0));
+        assert_eq!(buffer.value(&1, 12, 0.0), Some(20.0));
+        assert_eq!(buffer.value(&1, 12, 0.5), Some(25.0));
+        // before the oldest snapshot
+        assert_eq!(buffer.value(&1, 8, 0.0), Some(0.0));
+        assert_eq!(buffer.value(&2, 10, 0.0), None);
+    }
+
+    #[test]
+    fn extrapolation_is_capped() {
+        let mut buffer = InterpolationBuffer::<u32, f32>::new();
+        buffer.set_max_extrapolation(2.0);
+        buffer.insert(&1, 65535, 0.0);
+        buffer.insert(&1, 0, 10.0);
+
+        assert_eq!(buffer.value(&1, 0, 0.5), Some(15.0));
+        assert_eq!(buffer.value(&1, 2, 0.0), Some(30.0));
+        assert_eq!(buffer.value(&1, 5, 0.0), Some(30.0));
+
+        // older than the kept history
+        buffer.set_history_length(4);
+        buffer.insert(&1, 10, 100.0);
+        buffer.insert(&1, 5, 50.0);
+        assert_eq!(buffer.value(&1, 5, 0.0), Some(100.0));
+    }
+}
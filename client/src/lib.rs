@@ -16,7 +16,8 @@ extern crate cfg_if;
 pub mod transport;
 pub mod shared {
     pub use naia_shared::{
-        default_channels, sequence_greater_than, EntityRef, Random, SocketConfig, Tick,
+        default_channels, sequence_greater_than, BandwidthBreakdown, BandwidthShare, EntityRef,
+        LockstepFrame, NetworkStats, PrometheusExporter, Random, SocketConfig, Tick,
     };
 }
 pub mod internal {
@@ -29,15 +30,25 @@ mod command_history;
 mod connection;
 mod error;
 mod events;
+mod interpolation;
+mod lockstep;
+mod prediction;
 mod world;
 
 pub use client::Client;
 pub use client_config::ClientConfig;
 pub use command_history::CommandHistory;
+pub use connection::{network_clock::NetworkClock, tick_buffer_messages::TickBufferMessages};
 pub use error::NaiaClientError;
 pub use events::{
-    ClientTickEvent, ConnectEvent, DespawnEntityEvent, DisconnectEvent, ErrorEvent, Events,
-    InsertComponentEvent, MessageEvent, RejectEvent, RemoveComponentEvent, ServerTickEvent,
-    SpawnEntityEvent, UpdateComponentEvent,
+    ClientTickEvent, ConnectEvent, DespawnEntityEvent, DisconnectEvent, EntityAuthDeniedEvent,
+    EntityAuthGrantedEvent, EntityAuthRevokedEvent, EntityEnteredScopeEvent, EntityLeftScopeEvent,
+    ErrorEvent, Events, InsertComponentEvent, InsertResourceEvent, MessageEvent,
+    PredictedSpawnConfirmedEvent, PredictedSpawnRolledBackEvent, RejectEvent, RemoveComponentEvent,
+    RemoveResourceEvent, ServerClockJumpEvent, ServerTickEvent, SpawnEntityEvent,
+    UpdateComponentEvent, UpdateResourceEvent,
 };
-pub use world::entity_mut::EntityMut;
+pub use interpolation::{Interpolate, InterpolationBuffer};
+pub use lockstep::Lockstep;
+pub use prediction::{Predict, Prediction};
+pub use world::{entity_auth_status::EntityAuthStatus, entity_mut::EntityMut};
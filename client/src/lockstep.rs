@@ -0,0 +1,255 @@
+use std::{collections::VecDeque, hash::Hash};
+
+use log::warn;
+
+use naia_shared::{
+    sequence_greater_than, LockstepChannel, LockstepChecksumMessage, LockstepFrame,
+    LockstepFrameMessage, LockstepInputChannel, LockstepInputMessage, LockstepJoinMessage, Serde,
+    Tick,
+};
+
+use crate::{events::MessageEvent, Client, Events};
+
+/// Runs the Client side of a deterministic lockstep simulation, driven by the
+/// Server's `Lockstep`. The Protocol must add the `LockstepPlugin`.
+///
+/// Send the local input on every Client Tick with `send_input()`, which
+/// schedules it `input_delay()` Ticks ahead. Pass each frame's Events to
+/// `receive()`, then simulate every Frame returned by `next_frame()`, in
+/// order. After simulating a Tick, `send_checksum()` lets the Server detect
+/// players whose simulations diverged.
+///
+/// A Client joining a simulation already underway starts from `next_tick()`,
+/// so it needs the simulation state as of that Tick from the application.
+pub struct Lockstep<I: Serde> {
+    player: Option<u16>,
+    input_delay: u16,
+    /// The Tick of the next Frame to simulate
+    next_tick: Option<Tick>,
+    frames: VecDeque<LockstepFrame<I>>,
+    missing_tick: Option<Tick>,
+}
+
+impl<I: Serde> Default for Lockstep<I> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<I: Serde> Lockstep<I> {
+    pub fn new() -> Self {
+        Self {
+            player: None,
+            input_delay: 0,
+            next_tick: None,
+            frames: VecDeque::new(),
+            missing_tick: None,
+        }
+    }
+
+    /// Gets the local player id, once the Server added this Client as a player
+    pub fn player(&self) -> Option<u16> {
+        self.player
+    }
+
+    /// How many Ticks ahead of the Client Tick inputs are scheduled
+    pub fn input_delay(&self) -> u16 {
+        self.input_delay
+    }
+
+    /// The Tick of the next Frame to simulate, once joined
+    pub fn next_tick(&self) -> Option<Tick> {
+        self.next_tick
+    }
+
+    // Inputs
+
+    /// Sends the local input for the given Client Tick, scheduled
+    /// `input_delay()` Ticks ahead. Returns the Tick it will be simulated on,
+    /// or None if this Client has not joined yet.
+    pub fn send_input<E: Copy + Eq + Hash + Send + Sync>(
+        &self,
+        client: &mut Client<E>,
+        client_tick: Tick,
+        input: &I,
+    ) -> Option<Tick> {
+        self.player?;
+        let tick = client_tick.wrapping_add(self.input_delay);
+        // the LockstepPlugin sets up the Channels, so sending can not fail
+        let _ = client.send_tick_buffer_message::<LockstepInputChannel, _>(
+            &tick,
+            &LockstepInputMessage::new(input),
+        );
+        Some(tick)
+    }
+
+    /// Sends a checksum of the simulated state after the given Tick, to be
+    /// compared with the other players'
+    pub fn send_checksum<E: Copy + Eq + Hash + Send + Sync>(
+        &self,
+        client: &mut Client<E>,
+        tick: Tick,
+        checksum: u64,
+    ) {
+        let _ = client
+            .send_message::<LockstepChannel, _>(&LockstepChecksumMessage::new(tick, checksum));
+    }
+
+    // Frames
+
+    /// Reads the lockstep messages from the Server out of the Events
+    pub fn receive<E: Copy>(&mut self, events: &mut Events<E>) {
+        for message in events.read::<MessageEvent<LockstepChannel, LockstepJoinMessage>>() {
+            self.receive_join(&message);
+        }
+        for message in events.read::<MessageEvent<LockstepChannel, LockstepFrameMessage>>() {
+            self.receive_frame(&message);
+        }
+    }
+
+    fn receive_join(&mut self, message: &LockstepJoinMessage) {
+        self.player = Some(message.player);
+        self.input_delay = message.input_delay;
+        self.next_tick = Some(message.start_tick);
+        self.frames.clear();
+        self.missing_tick = None;
+    }
+
+    fn receive_frame(&mut self, message: &LockstepFrameMessage) {
+        let Some(next_tick) = self.next_tick else {
+            return;
+        };
+        if self.missing_tick.is_some() {
+            // no later Frame can be simulated
+            return;
+        }
+        let expected_tick = match self.frames.back() {
+            Some(frame) => frame.tick().wrapping_add(1),
+            None => next_tick,
+        };
+        if sequence_greater_than(expected_tick, message.tick) {
+            return;
+        }
+        if message.tick != expected_tick {
+            warn!("Lockstep: Frame {expected_tick} is missing");
+            self.missing_tick = Some(expected_tick);
+            return;
+        }
+        match message.to_frame::<I>() {
+            Ok(frame) => self.frames.push_back(frame),
+            Err(_) => {
+                warn!("Lockstep: Frame {} is malformed", message.tick);
+                self.missing_tick = Some(message.tick);
+            }
+        }
+    }
+
+    /// Gets the Tick of a Frame which never arrived or could not be read. The
+    /// simulation cannot continue past it, so `next_frame()` stops before it
+    /// until the Client joins again.
+    pub fn missing_frame(&self) -> Option<Tick> {
+        self.missing_tick
+    }
+
+    /// Takes the Frame of the next Tick to simulate, if it has arrived. Frames
+    /// received on joining late are returned back to back, so that the
+    /// simulation catches up.
+    pub fn next_frame(&mut self) -> Option<LockstepFrame<I>> {
+        let next_tick = self.next_tick?;
+        if self.frames.front()?.tick() != next_tick {
+            return None;
+        }
+        self.next_tick = Some(next_tick.wrapping_add(1));
+        self.frames.pop_front()
+    }
+
+    /// Gets how many Ticks the Frame needed next is overdue, given the
+    /// Client's current Server Tick. The simulation is stalled waiting on the
+    /// network while this is above 0.
+    pub fn stalled_ticks(&self, server_tick: Tick) -> u16 {
+        let Some(next_tick) = self.next_tick else {
+            return 0;
+        };
+        if !self.frames.is_empty() || sequence_greater_than(next_tick, server_tick) {
+            return 0;
+        }
+        server_tick.wrapping_sub(next_tick).wrapping_add(1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use naia_shared::{LockstepFrameMessage, LockstepInputMessage, LockstepJoinMessage};
+
+    use super::Lockstep;
+
+    fn frame_message(tick: u16, input: u8) -> LockstepFrameMessage {
+        let mut message = LockstepFrameMessage::new(tick);
+        message
+            .inputs
+            .push((0, LockstepInputMessage::new(&input).input));
+        message
+    }
+
+    #[test]
+    fn frames_play_in_order_after_joining() {
+        let mut lockstep = Lockstep::<u8>::new();
+
+        // Frames before joining are ignored
+        lockstep.receive_frame(&frame_message(65535, 1));
+        assert!(lockstep.next_frame().is_none());
+
+        lockstep.receive_join(&LockstepJoinMessage::new(3, 65535, 2));
+        assert_eq!(lockstep.player(), Some(3));
+        assert_eq!(lockstep.stalled_ticks(65534), 0);
+        assert_eq!(lockstep.stalled_ticks(1), 3);
+
+        // catching up across the Tick wrap
+        lockstep.receive_frame(&frame_message(65535, 1));
+        lockstep.receive_frame(&frame_message(0, 2));
+        lockstep.receive_frame(&frame_message(0, 9));
+        assert_eq!(lockstep.stalled_ticks(1), 0);
+
+        let frame = lockstep.next_frame().unwrap();
+        assert_eq!(frame.tick(), 65535);
+        assert_eq!(frame.input(0), Some(&1));
+        let frame = lockstep.next_frame().unwrap();
+        assert_eq!(frame.tick(), 0);
+        assert_eq!(frame.input(0), Some(&2));
+        assert!(lockstep.next_frame().is_none());
+        assert_eq!(lockstep.next_tick(), Some(1));
+        assert_eq!(lockstep.stalled_ticks(1), 1);
+    }
+
+    #[test]
+    fn malformed_frame_is_reported_missing() {
+        let mut lockstep = Lockstep::<u8>::new();
+        lockstep.receive_join(&LockstepJoinMessage::new(0, 10, 2));
+
+        lockstep.receive_frame(&frame_message(10, 1));
+        let mut malformed = LockstepFrameMessage::new(11);
+        malformed.inputs.push((0, Vec::new()));
+        lockstep.receive_frame(&malformed);
+        lockstep.receive_frame(&frame_message(12, 3));
+        assert_eq!(lockstep.missing_frame(), Some(11));
+
+        assert_eq!(lockstep.next_frame().unwrap().tick(), 10);
+        assert!(lockstep.next_frame().is_none());
+
+        // joining again starts over
+        lockstep.receive_join(&LockstepJoinMessage::new(0, 12, 2));
+        assert_eq!(lockstep.missing_frame(), None);
+        lockstep.receive_frame(&frame_message(12, 3));
+        assert_eq!(lockstep.next_frame().unwrap().tick(), 12);
+    }
+
+    #[test]
+    fn skipped_frame_is_reported_missing() {
+        let mut lockstep = Lockstep::<u8>::new();
+        lockstep.receive_join(&LockstepJoinMessage::new(0, 10, 2));
+
+        lockstep.receive_frame(&frame_message(11, 1));
+        assert_eq!(lockstep.missing_frame(), Some(10));
+        assert!(lockstep.next_frame().is_none());
+    }
+}
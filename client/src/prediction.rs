@@ -0,0 +1,262 @@
+use std::{
+    collections::{HashMap, VecDeque},
+    hash::Hash,
+};
+
+use log::warn;
+
+use naia_shared::{sequence_greater_than, Tick};
+
+use crate::{CommandHistory, Interpolate};
+
+/// Predicted state, which can be checked against authoritative state from
+/// the Server
+pub trait Predict: Interpolate + PartialEq {
+    /// Returns whether the predicted state differs enough from the
+    /// authoritative state to need a correction. Override this to compare
+    /// with a tolerance.
+    fn mispredicted(&self, authoritative: &Self) -> bool {
+        self != authoritative
+    }
+}
+
+impl Predict for f32 {}
+impl Predict for f64 {}
+
+struct PredictedEntity<S: Predict, C: Clone> {
+    commands: CommandHistory<C>,
+    /// Predicted state after the command of each Tick was applied, oldest first
+    snapshots: VecDeque<(Tick, S)>,
+    state: S,
+    /// The state which was shown before the last correction, advanced along
+    /// with the predicted state, and how much of it is still shown
+    correction: Option<(S, f32)>,
+}
+
+/// Runs Client-side prediction for Entities driven by local commands.
+///
+/// Apply each command with `predict()` on the Client Tick it is sent for,
+/// and pass authoritative state received for a Server Tick to `reconcile()`.
+/// On a misprediction, the state is restored to the authoritative state and
+/// all later commands are replayed through the step function.
+pub struct Prediction<E: Copy + Eq + Hash, S: Predict, C: Clone> {
+    entities: HashMap<E, PredictedEntity<S, C>>,
+    smoothing: f32,
+}
+
+impl<E: Copy + Eq + Hash, S: Predict, C: Clone> Default for Prediction<E, S, C> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<E: Copy + Eq + Hash, S: Predict, C: Clone> Prediction<E, S, C> {
+    pub fn new() -> Self {
+        Self {
+            entities: HashMap::new(),
+            smoothing: 0.0,
+        }
+    }
+
+    /// Sets how much of a correction is still shown by `render_state()` after
+    /// each predicted Tick, between 0.0 (corrections snap into place, the
+    /// default) and 1.0
+    pub fn set_smoothing(&mut self, smoothing: f32) {
+        self.smoothing = smoothing.clamp(0.0, 1.0);
+    }
+
+    // Entities
+
+    /// Starts predicting an Entity from the given state
+    pub fn register(&mut self, entity: &E, state: S) {
+        self.entities.insert(
+            *entity,
+            PredictedEntity {
+                commands: CommandHistory::default(),
+                snapshots: VecDeque::new(),
+                state,
+                correction: None,
+            },
+        );
+    }
+
+    /// Stops predicting an Entity
+    pub fn unregister(&mut self, entity: &E) {
+        self.entities.remove(entity);
+    }
+
+    pub fn is_predicted(&self, entity: &E) -> bool {
+        self.entities.contains_key(entity)
+    }
+
+    /// Gets the predicted state of an Entity
+    pub fn state(&self, entity: &E) -> Option<&S> {
+        self.entities
+            .get(entity)
+            .map(|predicted_entity| &predicted_entity.state)
+    }
+
+    /// Gets the state of an Entity to show, which eases out of the last
+    /// correction if smoothing is enabled
+    pub fn render_state(&self, entity: &E) -> Option<S> {
+        let predicted_entity = self.entities.get(entity)?;
+        match &predicted_entity.correction {
+            Some((shown, weight)) => Some(predicted_entity.state.interpolate(shown, *weight)),
+            None => Some(predicted_entity.state.clone()),
+        }
+    }
+
+    // Ticks
+
+    /// Applies a command to the predicted state of an Entity for the given
+    /// Client Tick, and records both. Ticks must be given in increasing order.
+    /// Returns false without applying the command if the Entity is not
+    /// registered, or the Tick was already predicted.
+    pub fn predict<F: FnMut(&mut S, &C)>(
+        &mut self,
+        entity: &E,
+        tick: Tick,
+        command: C,
+        mut step: F,
+    ) -> bool {
+        let Some(predicted_entity) = self.entities.get_mut(entity) else {
+            warn!("Prediction: ignoring command for an Entity which is not registered");
+            return false;
+        };
+        if !predicted_entity.commands.can_insert(&tick) {
+            warn!("Prediction: ignoring command for a Tick which was already predicted");
+            return false;
+        }
+
+        step(&mut predicted_entity.state, &command);
+        predicted_entity
+            .snapshots
+            .push_back((tick, predicted_entity.state.clone()));
+
+        if let Some((shown, weight)) = &mut predicted_entity.correction {
+            step(shown, &command);
+            *weight *= self.smoothing;
+            if *weight < 0.01 {
+                predicted_entity.correction = None;
+            }
+        }
+
+        predicted_entity.commands.insert(tick, command);
+
+        true
+    }
+
+    /// Checks the authoritative state of an Entity at the given Tick against
+    /// what was predicted for it. On a misprediction, restores the
+    /// authoritative state and replays every later command through `step`.
+    /// Returns whether a correction was made.
+    pub fn reconcile<F: FnMut(&mut S, &C)>(
+        &mut self,
+        entity: &E,
+        tick: Tick,
+        authoritative: &S,
+        mut step: F,
+    ) -> bool {
+        let Some(predicted_entity) = self.entities.get_mut(entity) else {
+            return false;
+        };
+
+        // drop the snapshots which were already confirmed
+        while let Some((snapshot_tick, _)) = predicted_entity.snapshots.front() {
+            if !sequence_greater_than(tick, *snapshot_tick) {
+                break;
+            }
+            predicted_entity.snapshots.pop_front();
+        }
+
+        let predicted_correctly = match predicted_entity.snapshots.front() {
+            Some((snapshot_tick, snapshot)) if *snapshot_tick == tick => {
+                !snapshot.mispredicted(authoritative)
+            }
+            _ => false,
+        };
+        let replays = predicted_entity.commands.replays(&tick);
+        if predicted_correctly {
+            predicted_entity.snapshots.pop_front();
+            return false;
+        }
+
+        // restore the authoritative state, and replay the commands since
+        let shown = match &predicted_entity.correction {
+            Some((shown, weight)) => predicted_entity.state.interpolate(shown, *weight),
+            None => predicted_entity.state.clone(),
+        };
+        predicted_entity.state = authoritative.clone();
+        predicted_entity.snapshots.clear();
+        // replays are ordered from newest to oldest
+        for (command_tick, command) in replays.iter().rev() {
+            step(&mut predicted_entity.state, command);
+            predicted_entity
+                .snapshots
+                .push_back((*command_tick, predicted_entity.state.clone()));
+        }
+
+        if self.smoothing > 0.0 {
+            predicted_entity.correction = Some((shown, 1.0));
+        }
+
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Prediction;
+
+    fn step(state: &mut f32, command: &f32) {
+        *state += command;
+    }
+
+    #[test]
+    fn corrects_mispredictions_by_replaying() {
+        let mut prediction = Prediction::<u32, f32, f32>::new();
+        prediction.register(&1, 0.0);
+        assert!(prediction.predict(&1, 1, 1.0, step));
+        assert!(prediction.predict(&1, 2, 2.0, step));
+        assert!(prediction.predict(&1, 3, 3.0, step));
+        assert_eq!(prediction.state(&1), Some(&6.0));
+
+        // the Server agrees with Tick 1
+        assert!(!prediction.reconcile(&1, 1, &1.0, step));
+        assert_eq!(prediction.state(&1), Some(&6.0));
+
+        // the Server was pushed at Tick 2, so Ticks 2 & 3 are replayed from there
+        assert!(prediction.reconcile(&1, 2, &10.0, step));
+        assert_eq!(prediction.state(&1), Some(&13.0));
+
+        // the replayed prediction for Tick 3 is kept
+        assert!(!prediction.reconcile(&1, 3, &13.0, step));
+    }
+
+    #[test]
+    fn smoothing_eases_out_of_corrections() {
+        let mut prediction = Prediction::<u32, f32, f32>::new();
+        prediction.set_smoothing(0.5);
+        prediction.register(&1, 0.0);
+        prediction.predict(&1, 1, 0.0, step);
+
+        assert!(prediction.reconcile(&1, 1, &8.0, step));
+        assert_eq!(prediction.render_state(&1), Some(0.0));
+
+        prediction.predict(&1, 2, 1.0, step);
+        assert_eq!(prediction.state(&1), Some(&9.0));
+        assert_eq!(prediction.render_state(&1), Some(5.0));
+    }
+
+    #[test]
+    fn ignores_unregistered_entities_and_repeated_ticks() {
+        let mut prediction = Prediction::<u32, f32, f32>::new();
+        assert!(!prediction.predict(&1, 1, 1.0, step));
+        assert_eq!(prediction.state(&1), None);
+
+        prediction.register(&1, 0.0);
+        assert!(prediction.predict(&1, 1, 1.0, step));
+        assert!(!prediction.predict(&1, 1, 5.0, step));
+        assert_eq!(prediction.state(&1), Some(&1.0));
+    }
+}
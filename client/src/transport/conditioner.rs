@@ -1,4 +1,6 @@
-use naia_shared::{link_condition_logic, LinkConditionerConfig, TimeQueue};
+use std::sync::Arc;
+
+use naia_shared::{link_condition_logic, Clock, LinkConditionerConfig, TimeQueue};
 
 use super::{server_addr::ServerAddr, PacketReceiver, RecvError};
 
@@ -9,6 +11,7 @@ pub struct ConditionedPacketReceiver {
     link_conditioner_config: LinkConditionerConfig,
     time_queue: TimeQueue<Box<[u8]>>,
     last_payload: Option<Box<[u8]>>,
+    clock: Arc<dyn Clock>,
 }
 
 impl ConditionedPacketReceiver {
@@ -16,18 +19,21 @@ impl ConditionedPacketReceiver {
     pub fn new(
         inner_receiver: Box<dyn PacketReceiver>,
         link_conditioner_config: &LinkConditionerConfig,
+        clock: &Arc<dyn Clock>,
     ) -> Self {
         ConditionedPacketReceiver {
             inner_receiver,
             link_conditioner_config: link_conditioner_config.clone(),
             time_queue: TimeQueue::new(),
             last_payload: None,
+            clock: clock.clone(),
         }
     }
 }
 
 impl PacketReceiver for ConditionedPacketReceiver {
     fn receive(&mut self) -> Result<Option<&[u8]>, RecvError> {
+        let now = self.clock.now();
         loop {
             match self.inner_receiver.receive() {
                 Ok(option) => match option {
@@ -38,6 +44,7 @@ impl PacketReceiver for ConditionedPacketReceiver {
                         link_condition_logic::process_packet(
                             &self.link_conditioner_config,
                             &mut self.time_queue,
+                            &now,
                             payload.into(),
                         );
                     }
@@ -48,8 +55,8 @@ impl PacketReceiver for ConditionedPacketReceiver {
             }
         }
 
-        if self.time_queue.has_item() {
-            self.last_payload = Some(self.time_queue.pop_item().unwrap());
+        if self.time_queue.has_item(&now) {
+            self.last_payload = Some(self.time_queue.pop_item(&now).unwrap());
             return Ok(Some(self.last_payload.as_ref().unwrap()));
         } else {
             Ok(None)
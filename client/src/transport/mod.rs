@@ -10,6 +10,8 @@ cfg_if! {
     } else {}
 }
 
+pub mod replay;
+
 mod server_addr;
 pub use server_addr::ServerAddr;
 
@@ -17,6 +19,10 @@ pub use inner::{PacketReceiver, PacketSender, RecvError, SendError, Socket};
 
 mod inner {
 
+    use std::sync::Arc;
+
+    use naia_shared::Clock;
+
     use super::ServerAddr;
 
     pub struct SendError;
@@ -24,7 +30,10 @@ mod inner {
     pub struct RecvError;
 
     pub trait Socket {
-        fn connect(self: Box<Self>) -> (Box<dyn PacketSender>, Box<dyn PacketReceiver>);
+        fn connect(
+            self: Box<Self>,
+            clock: &Arc<dyn Clock>,
+        ) -> (Box<dyn PacketSender>, Box<dyn PacketReceiver>);
     }
 
     pub trait PacketSender: Send + Sync {
@@ -0,0 +1,477 @@
+use std::{
+    collections::VecDeque,
+    hash::Hash,
+    net::{IpAddr, Ipv4Addr, SocketAddr},
+    sync::{Arc, Mutex},
+};
+
+use naia_shared::{
+    sequence_greater_than, BitReader, BitWriter, Clock, GameInstant, Instant, Keyframe, PacketType,
+    PingIndex, Recording, Serde, StandardHeader, Tick, UnsignedVariableInteger, WorldMutType,
+};
+
+use super::{
+    PacketReceiver as TransportReceiver, PacketSender as TransportSender, RecvError, SendError,
+    ServerAddr as TransportAddr, Socket as TransportSocket,
+};
+use crate::Client;
+
+const REPLAY_ADDR: SocketAddr = SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), 0);
+
+// Socket
+/// Plays a Recording made by `Server::start_recording()` back into a Client,
+/// as if a live Server were sending it. The handshake & pings are answered
+/// locally, and recorded packets are released at their recorded pace once
+/// connected. Recordings hold uncompressed packets, so the Client's Protocol
+/// must not enable compression.
+pub struct Socket {
+    state: Arc<Mutex<ReplayState>>,
+}
+
+impl Socket {
+    pub fn new(recording: Recording) -> Self {
+        Self {
+            state: Arc::new(Mutex::new(ReplayState::new(recording))),
+        }
+    }
+
+    /// Gets a handle to control playback with, which stays valid after the
+    /// Socket is passed to the Client
+    pub fn control(&self) -> ReplayControl {
+        ReplayControl {
+            state: self.state.clone(),
+        }
+    }
+}
+
+impl From<Socket> for Box<dyn TransportSocket> {
+    fn from(socket: Socket) -> Self {
+        Box::new(socket)
+    }
+}
+
+impl TransportSocket for Socket {
+    fn connect(
+        self: Box<Self>,
+        clock: &Arc<dyn Clock>,
+    ) -> (Box<dyn TransportSender>, Box<dyn TransportReceiver>) {
+        self.state.lock().unwrap().clock = Some(clock.clone());
+        let sender = Box::new(PacketSender {
+            state: self.state.clone(),
+        });
+        let receiver = Box::new(PacketReceiver {
+            state: self.state,
+            buffer: Vec::new(),
+        });
+        (sender, receiver)
+    }
+}
+
+// Replay Control
+/// Controls the playback of a replay Socket
+#[derive(Clone)]
+pub struct ReplayControl {
+    state: Arc<Mutex<ReplayState>>,
+}
+
+impl ReplayControl {
+    /// Skips ahead to the given Server Tick, releasing every packet before it
+    /// right away. If the Tick was already played, playback restarts from
+    /// the newest Keyframe at or before it, or from the start of the
+    /// Recording: the Client despawns its replicated Entities, connects
+    /// again, and every packet before the Keyframe is released at once.
+    /// Returns false if the Recording holds no packets.
+    pub fn seek<E: Copy + Eq + Hash + Send + Sync, W: WorldMutType<E>>(
+        &self,
+        client: &mut Client<E>,
+        world: W,
+        tick: Tick,
+    ) -> bool {
+        let mut state = self.state.lock().unwrap();
+        if state.recording.packets().is_empty() {
+            return false;
+        }
+        if state.seek(tick) {
+            return true;
+        }
+        state.rewind(tick);
+        drop(state);
+
+        let socket = Socket {
+            state: self.state.clone(),
+        };
+        client.reconnect_replay(world, socket.into());
+        true
+    }
+
+    /// Gets the Ticks of the Keyframes in the Recording, which can be passed
+    /// to `seek()`
+    pub fn keyframe_ticks(&self) -> Vec<Tick> {
+        let state = self.state.lock().unwrap();
+        state
+            .recording
+            .keyframes()
+            .iter()
+            .map(|keyframe| keyframe.tick)
+            .collect()
+    }
+
+    /// Gets the newest Keyframe taken at or before the given Tick
+    pub fn keyframe_at(&self, tick: Tick) -> Option<Keyframe> {
+        let state = self.state.lock().unwrap();
+        state.recording.keyframe_at(tick).cloned()
+    }
+
+    /// Returns whether every recorded packet has been played
+    pub fn is_finished(&self) -> bool {
+        let state = self.state.lock().unwrap();
+        state.next_packet >= state.recording.packets().len()
+    }
+}
+
+// Replay State
+struct ReplayState {
+    recording: Recording,
+    // the Clock of the Client which connected
+    clock: Option<Arc<dyn Clock>>,
+    // when the Client first reached out, from which playback runs
+    start_instant: Option<Instant>,
+    // how far into the Recording playback was when it started running
+    start_millis: u32,
+    connected: bool,
+    next_packet: usize,
+    responses: VecDeque<Vec<u8>>,
+}
+
+impl ReplayState {
+    fn new(recording: Recording) -> Self {
+        Self {
+            recording,
+            clock: None,
+            start_instant: None,
+            start_millis: 0,
+            connected: false,
+            next_packet: 0,
+            responses: VecDeque::new(),
+        }
+    }
+
+    fn elapsed_millis(&self) -> u32 {
+        let running_millis = match (&self.clock, &self.start_instant) {
+            (Some(clock), Some(instant)) => clock.elapsed(instant).as_millis() as u32,
+            _ => 0,
+        };
+        self.start_millis + running_millis
+    }
+
+    fn game_time_now(&self) -> GameInstant {
+        self.recording
+            .start_game_instant()
+            .add_millis(self.elapsed_millis())
+    }
+
+    /// The Server Tick & its game time, as of the newest packet due to be
+    /// released by now
+    fn server_tick(&self) -> (Tick, GameInstant) {
+        let elapsed_millis = self.elapsed_millis();
+        let due_packets = self
+            .recording
+            .packets()
+            .iter()
+            .take_while(|packet| packet.millis <= elapsed_millis);
+        if let Some(packet) = due_packets.last() {
+            let mut reader = BitReader::new(&packet.payload);
+            if StandardHeader::de(&mut reader).is_ok() {
+                if let (Ok(tick), Ok(tick_instant)) =
+                    (Tick::de(&mut reader), GameInstant::de(&mut reader))
+                {
+                    return (tick, tick_instant);
+                }
+            }
+        }
+        (
+            self.recording.start_tick(),
+            self.recording.start_tick_instant().clone(),
+        )
+    }
+
+    fn seek(&mut self, tick: Tick) -> bool {
+        let packets = self.recording.packets();
+        let Some(index) = packets
+            .iter()
+            .position(|packet| !sequence_greater_than(tick, packet.tick))
+        else {
+            // past the end of the Recording
+            let Some(last_packet) = packets.last() else {
+                return false;
+            };
+            self.skip_to(last_packet.millis);
+            return true;
+        };
+        if index < self.next_packet {
+            return false;
+        }
+        self.skip_to(packets[index].millis);
+        true
+    }
+
+    // Restarts playback for a new connection, from the newest Keyframe at or
+    // before the given Tick
+    fn rewind(&mut self, tick: Tick) {
+        let Some(keyframe) = self.recording.keyframe_at(tick) else {
+            self.restart(0);
+            return;
+        };
+        // the Keyframe is taken in between packets, so time it from the
+        // packet before it
+        let packets = self.recording.packets();
+        let millis = match (keyframe.packet_index as usize)
+            .checked_sub(1)
+            .and_then(|index| packets.get(index))
+        {
+            Some(packet) => {
+                let ticks = keyframe.tick.wrapping_sub(packet.tick) as u32;
+                let tick_millis = self.recording.tick_duration().as_millis() as u32;
+                packet.millis + ticks * tick_millis
+            }
+            None => 0,
+        };
+        self.restart(millis);
+    }
+
+    fn restart(&mut self, millis: u32) {
+        self.connected = false;
+        self.next_packet = 0;
+        self.responses.clear();
+        self.start_instant = None;
+        self.start_millis = millis;
+    }
+
+    fn skip_to(&mut self, millis: u32) {
+        let elapsed_millis = self.elapsed_millis();
+        if millis > elapsed_millis {
+            self.start_millis += millis - elapsed_millis;
+        }
+    }
+
+    // Stands in for the Server's side of the handshake & pings
+    fn respond(&mut self, payload: &[u8]) {
+        if self.start_instant.is_none() {
+            self.start_instant = self.clock.as_ref().map(|clock| clock.now());
+        }
+
+        let mut reader = BitReader::new(payload);
+        let Ok(header) = StandardHeader::de(&mut reader) else {
+            return;
+        };
+
+        let mut writer = BitWriter::new();
+        match header.packet_type {
+            PacketType::ClientChallengeRequest => {
+                let Ok(timestamp) = u64::de(&mut reader) else {
+                    return;
+                };
+                StandardHeader::new(PacketType::ServerChallengeResponse, 0, 0, 0).ser(&mut writer);
+                timestamp.ser(&mut writer);
+                // the Client only echoes the digest back
+                Vec::<u8>::new().ser(&mut writer);
+            }
+            PacketType::ClientValidateRequest => {
+                StandardHeader::new(PacketType::ServerValidateResponse, 0, 0, 0).ser(&mut writer);
+            }
+            PacketType::ClientConnectRequest => {
+                StandardHeader::new(PacketType::ServerConnectResponse, 0, 0, 0).ser(&mut writer);
+                self.connected = true;
+            }
+            PacketType::Ping => {
+                let Ok(ping_index) = PingIndex::de(&mut reader) else {
+                    return;
+                };
+                let (tick, tick_instant) = self.server_tick();
+                let tick_duration_micros = self.recording.tick_duration().as_micros();
+
+                StandardHeader::new(PacketType::Pong, 0, 0, 0).ser(&mut writer);
+                tick.ser(&mut writer);
+                tick_instant.ser(&mut writer);
+                ping_index.ser(&mut writer);
+                self.game_time_now().ser(&mut writer);
+                UnsignedVariableInteger::<9>::new(tick_duration_micros as u64).ser(&mut writer);
+                UnsignedVariableInteger::<9>::new(0).ser(&mut writer);
+                self.game_time_now().ser(&mut writer);
+            }
+            _ => {
+                // the Recording plays regardless of what the Client sends
+                return;
+            }
+        }
+        self.responses.push_back(writer.to_bytes().into_vec());
+    }
+
+    fn next_payload(&mut self) -> Option<Vec<u8>> {
+        if let Some(response) = self.responses.pop_front() {
+            return Some(response);
+        }
+        if !self.connected {
+            return None;
+        }
+        let packet = self.recording.packets().get(self.next_packet)?;
+        if packet.millis > self.elapsed_millis() {
+            return None;
+        }
+        self.next_packet += 1;
+        Some(packet.payload.clone())
+    }
+}
+
+// Packet Sender
+struct PacketSender {
+    state: Arc<Mutex<ReplayState>>,
+}
+
+impl TransportSender for PacketSender {
+    /// Sends a packet from the Client Socket
+    fn send(&self, payload: &[u8]) -> Result<(), SendError> {
+        self.state.lock().unwrap().respond(payload);
+        Ok(())
+    }
+    /// Get the Server's Socket address
+    fn server_addr(&self) -> TransportAddr {
+        TransportAddr::Found(REPLAY_ADDR)
+    }
+}
+
+// Packet Receiver
+#[derive(Clone)]
+struct PacketReceiver {
+    state: Arc<Mutex<ReplayState>>,
+    buffer: Vec<u8>,
+}
+
+impl TransportReceiver for PacketReceiver {
+    /// Receives a packet from the Client Socket
+    fn receive(&mut self) -> Result<Option<&[u8]>, RecvError> {
+        let Some(payload) = self.state.lock().unwrap().next_payload() else {
+            return Ok(None);
+        };
+        self.buffer = payload;
+        Ok(Some(&self.buffer))
+    }
+    /// Get the Server's Socket address
+    fn server_addr(&self) -> TransportAddr {
+        TransportAddr::Found(REPLAY_ADDR)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use naia_shared::{
+        BitReader, BitWriter, Clock, GameInstant, Instant, PacketType, Recording, Serde,
+        StandardHeader, SystemClock,
+    };
+
+    use super::Socket;
+    use crate::transport::Socket as TransportSocket;
+
+    fn packet_type(payload: &[u8]) -> PacketType {
+        let mut reader = BitReader::new(payload);
+        StandardHeader::de(&mut reader).unwrap().packet_type
+    }
+
+    fn data_packet(tick: u16) -> Vec<u8> {
+        let mut writer = BitWriter::new();
+        StandardHeader::new(PacketType::Data, tick, 0, 0).ser(&mut writer);
+        tick.ser(&mut writer);
+        writer.to_bytes().into_vec()
+    }
+
+    #[test]
+    fn plays_recording_after_handshake() {
+        let start = Instant::now();
+        let game_instant = GameInstant::new(&start, &start);
+        let mut recording = Recording::new(
+            1,
+            game_instant.clone(),
+            game_instant,
+            std::time::Duration::from_millis(50),
+        );
+        recording.push_packet(0, 1, &data_packet(1));
+        recording.push_packet(60_000, 1200, &data_packet(1200));
+
+        let socket = Socket::new(recording);
+        let control = socket.control();
+        let socket: Box<dyn TransportSocket> = socket.into();
+        let clock: Arc<dyn Clock> = Arc::new(SystemClock);
+        let (sender, mut receiver) = socket.connect(&clock);
+
+        // nothing plays before the Client is connected
+        assert!(receiver.receive().ok().unwrap().is_none());
+
+        let mut writer = BitWriter::new();
+        StandardHeader::new(PacketType::ClientConnectRequest, 0, 0, 0).ser(&mut writer);
+        assert!(sender.send(&writer.to_bytes()).is_ok());
+
+        let response = receiver.receive().ok().unwrap().unwrap().to_vec();
+        assert!(packet_type(&response) == PacketType::ServerConnectResponse);
+        let packet = receiver.receive().ok().unwrap().unwrap().to_vec();
+        assert_eq!(packet, data_packet(1));
+        assert!(receiver.receive().ok().unwrap().is_none());
+
+        // already played
+        assert!(!control.state.lock().unwrap().seek(1));
+        assert!(control.state.lock().unwrap().seek(1200));
+        let packet = receiver.receive().ok().unwrap().unwrap().to_vec();
+        assert_eq!(packet, data_packet(1200));
+        assert!(control.is_finished());
+    }
+
+    #[test]
+    fn rewinds_to_keyframe() {
+        let start = Instant::now();
+        let game_instant = GameInstant::new(&start, &start);
+        let mut recording = Recording::new(
+            1,
+            game_instant.clone(),
+            game_instant,
+            std::time::Duration::from_millis(50),
+        );
+        recording.push_packet(0, 1, &data_packet(1));
+        recording.push_keyframe(20, Vec::new());
+        recording.push_packet(1_000, 20, &data_packet(20));
+        recording.push_packet(60_000, 1200, &data_packet(1200));
+
+        let socket = Socket::new(recording);
+        let control = socket.control();
+        assert_eq!(control.keyframe_ticks(), vec![20]);
+        assert!(control.keyframe_at(10).is_none());
+        assert_eq!(control.keyframe_at(30).unwrap().packet_index, 1);
+
+        let socket: Box<dyn TransportSocket> = socket.into();
+        let clock: Arc<dyn Clock> = Arc::new(SystemClock);
+        let (sender, mut receiver) = socket.connect(&clock);
+        let mut writer = BitWriter::new();
+        StandardHeader::new(PacketType::ClientConnectRequest, 0, 0, 0).ser(&mut writer);
+        let connect_request = writer.to_bytes();
+        assert!(sender.send(&connect_request).is_ok());
+        assert!(control.state.lock().unwrap().seek(1200));
+        while receiver.receive().ok().unwrap().is_some() {}
+        assert!(control.is_finished());
+
+        // playback restarts for a new connection, up to the Keyframe at once
+        control.state.lock().unwrap().rewind(30);
+        assert!(!control.is_finished());
+        assert!(receiver.receive().ok().unwrap().is_none());
+        assert!(sender.send(&connect_request).is_ok());
+        let response = receiver.receive().ok().unwrap().unwrap().to_vec();
+        assert!(packet_type(&response) == PacketType::ServerConnectResponse);
+        let packet = receiver.receive().ok().unwrap().unwrap().to_vec();
+        assert_eq!(packet, data_packet(1));
+        assert!(receiver.receive().ok().unwrap().is_none());
+
+        // the packet after the Keyframe plays at its recorded pace
+        assert!(control.state.lock().unwrap().seek(20));
+        let packet = receiver.receive().ok().unwrap().unwrap().to_vec();
+        assert_eq!(packet, data_packet(20));
+    }
+}
@@ -4,7 +4,7 @@ use std::{
     sync::{Arc, Mutex},
 };
 
-use naia_shared::LinkConditionerConfig;
+use naia_shared::{Clock, LinkConditionerConfig};
 
 use super::{
     conditioner::ConditionedPacketReceiver, PacketReceiver as TransportReceiver,
@@ -47,14 +47,21 @@ impl Into<Box<dyn TransportSocket>> for Socket {
 }
 
 impl TransportSocket for Socket {
-    fn connect(self: Box<Self>) -> (Box<dyn TransportSender>, Box<dyn TransportReceiver>) {
+    fn connect(
+        self: Box<Self>,
+        clock: &Arc<dyn Clock>,
+    ) -> (Box<dyn TransportSender>, Box<dyn TransportReceiver>) {
         let sender = Box::new(PacketSender::new(self.socket.clone(), self.server_addr));
 
         let receiver: Box<dyn TransportReceiver> = {
             let inner_receiver =
                 Box::new(PacketReceiver::new(self.socket.clone(), self.server_addr));
             if let Some(config) = &self.config {
-                Box::new(ConditionedPacketReceiver::new(inner_receiver, config))
+                Box::new(ConditionedPacketReceiver::new(
+                    inner_receiver,
+                    config,
+                    clock,
+                ))
             } else {
                 inner_receiver
             }
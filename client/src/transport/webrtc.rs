@@ -1,4 +1,6 @@
-use naia_shared::SocketConfig;
+use std::sync::Arc;
+
+use naia_shared::{Clock, SocketConfig};
 
 use naia_client_socket::{PacketReceiver, PacketSender, ServerAddr, Socket as ClientSocket};
 
@@ -56,7 +58,10 @@ impl Into<Box<dyn TransportSocket>> for Socket {
 }
 
 impl TransportSocket for Socket {
-    fn connect(self: Box<Self>) -> (Box<dyn TransportSender>, Box<dyn TransportReceiver>) {
+    fn connect(
+        self: Box<Self>,
+        _clock: &Arc<dyn Clock>,
+    ) -> (Box<dyn TransportSender>, Box<dyn TransportReceiver>) {
         let (inner_sender, inner_receiver) =
             ClientSocket::connect(&self.server_session_url, &self.config);
         return (Box::new(inner_sender), Box::new(inner_receiver));
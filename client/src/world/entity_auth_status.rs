@@ -0,0 +1,13 @@
+/// The status of the Client's authority over a delegated Entity
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum EntityAuthStatus {
+    /// The Entity is delegated, and authority over it can be requested
+    Available,
+    /// Authority has been requested, and the Server has not yet responded
+    Requested,
+    /// The Client holds authority over the Entity, and its Component state is
+    /// replicated from the Client
+    Granted,
+    /// The Server denied the last request for authority
+    Denied,
+}
@@ -2,12 +2,13 @@ use std::collections::HashSet;
 
 use naia_shared::{ComponentKind, GlobalEntity};
 
-use crate::world::entity_owner::EntityOwner;
+use crate::world::{entity_auth_status::EntityAuthStatus, entity_owner::EntityOwner};
 
 pub struct GlobalEntityRecord {
     pub global_entity: GlobalEntity,
     pub component_kinds: HashSet<ComponentKind>,
     pub owner: EntityOwner,
+    pub auth_status: Option<EntityAuthStatus>,
 }
 
 impl GlobalEntityRecord {
@@ -19,6 +20,7 @@ impl GlobalEntityRecord {
             global_entity,
             component_kinds: HashSet::new(),
             owner,
+            auth_status: None,
         }
     }
 }
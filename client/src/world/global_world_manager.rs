@@ -1,17 +1,25 @@
 use std::{
-    collections::HashMap,
+    collections::{HashMap, HashSet},
     hash::Hash,
     sync::{Arc, RwLock},
+    time::Duration,
 };
 
 use naia_shared::{
-    BigMap, ComponentKind, EntityAndGlobalEntityConverter, EntityDoesNotExistError,
-    GlobalDiffHandler, GlobalEntity, GlobalWorldManagerType, MutChannelType, PropertyMutator,
+    BigMap, Clock, ComponentKind, DuplicateComponentError, EntityAndGlobalEntityConverter,
+    EntityDoesNotExistError, EntityHierarchy, GlobalDiffHandler, GlobalEntity,
+    GlobalWorldManagerType, Instant, MutChannelType, MutReceiver, PredictionKey, PropertyMutator,
     Replicate,
 };
 
 use super::global_entity_record::GlobalEntityRecord;
-use crate::world::{entity_owner::EntityOwner, mut_channel::MutChannelData};
+use crate::{
+    world::{
+        entity_auth_status::EntityAuthStatus, entity_owner::EntityOwner,
+        mut_channel::MutChannelData,
+    },
+    NaiaClientError,
+};
 
 pub struct GlobalWorldManager<E: Copy + Eq + Hash + Send + Sync> {
     diff_handler: Arc<RwLock<GlobalDiffHandler<E>>>,
@@ -19,14 +27,36 @@ pub struct GlobalWorldManager<E: Copy + Eq + Hash + Send + Sync> {
     entity_records: HashMap<E, GlobalEntityRecord>,
     /// Map from the internal [`GlobalEntity`] to the external (e.g. Bevy's) entity id
     global_entity_map: BigMap<GlobalEntity, E>,
+    /// Parent / child relationships between Server Entities
+    entity_hierarchy: EntityHierarchy<E>,
+    /// Server Entities which have left the Client's scope, but are kept with
+    /// their last known state
+    dormant_entities: HashSet<E>,
+    /// Local Entities spawned in prediction of Server Entities, which have
+    /// not been confirmed yet
+    predicted_spawns: HashMap<PredictionKey, (E, Instant)>,
+    next_prediction_key: PredictionKey,
+    confirmed_predictions: Vec<(PredictionKey, E)>,
+    /// Server Entities which hold a replicated Resource
+    resources: HashMap<ComponentKind, E>,
+    resource_entities: HashSet<E>,
+    clock: Arc<dyn Clock>,
 }
 
 impl<E: Copy + Eq + Hash + Send + Sync> GlobalWorldManager<E> {
-    pub fn new() -> Self {
+    pub fn new(clock: &Arc<dyn Clock>) -> Self {
         Self {
             diff_handler: Arc::new(RwLock::new(GlobalDiffHandler::new())),
             entity_records: HashMap::default(),
             global_entity_map: BigMap::new(),
+            entity_hierarchy: EntityHierarchy::new(),
+            dormant_entities: HashSet::new(),
+            predicted_spawns: HashMap::new(),
+            next_prediction_key: PredictionKey::new(0),
+            confirmed_predictions: Vec::new(),
+            resources: HashMap::new(),
+            resource_entities: HashSet::new(),
+            clock: clock.clone(),
         }
     }
 
@@ -86,26 +116,33 @@ impl<E: Copy + Eq + Hash + Send + Sync> GlobalWorldManager<E> {
     }
 
     // Insert Component
-    pub fn host_insert_component(&mut self, entity: &E, component: &mut dyn Replicate) {
+    pub fn host_insert_component(
+        &mut self,
+        entity: &E,
+        component: &mut dyn Replicate,
+    ) -> Result<(), DuplicateComponentError> {
         let component_kind = component.kind();
         let diff_mask_length: u8 = component.diff_mask_size();
 
         if !self.entity_records.contains_key(entity) {
             panic!("entity does not exist!");
         }
-        let component_kind_set = &mut self.entity_records.get_mut(entity).unwrap().component_kinds;
-        component_kind_set.insert(component_kind);
 
         let mut_sender = self
             .diff_handler
             .as_ref()
             .write()
             .expect("DiffHandler should be initialized")
-            .register_component(self, entity, &component_kind, diff_mask_length);
+            .register_component(self, entity, &component_kind, diff_mask_length)?;
+
+        let component_kind_set = &mut self.entity_records.get_mut(entity).unwrap().component_kinds;
+        component_kind_set.insert(component_kind);
 
         let prop_mutator = PropertyMutator::new(mut_sender);
 
         component.set_mutator(&prop_mutator);
+
+        Ok(())
     }
 
     // Remove Component
@@ -124,6 +161,151 @@ impl<E: Copy + Eq + Hash + Send + Sync> GlobalWorldManager<E> {
             .expect("Haven't initialized DiffHandler")
             .deregister_component(entity, component_kind);
     }
+
+    pub fn component_receiver(
+        &self,
+        entity: &E,
+        component_kind: &ComponentKind,
+    ) -> Option<MutReceiver> {
+        self.diff_handler
+            .as_ref()
+            .read()
+            .expect("Haven't initialized DiffHandler")
+            .receiver(&None, entity, component_kind)
+    }
+
+    // Delegation
+    pub fn entity_auth_status(&self, entity: &E) -> Option<EntityAuthStatus> {
+        if let Some(record) = self.entity_records.get(entity) {
+            return record.auth_status;
+        }
+        return None;
+    }
+
+    pub fn entity_set_auth_status(
+        &mut self,
+        entity: &E,
+        auth_status: Option<EntityAuthStatus>,
+    ) -> Result<(), NaiaClientError> {
+        let Some(record) = self.entity_records.get_mut(entity) else {
+            return Err(NaiaClientError::EntityNotFound);
+        };
+        if record.owner != EntityOwner::Server {
+            return Err(NaiaClientError::EntityNotServerOwned);
+        }
+
+        record.auth_status = auth_status;
+        Ok(())
+    }
+
+    pub fn granted_entities(&self) -> Vec<E> {
+        let mut output = Vec::new();
+
+        for (entity, record) in &self.entity_records {
+            if record.auth_status == Some(EntityAuthStatus::Granted) {
+                output.push(*entity);
+            }
+        }
+
+        output
+    }
+}
+
+// Hierarchy
+impl<E: Copy + Eq + Hash + Send + Sync> GlobalWorldManager<E> {
+    pub fn entity_parent(&self, entity: &E) -> Option<E> {
+        self.entity_hierarchy.parent(entity)
+    }
+
+    pub fn entity_children(&self, entity: &E) -> Vec<E> {
+        self.entity_hierarchy.children(entity).to_vec()
+    }
+
+    /// Sets or clears the parent of an Entity, returning false if this would
+    /// create a cycle
+    pub fn entity_set_parent(&mut self, child: &E, parent: Option<&E>) -> bool {
+        match parent {
+            Some(parent) => self.entity_hierarchy.set_parent(child, parent),
+            None => {
+                self.entity_hierarchy.remove_parent(child);
+                true
+            }
+        }
+    }
+}
+
+// Dormancy
+impl<E: Copy + Eq + Hash + Send + Sync> GlobalWorldManager<E> {
+    pub fn entity_is_dormant(&self, entity: &E) -> bool {
+        self.dormant_entities.contains(entity)
+    }
+
+    /// Marks a Server Entity as dormant or awake, returning whether this
+    /// changed anything
+    pub fn entity_set_dormant(&mut self, entity: &E, dormant: bool) -> bool {
+        if dormant {
+            self.dormant_entities.insert(*entity)
+        } else {
+            self.dormant_entities.remove(entity)
+        }
+    }
+}
+
+// Predicted Spawns
+impl<E: Copy + Eq + Hash + Send + Sync> GlobalWorldManager<E> {
+    pub fn add_predicted_spawn(&mut self, entity: &E) -> PredictionKey {
+        let prediction_key = self.next_prediction_key;
+        self.next_prediction_key = prediction_key.next();
+        self.predicted_spawns
+            .insert(prediction_key, (*entity, self.clock.now()));
+        prediction_key
+    }
+
+    pub fn take_confirmed_predictions(&mut self) -> Vec<(PredictionKey, E)> {
+        std::mem::take(&mut self.confirmed_predictions)
+    }
+
+    /// Removes & returns every predicted spawn that has waited longer than
+    /// the timeout for its confirmation
+    pub fn take_expired_predictions(&mut self, timeout: &Duration) -> Vec<(PredictionKey, E)> {
+        let now = self.clock.now();
+        let expired_keys: Vec<PredictionKey> = self
+            .predicted_spawns
+            .iter()
+            .filter(|(_, (_, spawned_at))| now.duration_since(spawned_at) >= *timeout)
+            .map(|(prediction_key, _)| *prediction_key)
+            .collect();
+
+        let mut output = Vec::new();
+        for prediction_key in expired_keys {
+            let (entity, _) = self.predicted_spawns.remove(&prediction_key).unwrap();
+            output.push((prediction_key, entity));
+        }
+        output
+    }
+}
+
+// Resources
+impl<E: Copy + Eq + Hash + Send + Sync> GlobalWorldManager<E> {
+    pub fn resource_entity(&self, component_kind: &ComponentKind) -> Option<E> {
+        self.resources.get(component_kind).copied()
+    }
+
+    pub fn entity_is_resource(&self, entity: &E) -> bool {
+        self.resource_entities.contains(entity)
+    }
+
+    pub fn insert_resource_entity(&mut self, component_kind: &ComponentKind, entity: &E) {
+        self.resources.insert(*component_kind, *entity);
+        self.resource_entities.insert(*entity);
+    }
+
+    pub fn remove_resource_entity(&mut self, entity: &E) {
+        if self.resource_entities.remove(entity) {
+            self.resources
+                .retain(|_, resource_entity| resource_entity != entity);
+        }
+    }
 }
 
 impl<E: Copy + Eq + Hash + Send + Sync> GlobalWorldManagerType<E> for GlobalWorldManager<E> {
@@ -154,6 +336,12 @@ impl<E: Copy + Eq + Hash + Send + Sync> GlobalWorldManagerType<E> for GlobalWorl
         self.diff_handler.clone()
     }
 
+    fn take_predicted_entity(&mut self, prediction_key: &PredictionKey) -> Option<E> {
+        let (entity, _) = self.predicted_spawns.remove(prediction_key)?;
+        self.confirmed_predictions.push((*prediction_key, entity));
+        Some(entity)
+    }
+
     fn remote_spawn_entity(&mut self, entity: &E, _user_key: &u64) {
         if self.entity_records.contains_key(entity) {
             panic!("entity already initialized!");
@@ -170,6 +358,22 @@ impl<E: Copy + Eq + Hash + Send + Sync> GlobalWorldManagerType<E> for GlobalWorl
             .entity_records
             .remove(entity)
             .expect("Cannot despawn non-existant entity!");
+
+        // Components of Entities the Client held authority over are registered
+        if !record.component_kinds.is_empty() {
+            let mut diff_handler = self
+                .diff_handler
+                .as_ref()
+                .write()
+                .expect("Haven't initialized DiffHandler");
+            for component_kind in &record.component_kinds {
+                diff_handler.deregister_component(entity, component_kind);
+            }
+        }
+
+        self.entity_hierarchy.remove_entity(entity);
+        self.dormant_entities.remove(entity);
+
         let global_entity = record.global_entity;
         self.global_entity_map.remove(&global_entity);
     }
@@ -27,4 +27,8 @@ impl MutChannelType for MutChannelData {
     fn send(&self, diff: u8) {
         self.receiver.mutate(diff);
     }
+
+    fn send_elements(&self, diff: u8, elements: &[u32]) {
+        self.receiver.mutate_elements(diff, elements);
+    }
 }
@@ -40,7 +40,7 @@ impl App {
         let auth = Auth::new("ronald", "12345");
         client.auth(auth);
 
-        client.connect(socket);
+        client.connect(socket).unwrap();
 
         App {
             client,
@@ -71,7 +71,7 @@ impl App {
             self.client.auth(auth);
 
             let socket = webrtc::Socket::new("http://127.0.0.1:14191", &self.socket_config);
-            self.client.connect(socket);
+            self.client.connect(socket).unwrap();
         }
         for server_address in events.read::<DisconnectEvent>() {
             info!("Client disconnected from: {}", server_address);
@@ -89,7 +89,7 @@ impl App {
             // &string_message);
             self.message_count += 1;
         }
-        for entity in events.read::<SpawnEntityEvent>() {
+        for (_, entity) in events.read::<SpawnEntityEvent>() {
             if let Some(character) = self
                 .client
                 .entity(self.world.proxy(), &entity)
@@ -122,7 +122,7 @@ impl App {
                 );
             }
         }
-        for (_, character) in events.read::<RemoveComponentEvent<Character>>() {
+        for (_, _, character) in events.read::<RemoveComponentEvent<Character>>() {
             info!(
                 "data delete of Character - x: {}, y: {}, name: {} {}",
                 *character.x,
@@ -66,7 +66,10 @@ impl App {
                     .id();
 
                 // Add the Character Entity to the main Room
-                server.room_mut(&main_room_key).add_entity(&character_key);
+                server
+                    .room_mut(&main_room_key)
+                    .add_entity(&character_key)
+                    .unwrap();
             }
         }
 
@@ -130,7 +133,8 @@ impl App {
 
                     let new_message = StringMessage::new(new_message_contents);
                     self.server
-                        .send_message::<UnorderedReliableChannel, _>(&user_key, &new_message);
+                        .send_message::<UnorderedReliableChannel, _>(&user_key, &new_message)
+                        .unwrap();
                 }
 
                 // Iterate through Characters, marching them from (0,0) to (20, N)
@@ -143,13 +143,13 @@ pub fn message_events(
 }
 
 pub fn spawn_entity_events(mut event_reader: EventReader<SpawnEntityEvent>) {
-    for SpawnEntityEvent(_entity) in event_reader.iter() {
+    for SpawnEntityEvent(_tick, _entity) in event_reader.iter() {
         info!("spawned entity");
     }
 }
 
 pub fn despawn_entity_events(mut event_reader: EventReader<DespawnEntityEvent>) {
-    for DespawnEntityEvent(_entity) in event_reader.iter() {
+    for DespawnEntityEvent(_tick, _entity) in event_reader.iter() {
         info!("despawned entity");
     }
 }
@@ -162,7 +162,7 @@ pub fn insert_component_events(
     position_query: Query<&Position>,
 ) {
     for events in event_reader.iter() {
-        for entity in events.read::<Color>() {
+        for (_tick, entity) in events.read::<Color>() {
             // When we receive a replicated Color component for a given Entity,
             // use that value to also insert a local-only SpriteBundle component into this entity
             info!("add Color Component to entity");
@@ -226,7 +226,7 @@ pub fn insert_component_events(
                 }
             }
         }
-        for entity in events.read::<Position>() {
+        for (_tick, entity) in events.read::<Position>() {
             info!("add Position Component to entity");
             if let Ok(position) = position_query.get(entity) {
                 // initialize interpolation
@@ -292,10 +292,10 @@ pub fn update_component_events(
 
 pub fn remove_component_events(mut event_reader: EventReader<RemoveComponentEvents>) {
     for events in event_reader.iter() {
-        for (_entity, _component) in events.read::<Position>() {
+        for (_tick, _entity, _component) in events.read::<Position>() {
             info!("removed Position component from entity");
         }
-        for (_entity, _component) in events.read::<Color>() {
+        for (_tick, _entity, _component) in events.read::<Color>() {
             info!("removed Color component from entity");
         }
     }
@@ -329,7 +329,9 @@ pub fn tick_events(
         global.command_history.insert(*client_tick, command.clone());
 
         // Send command
-        client.send_tick_buffer_message::<PlayerCommandChannel, KeyCommand>(client_tick, &command);
+        client
+            .send_tick_buffer_message::<PlayerCommandChannel, KeyCommand>(client_tick, &command)
+            .unwrap();
 
         if let Ok(mut position) = position_query.get_mut(predicted_entity) {
             // Apply command
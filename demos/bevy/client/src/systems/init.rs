@@ -17,7 +17,7 @@ pub fn init(
 
     client.auth(Auth::new("charlie", "12345"));
     let socket = webrtc::Socket::new("http://127.0.0.1:14191", client.socket_config());
-    client.connect(socket);
+    client.connect(socket).unwrap();
 
     // Setup Camera
     commands.spawn(Camera2dBundle::default());
@@ -90,7 +90,10 @@ pub fn connect_events(
             // return Entity id
             .id();
 
-        server.room_mut(&global.main_room_key).add_entity(&entity);
+        server
+            .room_mut(&global.main_room_key)
+            .add_entity(&entity)
+            .unwrap();
 
         global.user_to_square_map.insert(*user_key, entity);
         global.square_to_user_map.insert(entity, *user_key);
@@ -99,10 +102,12 @@ pub fn connect_events(
         let mut assignment_message = EntityAssignment::new(true);
         assignment_message.entity.set(&server, &entity);
 
-        server.send_message::<EntityAssignmentChannel, EntityAssignment>(
-            user_key,
-            &assignment_message,
-        );
+        server
+            .send_message::<EntityAssignmentChannel, EntityAssignment>(
+                user_key,
+                &assignment_message,
+            )
+            .unwrap();
     }
 }
 
@@ -236,7 +241,8 @@ pub fn insert_component_events(
 
                 server
                     .room_mut(&global.main_room_key)
-                    .add_entity(&server_entity);
+                    .add_entity(&server_entity)
+                    .unwrap();
 
                 global.user_to_cursor_map.insert(user_key, client_entity);
                 global
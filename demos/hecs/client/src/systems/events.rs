@@ -26,7 +26,7 @@ pub fn process_events(app: &mut App) {
     }
 
     // Spawn Entity Events
-    for entity in events.read::<SpawnEntityEvent>() {
+    for (_tick, entity) in events.read::<SpawnEntityEvent>() {
         let new_id = app.next_id;
         app.next_id = app.next_id.wrapping_add(1);
         app.entity_to_id_map.insert(entity, new_id);
@@ -34,35 +34,35 @@ pub fn process_events(app: &mut App) {
     }
 
     // Insert Component Events
-    for entity in events.read::<InsertComponentEvent<Marker>>() {
+    for (_tick, entity) in events.read::<InsertComponentEvent<Marker>>() {
         let id = app.entity_to_id_map.get(&entity).unwrap();
         info!("insert Marker component into entity: {id}");
     }
-    for entity in events.read::<InsertComponentEvent<Name>>() {
+    for (_tick, entity) in events.read::<InsertComponentEvent<Name>>() {
         let id = app.entity_to_id_map.get(&entity).unwrap();
         info!("insert Name component into entity: {id}");
     }
-    for entity in events.read::<InsertComponentEvent<Position>>() {
+    for (_tick, entity) in events.read::<InsertComponentEvent<Position>>() {
         let id = app.entity_to_id_map.get(&entity).unwrap();
         info!("insert Position component into entity: {id}");
     }
 
     // Remove Component Events
-    for (entity, _) in events.read::<RemoveComponentEvent<Marker>>() {
+    for (_tick, entity, _) in events.read::<RemoveComponentEvent<Marker>>() {
         let id = app.entity_to_id_map.get(&entity).unwrap();
         info!("remove Marker component from entity: {id}");
     }
-    for (entity, _) in events.read::<RemoveComponentEvent<Name>>() {
+    for (_tick, entity, _) in events.read::<RemoveComponentEvent<Name>>() {
         let id = app.entity_to_id_map.get(&entity).unwrap();
         info!("remove Name component from entity: {id}");
     }
-    for (entity, _) in events.read::<RemoveComponentEvent<Position>>() {
+    for (_tick, entity, _) in events.read::<RemoveComponentEvent<Position>>() {
         let id = app.entity_to_id_map.get(&entity).unwrap();
         info!("remove Position component from entity: {id}");
     }
 
     // Despawn Events
-    for entity in events.read::<DespawnEntityEvent>() {
+    for (_tick, entity) in events.read::<DespawnEntityEvent>() {
         let id = app.entity_to_id_map.remove(&entity).unwrap();
         info!("deletion of entity: {id}");
     }
@@ -19,7 +19,7 @@ pub fn app_init(
     let socket = webrtc::Socket::new(server_addr, protocol.socket_config());
     let mut client = Client::new(client_config, protocol);
     client.auth(auth);
-    client.connect(socket);
+    client.connect(socket).unwrap();
 
     App {
         client,
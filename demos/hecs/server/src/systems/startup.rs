@@ -45,6 +45,7 @@ pub fn app_init(
             server
                 .spawn_entity(&mut world)
                 .enter_room(&main_room_key)
+                .unwrap()
                 .insert_component(position_ref)
                 .insert_component(name_ref)
                 .id();
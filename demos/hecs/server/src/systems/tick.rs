@@ -91,6 +91,7 @@ pub fn march_and_mark(app: &mut App) {
         app.server
             .spawn_entity(&mut app.world)
             .enter_room(&app.main_room_key)
+            .unwrap()
             .insert_component(position_ref)
             .insert_component(name_ref)
             .id();
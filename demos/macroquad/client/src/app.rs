@@ -63,7 +63,7 @@ impl App {
         let socket = webrtc::Socket::new("http://127.0.0.1:14191", &protocol.socket);
         let mut client = Client::new(ClientConfig::default(), protocol);
         client.auth(Auth::new("charlie", "12345"));
-        client.connect(socket);
+        client.connect(socket).unwrap();
 
         App {
             client,
@@ -200,13 +200,13 @@ impl App {
         }
 
         // Spawn Entity Events
-        for entity in events.read::<SpawnEntityEvent>() {
+        for (_, entity) in events.read::<SpawnEntityEvent>() {
             self.squares.insert(entity);
             info!("spawned entity");
         }
 
         // Despawn Entity Events
-        for entity in events.read::<DespawnEntityEvent>() {
+        for (_, entity) in events.read::<DespawnEntityEvent>() {
             self.squares.remove(&entity);
             self.interp_entities.remove(&entity);
             info!("despawned entity");
@@ -214,7 +214,7 @@ impl App {
         }
 
         // Insert Component Events
-        for entity in events.read::<InsertComponentEvent<Position>>() {
+        for (_, entity) in events.read::<InsertComponentEvent<Position>>() {
             if let Some(position) = self.world.proxy().component::<Position>(&entity) {
                 self.interp_entities
                     .insert(entity, Interp::new(*position.x, *position.y));
@@ -277,7 +277,8 @@ impl App {
 
             // Send command
             self.client
-                .send_tick_buffer_message::<PlayerCommandChannel, _>(&client_tick, &command);
+                .send_tick_buffer_message::<PlayerCommandChannel, _>(&client_tick, &command)
+                .unwrap();
 
             // Apply command
             if let Some(mut position) = self
@@ -115,6 +115,7 @@ impl App {
                 .spawn_entity(self.world.proxy_mut())
                 // Entity enters Room
                 .enter_room(&self.main_room_key)
+                .unwrap()
                 // Add Position component to Entity
                 .insert_component(position_component)
                 // Add Color component to Entity
@@ -137,7 +138,8 @@ impl App {
             // self.server.entity_property(assigment_message).set(&entity_id);
 
             self.server
-                .send_message::<EntityAssignmentChannel, _>(&user_key, &assignment_message);
+                .send_message::<EntityAssignmentChannel, _>(&user_key, &assignment_message)
+                .unwrap();
         }
 
         // Disconnect Events
@@ -192,6 +194,7 @@ impl App {
                 .spawn_entity(self.world.proxy_mut())
                 // Entity enters Room
                 .enter_room(&self.main_room_key)
+                .unwrap()
                 // Add Position component to Entity
                 .insert_component(server_cursor_position)
                 // Add Color component to Entity
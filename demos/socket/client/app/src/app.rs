@@ -1,4 +1,4 @@
-use std::time::Duration;
+use std::{sync::Arc, time::Duration};
 
 cfg_if! {
     if #[cfg(feature = "mquad")] {
@@ -10,7 +10,7 @@ cfg_if! {
 
 use naia_client_socket::{PacketReceiver, PacketSender, ServerAddr, Socket};
 
-use naia_shared::Timer;
+use naia_shared::{Clock, SystemClock, Timer};
 
 use naia_socket_demo_shared::{shared_config, PING_MSG, PONG_MSG};
 
@@ -29,11 +29,13 @@ impl App {
         let (packet_sender, packet_receiver) =
             Socket::connect("http://127.0.0.1:14191", &shared_config());
 
+        let clock: Arc<dyn Clock> = Arc::new(SystemClock);
+
         App {
             packet_sender,
             packet_receiver,
             message_count: 0,
-            timer: Timer::new(Duration::from_secs(1)),
+            timer: Timer::new(Duration::from_secs(1), &clock),
             server_addr_str: None,
         }
     }
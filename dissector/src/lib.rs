@@ -0,0 +1,14 @@
+//! # Naia Dissector
+//! Decodes naia packets into their headers, Messages, Component updates &
+//! Entity actions, from a Recording or a capture of the UDP transport.
+
+#![deny(trivial_numeric_casts, unstable_features, unused_import_braces)]
+
+mod packet_dissector;
+mod pcap;
+
+pub use packet_dissector::{
+    DissectedAction, DissectedComponent, DissectedField, DissectedMessage, DissectedPacket,
+    DissectedUpdate, PacketDirection, PacketDissector,
+};
+pub use pcap::{read_pcap, CaptureError, CapturedDatagram};
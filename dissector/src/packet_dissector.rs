@@ -0,0 +1,592 @@
+use std::fmt;
+
+use naia_shared::{
+    BitReader, ChannelKind, ChannelMode, ComponentKind, Decoder, EntityActionType,
+    FakeEntityConverter, GameInstant, IndexedMessageReader, LocalEntity, MessageIndex, PacketIndex,
+    PacketType, PredictionKey, Protocol, Recording, Serde, SerdeErr, ShortMessageIndex,
+    StandardHeader, Tick, UnsignedVariableInteger, REDUNDANT_PACKET_ACKS_SIZE,
+};
+
+/// Which host sent a packet, which decides how its data is laid out
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PacketDirection {
+    ServerToClient,
+    ClientToServer,
+}
+
+/// A Message read from a packet
+pub struct DissectedMessage {
+    pub channel: String,
+    /// The Message's index within its Channel, for Channels which send one
+    pub index: Option<MessageIndex>,
+    /// The Tick a Tick Buffered Message was sent for
+    pub tick: Option<Tick>,
+    pub name: String,
+}
+
+/// A whole Component read from a packet, as sent when it is inserted
+pub struct DissectedComponent {
+    pub name: String,
+}
+
+/// A changed field of a Component update
+pub struct DissectedField {
+    pub name: &'static str,
+    pub bits: u32,
+}
+
+/// A Component update read from a packet
+pub struct DissectedUpdate {
+    pub entity: LocalEntity,
+    pub component: String,
+    pub fields: Vec<DissectedField>,
+}
+
+/// An Entity action read from a packet
+pub enum DissectedAction {
+    SpawnEntity(LocalEntity, Option<PredictionKey>, Vec<DissectedComponent>),
+    DespawnEntity(LocalEntity),
+    InsertComponent(LocalEntity, DissectedComponent),
+    RemoveComponent(LocalEntity, String),
+    Noop,
+}
+
+/// Everything that could be read from one packet. Entities are shown as the
+/// receiving host refers to them.
+pub struct DissectedPacket {
+    pub direction: PacketDirection,
+    /// Bytes of the packet, after decompression
+    pub bytes: usize,
+    pub header: StandardHeader,
+    /// Indices of the packets the header acknowledges, newest first
+    pub acked_packets: Vec<PacketIndex>,
+    /// The sender's Tick, for data packets
+    pub tick: Option<Tick>,
+    /// The Server's game time at its Tick, for data packets sent by the Server
+    pub tick_instant: Option<GameInstant>,
+    pub tick_buffered_messages: Vec<DissectedMessage>,
+    pub messages: Vec<DissectedMessage>,
+    pub updates: Vec<DissectedUpdate>,
+    /// Entity actions, with their index and the sender's Tick they were
+    /// written for
+    pub actions: Vec<(MessageIndex, Tick, DissectedAction)>,
+    /// Whether reading stopped early, because the packet was truncated or
+    /// does not match the Protocol
+    pub malformed: bool,
+}
+
+/// Decodes packets into their headers, Messages, Component updates & Entity
+/// actions, using the Protocol they were sent with. Packets can come from a
+/// `Recording`, or from a capture of the UDP transport read by `read_pcap()`.
+pub struct PacketDissector<'p> {
+    protocol: &'p Protocol,
+    server_to_client_decoder: Option<Decoder>,
+    client_to_server_decoder: Option<Decoder>,
+}
+
+impl<'p> PacketDissector<'p> {
+    pub fn new(protocol: &'p Protocol) -> Self {
+        let (server_to_client_decoder, client_to_server_decoder) = match &protocol.compression {
+            Some(config) => (
+                config.server_to_client.clone().map(Decoder::new),
+                config.client_to_server.clone().map(Decoder::new),
+            ),
+            None => (None, None),
+        };
+
+        Self {
+            protocol,
+            server_to_client_decoder,
+            client_to_server_decoder,
+        }
+    }
+
+    /// Decodes a packet as it was written, before any compression. Fails if
+    /// not even the header can be read.
+    pub fn dissect(
+        &self,
+        direction: PacketDirection,
+        payload: &[u8],
+    ) -> Result<DissectedPacket, SerdeErr> {
+        let mut reader = BitReader::new(payload);
+        let header = StandardHeader::de(&mut reader)?;
+
+        let mut packet = DissectedPacket {
+            direction,
+            bytes: payload.len(),
+            header,
+            acked_packets: acked_packets(&header),
+            tick: None,
+            tick_instant: None,
+            tick_buffered_messages: Vec::new(),
+            messages: Vec::new(),
+            updates: Vec::new(),
+            actions: Vec::new(),
+            malformed: false,
+        };
+
+        if header.packet_type == PacketType::Data {
+            packet.malformed = self.read_data(&mut reader, &mut packet).is_err();
+        }
+
+        Ok(packet)
+    }
+
+    /// Decodes a packet as it went over the wire, decompressing it first if
+    /// the Protocol compresses packets sent in that direction
+    pub fn dissect_compressed(
+        &mut self,
+        direction: PacketDirection,
+        payload: &[u8],
+    ) -> Result<DissectedPacket, SerdeErr> {
+        let decoder = match direction {
+            PacketDirection::ServerToClient => &mut self.server_to_client_decoder,
+            PacketDirection::ClientToServer => &mut self.client_to_server_decoder,
+        };
+        match decoder {
+            Some(decoder) => {
+                let decoded_payload = decoder.decode(payload)?.to_vec();
+                self.dissect(direction, &decoded_payload)
+            }
+            None => self.dissect(direction, payload),
+        }
+    }
+
+    /// Decodes every packet of a Recording, which are all sent by the Server
+    pub fn dissect_recording(
+        &self,
+        recording: &Recording,
+    ) -> Vec<Result<DissectedPacket, SerdeErr>> {
+        recording
+            .packets()
+            .iter()
+            .map(|packet| self.dissect(PacketDirection::ServerToClient, &packet.payload))
+            .collect()
+    }
+
+    fn read_data(
+        &self,
+        reader: &mut BitReader,
+        packet: &mut DissectedPacket,
+    ) -> Result<(), SerdeErr> {
+        let tick = Tick::de(reader)?;
+        packet.tick = Some(tick);
+        if packet.direction == PacketDirection::ServerToClient {
+            packet.tick_instant = Some(GameInstant::de(reader)?);
+        }
+
+        self.read_tick_buffered_messages(tick, reader, &mut packet.tick_buffered_messages)?;
+        self.read_messages(reader, &mut packet.messages)?;
+
+        let has_world_events = packet.direction == PacketDirection::ServerToClient
+            || self.protocol.client_authoritative_entities;
+        if has_world_events {
+            self.read_updates(reader, &mut packet.updates)?;
+            self.read_actions(tick, reader, &mut packet.actions)?;
+        }
+
+        Ok(())
+    }
+
+    fn read_tick_buffered_messages(
+        &self,
+        packet_tick: Tick,
+        reader: &mut BitReader,
+        output: &mut Vec<DissectedMessage>,
+    ) -> Result<(), SerdeErr> {
+        loop {
+            let channel_continue = bool::de(reader)?;
+            if !channel_continue {
+                break;
+            }
+
+            let channel_kind = ChannelKind::de(&self.protocol.channel_kinds, reader)?;
+            let channel = self.protocol.channel_kinds.kind_to_name(&channel_kind);
+            let mut last_read_tick = packet_tick;
+
+            loop {
+                let message_continue = bool::de(reader)?;
+                if !message_continue {
+                    break;
+                }
+
+                let tick_diff = UnsignedVariableInteger::<3>::de(reader)?.get() as Tick;
+                last_read_tick = last_read_tick.wrapping_sub(tick_diff);
+
+                let message_count = UnsignedVariableInteger::<3>::de(reader)?.get();
+                let mut last_read_index: ShortMessageIndex = 0;
+                for _ in 0..message_count {
+                    let index_diff =
+                        UnsignedVariableInteger::<2>::de(reader)?.get() as ShortMessageIndex;
+                    last_read_index = last_read_index.wrapping_add(index_diff);
+
+                    let mut message = self.read_message(&channel, reader)?;
+                    message.index = Some(MessageIndex::from(last_read_index));
+                    message.tick = Some(last_read_tick);
+                    output.push(message);
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn read_messages(
+        &self,
+        reader: &mut BitReader,
+        output: &mut Vec<DissectedMessage>,
+    ) -> Result<(), SerdeErr> {
+        loop {
+            let channel_continue = bool::de(reader)?;
+            if !channel_continue {
+                break;
+            }
+
+            let channel_kind = ChannelKind::de(&self.protocol.channel_kinds, reader)?;
+            let channel = self.protocol.channel_kinds.kind_to_name(&channel_kind);
+            let channel_mode = self.protocol.channel_kinds.channel(&channel_kind).mode;
+
+            let mut last_read_index: Option<MessageIndex> = None;
+            loop {
+                let message_continue = bool::de(reader)?;
+                if !message_continue {
+                    break;
+                }
+
+                let index = match channel_mode {
+                    ChannelMode::UnorderedUnreliable => None,
+                    // Tick Buffered Messages are never written in this section
+                    ChannelMode::TickBuffered(_) => return Err(SerdeErr),
+                    _ => {
+                        let index =
+                            IndexedMessageReader::read_message_index(reader, &last_read_index)?;
+                        last_read_index = Some(index);
+                        Some(index)
+                    }
+                };
+
+                let mut message = self.read_message(&channel, reader)?;
+                message.index = index;
+                output.push(message);
+            }
+        }
+
+        Ok(())
+    }
+
+    fn read_message(
+        &self,
+        channel: &str,
+        reader: &mut BitReader,
+    ) -> Result<DissectedMessage, SerdeErr> {
+        let message = self
+            .protocol
+            .message_kinds
+            .read(reader, &FakeEntityConverter)?;
+
+        Ok(DissectedMessage {
+            channel: channel.to_string(),
+            index: None,
+            tick: None,
+            name: message.name(),
+        })
+    }
+
+    fn read_updates(
+        &self,
+        reader: &mut BitReader,
+        output: &mut Vec<DissectedUpdate>,
+    ) -> Result<(), SerdeErr> {
+        loop {
+            let update_continue = bool::de(reader)?;
+            if !update_continue {
+                break;
+            }
+
+            let entity = LocalEntity::remote_de(reader)?;
+
+            loop {
+                let component_continue = bool::de(reader)?;
+                if !component_continue {
+                    break;
+                }
+
+                let (component_kind, fields) =
+                    self.protocol.component_kinds.read_update_fields(reader)?;
+                output.push(DissectedUpdate {
+                    entity,
+                    component: self.protocol.component_kinds.kind_to_name(&component_kind),
+                    fields: fields
+                        .into_iter()
+                        .map(|(name, bits)| DissectedField { name, bits })
+                        .collect(),
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    fn read_actions(
+        &self,
+        packet_tick: Tick,
+        reader: &mut BitReader,
+        output: &mut Vec<(MessageIndex, Tick, DissectedAction)>,
+    ) -> Result<(), SerdeErr> {
+        let mut last_read_index: Option<MessageIndex> = None;
+
+        loop {
+            let action_continue = bool::de(reader)?;
+            if !action_continue {
+                break;
+            }
+
+            let action_index = IndexedMessageReader::read_message_index(reader, &last_read_index)?;
+            last_read_index = Some(action_index);
+
+            let tick_diff = UnsignedVariableInteger::<3>::de(reader)?.get() as Tick;
+            let action_tick = packet_tick.wrapping_sub(tick_diff);
+
+            let action = match EntityActionType::de(reader)? {
+                EntityActionType::SpawnEntity => {
+                    let entity = LocalEntity::remote_de(reader)?;
+                    let prediction_key = Option::<PredictionKey>::de(reader)?;
+
+                    let components_num = UnsignedVariableInteger::<3>::de(reader)?.get();
+                    let mut components = Vec::new();
+                    for _ in 0..components_num {
+                        components.push(self.read_component(reader)?);
+                    }
+
+                    DissectedAction::SpawnEntity(entity, prediction_key, components)
+                }
+                EntityActionType::DespawnEntity => {
+                    DissectedAction::DespawnEntity(LocalEntity::remote_de(reader)?)
+                }
+                EntityActionType::InsertComponent => {
+                    let entity = LocalEntity::remote_de(reader)?;
+                    DissectedAction::InsertComponent(entity, self.read_component(reader)?)
+                }
+                EntityActionType::RemoveComponent => {
+                    let entity = LocalEntity::remote_de(reader)?;
+                    let component_kind = ComponentKind::de(&self.protocol.component_kinds, reader)?;
+                    DissectedAction::RemoveComponent(
+                        entity,
+                        self.protocol.component_kinds.kind_to_name(&component_kind),
+                    )
+                }
+                EntityActionType::Noop => DissectedAction::Noop,
+            };
+            output.push((action_index, action_tick, action));
+        }
+
+        Ok(())
+    }
+
+    fn read_component(&self, reader: &mut BitReader) -> Result<DissectedComponent, SerdeErr> {
+        let component = self
+            .protocol
+            .component_kinds
+            .read(reader, &FakeEntityConverter)?;
+
+        Ok(DissectedComponent {
+            name: component.name(),
+        })
+    }
+}
+
+fn acked_packets(header: &StandardHeader) -> Vec<PacketIndex> {
+    let mut output = vec![header.sender_ack_index];
+    let mut ack_bitfield = header.sender_ack_bitfield;
+    for i in 1..=REDUNDANT_PACKET_ACKS_SIZE {
+        if ack_bitfield & 1 == 1 {
+            output.push(header.sender_ack_index.wrapping_sub(i));
+        }
+        ack_bitfield >>= 1;
+    }
+    output
+}
+
+impl fmt::Display for DissectedPacket {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "{:?} packet #{} ({:?}, {} bytes), acks {:?}",
+            self.header.packet_type,
+            self.header.sender_packet_index,
+            self.direction,
+            self.bytes,
+            self.acked_packets
+        )?;
+
+        if let Some(tick) = self.tick {
+            match &self.tick_instant {
+                Some(tick_instant) => {
+                    writeln!(f, "  tick {} at {}ms", tick, tick_instant.as_millis())?
+                }
+                None => writeln!(f, "  tick {}", tick)?,
+            }
+        }
+        for message in &self.tick_buffered_messages {
+            writeln!(f, "  tick buffered message {}", message)?;
+        }
+        for message in &self.messages {
+            writeln!(f, "  message {}", message)?;
+        }
+        for update in &self.updates {
+            let fields: Vec<String> = update
+                .fields
+                .iter()
+                .map(|field| format!("{} ({} bits)", field.name, field.bits))
+                .collect();
+            writeln!(
+                f,
+                "  update {:?} {} {{ {} }}",
+                update.entity,
+                update.component,
+                fields.join(", ")
+            )?;
+        }
+        for (index, tick, action) in &self.actions {
+            write!(f, "  action #{} @{} ", index, tick)?;
+            match action {
+                DissectedAction::SpawnEntity(entity, prediction_key, components) => {
+                    let components: Vec<String> = components
+                        .iter()
+                        .map(|component| component.to_string())
+                        .collect();
+                    write!(f, "spawn {:?} [{}]", entity, components.join(", "))?;
+                    if let Some(prediction_key) = prediction_key {
+                        write!(f, " predicted by {:?}", prediction_key)?;
+                    }
+                    writeln!(f)?;
+                }
+                DissectedAction::DespawnEntity(entity) => writeln!(f, "despawn {:?}", entity)?,
+                DissectedAction::InsertComponent(entity, component) => {
+                    writeln!(f, "insert {} into {:?}", component, entity)?
+                }
+                DissectedAction::RemoveComponent(entity, component) => {
+                    writeln!(f, "remove {} from {:?}", component, entity)?
+                }
+                DissectedAction::Noop => writeln!(f, "noop")?,
+            }
+        }
+
+        if self.malformed {
+            writeln!(f, "  malformed, the rest could not be read")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl fmt::Display for DissectedMessage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[{}]", self.channel)?;
+        if let Some(tick) = self.tick {
+            write!(f, " tick {}", tick)?;
+        }
+        if let Some(index) = self.index {
+            write!(f, " #{}", index)?;
+        }
+        write!(f, " {}", self.name)
+    }
+}
+
+impl fmt::Display for DissectedComponent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use naia_shared::{
+        BitWriter, ChannelKind, EntityActionType, FakeEntityConverter, GameInstant,
+        IndexedMessageWriter, Instant, LocalEntity, MessageContainer, PacketType, Protocol, Serde,
+        StandardHeader, TickIntervalChannel, TickIntervalMessage, UnsignedVariableInteger,
+    };
+
+    use super::{DissectedAction, PacketDirection, PacketDissector};
+
+    #[test]
+    fn dissects_data_packet() {
+        let protocol = Protocol::default();
+        let mut writer = BitWriter::new();
+
+        StandardHeader::new(PacketType::Data, 12, 10, 0b101).ser(&mut writer);
+        let tick: u16 = 300;
+        tick.ser(&mut writer);
+        let start = Instant::now();
+        GameInstant::new(&start, &start).ser(&mut writer);
+
+        // no tick buffered messages
+        false.ser(&mut writer);
+
+        // one message on an indexed channel
+        true.ser(&mut writer);
+        ChannelKind::of::<TickIntervalChannel>().ser(&protocol.channel_kinds, &mut writer);
+        true.ser(&mut writer);
+        IndexedMessageWriter::write_message_index(&mut writer, &None, &7);
+        let message = MessageContainer::from_write(
+            Box::new(TickIntervalMessage {
+                tick: 299,
+                interval_micros: 50_000,
+            }),
+            &mut FakeEntityConverter,
+        );
+        message.write(
+            &protocol.message_kinds,
+            &mut writer,
+            &mut FakeEntityConverter,
+        );
+        false.ser(&mut writer);
+        false.ser(&mut writer);
+
+        // no updates
+        false.ser(&mut writer);
+
+        // one despawn action
+        true.ser(&mut writer);
+        IndexedMessageWriter::write_message_index(&mut writer, &None, &3);
+        // resent, first written 2 Ticks earlier
+        UnsignedVariableInteger::<3>::new(2).ser(&mut writer);
+        EntityActionType::DespawnEntity.ser(&mut writer);
+        LocalEntity::new_host(9).host_ser(&mut writer);
+        false.ser(&mut writer);
+
+        let payload = writer.to_bytes();
+        let dissector = PacketDissector::new(&protocol);
+        let packet = dissector
+            .dissect(PacketDirection::ServerToClient, &payload)
+            .unwrap();
+
+        assert!(!packet.malformed);
+        assert_eq!(packet.header.sender_packet_index, 12);
+        assert_eq!(packet.acked_packets, vec![10, 9, 7]);
+        assert_eq!(packet.tick, Some(300));
+        assert_eq!(packet.messages.len(), 1);
+        assert_eq!(packet.messages[0].channel, "TickIntervalChannel");
+        assert_eq!(packet.messages[0].name, "TickIntervalMessage");
+        assert_eq!(packet.messages[0].index, Some(7));
+        assert!(matches!(
+            packet.actions[0],
+            (
+                3,
+                298,
+                DissectedAction::DespawnEntity(LocalEntity::Remote(9))
+            )
+        ));
+
+        // the same packet cut short still shows what could be read
+        let packet = dissector
+            .dissect(
+                PacketDirection::ServerToClient,
+                &payload[..payload.len() - 2],
+            )
+            .unwrap();
+        assert!(packet.malformed);
+        assert_eq!(packet.messages.len(), 1);
+        assert!(packet.actions.is_empty());
+    }
+}
@@ -0,0 +1,272 @@
+use std::{
+    error::Error,
+    fmt,
+    net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr},
+    time::Duration,
+};
+
+use crate::packet_dissector::PacketDirection;
+
+const LINKTYPE_NULL: u32 = 0;
+const LINKTYPE_ETHERNET: u32 = 1;
+const LINKTYPE_RAW: u32 = 101;
+const LINKTYPE_LINUX_SLL: u32 = 113;
+const LINKTYPE_LINUX_SLL2: u32 = 276;
+
+const ETHERTYPE_IPV4: u16 = 0x0800;
+const ETHERTYPE_IPV6: u16 = 0x86DD;
+const ETHERTYPE_VLAN: u16 = 0x8100;
+
+const IP_PROTOCOL_UDP: u8 = 17;
+
+/// A UDP datagram read from a packet capture
+pub struct CapturedDatagram {
+    /// Time since the Unix epoch the datagram was captured at
+    pub timestamp: Duration,
+    pub source: SocketAddr,
+    pub destination: SocketAddr,
+    pub payload: Vec<u8>,
+}
+
+impl CapturedDatagram {
+    /// Which way the datagram went, given the port the Server listens for
+    /// data on. None if it is not traffic to or from that port.
+    pub fn direction(&self, server_port: u16) -> Option<PacketDirection> {
+        if self.source.port() == server_port {
+            Some(PacketDirection::ServerToClient)
+        } else if self.destination.port() == server_port {
+            Some(PacketDirection::ClientToServer)
+        } else {
+            None
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum CaptureError {
+    /// The file does not start with a pcap header. Note that pcapng files
+    /// must be converted to pcap first.
+    NotPcap,
+    UnsupportedLinkType(u32),
+    Truncated,
+}
+
+impl fmt::Display for CaptureError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NotPcap => write!(f, "Capture is not in the pcap format"),
+            Self::UnsupportedLinkType(link_type) => {
+                write!(f, "Capture has unsupported link type {}", link_type)
+            }
+            Self::Truncated => write!(f, "Capture is truncated"),
+        }
+    }
+}
+
+impl Error for CaptureError {}
+
+/// Reads every UDP datagram out of a pcap capture. Only naia's UDP transport
+/// can be dissected from a capture, as WebRTC data channels are encrypted.
+pub fn read_pcap(bytes: &[u8]) -> Result<Vec<CapturedDatagram>, CaptureError> {
+    let magic = bytes.get(0..4).ok_or(CaptureError::NotPcap)?;
+    let (big_endian, nanoseconds) = match magic {
+        [0xd4, 0xc3, 0xb2, 0xa1] => (false, false),
+        [0xa1, 0xb2, 0xc3, 0xd4] => (true, false),
+        [0x4d, 0x3c, 0xb2, 0xa1] => (false, true),
+        [0xa1, 0xb2, 0x3c, 0x4d] => (true, true),
+        _ => return Err(CaptureError::NotPcap),
+    };
+    let read_u32 = |offset: usize| -> Result<u32, CaptureError> {
+        let field: [u8; 4] = bytes
+            .get(offset..offset + 4)
+            .ok_or(CaptureError::Truncated)?
+            .try_into()
+            .unwrap();
+        Ok(if big_endian {
+            u32::from_be_bytes(field)
+        } else {
+            u32::from_le_bytes(field)
+        })
+    };
+
+    let link_type = read_u32(20)? & 0x0fff_ffff;
+    if !matches!(
+        link_type,
+        LINKTYPE_NULL | LINKTYPE_ETHERNET | LINKTYPE_RAW | LINKTYPE_LINUX_SLL | LINKTYPE_LINUX_SLL2
+    ) {
+        return Err(CaptureError::UnsupportedLinkType(link_type));
+    }
+
+    let mut output = Vec::new();
+    let mut offset = 24;
+    while offset < bytes.len() {
+        let seconds = read_u32(offset)?;
+        let fraction = read_u32(offset + 4)?;
+        let captured_length = read_u32(offset + 8)? as usize;
+        let frame_start = offset + 16;
+        let frame = bytes
+            .get(frame_start..frame_start + captured_length)
+            .ok_or(CaptureError::Truncated)?;
+        offset = frame_start + captured_length;
+
+        let timestamp = if nanoseconds {
+            Duration::new(seconds as u64, fraction)
+        } else {
+            Duration::new(seconds as u64, fraction * 1000)
+        };
+
+        // Frames which aren't complete UDP datagrams over IP are skipped
+        if let Some((source, destination, payload)) = read_frame(link_type, frame) {
+            output.push(CapturedDatagram {
+                timestamp,
+                source,
+                destination,
+                payload: payload.to_vec(),
+            });
+        }
+    }
+
+    Ok(output)
+}
+
+fn read_frame(link_type: u32, frame: &[u8]) -> Option<(SocketAddr, SocketAddr, &[u8])> {
+    match link_type {
+        LINKTYPE_NULL => {
+            // The address family is in the capturing host's byte order, but
+            // the IP header says which version it is anyway
+            read_ip(frame.get(4..)?)
+        }
+        LINKTYPE_ETHERNET => {
+            let mut ether_type = read_u16(frame, 12)?;
+            let mut header_length = 14;
+            while ether_type == ETHERTYPE_VLAN {
+                ether_type = read_u16(frame, header_length + 2)?;
+                header_length += 4;
+            }
+            read_ether_type(ether_type, frame.get(header_length..)?)
+        }
+        LINKTYPE_RAW => read_ip(frame),
+        LINKTYPE_LINUX_SLL => read_ether_type(read_u16(frame, 14)?, frame.get(16..)?),
+        LINKTYPE_LINUX_SLL2 => read_ether_type(read_u16(frame, 0)?, frame.get(20..)?),
+        _ => None,
+    }
+}
+
+fn read_ether_type(ether_type: u16, packet: &[u8]) -> Option<(SocketAddr, SocketAddr, &[u8])> {
+    match ether_type {
+        ETHERTYPE_IPV4 | ETHERTYPE_IPV6 => read_ip(packet),
+        _ => None,
+    }
+}
+
+fn read_ip(packet: &[u8]) -> Option<(SocketAddr, SocketAddr, &[u8])> {
+    let version = packet.first()? >> 4;
+    let (source, destination, protocol, datagram) = match version {
+        4 => {
+            let header_length = ((packet[0] & 0x0f) as usize) * 4;
+            let total_length = read_u16(packet, 2)? as usize;
+            let source: [u8; 4] = packet.get(12..16)?.try_into().ok()?;
+            let destination: [u8; 4] = packet.get(16..20)?.try_into().ok()?;
+            (
+                IpAddr::V4(Ipv4Addr::from(source)),
+                IpAddr::V4(Ipv4Addr::from(destination)),
+                *packet.get(9)?,
+                packet.get(header_length..total_length)?,
+            )
+        }
+        6 => {
+            // Extension headers aren't followed, naia's datagrams don't use them
+            let payload_length = read_u16(packet, 4)? as usize;
+            let source: [u8; 16] = packet.get(8..24)?.try_into().ok()?;
+            let destination: [u8; 16] = packet.get(24..40)?.try_into().ok()?;
+            (
+                IpAddr::V6(Ipv6Addr::from(source)),
+                IpAddr::V6(Ipv6Addr::from(destination)),
+                *packet.get(6)?,
+                packet.get(40..40 + payload_length)?,
+            )
+        }
+        _ => return None,
+    };
+    if protocol != IP_PROTOCOL_UDP {
+        return None;
+    }
+
+    let source_port = read_u16(datagram, 0)?;
+    let destination_port = read_u16(datagram, 2)?;
+    let datagram_length = read_u16(datagram, 4)? as usize;
+    let payload = datagram.get(8..datagram_length)?;
+
+    Some((
+        SocketAddr::new(source, source_port),
+        SocketAddr::new(destination, destination_port),
+        payload,
+    ))
+}
+
+fn read_u16(bytes: &[u8], offset: usize) -> Option<u16> {
+    let field: [u8; 2] = bytes.get(offset..offset + 2)?.try_into().ok()?;
+    Some(u16::from_be_bytes(field))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{read_pcap, CaptureError, PacketDirection};
+
+    fn ethernet_udp_frame(source_port: u16, destination_port: u16, payload: &[u8]) -> Vec<u8> {
+        let mut frame = vec![0; 12];
+        frame.extend_from_slice(&0x0800u16.to_be_bytes());
+
+        let total_length = (20 + 8 + payload.len()) as u16;
+        frame.extend_from_slice(&[0x45, 0]);
+        frame.extend_from_slice(&total_length.to_be_bytes());
+        frame.extend_from_slice(&[0, 0, 0, 0, 64, 17, 0, 0]);
+        frame.extend_from_slice(&[127, 0, 0, 1]);
+        frame.extend_from_slice(&[127, 0, 0, 2]);
+
+        frame.extend_from_slice(&source_port.to_be_bytes());
+        frame.extend_from_slice(&destination_port.to_be_bytes());
+        frame.extend_from_slice(&((8 + payload.len()) as u16).to_be_bytes());
+        frame.extend_from_slice(&[0, 0]);
+        frame.extend_from_slice(payload);
+        frame
+    }
+
+    #[test]
+    fn reads_udp_datagrams() {
+        let mut capture = vec![0xd4, 0xc3, 0xb2, 0xa1, 2, 0, 4, 0];
+        capture.extend_from_slice(&[0; 8]);
+        capture.extend_from_slice(&65535u32.to_le_bytes());
+        capture.extend_from_slice(&1u32.to_le_bytes());
+
+        for (seconds, frame) in [
+            (1u32, ethernet_udp_frame(14191, 50000, &[1, 2, 3])),
+            (2u32, ethernet_udp_frame(50000, 14191, &[4, 5])),
+        ] {
+            capture.extend_from_slice(&seconds.to_le_bytes());
+            capture.extend_from_slice(&500u32.to_le_bytes());
+            capture.extend_from_slice(&(frame.len() as u32).to_le_bytes());
+            capture.extend_from_slice(&(frame.len() as u32).to_le_bytes());
+            capture.extend_from_slice(&frame);
+        }
+
+        let datagrams = read_pcap(&capture).unwrap();
+        assert_eq!(datagrams.len(), 2);
+        assert_eq!(datagrams[0].payload, vec![1, 2, 3]);
+        assert_eq!(datagrams[0].source.to_string(), "127.0.0.1:14191");
+        assert_eq!(
+            datagrams[0].direction(14191),
+            Some(PacketDirection::ServerToClient)
+        );
+        assert_eq!(datagrams[1].timestamp.as_micros(), 2_000_500);
+        assert_eq!(
+            datagrams[1].direction(14191),
+            Some(PacketDirection::ClientToServer)
+        );
+        assert_eq!(datagrams[1].direction(14192), None);
+
+        capture.truncate(capture.len() - 1);
+        assert!(matches!(read_pcap(&capture), Err(CaptureError::Truncated)));
+        assert!(matches!(read_pcap(&[0; 24]), Err(CaptureError::NotPcap)));
+    }
+}
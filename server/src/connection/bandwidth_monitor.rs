@@ -1,26 +1,28 @@
-use std::{collections::HashMap, net::SocketAddr, time::Duration};
+use std::{collections::HashMap, net::SocketAddr, sync::Arc, time::Duration};
 
-use naia_shared::BandwidthMonitor as SingleBandwidthMonitor;
+use naia_shared::{BandwidthMonitor as SingleBandwidthMonitor, Clock};
 
 pub struct BandwidthMonitor {
     total_monitor: SingleBandwidthMonitor,
     client_monitors: HashMap<SocketAddr, SingleBandwidthMonitor>,
     bandwidth_measure_duration: Duration,
+    clock: Arc<dyn Clock>,
 }
 
 impl BandwidthMonitor {
-    pub fn new(bandwidth_measure_duration: Duration) -> Self {
+    pub fn new(bandwidth_measure_duration: Duration, clock: &Arc<dyn Clock>) -> Self {
         BandwidthMonitor {
             bandwidth_measure_duration,
-            total_monitor: SingleBandwidthMonitor::new(bandwidth_measure_duration),
+            total_monitor: SingleBandwidthMonitor::new(bandwidth_measure_duration, clock),
             client_monitors: HashMap::new(),
+            clock: clock.clone(),
         }
     }
 
     pub fn create_client(&mut self, address: &SocketAddr) {
         self.client_monitors.insert(
             *address,
-            SingleBandwidthMonitor::new(self.bandwidth_measure_duration),
+            SingleBandwidthMonitor::new(self.bandwidth_measure_duration, &self.clock),
         );
     }
 
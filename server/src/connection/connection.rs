@@ -1,22 +1,30 @@
-use std::{hash::Hash, net::SocketAddr};
+use std::{
+    collections::{HashMap, HashSet},
+    hash::Hash,
+    net::SocketAddr,
+    sync::Arc,
+};
 
 use log::warn;
 
 use naia_shared::{
-    BaseConnection, BigMapKey, BitReader, BitWriter, ChannelKinds, ConnectionConfig,
-    EntityConverter, EntityEvent, HostType, HostWorldEvents, Instant, PacketType, Protocol, Serde,
-    SerdeErr, StandardHeader, Tick, WorldMutType, WorldRefType,
+    BaseConnection, BigMapKey, BitReader, BitWriter, ChannelKind, ChannelKinds, Clock,
+    ConnectionConfig, EntityAuthAction, EntityAuthChannel, EntityAuthMessage,
+    EntityAuthUpdateMessage, EntityConverter, EntityConverterMut, EntityEvent, HostType,
+    HostWorldEvents, Instant, MessageContainer, MessageKind, NetworkStats, PacketType, Protocol,
+    Serde, SerdeErr, StandardHeader, Tick, TickBufferReceiver, TickBufferSender,
+    UnsignedVariableInteger, WorldMutType, WorldRefType,
 };
 
 use crate::{
-    connection::{
-        io::Io, ping_config::PingConfig, tick_buffer_messages::TickBufferMessages,
-        tick_buffer_receiver::TickBufferReceiver,
-    },
+    connection::{io::Io, ping_config::PingConfig, tick_buffer_messages::TickBufferMessages},
     events::Events,
     time_manager::TimeManager,
     user::UserKey,
-    world::global_world_manager::GlobalWorldManager,
+    world::{
+        global_world_manager::GlobalWorldManager,
+        validation::{ComponentValidators, UserStateValidator, Validation, ValidationOutcome},
+    },
 };
 
 use super::ping_manager::PingManager;
@@ -27,6 +35,16 @@ pub struct Connection<E: Copy + Eq + Hash + Send + Sync> {
     pub base: BaseConnection<E>,
     pub ping_manager: PingManager,
     tick_buffer: TickBufferReceiver,
+    pub tick_buffer_sender: TickBufferSender,
+    incoming_auth_actions: Vec<(E, EntityAuthAction)>,
+    incoming_auth_updates: Vec<(E, Box<[u8]>)>,
+    /// The client's Entities which a validator despawned, and which the client
+    /// has yet to despawn itself. Any state received for them is discarded.
+    discarded_entities: HashSet<E>,
+    /// Entities which have left the client's scope but are kept on the client,
+    /// their updates are held back until they re-enter it. Maps to when each
+    /// went dormant.
+    pub dormant_entities: HashMap<E, Instant>,
 }
 
 impl<E: Copy + Eq + Hash + Send + Sync> Connection<E> {
@@ -37,6 +55,7 @@ impl<E: Copy + Eq + Hash + Send + Sync> Connection<E> {
         user_key: &UserKey,
         channel_kinds: &ChannelKinds,
         global_world_manager: &GlobalWorldManager<E>,
+        clock: &Arc<dyn Clock>,
     ) -> Self {
         Connection {
             address: *user_address,
@@ -48,9 +67,15 @@ impl<E: Copy + Eq + Hash + Send + Sync> Connection<E> {
                 connection_config,
                 channel_kinds,
                 global_world_manager,
+                clock,
             ),
-            tick_buffer: TickBufferReceiver::new(channel_kinds),
-            ping_manager: PingManager::new(ping_config),
+            tick_buffer: TickBufferReceiver::new(HostType::Server, channel_kinds),
+            tick_buffer_sender: TickBufferSender::new(HostType::Server, channel_kinds),
+            ping_manager: PingManager::new(ping_config, clock),
+            incoming_auth_actions: Vec::new(),
+            incoming_auth_updates: Vec::new(),
+            discarded_entities: HashSet::new(),
+            dormant_entities: HashMap::new(),
         }
     }
 
@@ -58,10 +83,28 @@ impl<E: Copy + Eq + Hash + Send + Sync> Connection<E> {
         self.user_key
     }
 
+    pub fn network_stats(&self, protocol: &Protocol, io: &Io) -> NetworkStats {
+        let mut stats = self.base.network_stats(protocol);
+        if let Some(counter) = io.client_packet_counter(&self.address) {
+            stats.add_packets(counter);
+        }
+        stats.add_channel_bits(
+            &protocol.channel_kinds,
+            self.tick_buffer_sender.channel_bits_sent(),
+        );
+        stats.add_message_bits(
+            &protocol.message_kinds,
+            self.tick_buffer_sender.message_bits_sent(),
+        );
+        stats.messages_dropped = self.tick_buffer_sender.messages_dropped();
+        stats
+    }
+
     // Incoming Data
 
     pub fn process_incoming_header(&mut self, header: &StandardHeader) {
-        self.base.process_incoming_header(header, &mut []);
+        self.base
+            .process_incoming_header(header, &mut [&mut self.tick_buffer_sender]);
     }
 
     /// Read packet data received from a client, storing necessary data in an internal buffer
@@ -109,14 +152,17 @@ impl<E: Copy + Eq + Hash + Send + Sync> Connection<E> {
         return Ok(());
     }
 
-    /// Receive & process stored packet data
+    /// Receive & process stored packet data. The registered validators check
+    /// the Entity state the client proposes before it is applied, and any
+    /// verdicts the Server must act on are returned.
     pub fn process_packets<W: WorldMutType<E>>(
         &mut self,
         protocol: &Protocol,
         global_world_manager: &mut GlobalWorldManager<E>,
+        validators: &ComponentValidators<E>,
         world: &mut W,
         incoming_events: &mut Events<E>,
-    ) {
+    ) -> ValidationOutcome<E> {
         // Receive Message Events
         let messages = self.base.message_manager.receive_messages(
             global_world_manager,
@@ -124,28 +170,173 @@ impl<E: Copy + Eq + Hash + Send + Sync> Connection<E> {
             &mut self.base.remote_world_manager.entity_waitlist,
         );
         for (channel_kind, messages) in messages {
+            if channel_kind == ChannelKind::of::<EntityAuthChannel>() {
+                self.receive_auth_messages(global_world_manager, messages);
+                continue;
+            }
             for message in messages {
                 incoming_events.push_message(&self.user_key, &channel_kind, message);
             }
         }
 
         // read world events
-        if protocol.client_authoritative_entities {
-            let remote_events = self.base.remote_world_reader.take_incoming_events();
-            let world_events = self.base.remote_world_manager.process_world_events(
-                global_world_manager,
-                &mut self.base.local_world_manager,
-                &protocol.component_kinds,
-                world,
-                remote_events,
-            );
-            for event in &world_events {
-                if let EntityEvent::SpawnEntity(entity) = event {
-                    global_world_manager.remote_spawn_entity_record(entity, &self.user_key);
+        if !protocol.client_authoritative_entities {
+            return ValidationOutcome::default();
+        }
+        let remote_events = self.base.remote_world_reader.take_incoming_events();
+        let mut validator =
+            UserStateValidator::new(&self.user_key, validators, &self.discarded_entities);
+        let mut world_events = self.base.remote_world_manager.process_world_events(
+            global_world_manager,
+            &mut self.base.local_world_manager,
+            &protocol.component_kinds,
+            world,
+            remote_events,
+            Some(&mut validator),
+        );
+        let outcome = ValidationOutcome {
+            despawned_entities: validator.despawned_entities,
+            kick: validator.kick,
+        };
+
+        for event in &world_events {
+            if let EntityEvent::SpawnEntity(_, entity) = event {
+                global_world_manager.remote_spawn_entity_record(entity, &self.user_key);
+            }
+        }
+
+        // the Server already emitted events for the despawn of discarded Entities,
+        // so the client confirming it emits none
+        world_events.retain(|event| match event {
+            EntityEvent::DespawnEntity(_, entity) => !self.discarded_entities.remove(entity),
+            _ => true,
+        });
+        self.discarded_entities
+            .extend(outcome.despawned_entities.iter().copied());
+
+        incoming_events.receive_entity_events(&self.user_key, world_events);
+        outcome
+    }
+
+    fn receive_auth_messages(
+        &mut self,
+        global_world_manager: &GlobalWorldManager<E>,
+        messages: Vec<MessageContainer>,
+    ) {
+        for message in messages {
+            if message.kind() == MessageKind::of::<EntityAuthUpdateMessage>() {
+                let message = message
+                    .to_boxed_any()
+                    .downcast::<EntityAuthUpdateMessage>()
+                    .unwrap();
+                if let Some(entity) = message.entity.get(global_world_manager) {
+                    self.incoming_auth_updates.push((entity, message.bytes));
+                }
+            } else if message.kind() == MessageKind::of::<EntityAuthMessage>() {
+                let message = message
+                    .to_boxed_any()
+                    .downcast::<EntityAuthMessage>()
+                    .unwrap();
+                if let Some(entity) = message.entity.get(global_world_manager) {
+                    self.incoming_auth_actions.push((entity, message.action));
+                }
+            } else {
+                warn!("Server Error: received unexpected message on Entity authority channel");
+            }
+        }
+    }
+
+    /// Take all Entity authority actions received from the client
+    pub fn take_auth_actions(&mut self) -> Vec<(E, EntityAuthAction)> {
+        std::mem::take(&mut self.incoming_auth_actions)
+    }
+
+    /// Apply the Component state received for delegated Entities this client holds
+    /// authority over, once the registered validators accept it. State received for
+    /// any other Entity is discarded.
+    pub fn apply_auth_updates<W: WorldMutType<E>>(
+        &mut self,
+        protocol: &Protocol,
+        global_world_manager: &GlobalWorldManager<E>,
+        validators: &ComponentValidators<E>,
+        world: &mut W,
+        outcome: &mut ValidationOutcome<E>,
+    ) {
+        let updates = std::mem::take(&mut self.incoming_auth_updates);
+        for (entity, bytes) in updates {
+            if global_world_manager.entity_authority(&entity) != Some(self.user_key) {
+                warn!("Server Error: received Entity update from a client without authority");
+                continue;
+            }
+            if self
+                .apply_auth_update(
+                    protocol,
+                    global_world_manager,
+                    validators,
+                    world,
+                    &entity,
+                    &bytes,
+                    outcome,
+                )
+                .is_err()
+            {
+                warn!("Server Error: cannot read malformed Entity update");
+            }
+        }
+    }
+
+    fn apply_auth_update<W: WorldMutType<E>>(
+        &self,
+        protocol: &Protocol,
+        global_world_manager: &GlobalWorldManager<E>,
+        validators: &ComponentValidators<E>,
+        world: &mut W,
+        entity: &E,
+        bytes: &[u8],
+        outcome: &mut ValidationOutcome<E>,
+    ) -> Result<(), SerdeErr> {
+        let converter = EntityConverter::new(global_world_manager, &self.base.local_world_manager);
+        let mut reader = BitReader::new(bytes);
+
+        let components_num = UnsignedVariableInteger::<3>::de(&mut reader)?.get();
+        for _ in 0..components_num {
+            let component = protocol.component_kinds.read(&mut reader, &converter)?;
+            let component_kind = component.kind();
+            if outcome.kick || outcome.despawned_entities.contains(entity) {
+                continue;
+            }
+
+            let verdict = match validators.get(&component_kind) {
+                Some(validator) => {
+                    let previous = world.component_of_kind(entity, &component_kind);
+                    validator.validate(
+                        &self.user_key,
+                        entity,
+                        previous.as_deref(),
+                        component.as_ref(),
+                    )
                 }
+                None => Validation::Accept,
+            };
+            let accepted = match verdict {
+                Validation::Accept => component,
+                Validation::Replace(replacement) => replacement,
+                Validation::Reject => continue,
+                Validation::Despawn => {
+                    outcome.despawned_entities.push(*entity);
+                    continue;
+                }
+                Validation::Kick => {
+                    outcome.kick = true;
+                    continue;
+                }
+            };
+            if let Some(mut component_mut) = world.component_mut_of_kind(entity, &component_kind) {
+                component_mut.mirror(accepted.as_ref());
             }
-            incoming_events.receive_entity_events(&self.user_key, world_events);
         }
+
+        Ok(())
     }
 
     pub fn tick_buffer_messages(&mut self, tick: &Tick, messages: &mut TickBufferMessages) {
@@ -169,11 +360,28 @@ impl<E: Copy + Eq + Hash + Send + Sync> Connection<E> {
     ) {
         let rtt_millis = self.ping_manager.rtt_average;
         self.base.collect_outgoing_messages(now, &rtt_millis);
+        self.tick_buffer_sender.collect_outgoing_messages(
+            &time_manager.current_tick(),
+            &self.client_receivable_tick(time_manager),
+        );
         let mut host_world_events = self
             .base
             .host_world_manager
             .take_outgoing_events(now, &rtt_millis);
 
+        // the client holding authority over a delegated Entity is the source of its state,
+        // so never send updates of that Entity back to it
+        host_world_events.next_send_updates.retain(|entity, _| {
+            global_world_manager.entity_authority(entity) != Some(self.user_key)
+        });
+
+        // dormant Entities keep accumulating changes, which are sent once they
+        // re-enter the client's scope. Diff masks are only cleared once written,
+        // so leaving them out here keeps them dirty.
+        host_world_events
+            .next_send_updates
+            .retain(|entity, _| !self.dormant_entities.contains_key(entity));
+
         let mut any_sent = false;
         loop {
             if self.send_outgoing_packet(
@@ -195,6 +403,21 @@ impl<E: Copy + Eq + Hash + Send + Sync> Connection<E> {
         }
     }
 
+    /// Gets the oldest Tick the client can still deliver Tick Buffered
+    /// messages for, once a packet sent now arrives. The client delivers each
+    /// Tick about one latency, a jitter margin and a Tick behind the Server,
+    /// so only that margin is left by the time the packet arrives.
+    fn client_receivable_tick(&self, time_manager: &TimeManager) -> Tick {
+        let current_tick = time_manager.current_tick();
+        let tick_millis = time_manager.average_tick_duration().as_secs_f32() * 1000.0;
+        if tick_millis <= 0.0 {
+            return current_tick;
+        }
+        let margin_millis = (self.ping_manager.jitter_average * 1.5) + tick_millis;
+        let margin_ticks = (margin_millis / tick_millis).ceil() as Tick;
+        current_tick.wrapping_sub(margin_ticks)
+    }
+
     /// Send any message, component actions and component updates to the client
     /// Will split the data into multiple packets.
     fn send_outgoing_packet<W: WorldRefType<E>>(
@@ -207,35 +430,62 @@ impl<E: Copy + Eq + Hash + Send + Sync> Connection<E> {
         time_manager: &TimeManager,
         host_world_events: &mut HostWorldEvents<E>,
     ) -> bool {
-        if host_world_events.has_events() || self.base.message_manager.has_outgoing_messages() {
+        if host_world_events.has_events()
+            || self.base.message_manager.has_outgoing_messages()
+            || self.tick_buffer_sender.has_outgoing_messages()
+        {
             let next_packet_index = self.base.next_packet_index();
 
             let mut writer = BitWriter::new();
 
             // Reserve bits we know will be required to finish the message:
-            // 1. Messages finish bit
-            // 2. Updates finish bit
-            // 3. Actions finish bit
-            writer.reserve_bits(3);
+            // 1. Tick buffer finish bit
+            // 2. Messages finish bit
+            // 3. Updates finish bit
+            // 4. Actions finish bit
+            writer.reserve_bits(4);
 
             // write header
             self.base
                 .write_outgoing_header(PacketType::Data, &mut writer);
 
             // write server tick
-            time_manager.current_tick().ser(&mut writer);
+            let server_tick = time_manager.current_tick();
+            server_tick.ser(&mut writer);
 
             // write server tick instant
             time_manager.current_tick_instant().ser(&mut writer);
 
-            // write common data packet
             let mut has_written = false;
 
+            // write tick buffered messages
+            {
+                let mut converter = EntityConverterMut::new(
+                    global_world_manager,
+                    &mut self.base.local_world_manager,
+                );
+                self.tick_buffer_sender.write_messages(
+                    protocol,
+                    &mut converter,
+                    &mut writer,
+                    next_packet_index,
+                    &server_tick,
+                    &mut has_written,
+                );
+
+                // finish tick buffered messages
+                false.ser(&mut writer);
+                writer.release_bits(1);
+            }
+
+            // write common data packet
+
             self.base.write_outgoing_packet(
                 &protocol,
                 now,
                 &mut writer,
                 next_packet_index,
+                &server_tick,
                 world,
                 global_world_manager,
                 &mut has_written,
@@ -244,7 +494,10 @@ impl<E: Copy + Eq + Hash + Send + Sync> Connection<E> {
             );
 
             // send packet
-            if io.send_packet(&self.address, writer.to_packet()).is_err() {
+            let packet = writer.to_packet();
+            self.base
+                .record_sent_packet(packet.slice().len(), &self.tick_buffer_sender);
+            if io.send_packet(&self.address, packet).is_err() {
                 // TODO: pass this on and handle above
                 warn!("Server Error: Cannot send data packet to {}", &self.address);
             }
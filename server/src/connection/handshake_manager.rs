@@ -13,10 +13,34 @@ use crate::{cache_map::CacheMap, connection::connection::Connection};
 pub type Timestamp = u64;
 
 pub enum HandshakeResult {
-    Invalid,
+    Invalid(HandshakeFailure),
     Success(Option<MessageContainer>),
 }
 
+/// Why a Client's handshake failed
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum HandshakeFailure {
+    /// A handshake packet could not be read
+    MalformedPacket,
+    /// The Client's timestamp was not issued by this Server
+    InvalidTimestamp,
+    /// The Client & Server disagree on whether auth is required
+    AuthMismatch,
+    /// The Server rejected the Client's auth
+    AuthRejected,
+}
+
+impl HandshakeFailure {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::MalformedPacket => "malformed_packet",
+            Self::InvalidTimestamp => "invalid_timestamp",
+            Self::AuthMismatch => "auth_mismatch",
+            Self::AuthRejected => "auth_rejected",
+        }
+    }
+}
+
 pub struct HandshakeManager {
     connection_hash_key: hmac::Key,
     require_auth: bool,
@@ -77,14 +101,14 @@ impl HandshakeManager {
         // Verify that timestamp hash has been written by this
         // server instance
         let Some(timestamp) = self.timestamp_validate(reader) else {
-            return HandshakeResult::Invalid;
+            return HandshakeResult::Invalid(HandshakeFailure::InvalidTimestamp);
         };
         // Timestamp hash is validated, now start configured auth process
         let Ok(has_auth) = bool::de(reader) else {
-            return HandshakeResult::Invalid;
+            return HandshakeResult::Invalid(HandshakeFailure::MalformedPacket);
         };
         if has_auth != self.require_auth {
-            return HandshakeResult::Invalid;
+            return HandshakeResult::Invalid(HandshakeFailure::AuthMismatch);
         }
 
         self.address_to_timestamp_map.insert(*address, timestamp);
@@ -94,7 +118,7 @@ impl HandshakeManager {
         }
 
         let Ok(auth_message) = message_kinds.read(reader, &FakeEntityConverter) else {
-            return HandshakeResult::Invalid;
+            return HandshakeResult::Invalid(HandshakeFailure::MalformedPacket);
         };
 
         return HandshakeResult::Success(Some(auth_message));
@@ -1,6 +1,9 @@
-use std::{net::SocketAddr, panic, time::Duration};
+use std::{collections::HashMap, net::SocketAddr, panic, sync::Arc, time::Duration};
 
-use naia_shared::{CompressionConfig, Decoder, Encoder, OutgoingPacket, OwnedBitReader};
+use naia_shared::{
+    BitReader, Clock, CompressionConfig, Decoder, Encoder, Instant, OutgoingPacket, OwnedBitReader,
+    PacketCounter, PacketType, Recording, Serde, StandardHeader, Tick,
+};
 
 use super::bandwidth_monitor::BandwidthMonitor;
 use crate::{
@@ -15,15 +18,22 @@ pub struct Io {
     incoming_bandwidth_monitor: Option<BandwidthMonitor>,
     outgoing_encoder: Option<Encoder>,
     incoming_decoder: Option<Decoder>,
+    recordings: HashMap<SocketAddr, (Instant, Recording)>,
+    total_packet_counter: PacketCounter,
+    client_packet_counters: HashMap<SocketAddr, PacketCounter>,
+    clock: Arc<dyn Clock>,
 }
 
 impl Io {
     pub fn new(
         bandwidth_measure_duration: &Option<Duration>,
         compression_config: &Option<CompressionConfig>,
+        clock: &Arc<dyn Clock>,
     ) -> Self {
-        let outgoing_bandwidth_monitor = bandwidth_measure_duration.map(BandwidthMonitor::new);
-        let incoming_bandwidth_monitor = bandwidth_measure_duration.map(BandwidthMonitor::new);
+        let outgoing_bandwidth_monitor =
+            bandwidth_measure_duration.map(|duration| BandwidthMonitor::new(duration, clock));
+        let incoming_bandwidth_monitor =
+            bandwidth_measure_duration.map(|duration| BandwidthMonitor::new(duration, clock));
 
         let outgoing_encoder = compression_config.as_ref().and_then(|config| {
             config
@@ -45,6 +55,10 @@ impl Io {
             incoming_bandwidth_monitor,
             outgoing_encoder,
             incoming_decoder,
+            recordings: HashMap::new(),
+            total_packet_counter: PacketCounter::new(),
+            client_packet_counters: HashMap::new(),
+            clock: clock.clone(),
         }
     }
 
@@ -73,6 +87,11 @@ impl Io {
         // get payload
         let mut payload = packet.slice();
 
+        // Recording
+        if let Some((start_instant, recording)) = self.recordings.get_mut(address) {
+            record_packet(start_instant, &self.clock.now(), recording, payload);
+        }
+
         // Compression
         if let Some(encoder) = &mut self.outgoing_encoder {
             payload = encoder.encode(payload);
@@ -82,6 +101,10 @@ impl Io {
         if let Some(monitor) = &mut self.outgoing_bandwidth_monitor {
             monitor.record_packet(address, payload.len());
         }
+        self.total_packet_counter.record_sent(payload.len());
+        if let Some(counter) = self.client_packet_counters.get_mut(address) {
+            counter.record_sent(payload.len());
+        }
 
         self.packet_sender
             .as_ref()
@@ -90,6 +113,27 @@ impl Io {
             .map_err(|_| NaiaServerError::SendError(*address))
     }
 
+    pub fn start_recording(&mut self, address: &SocketAddr, recording: Recording) {
+        self.recordings
+            .insert(*address, (self.clock.now(), recording));
+    }
+
+    pub fn record_keyframe(&mut self, address: &SocketAddr, tick: Tick, snapshot: Vec<u8>) {
+        if let Some((_, recording)) = self.recordings.get_mut(address) {
+            recording.push_keyframe(tick, snapshot);
+        }
+    }
+
+    pub fn is_recording(&self, address: &SocketAddr) -> bool {
+        self.recordings.contains_key(address)
+    }
+
+    pub fn stop_recording(&mut self, address: &SocketAddr) -> Option<Recording> {
+        self.recordings
+            .remove(address)
+            .map(|(_, recording)| recording)
+    }
+
     pub fn recv_reader(&mut self) -> Result<Option<(SocketAddr, OwnedBitReader)>, NaiaServerError> {
         let receive_result = self
             .packet_receiver
@@ -103,10 +147,17 @@ impl Io {
                 if let Some(monitor) = &mut self.incoming_bandwidth_monitor {
                     monitor.record_packet(&address, payload.len());
                 }
+                self.total_packet_counter.record_received(payload.len());
+                if let Some(counter) = self.client_packet_counters.get_mut(&address) {
+                    counter.record_received(payload.len());
+                }
 
                 // Decompression
                 if let Some(decoder) = &mut self.incoming_decoder {
-                    payload = decoder.decode(payload);
+                    let Ok(decoded_payload) = decoder.decode(payload) else {
+                        return Err(NaiaServerError::MalformedPacket(address));
+                    };
+                    payload = decoded_payload;
                 }
 
                 Ok(Some((address, OwnedBitReader::new(payload))))
@@ -121,25 +172,36 @@ impl Io {
     }
 
     pub fn register_client(&mut self, address: &SocketAddr) {
-        self.outgoing_bandwidth_monitor
-            .as_mut()
-            .expect("Need to call `enable_bandwidth_monitor()` on Io before calling this")
-            .create_client(address);
-        self.incoming_bandwidth_monitor
-            .as_mut()
-            .expect("Need to call `enable_bandwidth_monitor()` on Io before calling this")
-            .create_client(address);
+        self.client_packet_counters
+            .insert(*address, PacketCounter::new());
+
+        if let Some(monitor) = &mut self.outgoing_bandwidth_monitor {
+            monitor.create_client(address);
+        }
+        if let Some(monitor) = &mut self.incoming_bandwidth_monitor {
+            monitor.create_client(address);
+        }
     }
 
     pub fn deregister_client(&mut self, address: &SocketAddr) {
-        self.outgoing_bandwidth_monitor
-            .as_mut()
-            .expect("Need to call `enable_bandwidth_monitor()` on Io before calling this")
-            .delete_client(address);
-        self.incoming_bandwidth_monitor
-            .as_mut()
-            .expect("Need to call `enable_bandwidth_monitor()` on Io before calling this")
-            .delete_client(address);
+        self.client_packet_counters.remove(address);
+
+        if let Some(monitor) = &mut self.outgoing_bandwidth_monitor {
+            monitor.delete_client(address);
+        }
+        if let Some(monitor) = &mut self.incoming_bandwidth_monitor {
+            monitor.delete_client(address);
+        }
+    }
+
+    /// Gets the packets & bytes sent to & received from every address
+    pub fn total_packet_counter(&self) -> &PacketCounter {
+        &self.total_packet_counter
+    }
+
+    /// Gets the packets & bytes sent to & received from a registered client
+    pub fn client_packet_counter(&self, address: &SocketAddr) -> Option<&PacketCounter> {
+        self.client_packet_counters.get(address)
     }
 
     pub fn outgoing_bandwidth_total(&mut self) -> f32 {
@@ -174,3 +236,26 @@ impl Io {
             .client_bandwidth(address);
     }
 }
+
+// Only replicated state is recorded, handshakes & pings are answered by the
+// replaying Client's transport
+fn record_packet(
+    start_instant: &Instant,
+    now: &Instant,
+    recording: &mut Recording,
+    payload: &[u8],
+) {
+    let mut reader = BitReader::new(payload);
+    let Ok(header) = StandardHeader::de(&mut reader) else {
+        return;
+    };
+    match header.packet_type {
+        PacketType::Data | PacketType::Heartbeat => {}
+        _ => return,
+    }
+    let Ok(tick) = Tick::de(&mut reader) else {
+        return;
+    };
+    let millis = now.duration_since(start_instant).as_millis() as u32;
+    recording.push_packet(millis, tick, payload);
+}
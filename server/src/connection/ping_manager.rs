@@ -1,4 +1,6 @@
-use naia_shared::{BitReader, BitWriter, PingIndex, PingStore, Serde, Timer};
+use std::sync::Arc;
+
+use naia_shared::{BitReader, BitWriter, Clock, PingIndex, PingStore, Serde, Timer};
 
 use crate::{connection::ping_config::PingConfig, time_manager::TimeManager};
 
@@ -12,14 +14,14 @@ pub struct PingManager {
 }
 
 impl PingManager {
-    pub fn new(ping_config: &PingConfig) -> Self {
+    pub fn new(ping_config: &PingConfig, clock: &Arc<dyn Clock>) -> Self {
         let rtt_average = ping_config.rtt_initial_estimate.as_secs_f32() * 1000.0;
         let jitter_average = ping_config.jitter_initial_estimate.as_secs_f32() * 1000.0;
 
         PingManager {
             rtt_average: rtt_average,
             jitter_average: jitter_average,
-            ping_timer: Timer::new(ping_config.ping_interval),
+            ping_timer: Timer::new(ping_config.ping_interval, clock),
             sent_pings: PingStore::new(),
         }
     }
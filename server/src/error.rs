@@ -6,6 +6,40 @@ pub enum NaiaServerError {
     Wrapped(Box<dyn Error>),
     SendError(SocketAddr),
     RecvError,
+    /// A packet received from the given address could not be read, and was
+    /// dropped
+    MalformedPacket(SocketAddr),
+    UserNotFound,
+    RoomNotFound,
+    EntityNotFound,
+    /// The Entity must be removed from its current Room before being added to
+    /// another
+    EntityAlreadyInRoom,
+    /// The Channel is not configured to send Messages to Clients
+    ChannelNotSendable,
+    /// Tick Buffered Channels are sent on with
+    /// `Server::send_tick_buffer_message()`, and only those
+    ChannelTickBufferMismatch,
+    /// Authority can only be granted over Entities with delegation enabled
+    EntityNotDelegated,
+    /// Only Server-owned Entities can be delegated
+    EntityNotServerOwned,
+    /// The Entity is not in the User's scope
+    EntityNotInScope,
+    /// The Entity is already in the User's scope
+    EntityAlreadyInScope,
+    /// A snapshot can hold at most `u16::MAX` Entities
+    SnapshotTooLarge,
+    /// The Entity already has a Component of the same kind
+    ComponentAlreadyInserted,
+    /// Ticks can only be advanced by hand with `TickMode::Manual`
+    TickNotManual,
+    /// Resources must be registered with `Protocol::add_resource()`
+    ResourceNotRegistered,
+    /// An Entity can not be made a descendant of itself
+    EntityHierarchyCycle,
+    /// SpatialInterest cells must have a positive size
+    InvalidCellSize,
 }
 
 impl NaiaServerError {
@@ -25,6 +59,79 @@ impl fmt::Display for NaiaServerError {
             NaiaServerError::RecvError => {
                 write!(f, "Naia Server Error: RecvError")
             }
+            NaiaServerError::MalformedPacket(address) => {
+                write!(f, "Naia Server Error: Malformed packet from {}", address)
+            }
+            NaiaServerError::UserNotFound => {
+                write!(f, "Naia Server Error: No User exists for given Key!")
+            }
+            NaiaServerError::RoomNotFound => {
+                write!(f, "Naia Server Error: No Room exists for given Key!")
+            }
+            NaiaServerError::EntityNotFound => {
+                write!(f, "Naia Server Error: No Entity exists for given Key!")
+            }
+            NaiaServerError::EntityAlreadyInRoom => {
+                write!(f, "Naia Server Error: Entity already belongs to a Room! Remove the Entity from the Room before adding it to a new Room.")
+            }
+            NaiaServerError::ChannelNotSendable => {
+                write!(
+                    f,
+                    "Naia Server Error: Cannot send message to Client on this Channel"
+                )
+            }
+            NaiaServerError::ChannelTickBufferMismatch => {
+                write!(f, "Naia Server Error: Tick Buffered Channels can only be sent on with `Server.send_tick_buffer_message()`")
+            }
+            NaiaServerError::EntityNotDelegated => {
+                write!(f, "Naia Server Error: Entity is not delegated! Call `Server.enable_delegation()` first.")
+            }
+            NaiaServerError::EntityNotServerOwned => {
+                write!(
+                    f,
+                    "Naia Server Error: Only Server-owned Entities can be delegated!"
+                )
+            }
+            NaiaServerError::EntityNotInScope => {
+                write!(f, "Naia Server Error: Entity is not in scope for the User!")
+            }
+            NaiaServerError::EntityAlreadyInScope => {
+                write!(
+                    f,
+                    "Naia Server Error: Entity is already in scope for the User!"
+                )
+            }
+            NaiaServerError::SnapshotTooLarge => {
+                write!(
+                    f,
+                    "Naia Server Error: Cannot snapshot more than {} Entities",
+                    u16::MAX
+                )
+            }
+            NaiaServerError::ComponentAlreadyInserted => {
+                write!(
+                    f,
+                    "Naia Server Error: Entity already has a Component of this kind!"
+                )
+            }
+            NaiaServerError::TickNotManual => {
+                write!(f, "Naia Server Error: `Server.advance_tick()` requires `TickMode::Manual` in the ServerConfig")
+            }
+            NaiaServerError::ResourceNotRegistered => {
+                write!(f, "Naia Server Error: Resources must be registered with `Protocol::add_resource()`!")
+            }
+            NaiaServerError::EntityHierarchyCycle => {
+                write!(
+                    f,
+                    "Naia Server Error: Cannot make an Entity a descendant of itself!"
+                )
+            }
+            NaiaServerError::InvalidCellSize => {
+                write!(
+                    f,
+                    "Naia Server Error: SpatialInterest cell size must be positive!"
+                )
+            }
         }
     }
 }
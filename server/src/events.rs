@@ -23,6 +23,8 @@ pub struct Events<E: Copy> {
     inserts: HashMap<ComponentKind, Vec<(UserKey, E)>>,
     removes: HashMap<ComponentKind, Vec<(UserKey, E, Box<dyn Replicate>)>>,
     updates: HashMap<ComponentKind, Vec<(UserKey, E)>>,
+    auth_requests: Vec<(UserKey, E)>,
+    auth_releases: Vec<(UserKey, E)>,
     empty: bool,
 }
 
@@ -40,6 +42,8 @@ impl<E: Copy> Events<E> {
             inserts: HashMap::new(),
             removes: HashMap::new(),
             updates: HashMap::new(),
+            auth_requests: Vec::new(),
+            auth_releases: Vec::new(),
             empty: true,
         }
     }
@@ -210,6 +214,16 @@ impl<E: Copy> Events<E> {
         self.empty = false;
     }
 
+    pub(crate) fn push_auth_request(&mut self, user_key: &UserKey, entity: &E) {
+        self.auth_requests.push((*user_key, *entity));
+        self.empty = false;
+    }
+
+    pub(crate) fn push_auth_release(&mut self, user_key: &UserKey, entity: &E) {
+        self.auth_releases.push((*user_key, *entity));
+        self.empty = false;
+    }
+
     pub(crate) fn receive_entity_events(
         &mut self,
         user_key: &UserKey,
@@ -217,16 +231,16 @@ impl<E: Copy> Events<E> {
     ) {
         for event in entity_events {
             match event {
-                EntityEvent::SpawnEntity(entity) => {
+                EntityEvent::SpawnEntity(_tick, entity) => {
                     self.push_spawn(user_key, &entity);
                 }
-                EntityEvent::DespawnEntity(entity) => {
+                EntityEvent::DespawnEntity(_tick, entity) => {
                     self.push_despawn(user_key, &entity);
                 }
-                EntityEvent::InsertComponent(entity, component_kind) => {
+                EntityEvent::InsertComponent(_tick, entity, component_kind) => {
                     self.push_insert(user_key, &entity, &component_kind);
                 }
-                EntityEvent::RemoveComponent(entity, component_box) => {
+                EntityEvent::RemoveComponent(_tick, entity, component_box) => {
                     self.push_remove(user_key, &entity, component_box);
                 }
                 EntityEvent::UpdateComponent(_tick, entity, component_kind) => {
@@ -516,3 +530,33 @@ impl<E: Copy, C: Replicate> Event<E> for RemoveComponentEvent<C> {
         events.removes.contains_key(&component_kind)
     }
 }
+
+// Entity Auth Request Event
+pub struct EntityAuthRequestEvent;
+impl<E: Copy> Event<E> for EntityAuthRequestEvent {
+    type Iter = IntoIter<(UserKey, E)>;
+
+    fn iter(events: &mut Events<E>) -> Self::Iter {
+        let list = std::mem::take(&mut events.auth_requests);
+        return IntoIterator::into_iter(list);
+    }
+
+    fn has(events: &Events<E>) -> bool {
+        !events.auth_requests.is_empty()
+    }
+}
+
+// Entity Auth Release Event
+pub struct EntityAuthReleaseEvent;
+impl<E: Copy> Event<E> for EntityAuthReleaseEvent {
+    type Iter = IntoIter<(UserKey, E)>;
+
+    fn iter(events: &mut Events<E>) -> Self::Iter {
+        let list = std::mem::take(&mut events.auth_releases);
+        return IntoIterator::into_iter(list);
+    }
+
+    fn has(events: &Events<E>) -> bool {
+        !events.auth_releases.is_empty()
+    }
+}
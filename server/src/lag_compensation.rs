@@ -0,0 +1,184 @@
+use std::{
+    collections::{HashMap, VecDeque},
+    hash::Hash,
+    time::Duration,
+};
+
+use naia_shared::{sequence_greater_than, Tick};
+
+use crate::{Server, UserKey};
+
+/// Keeps a Tick-indexed history of chosen state per Entity (i.e. positions or
+/// hitboxes), so that the Server can check hits against the world as a
+/// shooting Client saw it.
+///
+/// Call `record()` for each tracked Entity once per Server Tick. When a
+/// command arrives through `receive_tick_buffer_messages()`, pass its Tick to
+/// `view_tick()` to find the Tick that Client was rendering, and read states
+/// at that Tick with `state_at()` or `states_at()`.
+pub struct LagCompensation<E: Copy + Eq + Hash, S: Clone> {
+    history: HashMap<E, VecDeque<(Tick, S)>>,
+    history_length: u16,
+    interpolation_delay: u16,
+}
+
+impl<E: Copy + Eq + Hash, S: Clone> LagCompensation<E, S> {
+    /// Creates a new LagCompensation, which keeps the given number of Ticks
+    /// of history per Entity. This bounds how far back a Client can be
+    /// compensated for.
+    pub fn new(history_length: u16) -> Self {
+        Self {
+            history: HashMap::new(),
+            history_length: history_length.max(1),
+            interpolation_delay: 1,
+        }
+    }
+
+    /// Sets how many Ticks behind their receiving Tick Clients render
+    /// replicated state. Defaults to 1, which matches the Client's
+    /// `InterpolationBuffer`.
+    pub fn set_interpolation_delay(&mut self, ticks: u16) {
+        self.interpolation_delay = ticks;
+    }
+
+    // Entities
+
+    /// Records the state of an Entity at the given Tick. Ticks must be given
+    /// in increasing order, recording a Tick again replaces its state.
+    pub fn record(&mut self, entity: &E, tick: Tick, state: S) {
+        let history = self.history.entry(*entity).or_default();
+
+        if let Some((newest_tick, newest_state)) = history.back_mut() {
+            if *newest_tick == tick {
+                *newest_state = state;
+                return;
+            }
+            if !sequence_greater_than(tick, *newest_tick) {
+                return;
+            }
+        }
+        history.push_back((tick, state));
+
+        // prune snapshots which fell out of the history, keeping the one
+        // which was current at the oldest kept Tick
+        let oldest_kept_tick = tick.wrapping_sub(self.history_length);
+        while history.len() > 1 {
+            let (next_tick, _) = &history[1];
+            if sequence_greater_than(*next_tick, oldest_kept_tick) {
+                break;
+            }
+            history.pop_front();
+        }
+    }
+
+    /// Forgets an Entity, i.e. after it has despawned
+    pub fn remove_entity(&mut self, entity: &E) {
+        self.history.remove(entity);
+    }
+
+    // Queries
+
+    /// Gets the state of an Entity as of the given Tick, which is the newest
+    /// state recorded at or before it. Returns None if the Entity was not
+    /// tracked yet at that Tick. Ticks older than the kept history are
+    /// clamped to the oldest kept Tick.
+    pub fn state_at(&self, entity: &E, tick: Tick) -> Option<&S> {
+        let history = self.history.get(entity)?;
+        let (newest_tick, _) = history.back()?;
+
+        let oldest_kept_tick = newest_tick.wrapping_sub(self.history_length);
+        let tick = if sequence_greater_than(oldest_kept_tick, tick) {
+            oldest_kept_tick
+        } else {
+            tick
+        };
+
+        history
+            .iter()
+            .rev()
+            .find(|(snapshot_tick, _)| !sequence_greater_than(*snapshot_tick, tick))
+            .map(|(_, state)| state)
+    }
+
+    /// Gets the state of every tracked Entity as of the given Tick
+    pub fn states_at(&self, tick: Tick) -> impl Iterator<Item = (E, &S)> {
+        self.history
+            .keys()
+            .filter_map(move |entity| Some((*entity, self.state_at(entity, tick)?)))
+    }
+
+    /// Gets the Tick a User's Client was rendering when it sent a command for
+    /// `command_tick`, estimated from the User's RTT & jitter. Returns None if
+    /// the User is not connected.
+    pub fn view_tick<W: Copy + Eq + Hash + Send + Sync>(
+        &self,
+        server: &Server<W>,
+        user_key: &UserKey,
+        command_tick: Tick,
+    ) -> Option<Tick> {
+        let rtt = server.rtt(user_key)?;
+        let jitter = server.jitter(user_key)?;
+        let lag = view_lag(
+            rtt,
+            jitter,
+            server.average_tick_duration(),
+            self.interpolation_delay,
+        );
+        Some(command_tick.wrapping_sub(lag))
+    }
+}
+
+/// Returns how many Ticks a Client's view lags behind the Tick it sends
+/// commands for. Clients send ahead of the Server by one latency, the jitter
+/// and 4 Ticks, and receive behind it by one latency, the jitter and 1 Tick.
+fn view_lag(rtt_millis: f32, jitter_millis: f32, tick_duration: Duration, delay: u16) -> u16 {
+    let tick_millis = tick_duration.as_secs_f32() * 1000.0;
+    if tick_millis <= 0.0 {
+        return delay;
+    }
+    let lag_millis = rtt_millis + (jitter_millis * 2.0) + (tick_millis * 5.0);
+    ((lag_millis / tick_millis).round() as u16).saturating_add(delay)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::{view_lag, LagCompensation};
+
+    #[test]
+    fn rewinds_to_recorded_state() {
+        let mut lag_compensation = LagCompensation::<u32, f32>::new(4);
+        lag_compensation.record(&1, 65534, 0.0);
+        lag_compensation.record(&1, 65535, 1.0);
+        // Tick 0 was not recorded
+        lag_compensation.record(&1, 1, 2.0);
+        lag_compensation.record(&2, 1, 20.0);
+
+        assert_eq!(lag_compensation.state_at(&1, 65535), Some(&1.0));
+        assert_eq!(lag_compensation.state_at(&1, 0), Some(&1.0));
+        assert_eq!(lag_compensation.state_at(&1, 5), Some(&2.0));
+        // before Entity 2 was tracked
+        assert_eq!(lag_compensation.state_at(&2, 0), None);
+
+        let mut states: Vec<(u32, f32)> = lag_compensation
+            .states_at(65535)
+            .map(|(entity, state)| (entity, *state))
+            .collect();
+        states.sort_by_key(|(entity, _)| *entity);
+        assert_eq!(states, vec![(1, 1.0)]);
+
+        // older than the kept history
+        lag_compensation.record(&1, 5, 3.0);
+        assert_eq!(lag_compensation.state_at(&1, 65534), Some(&2.0));
+        assert_eq!(lag_compensation.state_at(&1, 65000), Some(&2.0));
+        assert_eq!(lag_compensation.state_at(&1, 5), Some(&3.0));
+    }
+
+    #[test]
+    fn view_lag_accounts_for_latency() {
+        let tick_duration = Duration::from_millis(50);
+        assert_eq!(view_lag(0.0, 0.0, tick_duration, 1), 6);
+        assert_eq!(view_lag(100.0, 25.0, tick_duration, 1), 9);
+    }
+}
@@ -15,19 +15,27 @@ extern crate cfg_if;
 
 pub mod transport;
 pub mod shared {
-    pub use naia_shared::{default_channels, EntityRef, Random, SocketConfig};
+    pub use naia_shared::{
+        default_channels, BandwidthBreakdown, BandwidthShare, EntityRef, LockstepFrame,
+        NetworkStats, PrometheusExporter, Random, SocketConfig,
+    };
 }
 pub mod internal {
-    pub use crate::connection::handshake_manager::{HandshakeManager, HandshakeResult};
+    pub use crate::connection::handshake_manager::{
+        HandshakeFailure, HandshakeManager, HandshakeResult,
+    };
 }
 
 mod cache_map;
 mod connection;
 mod error;
 mod events;
+mod lag_compensation;
+mod lockstep;
 mod room;
 mod server;
 mod server_config;
+mod spatial_interest;
 mod time_manager;
 mod user;
 mod user_scope;
@@ -36,14 +44,18 @@ mod world;
 pub use connection::tick_buffer_messages::TickBufferMessages;
 pub use error::NaiaServerError;
 pub use events::{
-    AuthEvent, ConnectEvent, DespawnEntityEvent, DisconnectEvent, ErrorEvent, Events,
-    InsertComponentEvent, MessageEvent, RemoveComponentEvent, SpawnEntityEvent, TickEvent,
-    UpdateComponentEvent,
+    AuthEvent, ConnectEvent, DespawnEntityEvent, DisconnectEvent, EntityAuthReleaseEvent,
+    EntityAuthRequestEvent, ErrorEvent, Events, InsertComponentEvent, MessageEvent,
+    RemoveComponentEvent, SpawnEntityEvent, TickEvent, UpdateComponentEvent,
 };
+pub use lag_compensation::LagCompensation;
+pub use lockstep::{Lockstep, LockstepDesync};
 pub use room::{RoomKey, RoomMut, RoomRef};
 pub use server::Server;
-pub use server_config::ServerConfig;
+pub use server_config::{ServerConfig, TickMode};
+pub use spatial_interest::SpatialInterest;
 pub use user::{User, UserKey, UserMut, UserRef};
 pub use user_scope::UserScopeMut;
 pub use world::entity_mut::EntityMut;
 pub use world::entity_owner::EntityOwner;
+pub use world::validation::Validation;
@@ -0,0 +1,508 @@
+use std::{
+    collections::{HashMap, VecDeque},
+    hash::Hash,
+    marker::PhantomData,
+    ops::Range,
+};
+
+use log::warn;
+
+use naia_shared::{
+    sequence_greater_than, sequence_less_than, LockstepChannel, LockstepChecksumMessage,
+    LockstepFrame, LockstepFrameMessage, LockstepInputChannel, LockstepInputMessage,
+    LockstepJoinMessage, Serde, Tick,
+};
+
+use crate::{events::MessageEvent, Events, Server, TickBufferMessages, UserKey};
+
+/// Checksums which have not been reported by every player within this many
+/// Ticks of the newest Frame are compared with whatever has arrived
+const CHECKSUM_WINDOW: u16 = 128;
+
+/// The most Frames sent to one player per Tick, so that a player joining late
+/// catches up over several Ticks rather than all at once
+const FRAMES_PER_TICK: usize = 32;
+
+/// Checksums which differed between players for one Tick
+pub struct LockstepDesync {
+    pub tick: Tick,
+    pub checksums: Vec<(UserKey, u64)>,
+}
+
+struct LockstepPlayer {
+    id: u16,
+    /// The newest Tick a Frame held this player's input for
+    last_input_tick: Tick,
+    /// The Tick of the next Frame to send to this player
+    next_send_tick: Tick,
+}
+
+/// Relays inputs for a deterministic lockstep simulation, where Clients
+/// only exchange inputs and each simulate the whole game. The Protocol must
+/// add the `LockstepPlugin`.
+///
+/// Clients buffer their input for each Tick on the `LockstepInputChannel`.
+/// Call `process_tick()` with the `TickBufferMessages` of every Server Tick:
+/// it combines the input every player sent for that Tick into a Frame and
+/// sends it to each player, who simulate the Tick once its Frame arrives.
+/// A player whose input arrives too late has no input in that Frame.
+///
+/// Frames are kept so that players added with `add_player()` after the
+/// simulation started catch up by replaying them, starting from
+/// `first_tick()`. At most `max_frames()` are kept, and `checkpoint()` drops
+/// the ones a saved state makes unnecessary, so a player joining late must be
+/// given the simulation state as of `first_tick()` by the application.
+pub struct Lockstep<I: Serde> {
+    start_tick: Tick,
+    input_delay: u16,
+    players: HashMap<UserKey, LockstepPlayer>,
+    next_player_id: u16,
+    frames: VecDeque<LockstepFrameMessage>,
+    last_tick: Option<Tick>,
+    max_frames: usize,
+    checkpoint: Option<Tick>,
+    checksums: HashMap<Tick, HashMap<UserKey, u64>>,
+    phantom_i: PhantomData<I>,
+}
+
+impl<I: Serde> Lockstep<I> {
+    /// Creates a new Lockstep simulation, whose first Frame is for the given
+    /// Tick
+    pub fn new(start_tick: Tick) -> Self {
+        Self {
+            start_tick,
+            input_delay: 3,
+            players: HashMap::new(),
+            next_player_id: 0,
+            frames: VecDeque::new(),
+            last_tick: None,
+            max_frames: 1024,
+            checkpoint: None,
+            checksums: HashMap::new(),
+            phantom_i: PhantomData,
+        }
+    }
+
+    /// Sets how many Ticks ahead of their Client Tick players schedule their
+    /// inputs, which gives inputs time to reach the Server & Frames time to
+    /// reach each player. Defaults to 3. Only players added afterwards are
+    /// told about the new delay.
+    pub fn set_input_delay(&mut self, ticks: u16) {
+        self.input_delay = ticks;
+    }
+
+    pub fn input_delay(&self) -> u16 {
+        self.input_delay
+    }
+
+    pub fn start_tick(&self) -> Tick {
+        self.start_tick
+    }
+
+    /// The Tick of the newest Frame, if any were made yet
+    pub fn last_tick(&self) -> Option<Tick> {
+        self.last_tick
+    }
+
+    /// The Tick of the oldest Frame kept for players joining late, who start
+    /// simulating from it
+    pub fn first_tick(&self) -> Tick {
+        match self.frames.front() {
+            Some(frame) => frame.tick,
+            None => self.next_tick(),
+        }
+    }
+
+    fn next_tick(&self) -> Tick {
+        match self.last_tick {
+            Some(last_tick) => last_tick.wrapping_add(1),
+            None => self.start_tick,
+        }
+    }
+
+    /// Sets how many Frames are kept for players joining late. Defaults to
+    /// 1024. Older Frames are dropped once every player was sent them.
+    pub fn set_max_frames(&mut self, max_frames: usize) {
+        self.max_frames = max_frames;
+        self.prune_frames();
+    }
+
+    pub fn max_frames(&self) -> usize {
+        self.max_frames
+    }
+
+    /// Drops the Frames before the given Tick, i.e. once the application has
+    /// saved the simulation state as of that Tick to give to players joining
+    /// late. Frames some player was not sent yet are dropped once it has
+    /// been. The Tick must not be later than the next Frame's.
+    pub fn checkpoint(&mut self, tick: Tick) {
+        if sequence_greater_than(tick, self.next_tick()) {
+            warn!("Lockstep: cannot checkpoint Tick {tick}, which has no Frame yet");
+            return;
+        }
+        self.checkpoint = Some(tick);
+        self.prune_frames();
+    }
+
+    fn prune_frames(&mut self) {
+        while let Some(front) = self.frames.front() {
+            let over_max = self.frames.len() > self.max_frames;
+            let before_checkpoint = self
+                .checkpoint
+                .is_some_and(|checkpoint| sequence_less_than(front.tick, checkpoint));
+            let sent_to_all = self
+                .players
+                .values()
+                .all(|player| sequence_greater_than(player.next_send_tick, front.tick));
+            if !(over_max || before_checkpoint) || !sent_to_all {
+                break;
+            }
+            self.frames.pop_front();
+        }
+        if let Some(checkpoint) = self.checkpoint {
+            if !sequence_greater_than(checkpoint, self.first_tick()) {
+                self.checkpoint = None;
+            }
+        }
+    }
+
+    // Players
+
+    /// Adds a User as a player, returning its player id. The User is sent
+    /// every Frame from `first_tick()` on, a few each Tick, so that it can
+    /// catch up with the simulation.
+    pub fn add_player<E: Copy + Eq + Hash + Send + Sync>(
+        &mut self,
+        server: &mut Server<E>,
+        user_key: &UserKey,
+    ) -> u16 {
+        if let Some(player) = self.players.get(user_key) {
+            return player.id;
+        }
+        let id = self.insert_player(user_key);
+
+        // the LockstepPlugin sets up the Channel, so sending can not fail
+        let _ = server.send_message::<LockstepChannel, _>(
+            user_key,
+            &LockstepJoinMessage::new(id, self.first_tick(), self.input_delay),
+        );
+
+        id
+    }
+
+    fn insert_player(&mut self, user_key: &UserKey) -> u16 {
+        let id = self.next_player_id;
+        self.next_player_id = self.next_player_id.wrapping_add(1);
+        let last_input_tick = self.next_tick().wrapping_sub(1);
+        self.players.insert(
+            *user_key,
+            LockstepPlayer {
+                id,
+                last_input_tick,
+                next_send_tick: self.first_tick(),
+            },
+        );
+        id
+    }
+
+    /// Stops including a User's inputs in Frames, i.e. after it disconnected.
+    /// Player ids are not reused.
+    pub fn remove_player(&mut self, user_key: &UserKey) {
+        self.players.remove(user_key);
+    }
+
+    /// Gets the player id of a User
+    pub fn player(&self, user_key: &UserKey) -> Option<u16> {
+        self.players.get(user_key).map(|player| player.id)
+    }
+
+    /// Gets the players whose input was missing from at least the given
+    /// number of the newest Frames
+    pub fn stalled_players(&self, ticks: u16) -> Vec<UserKey> {
+        let Some(last_tick) = self.last_tick() else {
+            return Vec::new();
+        };
+        self.players
+            .iter()
+            .filter(|(_, player)| last_tick.wrapping_sub(player.last_input_tick) >= ticks)
+            .map(|(user_key, _)| *user_key)
+            .collect()
+    }
+
+    // Frames
+
+    /// Combines the inputs players sent for the given Server Tick into a
+    /// Frame, and sends it to every player, after any older Frames players
+    /// joining late were not sent yet. Must be called on every Server
+    /// Tick from the start Tick on, any skipped Ticks get empty Frames.
+    /// Returns None for Ticks before the start Tick, or which already have a
+    /// Frame.
+    pub fn process_tick<E: Copy + Eq + Hash + Send + Sync>(
+        &mut self,
+        server: &mut Server<E>,
+        tick: Tick,
+        messages: &mut TickBufferMessages,
+    ) -> Option<LockstepFrame<I>> {
+        let inputs = messages.read::<LockstepInputChannel, LockstepInputMessage>();
+        let frame = self.collect_frame(tick, inputs)?;
+
+        let user_keys: Vec<UserKey> = self.players.keys().copied().collect();
+        for user_key in &user_keys {
+            for index in self.take_frames_to_send(user_key) {
+                let _ = server.send_message::<LockstepChannel, _>(user_key, &self.frames[index]);
+            }
+        }
+        self.prune_frames();
+
+        Some(frame)
+    }
+
+    /// Gets the indices of the Frames to send a player next, and marks them
+    /// as sent
+    fn take_frames_to_send(&mut self, user_key: &UserKey) -> Range<usize> {
+        let first_tick = self.first_tick();
+        let frames_len = self.frames.len();
+        let Some(player) = self.players.get_mut(user_key) else {
+            return 0..0;
+        };
+        let start = usize::from(player.next_send_tick.wrapping_sub(first_tick)).min(frames_len);
+        let end = (start + FRAMES_PER_TICK).min(frames_len);
+        player.next_send_tick = player.next_send_tick.wrapping_add((end - start) as u16);
+        start..end
+    }
+
+    fn collect_frame(
+        &mut self,
+        tick: Tick,
+        inputs: Vec<(UserKey, LockstepInputMessage)>,
+    ) -> Option<LockstepFrame<I>> {
+        let next_tick = self.next_tick();
+        if sequence_greater_than(next_tick, tick) {
+            return None;
+        }
+
+        // fill in any skipped Ticks, so that players never wait on them
+        let mut skipped_tick = next_tick;
+        while skipped_tick != tick {
+            self.frames
+                .push_back(LockstepFrameMessage::new(skipped_tick));
+            skipped_tick = skipped_tick.wrapping_add(1);
+        }
+
+        let mut frame_message = LockstepFrameMessage::new(tick);
+        let mut frame_inputs = Vec::new();
+        for (user_key, message) in inputs {
+            let Some(player) = self.players.get_mut(&user_key) else {
+                continue;
+            };
+            if player.last_input_tick == tick {
+                // only the first input sent for a Tick counts
+                continue;
+            }
+            let Ok(input) = message.to_input::<I>() else {
+                warn!(
+                    "Lockstep: dropping malformed input from player {}",
+                    player.id
+                );
+                continue;
+            };
+            player.last_input_tick = tick;
+            frame_inputs.push((player.id, input));
+            frame_message.inputs.push((player.id, message.input));
+        }
+        frame_inputs.sort_by_key(|(id, _)| *id);
+        frame_message.inputs.sort_by_key(|(id, _)| *id);
+        self.frames.push_back(frame_message);
+        self.last_tick = Some(tick);
+
+        Some(LockstepFrame::new(tick, frame_inputs))
+    }
+
+    // Checksums
+
+    /// Reads the checksums players sent after simulating each Tick, and
+    /// returns the Ticks for which they differed
+    pub fn receive_checksums<E: Copy>(&mut self, events: &mut Events<E>) -> Vec<LockstepDesync> {
+        for (user_key, message) in
+            events.read::<MessageEvent<LockstepChannel, LockstepChecksumMessage>>()
+        {
+            self.record_checksum(&user_key, message.tick, message.checksum);
+        }
+        self.compare_checksums()
+    }
+
+    fn record_checksum(&mut self, user_key: &UserKey, tick: Tick, checksum: u64) {
+        if !self.players.contains_key(user_key) {
+            return;
+        }
+        self.checksums
+            .entry(tick)
+            .or_default()
+            .insert(*user_key, checksum);
+    }
+
+    fn compare_checksums(&mut self) -> Vec<LockstepDesync> {
+        let oldest_pending_tick = self
+            .last_tick()
+            .unwrap_or(self.start_tick)
+            .wrapping_sub(CHECKSUM_WINDOW);
+
+        let ready_ticks: Vec<Tick> = self
+            .checksums
+            .iter()
+            .filter(|(tick, checksums)| {
+                self.players
+                    .keys()
+                    .all(|user_key| checksums.contains_key(user_key))
+                    || !sequence_greater_than(**tick, oldest_pending_tick)
+            })
+            .map(|(tick, _)| *tick)
+            .collect();
+
+        let mut desyncs = Vec::new();
+        for tick in ready_ticks {
+            let Some(checksums) = self.checksums.remove(&tick) else {
+                continue;
+            };
+            let mut values = checksums.values();
+            let Some(first) = values.next() else {
+                continue;
+            };
+            if values.all(|checksum| checksum == first) {
+                continue;
+            }
+            desyncs.push(LockstepDesync {
+                tick,
+                checksums: checksums.into_iter().collect(),
+            });
+        }
+        desyncs.sort_by_key(|desync| desync.tick.wrapping_sub(oldest_pending_tick));
+        desyncs
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use naia_shared::{BigMapKey, LockstepInputMessage};
+
+    use super::{Lockstep, FRAMES_PER_TICK};
+    use crate::UserKey;
+
+    #[test]
+    fn frames_combine_player_inputs() {
+        let alice = UserKey::from_u64(1);
+        let bob = UserKey::from_u64(2);
+
+        let mut lockstep = Lockstep::<u8>::new(10);
+        assert_eq!(lockstep.insert_player(&alice), 0);
+        assert_eq!(lockstep.insert_player(&bob), 1);
+
+        // before the start Tick
+        assert!(lockstep.collect_frame(9, Vec::new()).is_none());
+
+        let inputs = vec![
+            (bob, LockstepInputMessage::new(&5u8)),
+            (alice, LockstepInputMessage::new(&4u8)),
+            (alice, LockstepInputMessage::new(&7u8)),
+        ];
+        let frame = lockstep.collect_frame(10, inputs).unwrap();
+        assert_eq!(frame.inputs(), &[(0, 4), (1, 5)]);
+
+        // Tick 11 was skipped, and Bob's input did not arrive
+        let inputs = vec![(alice, LockstepInputMessage::new(&6u8))];
+        let frame = lockstep.collect_frame(12, inputs).unwrap();
+        assert_eq!(frame.inputs(), &[(0, 6)]);
+        assert_eq!(lockstep.frames.len(), 3);
+        assert!(lockstep.frames[1].inputs.is_empty());
+        assert!(lockstep.stalled_players(2) == vec![bob]);
+
+        // already made
+        assert!(lockstep.collect_frame(12, Vec::new()).is_none());
+    }
+
+    #[test]
+    fn late_players_catch_up_a_few_frames_at_a_time() {
+        let alice = UserKey::from_u64(1);
+        let bob = UserKey::from_u64(2);
+
+        let mut lockstep = Lockstep::<u8>::new(0);
+        lockstep.insert_player(&alice);
+        lockstep.collect_frame(99, Vec::new()).unwrap();
+        assert_eq!(lockstep.take_frames_to_send(&alice), 0..FRAMES_PER_TICK);
+
+        // Bob joins at the first Frame
+        lockstep.insert_player(&bob);
+        assert_eq!(lockstep.take_frames_to_send(&bob), 0..FRAMES_PER_TICK);
+        lockstep.collect_frame(100, Vec::new()).unwrap();
+        assert_eq!(
+            lockstep.take_frames_to_send(&alice),
+            FRAMES_PER_TICK..(2 * FRAMES_PER_TICK)
+        );
+        assert_eq!(
+            lockstep.take_frames_to_send(&bob),
+            FRAMES_PER_TICK..(2 * FRAMES_PER_TICK)
+        );
+        while !lockstep.take_frames_to_send(&alice).is_empty() {}
+        assert_eq!(lockstep.players[&alice].next_send_tick, 101);
+        assert_eq!(lockstep.players[&bob].next_send_tick, 64);
+    }
+
+    #[test]
+    fn frames_are_dropped_once_sent_to_every_player() {
+        let alice = UserKey::from_u64(1);
+
+        let mut lockstep = Lockstep::<u8>::new(0);
+        lockstep.set_max_frames(10);
+        lockstep.insert_player(&alice);
+        lockstep.collect_frame(49, Vec::new()).unwrap();
+
+        // Alice still needs every Frame
+        lockstep.prune_frames();
+        assert_eq!(lockstep.first_tick(), 0);
+
+        lockstep.take_frames_to_send(&alice);
+        lockstep.prune_frames();
+        assert_eq!(lockstep.first_tick(), 32);
+        lockstep.take_frames_to_send(&alice);
+        lockstep.prune_frames();
+        assert_eq!(lockstep.first_tick(), 40);
+        assert_eq!(lockstep.frames.len(), 10);
+
+        // Frames before a checkpoint aren't needed by players joining later
+        lockstep.checkpoint(45);
+        assert_eq!(lockstep.first_tick(), 45);
+        assert!(lockstep.checkpoint.is_none());
+        lockstep.checkpoint(51);
+        assert_eq!(lockstep.first_tick(), 45);
+        lockstep.checkpoint(50);
+        assert_eq!(lockstep.first_tick(), 50);
+        assert_eq!(lockstep.last_tick(), Some(49));
+
+        // joining with no Frames kept
+        let bob = UserKey::from_u64(2);
+        lockstep.insert_player(&bob);
+        assert_eq!(lockstep.players[&bob].next_send_tick, 50);
+        assert_eq!(lockstep.players[&bob].last_input_tick, 49);
+    }
+
+    #[test]
+    fn mismatched_checksums_are_desyncs() {
+        let alice = UserKey::from_u64(1);
+        let bob = UserKey::from_u64(2);
+
+        let mut lockstep = Lockstep::<u8>::new(0);
+        lockstep.insert_player(&alice);
+        lockstep.insert_player(&bob);
+
+        lockstep.record_checksum(&alice, 0, 100);
+        lockstep.record_checksum(&alice, 1, 200);
+        assert!(lockstep.compare_checksums().is_empty());
+
+        lockstep.record_checksum(&bob, 0, 100);
+        lockstep.record_checksum(&bob, 1, 201);
+        let desyncs = lockstep.compare_checksums();
+        assert_eq!(desyncs.len(), 1);
+        assert_eq!(desyncs[0].tick, 1);
+        assert!(lockstep.checksums.is_empty());
+    }
+}
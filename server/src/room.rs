@@ -5,7 +5,7 @@ use std::{
 
 use naia_shared::{BigMapKey, Channel, ChannelKind, Message};
 
-use super::user::UserKey;
+use super::{user::UserKey, NaiaServerError};
 
 // RoomKey
 #[derive(Clone, Copy, Eq, PartialEq, Hash)]
@@ -26,6 +26,7 @@ pub struct Room<E: Copy + Eq + Hash> {
     users: HashSet<UserKey>,
     entities: HashSet<E>,
     entity_removal_queue: VecDeque<(UserKey, E)>,
+    dormant: bool,
 }
 
 impl<E: Copy + Eq + Hash> Room<E> {
@@ -34,6 +35,7 @@ impl<E: Copy + Eq + Hash> Room<E> {
             users: HashSet::new(),
             entities: HashSet::new(),
             entity_removal_queue: VecDeque::new(),
+            dormant: false,
         }
     }
 
@@ -68,15 +70,15 @@ impl<E: Copy + Eq + Hash> Room<E> {
         self.entities.insert(*entity);
     }
 
+    /// Returns whether the Entity was in the Room
     pub(crate) fn remove_entity(&mut self, entity: &E) -> bool {
-        if self.entities.remove(entity) {
-            for user_key in self.users.iter() {
-                self.entity_removal_queue.push_back((*user_key, *entity));
-            }
-            true
-        } else {
-            panic!("Room does not contain Entity");
+        if !self.entities.remove(entity) {
+            return false;
         }
+        for user_key in self.users.iter() {
+            self.entity_removal_queue.push_back((*user_key, *entity));
+        }
+        true
     }
 
     pub(crate) fn entities(&self) -> Iter<E> {
@@ -90,6 +92,16 @@ impl<E: Copy + Eq + Hash> Room<E> {
     pub(crate) fn entities_count(&self) -> usize {
         self.entities.len()
     }
+
+    // Dormancy
+
+    pub(crate) fn is_dormant(&self) -> bool {
+        self.dormant
+    }
+
+    pub(crate) fn set_dormant(&mut self, dormant: bool) {
+        self.dormant = dormant;
+    }
 }
 
 // room references
@@ -136,6 +148,12 @@ impl<'s, E: Copy + Eq + Hash + Send + Sync> RoomRef<'s, E> {
     pub fn entities_count(&self) -> usize {
         self.server.room_entities_count(&self.key)
     }
+
+    // Dormancy
+
+    pub fn is_dormant(&self) -> bool {
+        self.server.room_is_dormant(&self.key)
+    }
 }
 
 // RoomMut
@@ -190,10 +208,12 @@ impl<'s, E: Copy + Eq + Hash + Send + Sync> RoomMut<'s, E> {
         self.server.room_has_entity(&self.key, entity)
     }
 
-    pub fn add_entity(&mut self, entity: &E) -> &mut Self {
-        self.server.room_add_entity(&self.key, entity);
+    /// Adds an Entity to the Room, or returns an Error if the Entity already
+    /// belongs to another Room
+    pub fn add_entity(&mut self, entity: &E) -> Result<&mut Self, NaiaServerError> {
+        self.server.room_add_entity(&self.key, entity)?;
 
-        self
+        Ok(self)
     }
 
     pub fn remove_entity(&mut self, entity: &E) -> &mut Self {
@@ -206,11 +226,36 @@ impl<'s, E: Copy + Eq + Hash + Send + Sync> RoomMut<'s, E> {
         self.server.room_entities_count(&self.key)
     }
 
+    // Dormancy
+
+    /// Entities leaving the scope of a User through this Room go dormant on
+    /// their Client instead of being despawned
+    pub fn enable_dormancy(&mut self) -> &mut Self {
+        self.server.room_set_dormant(&self.key, true);
+
+        self
+    }
+
+    pub fn disable_dormancy(&mut self) -> &mut Self {
+        self.server.room_set_dormant(&self.key, false);
+
+        self
+    }
+
+    pub fn is_dormant(&self) -> bool {
+        self.server.room_is_dormant(&self.key)
+    }
+
     // Messages
 
-    pub fn broadcast_message<C: Channel, M: Message>(&mut self, message: &M) {
+    /// Sends a message to all connected users in the Room, or returns an
+    /// Error if the Channel can not be used for it
+    pub fn broadcast_message<C: Channel, M: Message>(
+        &mut self,
+        message: &M,
+    ) -> Result<(), NaiaServerError> {
         let cloned_message = message.clone_box();
         self.server
-            .room_broadcast_message(&ChannelKind::of::<C>(), &self.key, cloned_message);
+            .room_broadcast_message(&ChannelKind::of::<C>(), &self.key, cloned_message)
     }
 }
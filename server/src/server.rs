@@ -12,24 +12,33 @@ use log::warn;
 use bevy_ecs::prelude::Resource;
 
 use naia_shared::{
-    BigMap, BitReader, BitWriter, Channel, ChannelKind, ComponentKind,
-    EntityAndGlobalEntityConverter, EntityConverterMut, EntityDoesNotExistError, EntityRef,
-    GlobalEntity, Instant, Message, MessageContainer, PacketType, Protocol, Replicate, Serde,
-    SerdeErr, SocketConfig, StandardHeader, Tick, Timer, WorldMutType, WorldRefType,
+    BandwidthBreakdown, BigMap, BigMapKey, BitReader, BitWriter, Channel, ChannelKind,
+    ComponentKind, EntityAndGlobalEntityConverter, EntityAuthAction, EntityAuthChannel,
+    EntityAuthMessage, EntityConverterMut, EntityDoesNotExistError, EntityEvent, EntityHierarchy,
+    EntityHierarchyChannel, EntityParentMessage, EntityRef, EntityScopeChannel, EntityScopeMessage,
+    GlobalEntity, GrowableBitWriter, Instant, Message, MessageContainer, NetworkStats, PacketType,
+    PredictionKey, PrometheusWriter, Protocol, Recording, Replicate, Serde, SerdeErr, SocketConfig,
+    StandardHeader, Tick, TickIntervalChannel, TickIntervalMessage, Timer, UnsignedVariableInteger,
+    WorldMutType, WorldRefType,
 };
 
 use crate::{
     connection::{
         connection::Connection,
-        handshake_manager::{HandshakeManager, HandshakeResult},
+        handshake_manager::{HandshakeFailure, HandshakeManager, HandshakeResult},
         io::Io,
         tick_buffer_messages::TickBufferMessages,
     },
     time_manager::TimeManager,
     transport::Socket,
     world::{
-        entity_mut::EntityMut, entity_owner::EntityOwner, entity_scope_map::EntityScopeMap,
+        component_scope_map::ComponentScopeMap,
+        entity_mut::EntityMut,
+        entity_owner::EntityOwner,
+        entity_scope_map::EntityScopeMap,
         global_world_manager::GlobalWorldManager,
+        snapshot::SnapshotEntityConverter,
+        validation::{ComponentValidators, Validation},
     },
 };
 
@@ -37,7 +46,7 @@ use super::{
     error::NaiaServerError,
     events::Events,
     room::{Room, RoomKey, RoomMut, RoomRef},
-    server_config::ServerConfig,
+    server_config::{ServerConfig, TickMode},
     user::{User, UserKey, UserMut, UserRef},
     user_scope::UserScopeMut,
 };
@@ -64,9 +73,23 @@ pub struct Server<E: Copy + Eq + Hash + Send + Sync> {
     // Entities
     entity_room_map: HashMap<E, RoomKey>,
     entity_scope_map: EntityScopeMap<E>,
+    component_scope_map: ComponentScopeMap<E>,
+    entity_hierarchy: EntityHierarchy<E>,
+    reparented_entities: HashSet<E>,
+    pending_parent_messages: HashSet<(UserKey, E)>,
+    dormant_entities: HashSet<E>,
+    predicted_spawns: HashMap<E, (UserKey, PredictionKey)>,
+    resources: HashMap<ComponentKind, E>,
+    resource_entities: HashSet<E>,
     global_world_manager: GlobalWorldManager<E>,
+    component_validators: ComponentValidators<E>,
     // Events
     incoming_events: Events<E>,
+    // Recording
+    finished_recordings: HashMap<UserKey, Recording>,
+    // Stats
+    disconnected_network_stats: NetworkStats,
+    handshake_failures: HashMap<HandshakeFailure, u64>,
     // Ticks
     time_manager: TimeManager,
 }
@@ -75,13 +98,21 @@ impl<E: Copy + Eq + Hash + Send + Sync> Server<E> {
     /// Create a new Server
     pub fn new<P: Into<Protocol>>(server_config: ServerConfig, protocol: P) -> Self {
         let mut protocol: Protocol = protocol.into();
-        protocol.lock();
+        // a Protocol which is already locked can't be changed either way
+        let _ = protocol.try_lock();
 
-        let time_manager = TimeManager::new(protocol.tick_interval);
+        let clock = &server_config.clock;
+
+        let time_manager = TimeManager::new(
+            protocol.tick_interval,
+            server_config.tick_mode == TickMode::Manual,
+            clock,
+        );
 
         let io = Io::new(
             &server_config.connection.bandwidth_measure_duration,
             &protocol.compression,
+            clock,
         );
 
         Server {
@@ -90,9 +121,12 @@ impl<E: Copy + Eq + Hash + Send + Sync> Server<E> {
             protocol,
             // Connection
             io,
-            heartbeat_timer: Timer::new(server_config.connection.heartbeat_interval),
-            timeout_timer: Timer::new(server_config.connection.disconnection_timeout_duration),
-            ping_timer: Timer::new(server_config.ping.ping_interval),
+            heartbeat_timer: Timer::new(server_config.connection.heartbeat_interval, clock),
+            timeout_timer: Timer::new(
+                server_config.connection.disconnection_timeout_duration,
+                clock,
+            ),
+            ping_timer: Timer::new(server_config.ping.ping_interval, clock),
             handshake_manager: HandshakeManager::new(server_config.require_auth),
             // Users
             users: BigMap::new(),
@@ -103,9 +137,22 @@ impl<E: Copy + Eq + Hash + Send + Sync> Server<E> {
             // Entities
             entity_room_map: HashMap::new(),
             entity_scope_map: EntityScopeMap::new(),
+            component_scope_map: ComponentScopeMap::new(),
+            entity_hierarchy: EntityHierarchy::new(),
+            reparented_entities: HashSet::new(),
+            pending_parent_messages: HashSet::new(),
+            dormant_entities: HashSet::new(),
+            predicted_spawns: HashMap::new(),
+            resources: HashMap::new(),
+            resource_entities: HashSet::new(),
             global_world_manager: GlobalWorldManager::new(),
+            component_validators: ComponentValidators::new(),
             // Events
             incoming_events: Events::new(),
+            // Recording
+            finished_recordings: HashMap::new(),
+            disconnected_network_stats: NetworkStats::new(),
+            handshake_failures: HashMap::new(),
             // Ticks
             time_manager,
         }
@@ -114,7 +161,7 @@ impl<E: Copy + Eq + Hash + Send + Sync> Server<E> {
     /// Listen at the given addresses
     pub fn listen<S: Into<Box<dyn Socket>>>(&mut self, socket: S) {
         let boxed_socket: Box<dyn Socket> = socket.into();
-        let (packet_sender, packet_receiver) = boxed_socket.listen();
+        let (packet_sender, packet_receiver) = boxed_socket.listen(&self.server_config.clock);
         self.io.load(packet_sender, packet_receiver);
     }
 
@@ -185,6 +232,7 @@ impl<E: Copy + Eq + Hash + Send + Sync> Server<E> {
             user_key,
             &self.protocol.channel_kinds,
             &self.global_world_manager,
+            &self.server_config.clock,
         );
 
         // send connect response
@@ -202,8 +250,9 @@ impl<E: Copy + Eq + Hash + Send + Sync> Server<E> {
         }
 
         self.user_connections.insert(user.address, new_connection);
-        if self.io.bandwidth_monitor_enabled() {
-            self.io.register_client(&user.address);
+        self.io.register_client(&user.address);
+        if self.time_manager.tick_interval() != self.protocol.tick_interval {
+            self.send_tick_interval_message(user_key);
         }
         self.incoming_events.push_connection(user_key);
     }
@@ -225,7 +274,7 @@ impl<E: Copy + Eq + Hash + Send + Sync> Server<E> {
                     &user.address
                 );
             }
-            //
+            self.record_handshake_failure(HandshakeFailure::AuthRejected);
         }
         self.user_delete(user_key);
     }
@@ -233,10 +282,38 @@ impl<E: Copy + Eq + Hash + Send + Sync> Server<E> {
     // Messages
 
     /// Queues up an Message to be sent to the Client associated with a given
-    /// UserKey
-    pub fn send_message<C: Channel, M: Message>(&mut self, user_key: &UserKey, message: &M) {
+    /// UserKey, or returns an Error if the Channel can not be used for it.
+    /// Messages to Users which are not connected are dropped.
+    pub fn send_message<C: Channel, M: Message>(
+        &mut self,
+        user_key: &UserKey,
+        message: &M,
+    ) -> Result<(), NaiaServerError> {
+        let channel_kind = ChannelKind::of::<C>();
+        self.check_send_channel(&channel_kind, false)?;
         let cloned_message = M::clone_box(message);
-        self.send_message_inner(user_key, &ChannelKind::of::<C>(), cloned_message);
+        self.send_message_inner(user_key, &channel_kind, cloned_message);
+        Ok(())
+    }
+
+    /// Checks that Messages can be sent to Clients on the Channel, with or
+    /// without a Tick
+    fn check_send_channel(
+        &self,
+        channel_kind: &ChannelKind,
+        tick_buffered: bool,
+    ) -> Result<(), NaiaServerError> {
+        let channel_settings = self.protocol.channel_kinds.channel(channel_kind);
+
+        if !channel_settings.can_send_to_client() {
+            return Err(NaiaServerError::ChannelNotSendable);
+        }
+
+        if channel_settings.tick_buffered() != tick_buffered {
+            return Err(NaiaServerError::ChannelTickBufferMismatch);
+        }
+
+        Ok(())
     }
 
     /// Queues up an Message to be sent to the Client associated with a given
@@ -247,12 +324,6 @@ impl<E: Copy + Eq + Hash + Send + Sync> Server<E> {
         channel_kind: &ChannelKind,
         message_box: Box<dyn Message>,
     ) {
-        let channel_settings = self.protocol.channel_kinds.channel(channel_kind);
-
-        if !channel_settings.can_send_to_client() {
-            panic!("Cannot send message to Client on this Channel");
-        }
-
         if let Some(user) = self.users.get(user_key) {
             if let Some(connection) = self.user_connections.get_mut(&user.address) {
                 let mut converter = EntityConverterMut::new(
@@ -270,20 +341,84 @@ impl<E: Copy + Eq + Hash + Send + Sync> Server<E> {
         }
     }
 
-    /// Sends a message to all connected users using a given channel
-    pub fn broadcast_message<C: Channel, M: Message>(&mut self, message: &M) {
+    /// Sends a message to all connected users using a given channel, or
+    /// returns an Error if the Channel can not be used for it
+    pub fn broadcast_message<C: Channel, M: Message>(
+        &mut self,
+        message: &M,
+    ) -> Result<(), NaiaServerError> {
         let cloned_message = M::clone_box(message);
-        self.broadcast_message_inner(&ChannelKind::of::<C>(), cloned_message);
+        self.broadcast_message_inner(&ChannelKind::of::<C>(), cloned_message)
     }
 
     fn broadcast_message_inner(
         &mut self,
         channel_kind: &ChannelKind,
         message_box: Box<dyn Message>,
+    ) -> Result<(), NaiaServerError> {
+        self.check_send_channel(channel_kind, false)?;
+        for user_key in self.user_keys() {
+            self.send_message_inner(&user_key, channel_kind, message_box.clone());
+        }
+        Ok(())
+    }
+
+    /// Queues up a Message to be sent to the Client associated with a given
+    /// UserKey on a Tick Buffered Channel, or returns an Error if the Channel
+    /// can not be used for it. The Client receives it once it reaches the
+    /// given Server Tick.
+    pub fn send_tick_buffer_message<C: Channel, M: Message>(
+        &mut self,
+        user_key: &UserKey,
+        tick: &Tick,
+        message: &M,
+    ) -> Result<(), NaiaServerError> {
+        let channel_kind = ChannelKind::of::<C>();
+        self.check_send_channel(&channel_kind, true)?;
+        let cloned_message = M::clone_box(message);
+        self.send_tick_buffer_message_inner(user_key, tick, &channel_kind, cloned_message);
+        Ok(())
+    }
+
+    /// Sends a Message to all connected users on a Tick Buffered Channel, or
+    /// returns an Error if the Channel can not be used for it
+    pub fn broadcast_tick_buffer_message<C: Channel, M: Message>(
+        &mut self,
+        tick: &Tick,
+        message: &M,
+    ) -> Result<(), NaiaServerError> {
+        let channel_kind = ChannelKind::of::<C>();
+        self.check_send_channel(&channel_kind, true)?;
+        for user_key in self.user_keys() {
+            self.send_tick_buffer_message_inner(
+                &user_key,
+                tick,
+                &channel_kind,
+                M::clone_box(message),
+            );
+        }
+        Ok(())
+    }
+
+    fn send_tick_buffer_message_inner(
+        &mut self,
+        user_key: &UserKey,
+        tick: &Tick,
+        channel_kind: &ChannelKind,
+        message_box: Box<dyn Message>,
     ) {
-        self.user_keys().iter().for_each(|user_key| {
-            self.send_message_inner(user_key, channel_kind, message_box.clone())
-        })
+        if let Some(user) = self.users.get(user_key) {
+            if let Some(connection) = self.user_connections.get_mut(&user.address) {
+                let mut converter = EntityConverterMut::new(
+                    &self.global_world_manager,
+                    &mut connection.base.local_world_manager,
+                );
+                let message = MessageContainer::from_write(message_box, &mut converter);
+                connection
+                    .tick_buffer_sender
+                    .send_message(tick, channel_kind, message);
+            }
+        }
     }
 
     pub fn receive_tick_buffer_messages(&mut self, tick: &Tick) -> TickBufferMessages {
@@ -305,7 +440,8 @@ impl<E: Copy + Eq + Hash + Send + Sync> Server<E> {
     ///
     /// Return a collection of Entity Scope Sets, being a unique combination of
     /// a related Room, User, and Entity, used to determine which Entities to
-    /// replicate to which Users
+    /// replicate to which Users. Resources are always in scope, and are left
+    /// out.
     pub fn scope_checks(&self) -> Vec<(RoomKey, UserKey, E)> {
         let mut list: Vec<(RoomKey, UserKey, E)> = Vec::new();
 
@@ -314,6 +450,9 @@ impl<E: Copy + Eq + Hash + Send + Sync> Server<E> {
         for (room_key, room) in self.rooms.iter() {
             for user_key in room.user_keys() {
                 for entity in room.entities() {
+                    if self.resource_entities.contains(entity) {
+                        continue;
+                    }
                     list.push((room_key, *user_key, *entity));
                 }
             }
@@ -326,7 +465,7 @@ impl<E: Copy + Eq + Hash + Send + Sync> Server<E> {
     /// method, the Server will never communicate with it's connected
     /// Clients
     pub fn send_all_updates<W: WorldRefType<E>>(&mut self, world: W) {
-        let now = Instant::now();
+        let now = self.server_config.clock.now();
 
         // update entity scopes
         self.update_entity_scopes(&world);
@@ -384,20 +523,46 @@ impl<E: Copy + Eq + Hash + Send + Sync> Server<E> {
     /// Entity.
     /// Panics if the Entity does not exist.
     pub fn entity<W: WorldRefType<E>>(&self, world: W, entity: &E) -> EntityRef<E, W> {
+        match self.try_entity(world, entity) {
+            Ok(entity_ref) => entity_ref,
+            Err(error) => panic!("{}", error),
+        }
+    }
+
+    /// Retrieves an EntityRef that exposes read-only operations for the
+    /// Entity, or an Error if the Entity does not exist
+    pub fn try_entity<W: WorldRefType<E>>(
+        &self,
+        world: W,
+        entity: &E,
+    ) -> Result<EntityRef<E, W>, NaiaServerError> {
         if world.has_entity(entity) {
-            return EntityRef::new(world, entity);
+            return Ok(EntityRef::new(world, entity));
         }
-        panic!("No Entity exists for given Key!");
+        Err(NaiaServerError::EntityNotFound)
     }
 
     /// Retrieves an EntityMut that exposes read and write operations for the
     /// Entity.
     /// Panics if the Entity does not exist.
     pub fn entity_mut<W: WorldMutType<E>>(&mut self, world: W, entity: &E) -> EntityMut<E, W> {
+        match self.try_entity_mut(world, entity) {
+            Ok(entity_mut) => entity_mut,
+            Err(error) => panic!("{}", error),
+        }
+    }
+
+    /// Retrieves an EntityMut that exposes read and write operations for the
+    /// Entity, or an Error if the Entity does not exist
+    pub fn try_entity_mut<W: WorldMutType<E>>(
+        &mut self,
+        world: W,
+        entity: &E,
+    ) -> Result<EntityMut<E, W>, NaiaServerError> {
         if world.has_entity(entity) {
-            return EntityMut::new(self, world, entity);
+            return Ok(EntityMut::new(self, world, entity));
         }
-        panic!("No Entity exists for given Key!");
+        Err(NaiaServerError::EntityNotFound)
     }
 
     /// Gets a Vec of all Entities in the given World
@@ -412,6 +577,440 @@ impl<E: Copy + Eq + Hash + Send + Sync> Server<E> {
         return EntityOwner::Local;
     }
 
+    // Hierarchy
+
+    /// Makes `parent` the parent of `child`, detaching `child` from any
+    /// previous parent. An Entity with a parent is in scope for exactly the
+    /// Users the root of its tree is in scope for, regardless of its own Rooms
+    /// or User scopes. Parents are always spawned on Clients before their
+    /// children, and despawning an Entity also despawns all its descendants.
+    /// Returns an Error if either Entity is not replicated, or if this would
+    /// create a cycle.
+    pub fn entity_set_parent(&mut self, child: &E, parent: &E) -> Result<(), NaiaServerError> {
+        if !self.global_world_manager.has_entity(child)
+            || !self.global_world_manager.has_entity(parent)
+        {
+            return Err(NaiaServerError::EntityNotFound);
+        }
+        if self.entity_hierarchy.parent(child) == Some(*parent) {
+            return Ok(());
+        }
+        if !self.entity_hierarchy.set_parent(child, parent) {
+            return Err(NaiaServerError::EntityHierarchyCycle);
+        }
+
+        self.reparented_entities.insert(*child);
+        Ok(())
+    }
+
+    /// Detaches an Entity from its parent, making it the root of its own tree
+    pub fn entity_remove_parent(&mut self, child: &E) {
+        if self.entity_hierarchy.remove_parent(child).is_some() {
+            self.reparented_entities.insert(*child);
+        }
+    }
+
+    /// Gets the parent of an Entity, if it has one
+    pub fn entity_parent(&self, entity: &E) -> Option<E> {
+        self.entity_hierarchy.parent(entity)
+    }
+
+    /// Gets the children of an Entity, in the order they were attached
+    pub fn entity_children(&self, entity: &E) -> Vec<E> {
+        self.entity_hierarchy.children(entity).to_vec()
+    }
+
+    // Dormancy
+
+    /// When the Entity leaves the scope of a User, it goes dormant on their
+    /// Client instead of being despawned: the Client keeps its last known
+    /// state and receives an `EntityLeftScopeEvent`. Once it re-enters the
+    /// User's scope, only the properties which changed in the meantime are
+    /// sent. Component insertions & removals are still sent while dormant.
+    /// Dormant Entities are despawned from the Client once past
+    /// `ServerConfig::dormancy_timeout` or `max_dormant_entities`.
+    pub fn enable_dormancy(&mut self, entity: &E) {
+        self.dormant_entities.insert(*entity);
+    }
+
+    /// Entities leaving the scope of a User are despawned on their Client,
+    /// unless they leave it through a dormant Room. Entities which are
+    /// already dormant are despawned the next time their scope is evaluated.
+    pub fn disable_dormancy(&mut self, entity: &E) {
+        self.dormant_entities.remove(entity);
+    }
+
+    pub fn entity_is_dormant(&self, entity: &E) -> bool {
+        self.dormant_entities.contains(entity)
+    }
+
+    // Predicted Spawns
+
+    /// Marks the Entity as the one a User's Client predicted with the given
+    /// key, which the Client sent along with the command that caused the spawn.
+    /// When the Entity comes into that User's scope, the Client maps it onto
+    /// its predicted Entity instead of spawning a new one.
+    /// Returns an Error if the Entity or User does not exist, or if the Entity
+    /// is already in the User's scope, as it must be confirmed beforehand.
+    pub fn confirm_predicted_spawn(
+        &mut self,
+        entity: &E,
+        user_key: &UserKey,
+        prediction_key: PredictionKey,
+    ) -> Result<(), NaiaServerError> {
+        if !self.global_world_manager.has_entity(entity) {
+            return Err(NaiaServerError::EntityNotFound);
+        }
+        if !self.users.contains_key(user_key) {
+            return Err(NaiaServerError::UserNotFound);
+        }
+        if self.user_scope_has_entity(user_key, entity) {
+            return Err(NaiaServerError::EntityAlreadyInScope);
+        }
+        self.predicted_spawns
+            .insert(*entity, (*user_key, prediction_key));
+        Ok(())
+    }
+
+    // Resources
+
+    /// Inserts a Resource, which is replicated to every User without any
+    /// scoping. Inserting a Resource which already exists updates its value.
+    /// Returns an Error if the Resource type was not registered with
+    /// `Protocol::add_resource()`.
+    pub fn insert_resource<W: WorldMutType<E>, R: Replicate>(
+        &mut self,
+        mut world: W,
+        resource: R,
+    ) -> Result<(), NaiaServerError> {
+        self.insert_resource_inner(&mut world, resource)?;
+        Ok(())
+    }
+
+    /// Inserts a Resource which is only replicated to the Users in the given
+    /// Room. Inserting a Resource which already exists in another Room, or for
+    /// every User, replaces it. If the Resource is later taken out of the
+    /// Room, it is replicated to every User.
+    /// Returns an Error if the Room does not exist, or the Resource type was
+    /// not registered with `Protocol::add_resource()`.
+    pub fn insert_room_resource<W: WorldMutType<E>, R: Replicate>(
+        &mut self,
+        mut world: W,
+        room_key: &RoomKey,
+        resource: R,
+    ) -> Result<(), NaiaServerError> {
+        if !self.rooms.contains_key(room_key) {
+            return Err(NaiaServerError::RoomNotFound);
+        }
+        self.check_resource_kind(&ComponentKind::of::<R>())?;
+        if let Some(entity) = self.resources.get(&ComponentKind::of::<R>()).copied() {
+            if self.room_has_entity(room_key, &entity) {
+                self.insert_component(&mut world, &entity, resource);
+                return Ok(());
+            }
+            self.remove_resource_inner::<W, R>(&mut world);
+        }
+
+        let entity = self.insert_resource_inner(&mut world, resource)?;
+        self.room_add_entity(room_key, &entity)
+    }
+
+    /// Removes a Resource, returning its last value if it existed
+    pub fn remove_resource<W: WorldMutType<E>, R: Replicate>(&mut self, mut world: W) -> Option<R> {
+        self.remove_resource_inner::<W, R>(&mut world)
+    }
+
+    pub fn has_resource<R: Replicate>(&self) -> bool {
+        self.resources.contains_key(&ComponentKind::of::<R>())
+    }
+
+    /// Gets the Entity which holds a Resource, to read or mutate the Resource
+    /// through the World
+    pub fn resource_entity<R: Replicate>(&self) -> Option<E> {
+        self.resources.get(&ComponentKind::of::<R>()).copied()
+    }
+
+    fn insert_resource_inner<W: WorldMutType<E>, R: Replicate>(
+        &mut self,
+        world: &mut W,
+        resource: R,
+    ) -> Result<E, NaiaServerError> {
+        let component_kind = ComponentKind::of::<R>();
+        self.check_resource_kind(&component_kind)?;
+
+        if let Some(entity) = self.resources.get(&component_kind).copied() {
+            self.insert_component(world, &entity, resource);
+            return Ok(entity);
+        }
+
+        // Resources are held by an Entity of their own
+        let entity = world.spawn_entity();
+        self.spawn_entity_inner(&entity);
+        self.insert_component(world, &entity, resource);
+        self.resources.insert(component_kind, entity);
+        self.resource_entities.insert(entity);
+        Ok(entity)
+    }
+
+    /// Checks that the Component was registered as a Resource
+    fn check_resource_kind(&self, component_kind: &ComponentKind) -> Result<(), NaiaServerError> {
+        if !self.protocol.component_kinds.is_resource(component_kind) {
+            return Err(NaiaServerError::ResourceNotRegistered);
+        }
+        Ok(())
+    }
+
+    fn remove_resource_inner<W: WorldMutType<E>, R: Replicate>(
+        &mut self,
+        world: &mut W,
+    ) -> Option<R> {
+        let entity = self.resources.remove(&ComponentKind::of::<R>())?;
+        let resource = self.remove_component::<R, W>(world, &entity);
+        self.despawn_entity(world, &entity);
+        resource
+    }
+
+    // Snapshots
+
+    /// Saves the replicated Components of every replicated Entity in the
+    /// World, to be restored later with `restore()`. Entity references held
+    /// by Components are preserved. Rooms, scopes & hierarchy are not saved.
+    /// Returns an Error if there are more than `u16::MAX` Entities to save.
+    pub fn snapshot<W: WorldRefType<E>>(&self, world: W) -> Result<Vec<u8>, NaiaServerError> {
+        let entities: Vec<E> = self
+            .global_world_manager
+            .entities()
+            .into_iter()
+            .filter(|entity| world.has_entity(entity))
+            .collect();
+        if entities.len() > usize::from(u16::MAX) {
+            return Err(NaiaServerError::SnapshotTooLarge);
+        }
+        let global_entities = entities
+            .iter()
+            .map(|entity| {
+                self.global_world_manager
+                    .entity_to_global_entity(entity)
+                    .unwrap()
+            })
+            .collect();
+        let mut converter = SnapshotEntityConverter::new(global_entities);
+
+        let mut writer = GrowableBitWriter::new();
+        UnsignedVariableInteger::<7>::new(entities.len() as u64).ser(&mut writer);
+        for entity in &entities {
+            let component_kinds: Vec<ComponentKind> = self
+                .global_world_manager
+                .component_kinds(entity)
+                .unwrap_or_default()
+                .into_iter()
+                .filter(|component_kind| world.has_component_of_kind(entity, component_kind))
+                .collect();
+            UnsignedVariableInteger::<3>::new(component_kinds.len() as u64).ser(&mut writer);
+            for component_kind in &component_kinds {
+                let component = world.component_of_kind(entity, component_kind).unwrap();
+                component.write(&self.protocol.component_kinds, &mut writer, &mut converter);
+            }
+        }
+        Ok(writer.to_bytes())
+    }
+
+    /// Spawns the Entities saved by `snapshot()` into the World, and returns
+    /// them in their saved order. Existing Entities are left in place, except
+    /// for Resources, which are replaced. The snapshot must have been taken
+    /// with the same Protocol.
+    pub fn restore<W: WorldMutType<E>>(
+        &mut self,
+        mut world: W,
+        bytes: &[u8],
+    ) -> Result<Vec<E>, SerdeErr> {
+        let mut reader = BitReader::new(bytes);
+        let entity_count = UnsignedVariableInteger::<7>::de(&mut reader)?.get();
+        if entity_count > i128::from(u16::MAX) {
+            return Err(SerdeErr);
+        }
+
+        // spawn every Entity up front, so that Components can refer to any of them
+        let mut entities = Vec::new();
+        let mut global_entities = Vec::new();
+        for _ in 0..entity_count {
+            let entity = world.spawn_entity();
+            self.spawn_entity_inner(&entity);
+            global_entities.push(
+                self.global_world_manager
+                    .entity_to_global_entity(&entity)
+                    .unwrap(),
+            );
+            entities.push(entity);
+        }
+        let converter = SnapshotEntityConverter::new(global_entities);
+
+        let mut components = Vec::new();
+        if let Err(err) =
+            self.read_snapshot_components(&mut reader, &converter, &entities, &mut components)
+        {
+            for entity in &entities {
+                self.despawn_entity(&mut world, entity);
+            }
+            return Err(err);
+        }
+
+        for (entity, component) in components {
+            // read Components are owned by the remote, take a local copy
+            let mut component = component.copy_to_box();
+            let component_kind = component.kind();
+            if self.protocol.component_kinds.is_resource(&component_kind) {
+                if let Some(old_entity) = self.resources.insert(component_kind, entity) {
+                    self.despawn_entity(&mut world, &old_entity);
+                }
+                self.resource_entities.insert(entity);
+            }
+            // a Component listed twice for the same Entity keeps its first value
+            if self
+                .insert_component_worldless(&entity, component.as_mut())
+                .is_err()
+            {
+                continue;
+            }
+            world.insert_boxed_component(&entity, component);
+        }
+
+        Ok(entities)
+    }
+
+    fn read_snapshot_components(
+        &self,
+        reader: &mut BitReader,
+        converter: &SnapshotEntityConverter,
+        entities: &[E],
+        components: &mut Vec<(E, Box<dyn Replicate>)>,
+    ) -> Result<(), SerdeErr> {
+        for entity in entities {
+            let component_count = UnsignedVariableInteger::<3>::de(reader)?.get();
+            for _ in 0..component_count {
+                let component = self.protocol.component_kinds.read(reader, converter)?;
+                components.push((*entity, component));
+            }
+        }
+        Ok(())
+    }
+
+    // Validation
+
+    /// Registers a validator for the state of a Component which Clients propose
+    /// for their Client-authoritative Entities, or for delegated Entities they
+    /// hold authority over. It is given the proposing User, the Entity, the
+    /// Component's current state (None for insertions) and the proposed state,
+    /// and runs before the proposed state is applied. Replaces any previous
+    /// validator for the Component.
+    pub fn validate_component<C, F>(&mut self, validator: F)
+    where
+        C: Replicate,
+        F: Fn(&UserKey, &E, Option<&C>, &C) -> Validation<C> + Send + Sync + 'static,
+    {
+        self.component_validators.insert::<C, F>(validator);
+    }
+
+    /// Removes the validator registered for a Component, if any
+    pub fn remove_component_validator<C: Replicate>(&mut self) {
+        self.component_validators.remove(&ComponentKind::of::<C>());
+    }
+
+    // Authority Delegation
+
+    /// Marks a Server-owned Entity as delegated, which allows Clients it is
+    /// in-scope for to request authority over it.
+    /// Returns an Error if the Entity does not exist, or is not Server-owned.
+    pub fn enable_delegation(&mut self, entity: &E) -> Result<(), NaiaServerError> {
+        if self.global_world_manager.entity_is_delegated(entity) {
+            return Ok(());
+        }
+        self.global_world_manager
+            .entity_set_delegated(entity, true)?;
+        self.send_auth_message_to_scope(entity, EntityAuthAction::EnableDelegation);
+        Ok(())
+    }
+
+    /// Stops delegating an Entity, revoking authority from the Client
+    /// currently holding it, if any
+    pub fn disable_delegation(&mut self, entity: &E) {
+        if !self.global_world_manager.entity_is_delegated(entity) {
+            return;
+        }
+        self.revoke_authority(entity);
+        if self
+            .global_world_manager
+            .entity_set_delegated(entity, false)
+            .is_ok()
+        {
+            self.send_auth_message_to_scope(entity, EntityAuthAction::DisableDelegation);
+        }
+    }
+
+    /// Returns whether or not the Entity is delegated
+    pub fn entity_is_delegated(&self, entity: &E) -> bool {
+        self.global_world_manager.entity_is_delegated(entity)
+    }
+
+    /// Returns the User currently holding authority over the given delegated
+    /// Entity, if any
+    pub fn entity_authority(&self, entity: &E) -> Option<UserKey> {
+        self.global_world_manager.entity_authority(entity)
+    }
+
+    /// Gives a User authority over a delegated Entity, revoking it from any
+    /// other User holding it. While the User holds authority, the state of the
+    /// Entity's Components is replicated from their Client.
+    /// Returns an Error if the Entity is not delegated, or is not in scope for
+    /// the User.
+    pub fn grant_authority(
+        &mut self,
+        entity: &E,
+        user_key: &UserKey,
+    ) -> Result<(), NaiaServerError> {
+        if !self.global_world_manager.entity_is_delegated(entity) {
+            return Err(NaiaServerError::EntityNotDelegated);
+        }
+        if !self.users.contains_key(user_key) {
+            return Err(NaiaServerError::UserNotFound);
+        }
+        if self.global_world_manager.entity_authority(entity) == Some(*user_key) {
+            return Ok(());
+        }
+        if !self.user_scope_has_entity(user_key, entity) {
+            return Err(NaiaServerError::EntityNotInScope);
+        }
+
+        self.revoke_authority(entity);
+        self.global_world_manager
+            .entity_set_authority(entity, Some(*user_key))?;
+        self.send_auth_message(user_key, entity, EntityAuthAction::Grant);
+        Ok(())
+    }
+
+    /// Denies a User's request for authority over a delegated Entity
+    pub fn deny_authority(&mut self, entity: &E, user_key: &UserKey) {
+        if self.global_world_manager.entity_authority(entity) == Some(*user_key) {
+            return;
+        }
+        self.send_auth_message(user_key, entity, EntityAuthAction::Deny);
+    }
+
+    /// Takes authority over a delegated Entity away from the User holding it,
+    /// returning it to the Server
+    pub fn revoke_authority(&mut self, entity: &E) {
+        let Some(user_key) = self.global_world_manager.entity_authority(entity) else {
+            return;
+        };
+        if self
+            .global_world_manager
+            .entity_set_authority(entity, None)
+            .is_ok()
+        {
+            self.send_auth_message(&user_key, entity, EntityAuthAction::Revoke);
+        }
+    }
+
     // Users
 
     /// Returns whether or not a User exists for the given RoomKey
@@ -423,20 +1022,40 @@ impl<E: Copy + Eq + Hash + Send + Sync> Server<E> {
     /// associated with the given UserKey.
     /// Panics if the user does not exist.
     pub fn user(&self, user_key: &UserKey) -> UserRef<E> {
+        match self.try_user(user_key) {
+            Ok(user_ref) => user_ref,
+            Err(error) => panic!("{}", error),
+        }
+    }
+
+    /// Retrieves an UserRef that exposes read-only operations for the User
+    /// associated with the given UserKey, or an Error if the user does not
+    /// exist
+    pub fn try_user(&self, user_key: &UserKey) -> Result<UserRef<E>, NaiaServerError> {
         if self.users.contains_key(user_key) {
-            return UserRef::new(self, user_key);
+            return Ok(UserRef::new(self, user_key));
         }
-        panic!("No User exists for given Key!");
+        Err(NaiaServerError::UserNotFound)
     }
 
     /// Retrieves an UserMut that exposes read and write operations for the User
     /// associated with the given UserKey.
-    /// Returns None if the user does not exist.
+    /// Panics if the user does not exist.
     pub fn user_mut(&mut self, user_key: &UserKey) -> UserMut<E> {
+        match self.try_user_mut(user_key) {
+            Ok(user_mut) => user_mut,
+            Err(error) => panic!("{}", error),
+        }
+    }
+
+    /// Retrieves an UserMut that exposes read and write operations for the User
+    /// associated with the given UserKey, or an Error if the user does not
+    /// exist
+    pub fn try_user_mut(&mut self, user_key: &UserKey) -> Result<UserMut<E>, NaiaServerError> {
         if self.users.contains_key(user_key) {
-            return UserMut::new(self, user_key);
+            return Ok(UserMut::new(self, user_key));
         }
-        panic!("No User exists for given Key!");
+        Err(NaiaServerError::UserNotFound)
     }
 
     /// Return a list of all currently connected Users' keys
@@ -455,13 +1074,60 @@ impl<E: Copy + Eq + Hash + Send + Sync> Server<E> {
         self.users.len()
     }
 
+    /// Registers a rule deciding which Users can see a Component, on any
+    /// Entity in their scope. It is given the User & the Entity, and is
+    /// evaluated whenever the Entity enters a User's scope or the Component is
+    /// inserted. Call `refresh_component_visibility()` once whatever the rule
+    /// depends on changes, i.e. a User switching teams. Components excluded
+    /// through `user_scope()` stay hidden regardless. Replaces any previous
+    /// rule for the Component.
+    pub fn component_visibility<R, F>(&mut self, rule: F)
+    where
+        R: Replicate,
+        F: Fn(&UserKey, &E) -> bool + Send + Sync + 'static,
+    {
+        self.component_scope_map
+            .set_rule(ComponentKind::of::<R>(), Box::new(rule));
+    }
+
+    /// Removes the visibility rule registered for a Component, if any. Call
+    /// `refresh_component_visibility()` for Entities already in scope.
+    pub fn remove_component_visibility<R: Replicate>(&mut self) {
+        self.component_scope_map
+            .remove_rule(&ComponentKind::of::<R>());
+    }
+
+    /// Re-evaluates which Users can see each Component of the Entity, sending
+    /// insertions & removals to the Users whose scope it is in
+    pub fn refresh_component_visibility(&mut self, entity: &E) {
+        let Some(component_kinds) = self.global_world_manager.component_kinds(entity) else {
+            return;
+        };
+        for user_key in self.user_keys() {
+            for component_kind in &component_kinds {
+                self.sync_component_visibility(&user_key, entity, component_kind);
+            }
+        }
+    }
+
     /// Returns a UserScopeMut, which is used to include/exclude Entities for a
     /// given User
     pub fn user_scope(&mut self, user_key: &UserKey) -> UserScopeMut<E> {
+        match self.try_user_scope(user_key) {
+            Ok(user_scope) => user_scope,
+            Err(error) => panic!("{}", error),
+        }
+    }
+
+    /// Returns a UserScopeMut, or an Error if the User does not exist
+    pub fn try_user_scope(
+        &mut self,
+        user_key: &UserKey,
+    ) -> Result<UserScopeMut<E>, NaiaServerError> {
         if self.users.contains_key(user_key) {
-            return UserScopeMut::new(self, user_key);
+            return Ok(UserScopeMut::new(self, user_key));
         }
-        panic!("No User exists for given Key!");
+        Err(NaiaServerError::UserNotFound)
     }
 
     // Rooms
@@ -484,20 +1150,40 @@ impl<E: Copy + Eq + Hash + Send + Sync> Server<E> {
     /// Room associated with the given RoomKey.
     /// Panics if the room does not exist.
     pub fn room(&self, room_key: &RoomKey) -> RoomRef<E> {
+        match self.try_room(room_key) {
+            Ok(room_ref) => room_ref,
+            Err(error) => panic!("{}", error),
+        }
+    }
+
+    /// Retrieves an RoomRef that exposes read-only operations for the Room
+    /// associated with the given RoomKey, or an Error if the room does not
+    /// exist
+    pub fn try_room(&self, room_key: &RoomKey) -> Result<RoomRef<E>, NaiaServerError> {
         if self.rooms.contains_key(room_key) {
-            return RoomRef::new(self, room_key);
+            return Ok(RoomRef::new(self, room_key));
         }
-        panic!("No Room exists for given Key!");
+        Err(NaiaServerError::RoomNotFound)
     }
 
     /// Retrieves an RoomMut that exposes read and write operations for the
     /// Room associated with the given RoomKey.
     /// Panics if the room does not exist.
     pub fn room_mut(&mut self, room_key: &RoomKey) -> RoomMut<E> {
+        match self.try_room_mut(room_key) {
+            Ok(room_mut) => room_mut,
+            Err(error) => panic!("{}", error),
+        }
+    }
+
+    /// Retrieves an RoomMut that exposes read and write operations for the
+    /// Room associated with the given RoomKey, or an Error if the room does
+    /// not exist
+    pub fn try_room_mut(&mut self, room_key: &RoomKey) -> Result<RoomMut<E>, NaiaServerError> {
         if self.rooms.contains_key(room_key) {
-            return RoomMut::new(self, room_key);
+            return Ok(RoomMut::new(self, room_key));
         }
-        panic!("No Room exists for given Key!");
+        Err(NaiaServerError::RoomNotFound)
     }
 
     /// Return a list of all the Server's Rooms' keys
@@ -523,6 +1209,42 @@ impl<E: Copy + Eq + Hash + Send + Sync> Server<E> {
         return self.time_manager.current_tick();
     }
 
+    /// Changes the duration between Ticks at runtime, i.e. to tick slower in a
+    /// lobby than in a match. Connected Clients are told of the new rate, and
+    /// re-target their Ticks to it.
+    pub fn set_tick_interval(&mut self, tick_interval: Duration) {
+        if tick_interval == self.time_manager.tick_interval() {
+            return;
+        }
+        self.time_manager.set_tick_interval(tick_interval);
+
+        let user_keys: Vec<UserKey> = self
+            .user_connections
+            .values()
+            .map(|connection| connection.user_key)
+            .collect();
+        for user_key in user_keys {
+            self.send_tick_interval_message(&user_key);
+        }
+    }
+
+    /// Gets the current duration between Ticks
+    pub fn tick_interval(&self) -> Duration {
+        self.time_manager.tick_interval()
+    }
+
+    /// Advances the Server to its next Tick, and returns it. The TickEvent is
+    /// returned by the next call to `receive()`. Returns an Error if the
+    /// Server was not configured with `TickMode::Manual`.
+    pub fn advance_tick(&mut self) -> Result<Tick, NaiaServerError> {
+        if !self.time_manager.is_manual_tick() {
+            return Err(NaiaServerError::TickNotManual);
+        }
+        let tick = self.time_manager.advance_tick();
+        self.incoming_events.push_tick(tick);
+        Ok(tick)
+    }
+
     /// Gets the current average tick duration of the Server
     pub fn average_tick_duration(&self) -> Duration {
         self.time_manager.average_tick_duration()
@@ -545,6 +1267,128 @@ impl<E: Copy + Eq + Hash + Send + Sync> Server<E> {
         self.io.incoming_bandwidth_from_client(address)
     }
 
+    // Network stats
+
+    /// Gets the NetworkStats of the connection to the given User's Client
+    pub fn network_stats(&self, user_key: &UserKey) -> Option<NetworkStats> {
+        let user = self.users.get(user_key)?;
+        let connection = self.user_connections.get(&user.address)?;
+        Some(connection.network_stats(&self.protocol, &self.io))
+    }
+
+    /// Gets the bandwidth sent to the given User's Client over the last
+    /// `bandwidth_measure_duration`, by Component, Message & Channel. Returns
+    /// None if the User does not exist, or bandwidth is not being measured.
+    pub fn bandwidth_breakdown(&mut self, user_key: &UserKey) -> Option<BandwidthBreakdown> {
+        let user = self.users.get(user_key)?;
+        let connection = self.user_connections.get_mut(&user.address)?;
+        connection.base.bandwidth_breakdown(&self.protocol)
+    }
+
+    /// Gets the NetworkStats of every connection combined, including ones
+    /// which have since closed. Packets & bytes include handshakes with
+    /// Clients which never connected.
+    pub fn total_network_stats(&self) -> NetworkStats {
+        let mut stats = self.disconnected_network_stats.clone();
+        for connection in self.user_connections.values() {
+            stats.merge(&connection.network_stats(&self.protocol, &self.io));
+        }
+
+        let packet_counter = self.io.total_packet_counter();
+        stats.packets_sent = packet_counter.packets_sent;
+        stats.bytes_sent = packet_counter.bytes_sent;
+        stats.packets_received = packet_counter.packets_received;
+        stats.bytes_received = packet_counter.bytes_received;
+
+        for (failure, count) in &self.handshake_failures {
+            stats
+                .handshake_failures
+                .insert(failure.name().to_string(), *count);
+        }
+
+        stats
+    }
+
+    /// Writes the total NetworkStats, and those of every connected User, in
+    /// the Prometheus text format, i.e. to be handed to a `PrometheusExporter`
+    pub fn prometheus_metrics(&self) -> String {
+        let mut writer = PrometheusWriter::new();
+        writer.add_stats("naia_server", &[], &self.total_network_stats());
+
+        let mut user_keys = self.user_keys();
+        user_keys.sort_by_key(|user_key| user_key.to_u64());
+        for user_key in user_keys {
+            let Some(stats) = self.network_stats(&user_key) else {
+                continue;
+            };
+            let user_id = user_key.to_u64().to_string();
+            writer.add_stats("naia_server_connection", &[("user", &user_id)], &stats);
+        }
+
+        writer.to_text()
+    }
+
+    fn record_handshake_failure(&mut self, failure: HandshakeFailure) {
+        *self.handshake_failures.entry(failure).or_default() += 1;
+    }
+
+    // Recording
+
+    /// Starts recording everything replicated to a User, to be played back
+    /// into a Client later with its replay transport. Call this on the User's
+    /// `ConnectEvent` to record the whole session.
+    /// Returns an Error if the User does not exist.
+    pub fn start_recording(&mut self, user_key: &UserKey) -> Result<(), NaiaServerError> {
+        let Some(user) = self.users.get(user_key) else {
+            return Err(NaiaServerError::UserNotFound);
+        };
+        let recording = Recording::new(
+            self.time_manager.current_tick(),
+            self.time_manager.current_tick_instant(),
+            self.time_manager.game_time_now(),
+            self.time_manager.average_tick_duration(),
+        );
+        self.io.start_recording(&user.address, recording);
+        Ok(())
+    }
+
+    /// Adds a snapshot of the World to a User's recording, which a replay can
+    /// seek back to. Does nothing if the User is not being recorded.
+    pub fn record_keyframe<W: WorldRefType<E>>(
+        &mut self,
+        world: W,
+        user_key: &UserKey,
+    ) -> Result<(), NaiaServerError> {
+        let Some(user) = self.users.get(user_key) else {
+            return Err(NaiaServerError::UserNotFound);
+        };
+        let address = user.address;
+        if !self.io.is_recording(&address) {
+            return Ok(());
+        }
+        let snapshot = self.snapshot(world)?;
+        self.io
+            .record_keyframe(&address, self.time_manager.current_tick(), snapshot);
+        Ok(())
+    }
+
+    pub fn is_recording(&self, user_key: &UserKey) -> bool {
+        let Some(user) = self.users.get(user_key) else {
+            return false;
+        };
+        self.io.is_recording(&user.address)
+    }
+
+    /// Stops recording a User and returns the Recording, which is still
+    /// available after the User disconnects
+    pub fn stop_recording(&mut self, user_key: &UserKey) -> Option<Recording> {
+        if let Some(recording) = self.finished_recordings.remove(user_key) {
+            return Some(recording);
+        }
+        let user = self.users.get(user_key)?;
+        self.io.stop_recording(&user.address)
+    }
+
     // Ping
     /// Gets the average Round Trip Time measured to the given User's Client
     pub fn rtt(&self, user_key: &UserKey) -> Option<f32> {
@@ -572,13 +1416,22 @@ impl<E: Copy + Eq + Hash + Send + Sync> Server<E> {
     //// Entities
 
     /// Despawns the Entity, if it exists.
-    /// This will also remove all of the Entity’s Components.
+    /// This will also remove all of the Entity’s Components, and despawn all
+    /// of its descendants.
     /// Panics if the Entity does not exist.
     pub(crate) fn despawn_entity<W: WorldMutType<E>>(&mut self, world: &mut W, entity: &E) {
         if !world.has_entity(entity) {
             panic!("attempted to de-spawn nonexistent entity");
         }
 
+        // Despawn descendants, deepest first
+        for descendant in self.entity_hierarchy.descendants(entity).iter().rev() {
+            if world.has_entity(descendant) {
+                world.despawn_entity(descendant);
+                self.despawn_entity_worldless(descendant);
+            }
+        }
+
         // Delete from world
         world.despawn_entity(entity);
 
@@ -591,10 +1444,21 @@ impl<E: Copy + Eq + Hash + Send + Sync> Server<E> {
         for (_, connection) in self.user_connections.iter_mut() {
             //remove entity from user connection
             connection.base.host_world_manager.despawn_entity(entity);
+            connection.dormant_entities.remove(entity);
         }
 
         // Delete scope
         self.entity_scope_map.remove_entity(entity);
+        self.component_scope_map.remove_entity(entity);
+
+        // Delete from hierarchy, children become roots
+        self.entity_hierarchy.remove_entity(entity);
+        self.dormant_entities.remove(entity);
+        self.predicted_spawns.remove(entity);
+        if self.resource_entities.remove(entity) {
+            self.resources
+                .retain(|_, resource_entity| resource_entity != entity);
+        }
 
         // Delete room cache entry
         self.entity_room_map.remove(entity);
@@ -603,6 +1467,159 @@ impl<E: Copy + Eq + Hash + Send + Sync> Server<E> {
         self.global_world_manager.host_despawn_entity(entity);
     }
 
+    //// Authority Delegation
+
+    fn send_auth_message(&mut self, user_key: &UserKey, entity: &E, action: EntityAuthAction) {
+        let message = EntityAuthMessage::new(&self.global_world_manager, entity, action);
+        self.send_message_inner(
+            user_key,
+            &ChannelKind::of::<EntityAuthChannel>(),
+            Box::new(message),
+        );
+    }
+
+    fn send_auth_message_to_scope(&mut self, entity: &E, action: EntityAuthAction) {
+        let user_keys: Vec<UserKey> = self
+            .user_connections
+            .values()
+            .filter(|connection| connection.base.host_world_manager.host_has_entity(entity))
+            .map(|connection| connection.user_key)
+            .collect();
+        for user_key in user_keys {
+            self.send_auth_message(&user_key, entity, action);
+        }
+    }
+
+    fn user_scope_has_dormant_entity(&self, user_key: &UserKey, entity: &E) -> bool {
+        if let Some(user) = self.users.get(user_key) {
+            if let Some(connection) = self.user_connections.get(&user.address) {
+                return connection.dormant_entities.contains_key(entity);
+            }
+        }
+        false
+    }
+
+    fn user_scope_has_entity(&self, user_key: &UserKey, entity: &E) -> bool {
+        if let Some(user) = self.users.get(user_key) {
+            if let Some(connection) = self.user_connections.get(&user.address) {
+                return connection.base.host_world_manager.host_has_entity(entity)
+                    && !connection.dormant_entities.contains_key(entity);
+            }
+        }
+        false
+    }
+
+    fn receive_auth_action(&mut self, user_key: &UserKey, entity: &E, action: EntityAuthAction) {
+        match action {
+            EntityAuthAction::Request => {
+                if !self.global_world_manager.entity_is_delegated(entity)
+                    || !self.user_scope_has_entity(user_key, entity)
+                {
+                    self.send_auth_message(user_key, entity, EntityAuthAction::Deny);
+                    return;
+                }
+                match self.global_world_manager.entity_authority(entity) {
+                    Some(holder) if holder == *user_key => {
+                        self.send_auth_message(user_key, entity, EntityAuthAction::Grant);
+                    }
+                    Some(_) => {
+                        self.send_auth_message(user_key, entity, EntityAuthAction::Deny);
+                    }
+                    None => {
+                        self.incoming_events.push_auth_request(user_key, entity);
+                    }
+                }
+            }
+            EntityAuthAction::Release => {
+                self.release_authority(user_key, entity);
+            }
+            _ => {
+                warn!("Server Error: received unexpected Entity authority action from Client");
+            }
+        }
+    }
+
+    /// Returns authority over the Entity to the Server, if it is held by the given User
+    fn release_authority(&mut self, user_key: &UserKey, entity: &E) {
+        if self.global_world_manager.entity_authority(entity) != Some(*user_key) {
+            return;
+        }
+        if self
+            .global_world_manager
+            .entity_set_authority(entity, None)
+            .is_ok()
+        {
+            self.incoming_events.push_auth_release(user_key, entity);
+        }
+    }
+
+    //// Hierarchy
+
+    fn send_parent_message(&mut self, user_key: &UserKey, child: &E) {
+        let parent = self.entity_hierarchy.parent(child);
+        let message = EntityParentMessage::new(&self.global_world_manager, child, parent.as_ref());
+        self.send_message_inner(
+            user_key,
+            &ChannelKind::of::<EntityHierarchyChannel>(),
+            Box::new(message),
+        );
+    }
+
+    /// Sends the parent messages whose Entities are spawned on their Client.
+    /// An Entity re-entering scope while its previous despawn is in flight
+    /// still maps to its previous local Entity until then.
+    fn send_pending_parent_messages(&mut self) {
+        let mut ready = Vec::new();
+        self.pending_parent_messages.retain(|(user_key, child)| {
+            let Some(connection) = self
+                .users
+                .get(user_key)
+                .and_then(|user| self.user_connections.get(&user.address))
+            else {
+                return false;
+            };
+            let host_world_manager = &connection.base.host_world_manager;
+            if !host_world_manager.host_has_entity(child) {
+                return false;
+            }
+            let parent = self.entity_hierarchy.parent(child);
+            let spawned = host_world_manager.entity_channel_is_open(child)
+                && parent.is_none_or(|parent| host_world_manager.entity_channel_is_open(&parent));
+            if spawned {
+                ready.push((*user_key, *child));
+            }
+            !spawned
+        });
+        for (user_key, child) in ready {
+            self.send_parent_message(&user_key, &child);
+        }
+    }
+
+    //// Ticks
+
+    fn send_tick_interval_message(&mut self, user_key: &UserKey) {
+        let message = TickIntervalMessage::new(
+            self.time_manager.current_tick(),
+            self.time_manager.tick_interval(),
+        );
+        self.send_message_inner(
+            user_key,
+            &ChannelKind::of::<TickIntervalChannel>(),
+            Box::new(message),
+        );
+    }
+
+    //// Dormancy
+
+    fn send_scope_message(&mut self, user_key: &UserKey, entity: &E, in_scope: bool) {
+        let message = EntityScopeMessage::new(&self.global_world_manager, entity, in_scope);
+        self.send_message_inner(
+            user_key,
+            &ChannelKind::of::<EntityScopeChannel>(),
+            Box::new(message),
+        );
+    }
+
     //// Entity Scopes
 
     /// Remove all entities from a User's scope
@@ -620,6 +1637,60 @@ impl<E: Copy + Eq + Hash + Send + Sync> Server<E> {
             .insert(*user_key, *entity, is_contained);
     }
 
+    pub(crate) fn user_scope_set_component(
+        &mut self,
+        user_key: &UserKey,
+        entity: &E,
+        component_kind: &ComponentKind,
+        is_contained: bool,
+    ) {
+        if self
+            .component_scope_map
+            .set_hidden(*user_key, *entity, *component_kind, !is_contained)
+        {
+            self.sync_component_visibility(user_key, entity, component_kind);
+        }
+    }
+
+    /// Inserts or removes a Component on a User's connection to match whether
+    /// it is visible to them, if the Entity is in their scope
+    fn sync_component_visibility(
+        &mut self,
+        user_key: &UserKey,
+        entity: &E,
+        component_kind: &ComponentKind,
+    ) {
+        let Some(user) = self.users.get(user_key) else {
+            return;
+        };
+        let Some(connection) = self.user_connections.get_mut(&user.address) else {
+            return;
+        };
+        if !connection.base.host_world_manager.host_has_entity(entity) {
+            return;
+        }
+        if self
+            .component_scope_map
+            .is_hidden(user_key, entity, component_kind)
+        {
+            connection
+                .base
+                .host_world_manager
+                .remove_component(entity, component_kind);
+        } else {
+            let entity_has_component = self
+                .global_world_manager
+                .component_kinds(entity)
+                .is_some_and(|component_kinds| component_kinds.contains(component_kind));
+            if entity_has_component {
+                connection
+                    .base
+                    .host_world_manager
+                    .insert_component(entity, component_kind);
+            }
+        }
+    }
+
     //// Components
 
     /// Adds a Component to an Entity
@@ -645,7 +1716,12 @@ impl<E: Copy + Eq + Hash + Send + Sync> Server<E> {
         } else {
             // Entity does not have this Component type yet, initialize Component
 
-            self.insert_component_worldless(entity, &mut component);
+            if self
+                .insert_component_worldless(entity, &mut component)
+                .is_err()
+            {
+                return;
+            }
 
             // actually insert component into world
             world.insert_component(entity, component);
@@ -653,11 +1729,26 @@ impl<E: Copy + Eq + Hash + Send + Sync> Server<E> {
     }
 
     // This intended to be used by adapter crates, do not use this as it will not update the world
-    pub fn insert_component_worldless(&mut self, entity: &E, component: &mut dyn Replicate) {
+    pub fn insert_component_worldless(
+        &mut self,
+        entity: &E,
+        component: &mut dyn Replicate,
+    ) -> Result<(), NaiaServerError> {
         let component_kind = component.kind();
 
+        // update in world manager
+        self.global_world_manager
+            .host_insert_component(entity, component)
+            .map_err(|_| NaiaServerError::ComponentAlreadyInserted)?;
+
         // add component to connections already tracking entity
         for (_, connection) in self.user_connections.iter_mut() {
+            if self
+                .component_scope_map
+                .is_hidden(&connection.user_key, entity, &component_kind)
+            {
+                continue;
+            }
             // insert component into user's connection
             if connection.base.host_world_manager.host_has_entity(entity) {
                 connection
@@ -667,9 +1758,7 @@ impl<E: Copy + Eq + Hash + Send + Sync> Server<E> {
             }
         }
 
-        // update in world manager
-        self.global_world_manager
-            .host_insert_component(entity, component);
+        Ok(())
     }
 
     /// Removes a Component from an Entity
@@ -754,21 +1843,34 @@ impl<E: Copy + Eq + Hash + Send + Sync> Server<E> {
             panic!("Attempting to despawn entities on a nonexistent connection");
         };
 
-        let entity_events = connection
-            .base
-            .despawn_all_remote_entities(&mut self.global_world_manager, world);
+        let entity_events = connection.base.despawn_all_remote_entities(
+            &mut self.global_world_manager,
+            world,
+            self.time_manager.current_tick(),
+        );
         self.incoming_events
             .receive_entity_events(user_key, entity_events);
     }
 
     pub(crate) fn user_delete(&mut self, user_key: &UserKey) -> User {
+        for entity in self.global_world_manager.user_authority_entities(user_key) {
+            self.release_authority(user_key, &entity);
+        }
+
         let Some(user) = self.users.remove(user_key) else {
             panic!("Attempting to delete non-existant user!");
         };
 
-        self.user_connections.remove(&user.address);
+        if let Some(connection) = self.user_connections.remove(&user.address) {
+            // the closed connection still counts towards the total NetworkStats
+            let mut stats = connection.network_stats(&self.protocol, &self.io);
+            stats.waitlist_size = 0;
+            stats.entities_in_scope = 0;
+            self.disconnected_network_stats.merge(&stats);
+        }
         self.validated_users.remove(&user.address);
         self.entity_scope_map.remove_user(user_key);
+        self.component_scope_map.remove_user(user_key);
         self.handshake_manager.delete_user(&user.address);
 
         // Clean up all user data
@@ -779,9 +1881,12 @@ impl<E: Copy + Eq + Hash + Send + Sync> Server<E> {
                 .unsubscribe_user(user_key);
         }
 
-        // remove from bandwidth monitor
-        if self.io.bandwidth_monitor_enabled() {
-            self.io.deregister_client(&user.address);
+        // remove from packet counters & bandwidth monitor
+        self.io.deregister_client(&user.address);
+
+        // keep the recording around until it is stopped
+        if let Some(recording) = self.io.stop_recording(&user.address) {
+            self.finished_recordings.insert(*user_key, recording);
         }
 
         return user;
@@ -866,13 +1971,15 @@ impl<E: Copy + Eq + Hash + Send + Sync> Server<E> {
         channel_kind: &ChannelKind,
         room_key: &RoomKey,
         message_box: Box<dyn Message>,
-    ) {
+    ) -> Result<(), NaiaServerError> {
+        self.check_send_channel(channel_kind, false)?;
         if let Some(room) = self.rooms.get(room_key) {
             let user_keys: Vec<UserKey> = room.user_keys().cloned().collect();
             for user_key in &user_keys {
                 self.send_message_inner(user_key, channel_kind, message_box.clone())
             }
         }
+        Ok(())
     }
 
     //////// entities
@@ -889,26 +1996,32 @@ impl<E: Copy + Eq + Hash + Send + Sync> Server<E> {
     /// Add an Entity to a Room associated with the given RoomKey.
     /// Entities will only ever be in-scope for Users which are in a Room with
     /// them.
-    pub(crate) fn room_add_entity(&mut self, room_key: &RoomKey, entity: &E) {
-        let mut is_some = false;
-        if let Some(room) = self.rooms.get_mut(room_key) {
-            room.add_entity(entity);
-            is_some = true;
-        }
-        if !is_some {
-            return;
-        }
-        if self.entity_room_map.contains_key(entity) {
-            panic!("Entity already belongs to a Room! Remove the Entity from the Room before adding it to a new Room.");
+    pub(crate) fn room_add_entity(
+        &mut self,
+        room_key: &RoomKey,
+        entity: &E,
+    ) -> Result<(), NaiaServerError> {
+        if let Some(current_room_key) = self.entity_room_map.get(entity) {
+            if current_room_key == room_key {
+                return Ok(());
+            }
+            return Err(NaiaServerError::EntityAlreadyInRoom);
         }
+        let Some(room) = self.rooms.get_mut(room_key) else {
+            return Err(NaiaServerError::RoomNotFound);
+        };
+        room.add_entity(entity);
         self.entity_room_map.insert(*entity, *room_key);
+        Ok(())
     }
 
-    /// Remove an Entity from a Room, associated with the given RoomKey
+    /// Remove an Entity from a Room, associated with the given RoomKey. Does
+    /// nothing if the Entity is not in the Room.
     pub(crate) fn room_remove_entity(&mut self, room_key: &RoomKey, entity: &E) {
         if let Some(room) = self.rooms.get_mut(room_key) {
-            room.remove_entity(entity);
-            self.entity_room_map.remove(entity);
+            if room.remove_entity(entity) {
+                self.entity_room_map.remove(entity);
+            }
         }
     }
 
@@ -931,6 +2044,19 @@ impl<E: Copy + Eq + Hash + Send + Sync> Server<E> {
         0
     }
 
+    pub(crate) fn room_is_dormant(&self, room_key: &RoomKey) -> bool {
+        if let Some(room) = self.rooms.get(room_key) {
+            return room.is_dormant();
+        }
+        false
+    }
+
+    pub(crate) fn room_set_dormant(&mut self, room_key: &RoomKey, dormant: bool) {
+        if let Some(room) = self.rooms.get_mut(room_key) {
+            room.set_dormant(dormant);
+        }
+    }
+
     // Private methods
 
     /// Maintain connection with a client and read all incoming packet data
@@ -950,11 +2076,15 @@ impl<E: Copy + Eq + Hash + Send + Sync> Server<E> {
                     let Ok(header) = StandardHeader::de(&mut reader) else {
                         // Received a malformed packet
                         // TODO: increase suspicion against packet sender
+                        self.incoming_events
+                            .push_error(NaiaServerError::MalformedPacket(address));
                         continue;
                     };
 
                     let Ok(should_continue) = self.maintain_handshake(&address, &header, &mut reader) else {
                         warn!("Server Error: cannot read malformed packet");
+                        self.incoming_events
+                            .push_error(NaiaServerError::MalformedPacket(address));
                         continue;
                     };
                     if should_continue {
@@ -968,6 +2098,8 @@ impl<E: Copy + Eq + Hash + Send + Sync> Server<E> {
                         .is_err()
                     {
                         warn!("Server Error: cannot read malformed packet");
+                        self.incoming_events
+                            .push_error(NaiaServerError::MalformedPacket(address));
                         continue;
                     }
                 }
@@ -1004,6 +2136,8 @@ impl<E: Copy + Eq + Hash + Send + Sync> Server<E> {
                             &address
                         );
                     }
+                } else {
+                    self.record_handshake_failure(HandshakeFailure::MalformedPacket);
                 }
                 return Ok(true);
             }
@@ -1032,8 +2166,8 @@ impl<E: Copy + Eq + Hash + Send + Sync> Server<E> {
                             }
                         }
                     }
-                    HandshakeResult::Invalid => {
-                        // do nothing
+                    HandshakeResult::Invalid(failure) => {
+                        self.record_handshake_failure(failure);
                     }
                 }
                 return Ok(true);
@@ -1141,12 +2275,66 @@ impl<E: Copy + Eq + Hash + Send + Sync> Server<E> {
             return;
         };
 
-        connection.process_packets(
+        let mut outcome = connection.process_packets(
             &self.protocol,
             &mut self.global_world_manager,
+            &self.component_validators,
             world,
             &mut self.incoming_events,
         );
+
+        // process Entity authority
+        connection.apply_auth_updates(
+            &self.protocol,
+            &self.global_world_manager,
+            &self.component_validators,
+            world,
+            &mut outcome,
+        );
+        let user_key = connection.user_key;
+        for (entity, action) in connection.take_auth_actions() {
+            self.receive_auth_action(&user_key, &entity, action);
+        }
+
+        for entity in outcome.despawned_entities {
+            self.despawn_refused_entity(&user_key, &entity, world);
+        }
+        if outcome.kick {
+            self.user_disconnect(&user_key, world);
+        }
+    }
+
+    /// Despawns an Entity after a validator refused the state a User proposed
+    /// for it
+    fn despawn_refused_entity<W: WorldMutType<E>>(
+        &mut self,
+        user_key: &UserKey,
+        entity: &E,
+        world: &mut W,
+    ) {
+        if !world.has_entity(entity) {
+            return;
+        }
+        match self.global_world_manager.entity_owner(entity) {
+            Some(EntityOwner::Client(_)) | Some(EntityOwner::ClientWaiting(_)) => {
+                // the Client owns the Entity, so it is only forgotten once the Client
+                // has despawned it too
+                let tick = self.time_manager.current_tick();
+                let mut entity_events = Vec::new();
+                for component_kind in world.component_kinds(entity) {
+                    if let Some(component) = world.remove_component_of_kind(entity, &component_kind)
+                    {
+                        entity_events.push(EntityEvent::RemoveComponent(tick, *entity, component));
+                    }
+                }
+                world.despawn_entity(entity);
+                entity_events.push(EntityEvent::DespawnEntity(tick, *entity));
+                self.incoming_events
+                    .receive_entity_events(user_key, entity_events);
+                self.send_auth_message(user_key, entity, EntityAuthAction::Despawn);
+            }
+            _ => self.despawn_entity(world, entity),
+        }
     }
 
     fn handle_disconnects<W: WorldMutType<E>>(&mut self, world: &mut W) {
@@ -1255,66 +2443,372 @@ impl<E: Copy + Eq + Hash + Send + Sync> Server<E> {
     // Entity Scopes
 
     fn update_entity_scopes<W: WorldRefType<E>>(&mut self, world: &W) {
+        let mut changes = ScopeChanges::default();
+        // reused for every Entity tree, parents before children
+        let mut tree: Vec<E> = Vec::new();
+        // when Entities leaving scope here go dormant
+        let now = self.server_config.clock.now();
+
         for (_, room) in self.rooms.iter_mut() {
             while let Some((removed_user, removed_entity)) = room.pop_entity_removal_queue() {
+                // the scope of child Entities follows their root, not their Rooms
+                if self.entity_hierarchy.has_parent(&removed_entity) {
+                    continue;
+                }
                 if let Some(user) = self.users.get(&removed_user) {
                     if let Some(connection) = self.user_connections.get_mut(&user.address) {
                         // TODO: evaluate whether the Entity really needs to be despawned!
                         // What if the Entity shares another Room with this User? It shouldn't be despawned!
 
-                        //remove entity from user connection
+                        let goes_dormant =
+                            room.is_dormant() || self.dormant_entities.contains(&removed_entity);
+
+                        //remove entity & its descendants from user connection
+                        self.entity_hierarchy.tree_into(&removed_entity, &mut tree);
+                        for tree_entity in tree.iter() {
+                            if !connection
+                                .base
+                                .host_world_manager
+                                .host_has_entity(tree_entity)
+                            {
+                                continue;
+                            }
+                            let currently_dormant =
+                                connection.dormant_entities.contains_key(tree_entity);
+                            if goes_dormant {
+                                if currently_dormant {
+                                    continue;
+                                }
+                                // keep the entity on the client, hold back updates
+                                connection
+                                    .dormant_entities
+                                    .insert(*tree_entity, now.clone());
+                                changes.dormancy.push((removed_user, *tree_entity, true));
+                            } else {
+                                connection
+                                    .base
+                                    .host_world_manager
+                                    .despawn_entity(tree_entity);
+                                if connection.dormant_entities.remove(tree_entity).is_some() {
+                                    continue;
+                                }
+                            }
+                            changes.exited.push((removed_user, *tree_entity));
+                        }
+                    }
+                }
+            }
+
+            for entity in room.entities() {
+                // the scope of child Entities follows their root, not their Rooms
+                if self.entity_hierarchy.has_parent(entity) || !world.has_entity(entity) {
+                    continue;
+                }
+                self.entity_hierarchy.tree_into(entity, &mut tree);
+                let goes_dormant = room.is_dormant() || self.dormant_entities.contains(entity);
+
+                for user_key in room.user_keys() {
+                    let Some(user) = self.users.get(user_key) else {
+                        continue;
+                    };
+                    let Some(connection) = self.user_connections.get_mut(&user.address) else {
+                        continue;
+                    };
+                    // Resources are in scope for everyone in their Room
+                    let should_be_in_scope = self.resource_entities.contains(entity)
+                        || self.entity_scope_map.get(user_key, entity) == Some(&true);
+
+                    Self::apply_tree_scope(
+                        world,
+                        &self.global_world_manager,
+                        &self.component_scope_map,
+                        &mut self.predicted_spawns,
+                        connection,
+                        &tree,
+                        should_be_in_scope,
+                        goes_dormant,
+                        &now,
+                        &mut changes,
+                    );
+                }
+            }
+        }
+
+        // Entities which changed parents may now belong to a tree whose root no
+        // Room shares with a User, which the Rooms above never visit
+        for entity in std::mem::take(&mut self.reparented_entities) {
+            if !world.has_entity(&entity) {
+                continue;
+            }
+            let root = self.entity_hierarchy.root(&entity);
+            let room = self
+                .entity_room_map
+                .get(&root)
+                .and_then(|room_key| self.rooms.get(room_key));
+            let goes_dormant =
+                room.is_some_and(|room| room.is_dormant()) || self.dormant_entities.contains(&root);
+            self.entity_hierarchy.tree_into(&entity, &mut tree);
+
+            for connection in self.user_connections.values_mut() {
+                let user_key = connection.user_key;
+                let should_be_in_scope = match room {
+                    Some(room) => {
+                        room.has_user(&user_key)
+                            && (self.resource_entities.contains(&root)
+                                || self.entity_scope_map.get(&user_key, &root) == Some(&true))
+                    }
+                    // Resources outside of any Room are in scope for every User
+                    None => self.resource_entities.contains(&root),
+                };
+                let previously_entered = changes.entered.len();
+                Self::apply_tree_scope(
+                    world,
+                    &self.global_world_manager,
+                    &self.component_scope_map,
+                    &mut self.predicted_spawns,
+                    connection,
+                    &tree,
+                    should_be_in_scope,
+                    goes_dormant,
+                    &now,
+                    &mut changes,
+                );
+                // Clients which already had the Entity need its new parent
+                let entered = changes.entered[previously_entered..]
+                    .iter()
+                    .any(|(_, entered_entity)| *entered_entity == entity);
+                if !entered && connection.base.host_world_manager.host_has_entity(&entity) {
+                    changes.reparented.push((user_key, entity));
+                }
+            }
+        }
+
+        // Resources outside of any Room are in scope for every User
+        for entity in self.resource_entities.iter() {
+            if self.entity_room_map.contains_key(entity) || !world.has_entity(entity) {
+                continue;
+            }
+            for (user_key, user) in self.users.iter() {
+                let Some(connection) = self.user_connections.get_mut(&user.address) else {
+                    continue;
+                };
+                if connection.base.host_world_manager.host_has_entity(entity) {
+                    continue;
+                }
+                let component_kinds = self
+                    .global_world_manager
+                    .component_kinds(entity)
+                    .unwrap()
+                    .into_iter()
+                    .filter(|component_kind| {
+                        !self
+                            .component_scope_map
+                            .is_hidden(&user_key, entity, component_kind)
+                    })
+                    .collect();
+                connection.base.host_world_manager.init_entity(
+                    &mut connection.base.local_world_manager,
+                    entity,
+                    component_kinds,
+                );
+            }
+        }
+
+        // dormant Entities don't stay on Clients forever
+        self.expire_dormant_entities(&mut tree);
+
+        // let Clients know which of their Entities went dormant or woke up
+        for (user_key, entity, dormant) in changes.dormancy {
+            self.send_scope_message(&user_key, &entity, !dormant);
+        }
+
+        // let Clients know the parents of their newly in-scope Entities, and
+        // the new parents of the Entities they already had
+        for (user_key, entity) in changes.entered.iter() {
+            if self.entity_hierarchy.has_parent(entity) {
+                self.pending_parent_messages.insert((*user_key, *entity));
+            }
+        }
+        self.pending_parent_messages.extend(changes.reparented);
+        self.send_pending_parent_messages();
+
+        // let Clients know which of their newly in-scope Entities are delegated
+        for (user_key, entity) in changes.entered {
+            if self.global_world_manager.entity_is_delegated(&entity) {
+                self.send_auth_message(&user_key, &entity, EntityAuthAction::EnableDelegation);
+            }
+        }
+
+        // Users can't hold authority over Entities they can't see
+        for (user_key, entity) in changes.exited {
+            if self.global_world_manager.entity_authority(&entity) != Some(user_key) {
+                continue;
+            }
+            self.release_authority(&user_key, &entity);
+            if self.user_scope_has_dormant_entity(&user_key, &entity) {
+                // the Client keeps dormant Entities, so it needs to be told
+                self.send_auth_message(&user_key, &entity, EntityAuthAction::Revoke);
+            }
+        }
+    }
+
+    /// Despawns Entities from Clients which have been dormant there for longer
+    /// than `dormancy_timeout`, or past `max_dormant_entities`, along with
+    /// their dormant descendants
+    fn expire_dormant_entities(&mut self, tree: &mut Vec<E>) {
+        let timeout = self.server_config.dormancy_timeout;
+        let max_dormant_entities = self.server_config.max_dormant_entities;
+        let now = self.server_config.clock.now();
+
+        for connection in self.user_connections.values_mut() {
+            let mut expired: Vec<E> = match timeout {
+                Some(timeout) => connection
+                    .dormant_entities
+                    .iter()
+                    .filter(|(_, dormant_since)| now.duration_since(dormant_since) >= timeout)
+                    .map(|(entity, _)| *entity)
+                    .collect(),
+                None => Vec::new(),
+            };
+            if let Some(max_dormant_entities) = max_dormant_entities {
+                let kept = connection.dormant_entities.len() - expired.len();
+                if kept > max_dormant_entities {
+                    let mut oldest: Vec<(&E, &Instant)> = connection
+                        .dormant_entities
+                        .iter()
+                        .filter(|(entity, _)| !expired.contains(entity))
+                        .collect();
+                    oldest.sort_by_key(|(_, dormant_since)| *dormant_since);
+                    let excess = kept - max_dormant_entities;
+                    let oldest: Vec<E> = oldest[..excess]
+                        .iter()
+                        .map(|(entity, _)| **entity)
+                        .collect();
+                    expired.extend(oldest);
+                }
+            }
+
+            for entity in expired {
+                self.entity_hierarchy.tree_into(&entity, tree);
+                for tree_entity in tree.iter() {
+                    if connection.dormant_entities.remove(tree_entity).is_some() {
                         connection
                             .base
                             .host_world_manager
-                            .despawn_entity(&removed_entity);
+                            .despawn_entity(tree_entity);
                     }
                 }
             }
+        }
+    }
 
-            // TODO: we should be able to cache these tuples of keys to avoid building a new
-            // list each time
-            for user_key in room.user_keys() {
-                for entity in room.entities() {
-                    if world.has_entity(entity) {
-                        if let Some(user) = self.users.get(user_key) {
-                            if let Some(connection) = self.user_connections.get_mut(&user.address) {
-                                let currently_in_scope =
-                                    connection.base.host_world_manager.host_has_entity(entity);
-
-                                let should_be_in_scope = if let Some(in_scope) =
-                                    self.entity_scope_map.get(user_key, entity)
-                                {
-                                    *in_scope
-                                } else {
-                                    false
-                                };
-
-                                if should_be_in_scope {
-                                    if !currently_in_scope {
-                                        let component_kinds = self
-                                            .global_world_manager
-                                            .component_kinds(entity)
-                                            .unwrap();
-                                        // add entity & components to the connections local scope
-                                        connection.base.host_world_manager.init_entity(
-                                            &mut connection.base.local_world_manager,
-                                            entity,
-                                            component_kinds,
-                                        );
-                                    }
-                                } else if currently_in_scope {
-                                    // remove entity from the connections local scope
-                                    connection.base.host_world_manager.despawn_entity(entity);
-                                }
-                            }
+    /// Brings an Entity tree into, or takes it out of, a User's scope
+    #[allow(clippy::too_many_arguments)]
+    fn apply_tree_scope<W: WorldRefType<E>>(
+        world: &W,
+        global_world_manager: &GlobalWorldManager<E>,
+        component_scope_map: &ComponentScopeMap<E>,
+        predicted_spawns: &mut HashMap<E, (UserKey, PredictionKey)>,
+        connection: &mut Connection<E>,
+        tree: &[E],
+        should_be_in_scope: bool,
+        goes_dormant: bool,
+        now: &Instant,
+        changes: &mut ScopeChanges<E>,
+    ) {
+        let user_key = connection.user_key;
+        for tree_entity in tree.iter() {
+            if !world.has_entity(tree_entity) {
+                continue;
+            }
+            let currently_in_scope = connection
+                .base
+                .host_world_manager
+                .host_has_entity(tree_entity);
+            let currently_dormant = connection.dormant_entities.contains_key(tree_entity);
+
+            if should_be_in_scope {
+                if currently_dormant {
+                    // wake up, pending updates will be sent
+                    connection.dormant_entities.remove(tree_entity);
+                    changes.dormancy.push((user_key, *tree_entity, false));
+                } else if !currently_in_scope {
+                    let component_kinds = global_world_manager
+                        .component_kinds(tree_entity)
+                        .unwrap()
+                        .into_iter()
+                        .filter(|component_kind| {
+                            !component_scope_map.is_hidden(&user_key, tree_entity, component_kind)
+                        })
+                        .collect();
+                    // tag the spawn if the User predicted it
+                    if let Some((predicting_user_key, prediction_key)) =
+                        predicted_spawns.get(tree_entity)
+                    {
+                        if *predicting_user_key == user_key {
+                            connection
+                                .base
+                                .host_world_manager
+                                .set_spawn_prediction(tree_entity, prediction_key);
+                            predicted_spawns.remove(tree_entity);
                         }
                     }
+                    // add entity & components to the connections local scope
+                    connection.base.host_world_manager.init_entity(
+                        &mut connection.base.local_world_manager,
+                        tree_entity,
+                        component_kinds,
+                    );
+                    changes.entered.push((user_key, *tree_entity));
+                }
+            } else if currently_in_scope {
+                if goes_dormant {
+                    if currently_dormant {
+                        continue;
+                    }
+                    // keep the entity on the client, hold back updates
+                    connection
+                        .dormant_entities
+                        .insert(*tree_entity, now.clone());
+                    changes.dormancy.push((user_key, *tree_entity, true));
+                } else {
+                    // remove entity from the connections local scope
+                    connection
+                        .base
+                        .host_world_manager
+                        .despawn_entity(tree_entity);
+                    if connection.dormant_entities.remove(tree_entity).is_some() {
+                        continue;
+                    }
                 }
+                changes.exited.push((user_key, *tree_entity));
             }
         }
     }
 }
 
+/// Scope changes made while updating Entity scopes, acted upon once every
+/// scope is up to date
+struct ScopeChanges<E: Copy> {
+    entered: Vec<(UserKey, E)>,
+    exited: Vec<(UserKey, E)>,
+    // (User, Entity, went dormant)
+    dormancy: Vec<(UserKey, E, bool)>,
+    // Entities whose parent changed while they stayed in scope
+    reparented: Vec<(UserKey, E)>,
+}
+
+impl<E: Copy> Default for ScopeChanges<E> {
+    fn default() -> Self {
+        Self {
+            entered: Vec::new(),
+            exited: Vec::new(),
+            dormancy: Vec::new(),
+            reparented: Vec::new(),
+        }
+    }
+}
+
 impl<E: Copy + Eq + Hash + Send + Sync> EntityAndGlobalEntityConverter<E> for Server<E> {
     fn global_entity_to_entity(
         &self,
@@ -1,6 +1,6 @@
-use std::default::Default;
+use std::{default::Default, sync::Arc, time::Duration};
 
-use naia_shared::ConnectionConfig;
+use naia_shared::{Clock, ConnectionConfig, SystemClock};
 
 use crate::connection::ping_config::PingConfig;
 
@@ -14,6 +14,18 @@ pub struct ServerConfig {
     pub require_auth: bool,
     /// Configuration used to monitor the ping & jitter on the network
     pub ping: PingConfig,
+    /// Whether Ticks advance with time, or only when the application calls
+    /// `Server::advance_tick()`
+    pub tick_mode: TickMode,
+    /// The Clock all of the Server's timing reads from, such as a
+    /// `VirtualClock` for tests. Defaults to the system clock.
+    pub clock: Arc<dyn Clock>,
+    /// How long an Entity can stay dormant on a Client before it is despawned
+    /// there. `None` keeps dormant Entities until they re-enter scope.
+    pub dormancy_timeout: Option<Duration>,
+    /// The most dormant Entities each Client keeps. Past it, the Entities
+    /// which have been dormant the longest are despawned.
+    pub max_dormant_entities: Option<usize>,
 }
 
 impl Default for ServerConfig {
@@ -22,6 +34,20 @@ impl Default for ServerConfig {
             connection: ConnectionConfig::default(),
             require_auth: true,
             ping: PingConfig::default(),
+            tick_mode: TickMode::Timed,
+            clock: Arc::new(SystemClock),
+            dormancy_timeout: Some(Duration::from_secs(60)),
+            max_dormant_entities: Some(1024),
         }
     }
 }
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum TickMode {
+    /// A Tick passes every `tick_interval` of the Protocol, checked in
+    /// `Server::receive()`
+    Timed,
+    /// A Tick passes each time `Server::advance_tick()` is called, i.e. from
+    /// a fixed-step simulation loop. Clients sync to the resulting Tick rate.
+    Manual,
+}
@@ -0,0 +1,483 @@
+use std::{
+    collections::{HashMap, HashSet},
+    hash::Hash,
+};
+
+use crate::{NaiaServerError, Server, UserKey};
+
+type Cell = (i32, i32);
+
+/// Views overlapping more cells than this are not indexed by cell, but
+/// checked against every moved Entity instead
+const MAX_INDEXED_CELLS: i64 = 256;
+
+struct UserView<E: Copy + Eq + Hash> {
+    x: f32,
+    y: f32,
+    radius: f32,
+    visible: HashSet<E>,
+    dirty: bool,
+    // the cells the view was indexed in, when `visible` was last computed
+    indexed_cells: Option<(Cell, Cell)>,
+}
+
+/// Computes User scopes from 2D Entity positions and per-User view radii,
+/// using a uniform grid. A moved view only visits the Entities in the cells it
+/// overlaps, and a moved Entity only visits the views overlapping the cells it
+/// moved between.
+///
+/// Feed it positions as they change, then call `update_scopes()` once per
+/// tick in place of iterating over `Server::scope_checks()`. Entities & Users
+/// still need to share a Room for an Entity to come into scope.
+pub struct SpatialInterest<E: Copy + Eq + Hash> {
+    cell_size: f32,
+    hysteresis: f32,
+    cells: HashMap<Cell, HashSet<E>>,
+    entities: HashMap<E, (f32, f32)>,
+    // the cell each moved Entity was in when scopes were last computed
+    moved_entities: HashMap<E, Option<Cell>>,
+    users: HashMap<UserKey, UserView<E>>,
+    view_cells: HashMap<Cell, HashSet<UserKey>>,
+    wide_views: HashSet<UserKey>,
+    pending_changes: Vec<(UserKey, E, bool)>,
+}
+
+impl<E: Copy + Eq + Hash> SpatialInterest<E> {
+    /// Creates a new SpatialInterest, with grid cells of the given size. A
+    /// cell size close to the typical view radius works best.
+    /// Returns an Error if the cell size is not a positive number.
+    pub fn new(cell_size: f32) -> Result<Self, NaiaServerError> {
+        if !cell_size.is_finite() || cell_size <= 0.0 {
+            return Err(NaiaServerError::InvalidCellSize);
+        }
+        Ok(Self {
+            cell_size,
+            hysteresis: 0.0,
+            cells: HashMap::new(),
+            entities: HashMap::new(),
+            moved_entities: HashMap::new(),
+            users: HashMap::new(),
+            view_cells: HashMap::new(),
+            wide_views: HashSet::new(),
+            pending_changes: Vec::new(),
+        })
+    }
+
+    /// Sets a margin beyond each view radius in which an Entity already in
+    /// scope stays in scope, so that Entities moving along the edge of a
+    /// User's view do not flicker in and out of scope
+    pub fn set_hysteresis(&mut self, margin: f32) {
+        self.hysteresis = margin.max(0.0);
+        for view in self.users.values_mut() {
+            view.dirty = true;
+        }
+    }
+
+    // Entities
+
+    /// Inserts or moves an Entity
+    pub fn set_entity_position(&mut self, entity: &E, x: f32, y: f32) {
+        let new_cell = self.cell_of(x, y);
+        let old_cell = self
+            .entities
+            .insert(*entity, (x, y))
+            .map(|(old_x, old_y)| self.cell_of(old_x, old_y));
+        self.moved_entities.entry(*entity).or_insert(old_cell);
+        if old_cell == Some(new_cell) {
+            return;
+        }
+        if let Some(old_cell) = old_cell {
+            self.remove_from_cell(entity, &old_cell);
+        }
+        self.cells.entry(new_cell).or_default().insert(*entity);
+    }
+
+    /// Removes an Entity, which takes it out of the scope of every User that
+    /// could see it
+    pub fn remove_entity(&mut self, entity: &E) {
+        let Some((x, y)) = self.entities.remove(entity) else {
+            return;
+        };
+        let cell = self.cell_of(x, y);
+        self.remove_from_cell(entity, &cell);
+        let old_cell = self.moved_entities.remove(entity).flatten();
+
+        for user_key in self.views_overlapping(old_cell.unwrap_or(cell), cell) {
+            let view = self.users.get_mut(&user_key).unwrap();
+            if view.visible.remove(entity) {
+                self.pending_changes.push((user_key, *entity, false));
+            }
+        }
+    }
+
+    // Users
+
+    /// Inserts or moves the view of a User, with the given center and radius
+    pub fn set_user_view(&mut self, user_key: &UserKey, x: f32, y: f32, radius: f32) {
+        let view = self.users.entry(*user_key).or_insert_with(|| UserView {
+            x,
+            y,
+            radius,
+            visible: HashSet::new(),
+            dirty: true,
+            indexed_cells: None,
+        });
+        view.x = x;
+        view.y = y;
+        view.radius = radius;
+        view.dirty = true;
+    }
+
+    /// Removes a User, i.e. after it has disconnected
+    pub fn remove_user(&mut self, user_key: &UserKey) {
+        if let Some(view) = self.users.remove(user_key) {
+            self.unindex_view(user_key, view.indexed_cells);
+        }
+        self.pending_changes
+            .retain(|(pending_user_key, _, _)| pending_user_key != user_key);
+    }
+
+    // Scopes
+
+    /// Includes Entities that came into view of a User in their scope, and
+    /// excludes Entities that left it, since the last call
+    pub fn update_scopes(&mut self, server: &mut Server<E>)
+    where
+        E: Send + Sync,
+    {
+        for (user_key, entity, is_contained) in self.scope_changes() {
+            if !server.user_exists(&user_key) {
+                continue;
+            }
+            let mut user_scope = server.user_scope(&user_key);
+            if is_contained {
+                user_scope.include(&entity);
+            } else {
+                user_scope.exclude(&entity);
+            }
+        }
+    }
+
+    /// Returns every (User, Entity, is_contained) scope change since the last
+    /// call
+    pub fn scope_changes(&mut self) -> Vec<(UserKey, E, bool)> {
+        let mut changes = std::mem::take(&mut self.pending_changes);
+        let hysteresis = self.hysteresis;
+
+        // only the views a moved Entity left or entered can see it differently,
+        // moved views are recomputed below
+        for (entity, old_cell) in std::mem::take(&mut self.moved_entities) {
+            let (x, y) = self.entities[&entity];
+            let new_cell = self.cell_of(x, y);
+            for user_key in self.views_overlapping(old_cell.unwrap_or(new_cell), new_cell) {
+                let view = self.users.get_mut(&user_key).unwrap();
+                if view.dirty {
+                    continue;
+                }
+                let was_visible = view.visible.contains(&entity);
+                let is_visible = in_view(view, hysteresis, x, y, was_visible);
+                if is_visible == was_visible {
+                    continue;
+                }
+                if is_visible {
+                    view.visible.insert(entity);
+                } else {
+                    view.visible.remove(&entity);
+                }
+                changes.push((user_key, entity, is_visible));
+            }
+        }
+
+        let dirty_users: Vec<UserKey> = self
+            .users
+            .iter()
+            .filter(|(_, view)| view.dirty)
+            .map(|(user_key, _)| *user_key)
+            .collect();
+        for user_key in dirty_users {
+            let view = &self.users[&user_key];
+            let (min, max) = self.cells_in_reach(view);
+
+            let mut visible = HashSet::new();
+            let mut visit = |entities: &HashSet<E>| {
+                for entity in entities {
+                    let (x, y) = self.entities[entity];
+                    let was_visible = view.visible.contains(entity);
+                    if in_view(view, hysteresis, x, y, was_visible) {
+                        visible.insert(*entity);
+                    }
+                }
+            };
+            if cell_count(min, max) > self.cells.len() as i64 {
+                // fewer occupied cells than cells in reach, visit those instead
+                for (cell, entities) in self.cells.iter() {
+                    if cell_in(cell, min, max) {
+                        visit(entities);
+                    }
+                }
+            } else {
+                for cell_x in min.0..=max.0 {
+                    for cell_y in min.1..=max.1 {
+                        if let Some(entities) = self.cells.get(&(cell_x, cell_y)) {
+                            visit(entities);
+                        }
+                    }
+                }
+            }
+
+            for entity in view.visible.difference(&visible) {
+                changes.push((user_key, *entity, false));
+            }
+            for entity in visible.difference(&view.visible) {
+                changes.push((user_key, *entity, true));
+            }
+
+            let old_cells = view.indexed_cells;
+            self.unindex_view(&user_key, old_cells);
+            self.index_view(&user_key, (min, max));
+            let view = self.users.get_mut(&user_key).unwrap();
+            view.visible = visible;
+            view.dirty = false;
+            view.indexed_cells = Some((min, max));
+        }
+
+        changes
+    }
+
+    /// Returns whether the Entity is currently in view of the User, as of the
+    /// last computed scope changes
+    pub fn is_in_view(&self, user_key: &UserKey, entity: &E) -> bool {
+        self.users
+            .get(user_key)
+            .is_some_and(|view| view.visible.contains(entity))
+    }
+
+    fn cell_of(&self, x: f32, y: f32) -> Cell {
+        cell_of(self.cell_size, x, y)
+    }
+
+    /// The cells overlapped by a view's reach, including the hysteresis margin
+    fn cells_in_reach(&self, view: &UserView<E>) -> (Cell, Cell) {
+        let reach = view.radius + self.hysteresis;
+        (
+            self.cell_of(view.x - reach, view.y - reach),
+            self.cell_of(view.x + reach, view.y + reach),
+        )
+    }
+
+    /// The Users whose indexed views overlap either cell
+    fn views_overlapping(&self, cell_a: Cell, cell_b: Cell) -> HashSet<UserKey> {
+        let mut user_keys = self.wide_views.clone();
+        for cell in [cell_a, cell_b] {
+            if let Some(cell_user_keys) = self.view_cells.get(&cell) {
+                user_keys.extend(cell_user_keys);
+            }
+        }
+        user_keys
+    }
+
+    fn index_view(&mut self, user_key: &UserKey, (min, max): (Cell, Cell)) {
+        if cell_count(min, max) > MAX_INDEXED_CELLS {
+            self.wide_views.insert(*user_key);
+            return;
+        }
+        for cell_x in min.0..=max.0 {
+            for cell_y in min.1..=max.1 {
+                self.view_cells
+                    .entry((cell_x, cell_y))
+                    .or_default()
+                    .insert(*user_key);
+            }
+        }
+    }
+
+    fn unindex_view(&mut self, user_key: &UserKey, indexed_cells: Option<(Cell, Cell)>) {
+        let Some((min, max)) = indexed_cells else {
+            return;
+        };
+        if self.wide_views.remove(user_key) {
+            return;
+        }
+        for cell_x in min.0..=max.0 {
+            for cell_y in min.1..=max.1 {
+                if let Some(user_keys) = self.view_cells.get_mut(&(cell_x, cell_y)) {
+                    user_keys.remove(user_key);
+                    if user_keys.is_empty() {
+                        self.view_cells.remove(&(cell_x, cell_y));
+                    }
+                }
+            }
+        }
+    }
+
+    fn remove_from_cell(&mut self, entity: &E, cell: &Cell) {
+        if let Some(entities) = self.cells.get_mut(cell) {
+            entities.remove(entity);
+            if entities.is_empty() {
+                self.cells.remove(cell);
+            }
+        }
+    }
+}
+
+fn cell_of(cell_size: f32, x: f32, y: f32) -> Cell {
+    (
+        (x / cell_size).floor() as i32,
+        (y / cell_size).floor() as i32,
+    )
+}
+
+fn cell_count(min: Cell, max: Cell) -> i64 {
+    (max.0 as i64 - min.0 as i64 + 1).saturating_mul(max.1 as i64 - min.1 as i64 + 1)
+}
+
+fn cell_in(cell: &Cell, min: Cell, max: Cell) -> bool {
+    min.0 <= cell.0 && cell.0 <= max.0 && min.1 <= cell.1 && cell.1 <= max.1
+}
+
+fn in_view<E: Copy + Eq + Hash>(
+    view: &UserView<E>,
+    hysteresis: f32,
+    x: f32,
+    y: f32,
+    was_visible: bool,
+) -> bool {
+    let radius = if was_visible {
+        view.radius + hysteresis
+    } else {
+        view.radius
+    };
+    let dx = x - view.x;
+    let dy = y - view.y;
+    dx * dx + dy * dy <= radius * radius
+}
+
+#[cfg(test)]
+mod tests {
+    use naia_shared::BigMapKey;
+
+    use super::SpatialInterest;
+    use crate::UserKey;
+
+    fn sorted(mut changes: Vec<(UserKey, u32, bool)>) -> Vec<(u64, u32, bool)> {
+        let mut changes: Vec<(u64, u32, bool)> = changes
+            .drain(..)
+            .map(|(user_key, entity, is_contained)| (user_key.to_u64(), entity, is_contained))
+            .collect();
+        changes.sort();
+        changes
+    }
+
+    #[test]
+    fn entities_enter_and_leave_view() {
+        let user = UserKey::from_u64(0);
+        let mut interest = SpatialInterest::new(10.0).unwrap();
+        interest.set_user_view(&user, 0.0, 0.0, 15.0);
+        interest.set_entity_position(&1, 5.0, 5.0);
+        interest.set_entity_position(&2, 100.0, 0.0);
+
+        assert_eq!(sorted(interest.scope_changes()), vec![(0, 1, true)]);
+        assert!(interest.scope_changes().is_empty());
+
+        interest.set_entity_position(&1, 50.0, 0.0);
+        interest.set_entity_position(&2, -3.0, 0.0);
+        assert_eq!(
+            sorted(interest.scope_changes()),
+            vec![(0, 1, false), (0, 2, true)]
+        );
+
+        interest.set_user_view(&user, 50.0, 0.0, 15.0);
+        assert_eq!(
+            sorted(interest.scope_changes()),
+            vec![(0, 1, true), (0, 2, false)]
+        );
+
+        interest.remove_entity(&1);
+        assert_eq!(sorted(interest.scope_changes()), vec![(0, 1, false)]);
+    }
+
+    #[test]
+    fn hysteresis_keeps_edge_entities_in_scope() {
+        let user = UserKey::from_u64(0);
+        let mut interest = SpatialInterest::new(10.0).unwrap();
+        interest.set_hysteresis(5.0);
+        interest.set_user_view(&user, 0.0, 0.0, 10.0);
+
+        // outside the radius, not yet in scope
+        interest.set_entity_position(&1, 12.0, 0.0);
+        assert!(interest.scope_changes().is_empty());
+
+        interest.set_entity_position(&1, 9.0, 0.0);
+        assert_eq!(sorted(interest.scope_changes()), vec![(0, 1, true)]);
+
+        // inside the margin, stays in scope
+        interest.set_entity_position(&1, 12.0, 0.0);
+        interest.set_user_view(&user, 1.0, 0.0, 10.0);
+        assert!(interest.scope_changes().is_empty());
+
+        interest.set_entity_position(&1, 17.0, 0.0);
+        assert_eq!(sorted(interest.scope_changes()), vec![(0, 1, false)]);
+    }
+
+    #[test]
+    fn moved_entities_only_visit_overlapping_views() {
+        let near = UserKey::from_u64(0);
+        let far = UserKey::from_u64(1);
+        let mut interest = SpatialInterest::new(10.0).unwrap();
+        interest.set_user_view(&near, 0.0, 0.0, 15.0);
+        interest.set_user_view(&far, 1000.0, 0.0, 15.0);
+        interest.set_entity_position(&1, 5.0, 0.0);
+        assert_eq!(sorted(interest.scope_changes()), vec![(0, 1, true)]);
+
+        let cell = interest.cell_of(5.0, 0.0);
+        let user_keys = interest.views_overlapping(cell, cell);
+        assert!(user_keys.contains(&near));
+        assert!(!user_keys.contains(&far));
+
+        // moving between views leaves one & enters the other
+        interest.set_entity_position(&1, 995.0, 0.0);
+        assert_eq!(
+            sorted(interest.scope_changes()),
+            vec![(0, 1, false), (1, 1, true)]
+        );
+
+        // a moved view is re-indexed
+        interest.set_user_view(&near, 500.0, 0.0, 15.0);
+        interest.scope_changes();
+        let user_keys = interest.views_overlapping(cell, cell);
+        assert!(!user_keys.contains(&near));
+        interest.set_entity_position(&1, 505.0, 0.0);
+        assert_eq!(
+            sorted(interest.scope_changes()),
+            vec![(0, 1, true), (1, 1, false)]
+        );
+
+        interest.remove_user(&near);
+        assert!(interest
+            .view_cells
+            .values()
+            .all(|user_keys| !user_keys.contains(&near)));
+    }
+
+    #[test]
+    fn unbounded_views_are_not_indexed_by_cell() {
+        let user = UserKey::from_u64(0);
+        let mut interest = SpatialInterest::new(1.0).unwrap();
+        interest.set_user_view(&user, 0.0, 0.0, f32::INFINITY);
+        interest.set_entity_position(&1, -1.0e9, 0.0);
+        interest.set_entity_position(&2, 1.0e9, 1.0e9);
+        assert_eq!(
+            sorted(interest.scope_changes()),
+            vec![(0, 1, true), (0, 2, true)]
+        );
+        assert!(interest.wide_views.contains(&user));
+        assert!(interest.view_cells.is_empty());
+
+        interest.remove_entity(&1);
+        assert_eq!(sorted(interest.scope_changes()), vec![(0, 1, false)]);
+
+        interest.set_user_view(&user, 0.0, 0.0, 1.0);
+        assert_eq!(sorted(interest.scope_changes()), vec![(0, 2, false)]);
+        assert!(interest.wide_views.is_empty());
+    }
+}
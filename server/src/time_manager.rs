@@ -1,7 +1,7 @@
-use std::time::Duration;
+use std::{sync::Arc, time::Duration};
 
 use naia_shared::{
-    BitReader, BitWriter, GameDuration, GameInstant, Instant, PacketType, PingIndex, Serde,
+    BitReader, BitWriter, Clock, GameDuration, GameInstant, Instant, PacketType, PingIndex, Serde,
     SerdeErr, StandardHeader, Tick, UnsignedVariableInteger,
 };
 
@@ -11,19 +11,22 @@ pub struct TimeManager {
     current_tick: Tick,
     last_tick_game_instant: GameInstant,
     last_tick_instant: Instant,
+    tick_interval: Duration,
     tick_interval_millis: f32,
     tick_duration_avg: f32,
     tick_duration_avg_min: f32,
     tick_duration_avg_max: f32,
     tick_speedup_potential: f32,
+    manual_tick: bool,
+    clock: Arc<dyn Clock>,
 }
 
 impl TimeManager {
     /// Create a new TickManager with a given tick interval duration
-    pub fn new(tick_interval: Duration) -> Self {
-        let start_instant = Instant::now();
+    pub fn new(tick_interval: Duration, manual_tick: bool, clock: &Arc<dyn Clock>) -> Self {
+        let start_instant = clock.now();
         let last_tick_instant = start_instant.clone();
-        let last_tick_game_instant = GameInstant::new(&start_instant);
+        let last_tick_game_instant = GameInstant::new(&start_instant, &start_instant);
         let tick_interval_millis = tick_interval.as_secs_f32() * 1000.0;
         let tick_duration_avg = tick_interval_millis;
 
@@ -32,11 +35,14 @@ impl TimeManager {
             current_tick: 0,
             last_tick_game_instant,
             last_tick_instant,
+            tick_interval,
             tick_interval_millis,
             tick_duration_avg,
             tick_duration_avg_min: tick_duration_avg,
             tick_duration_avg_max: tick_duration_avg,
             tick_speedup_potential: 0.0,
+            manual_tick,
+            clock: clock.clone(),
         }
     }
 
@@ -48,18 +54,48 @@ impl TimeManager {
 
     /// Whether or not we should emit a tick event
     pub fn recv_server_tick(&mut self) -> bool {
-        let time_since_tick_ms = self.last_tick_instant.elapsed().as_secs_f32() * 1000.0;
+        if self.manual_tick {
+            return false;
+        }
+
+        let time_since_tick_ms = self.clock.elapsed(&self.last_tick_instant).as_secs_f32() * 1000.0;
 
         if time_since_tick_ms >= self.tick_interval_millis {
-            self.record_tick_duration(time_since_tick_ms);
-            self.last_tick_instant = Instant::now();
-            self.last_tick_game_instant = self.game_time_now();
-            self.current_tick = self.current_tick.wrapping_add(1);
+            self.advance_tick();
             return true;
         }
         return false;
     }
 
+    /// Moves on to the next tick, recording how long the last one took
+    pub fn advance_tick(&mut self) -> Tick {
+        let time_since_tick_ms = self.clock.elapsed(&self.last_tick_instant).as_secs_f32() * 1000.0;
+        self.record_tick_duration(time_since_tick_ms);
+        self.last_tick_instant = self.clock.now();
+        self.last_tick_game_instant = self.game_time_now();
+        self.current_tick = self.current_tick.wrapping_add(1);
+        self.current_tick
+    }
+
+    pub fn is_manual_tick(&self) -> bool {
+        self.manual_tick
+    }
+
+    pub fn tick_interval(&self) -> Duration {
+        self.tick_interval
+    }
+
+    /// Changes the duration between ticks. Tick duration stats start over
+    /// from the new interval, rather than averaging towards it.
+    pub fn set_tick_interval(&mut self, tick_interval: Duration) {
+        self.tick_interval = tick_interval;
+        self.tick_interval_millis = tick_interval.as_secs_f32() * 1000.0;
+        self.tick_duration_avg = self.tick_interval_millis;
+        self.tick_duration_avg_min = self.tick_interval_millis;
+        self.tick_duration_avg_max = self.tick_interval_millis;
+        self.tick_speedup_potential = 0.0;
+    }
+
     /// Gets the current tick of the Server
     pub fn current_tick(&self) -> Tick {
         self.current_tick
@@ -74,7 +110,7 @@ impl TimeManager {
     }
 
     pub fn game_time_now(&self) -> GameInstant {
-        GameInstant::new(&self.start_instant)
+        GameInstant::new(&self.start_instant, &self.clock.now())
     }
 
     pub fn game_time_since(&self, previous_instant: &GameInstant) -> GameDuration {
@@ -145,3 +181,53 @@ impl TimeManager {
         Ok(writer)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::{sync::Arc, time::Duration};
+
+    use naia_shared::{Clock, VirtualClock};
+
+    use super::TimeManager;
+
+    #[test]
+    fn manual_ticks_follow_the_caller() {
+        let clock = VirtualClock::new();
+        let time_clock: Arc<dyn Clock> = Arc::new(clock.clone());
+
+        let mut time_manager = TimeManager::new(Duration::from_millis(50), true, &time_clock);
+        clock.advance(Duration::from_millis(200));
+        assert!(!time_manager.recv_server_tick());
+        assert_eq!(time_manager.current_tick(), 0);
+
+        // ticking twice as fast as the tick interval
+        for _ in 0..100 {
+            time_manager.advance_tick();
+            clock.advance(Duration::from_millis(25));
+        }
+        assert_eq!(time_manager.current_tick(), 100);
+        assert_eq!(
+            time_manager.average_tick_duration(),
+            Duration::from_millis(25)
+        );
+    }
+
+    #[test]
+    fn tick_interval_changes_at_runtime() {
+        let clock = VirtualClock::new();
+        let time_clock: Arc<dyn Clock> = Arc::new(clock.clone());
+
+        let mut time_manager = TimeManager::new(Duration::from_millis(100), false, &time_clock);
+        time_manager.set_tick_interval(Duration::from_millis(16));
+        assert_eq!(
+            time_manager.average_tick_duration(),
+            Duration::from_millis(16)
+        );
+
+        clock.advance(Duration::from_millis(10));
+        assert!(!time_manager.recv_server_tick());
+        clock.advance(Duration::from_millis(6));
+        assert!(time_manager.recv_server_tick());
+        assert_eq!(time_manager.current_tick(), 1);
+    }
+}
@@ -1,6 +1,6 @@
-use std::net::SocketAddr;
+use std::{net::SocketAddr, sync::Arc};
 
-use naia_shared::{link_condition_logic, LinkConditionerConfig, TimeQueue};
+use naia_shared::{link_condition_logic, Clock, LinkConditionerConfig, TimeQueue};
 
 use super::{PacketReceiver, RecvError};
 
@@ -11,6 +11,7 @@ pub struct ConditionedPacketReceiver {
     link_conditioner_config: LinkConditionerConfig,
     time_queue: TimeQueue<(SocketAddr, Box<[u8]>)>,
     last_payload: Option<Box<[u8]>>,
+    clock: Arc<dyn Clock>,
 }
 
 impl ConditionedPacketReceiver {
@@ -18,18 +19,21 @@ impl ConditionedPacketReceiver {
     pub fn new(
         inner_receiver: Box<dyn PacketReceiver>,
         link_conditioner_config: &LinkConditionerConfig,
+        clock: &Arc<dyn Clock>,
     ) -> Self {
         ConditionedPacketReceiver {
             inner_receiver,
             link_conditioner_config: link_conditioner_config.clone(),
             time_queue: TimeQueue::new(),
             last_payload: None,
+            clock: clock.clone(),
         }
     }
 }
 
 impl PacketReceiver for ConditionedPacketReceiver {
     fn receive(&mut self) -> Result<Option<(SocketAddr, &[u8])>, RecvError> {
+        let now = self.clock.now();
         loop {
             match self.inner_receiver.receive() {
                 Ok(option) => match option {
@@ -40,6 +44,7 @@ impl PacketReceiver for ConditionedPacketReceiver {
                         link_condition_logic::process_packet(
                             &self.link_conditioner_config,
                             &mut self.time_queue,
+                            &now,
                             (addr, buffer.into()),
                         );
                     }
@@ -50,8 +55,8 @@ impl PacketReceiver for ConditionedPacketReceiver {
             }
         }
 
-        if self.time_queue.has_item() {
-            let (address, payload) = self.time_queue.pop_item().unwrap();
+        if self.time_queue.has_item(&now) {
+            let (address, payload) = self.time_queue.pop_item(&now).unwrap();
             self.last_payload = Some(payload);
             return Ok(Some((address, self.last_payload.as_ref().unwrap())));
         } else {
@@ -14,14 +14,19 @@ pub use inner::{PacketReceiver, PacketSender, RecvError, SendError, Socket};
 
 mod inner {
 
-    use std::net::SocketAddr;
+    use std::{net::SocketAddr, sync::Arc};
+
+    use naia_shared::Clock;
 
     pub struct SendError;
 
     pub struct RecvError;
 
     pub trait Socket {
-        fn listen(self: Box<Self>) -> (Box<dyn PacketSender>, Box<dyn PacketReceiver>);
+        fn listen(
+            self: Box<Self>,
+            clock: &Arc<dyn Clock>,
+        ) -> (Box<dyn PacketSender>, Box<dyn PacketReceiver>);
     }
 
     pub trait PacketSender: Send + Sync {
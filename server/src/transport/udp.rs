@@ -4,7 +4,7 @@ use std::{
     sync::{Arc, Mutex},
 };
 
-use naia_shared::LinkConditionerConfig;
+use naia_shared::{Clock, LinkConditionerConfig};
 
 use super::{
     conditioner::ConditionedPacketReceiver, PacketReceiver as TransportReceiver,
@@ -38,13 +38,20 @@ impl Into<Box<dyn TransportSocket>> for Socket {
 }
 
 impl TransportSocket for Socket {
-    fn listen(self: Box<Self>) -> (Box<dyn TransportSender>, Box<dyn TransportReceiver>) {
+    fn listen(
+        self: Box<Self>,
+        clock: &Arc<dyn Clock>,
+    ) -> (Box<dyn TransportSender>, Box<dyn TransportReceiver>) {
         let sender = Box::new(PacketSender::new(self.socket.clone()));
 
         let receiver: Box<dyn TransportReceiver> = {
             let inner_receiver = Box::new(PacketReceiver::new(self.socket.clone()));
             if let Some(config) = &self.config {
-                Box::new(ConditionedPacketReceiver::new(inner_receiver, config))
+                Box::new(ConditionedPacketReceiver::new(
+                    inner_receiver,
+                    config,
+                    clock,
+                ))
             } else {
                 inner_receiver
             }
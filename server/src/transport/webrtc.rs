@@ -1,6 +1,6 @@
-use std::net::SocketAddr;
+use std::{net::SocketAddr, sync::Arc};
 
-use naia_shared::SocketConfig;
+use naia_shared::{Clock, SocketConfig};
 
 use naia_server_socket::{PacketReceiver, PacketSender, Socket as ServerSocket};
 
@@ -46,7 +46,10 @@ impl Into<Box<dyn TransportSocket>> for Socket {
 }
 
 impl TransportSocket for Socket {
-    fn listen(self: Box<Self>) -> (Box<dyn TransportSender>, Box<dyn TransportReceiver>) {
+    fn listen(
+        self: Box<Self>,
+        _clock: &Arc<dyn Clock>,
+    ) -> (Box<dyn TransportSender>, Box<dyn TransportReceiver>) {
         let (inner_sender, inner_receiver) = ServerSocket::listen(&self.server_addrs, &self.config);
         return (Box::new(inner_sender), Box::new(inner_receiver));
     }
@@ -1,5 +1,7 @@
 use std::hash::Hash;
 
+use naia_shared::{ComponentKind, Replicate};
+
 use super::{server::Server, user::UserKey};
 
 pub struct UserScopeMut<'s, E: Copy + Eq + Hash + Send + Sync> {
@@ -26,6 +28,25 @@ impl<'s, E: Copy + Eq + Hash + Send + Sync> UserScopeMut<'s, E> {
         self
     }
 
+    /// Reveals a Component of an Entity to the User again, after it was
+    /// excluded. Components are visible to every User the Entity is in scope
+    /// for by default.
+    pub fn include_component<R: Replicate>(&mut self, entity: &E) -> &mut Self {
+        self.server
+            .user_scope_set_component(&self.key, entity, &ComponentKind::of::<R>(), true);
+
+        self
+    }
+
+    /// Hides a Component of an Entity from the User, while the Entity itself
+    /// may stay in scope
+    pub fn exclude_component<R: Replicate>(&mut self, entity: &E) -> &mut Self {
+        self.server
+            .user_scope_set_component(&self.key, entity, &ComponentKind::of::<R>(), false);
+
+        self
+    }
+
     /// Removes all Entities from the User's scope
     pub fn clear(&mut self) -> &mut Self {
         self.server.user_scope_remove_user(&self.key);
@@ -0,0 +1,131 @@
+use std::{
+    collections::{HashMap, HashSet},
+    hash::Hash,
+};
+
+use naia_shared::ComponentKind;
+
+use crate::user::UserKey;
+
+/// Decides whether a Component of an Entity is visible to a User
+pub type ComponentVisibilityRule<E> = Box<dyn Fn(&UserKey, &E) -> bool + Send + Sync>;
+
+/// Tracks which Components of an Entity are hidden from which Users, while
+/// the Entity itself may be in scope for them. A Component is hidden if it was
+/// excluded for the User & Entity, or if a rule for its kind says so.
+pub struct ComponentScopeMap<E: Copy + Eq + Hash> {
+    entities_of_user: HashMap<UserKey, HashSet<E>>,
+    users_of_entity: HashMap<E, HashSet<UserKey>>,
+    main_map: HashMap<(UserKey, E), HashSet<ComponentKind>>,
+    rules: HashMap<ComponentKind, ComponentVisibilityRule<E>>,
+}
+
+impl<E: Copy + Eq + Hash> ComponentScopeMap<E> {
+    pub fn new() -> Self {
+        Self {
+            main_map: HashMap::new(),
+            entities_of_user: HashMap::new(),
+            users_of_entity: HashMap::new(),
+            rules: HashMap::new(),
+        }
+    }
+
+    pub fn set_rule(&mut self, component_kind: ComponentKind, rule: ComponentVisibilityRule<E>) {
+        self.rules.insert(component_kind, rule);
+    }
+
+    pub fn remove_rule(&mut self, component_kind: &ComponentKind) {
+        self.rules.remove(component_kind);
+    }
+
+    pub fn is_hidden(
+        &self,
+        user_key: &UserKey,
+        entity: &E,
+        component_kind: &ComponentKind,
+    ) -> bool {
+        let key = (*user_key, *entity);
+
+        if let Some(hidden_kinds) = self.main_map.get(&key) {
+            if hidden_kinds.contains(component_kind) {
+                return true;
+            }
+        }
+        if let Some(rule) = self.rules.get(component_kind) {
+            return !rule(user_key, entity);
+        }
+        false
+    }
+
+    /// Hides or reveals a Component of an Entity for a User, returning whether
+    /// its visibility changed
+    pub fn set_hidden(
+        &mut self,
+        user_key: UserKey,
+        entity: E,
+        component_kind: ComponentKind,
+        hidden: bool,
+    ) -> bool {
+        let key = (user_key, entity);
+
+        if !hidden {
+            let Some(hidden_kinds) = self.main_map.get_mut(&key) else {
+                return false;
+            };
+            if !hidden_kinds.remove(&component_kind) {
+                return false;
+            }
+            if hidden_kinds.is_empty() {
+                self.remove(&user_key, &entity);
+            }
+            return true;
+        }
+
+        self.entities_of_user
+            .entry(user_key)
+            .or_default()
+            .insert(entity);
+        self.users_of_entity
+            .entry(entity)
+            .or_default()
+            .insert(user_key);
+
+        self.main_map.entry(key).or_default().insert(component_kind)
+    }
+
+    pub fn remove_user(&mut self, user_key: &UserKey) {
+        if let Some(entities) = self.entities_of_user.get(user_key) {
+            for entity in entities {
+                if let Some(users) = self.users_of_entity.get_mut(entity) {
+                    users.remove(user_key);
+                    self.main_map.remove(&(*user_key, *entity));
+                }
+            }
+        }
+
+        self.entities_of_user.remove(user_key);
+    }
+
+    pub fn remove_entity(&mut self, entity: &E) {
+        if let Some(users) = self.users_of_entity.get(entity) {
+            for user in users {
+                if let Some(entities) = self.entities_of_user.get_mut(user) {
+                    entities.remove(entity);
+                    self.main_map.remove(&(*user, *entity));
+                }
+            }
+        }
+
+        self.users_of_entity.remove(entity);
+    }
+
+    fn remove(&mut self, user_key: &UserKey, entity: &E) {
+        self.main_map.remove(&(*user_key, *entity));
+        if let Some(entities) = self.entities_of_user.get_mut(user_key) {
+            entities.remove(entity);
+        }
+        if let Some(users) = self.users_of_entity.get_mut(entity) {
+            users.remove(user_key);
+        }
+    }
+}
@@ -1,8 +1,8 @@
 use std::hash::Hash;
 
-use naia_shared::{ReplicaMutWrapper, Replicate, WorldMutType};
+use naia_shared::{PredictionKey, ReplicaMutWrapper, Replicate, WorldMutType};
 
-use crate::{room::RoomKey, server::Server};
+use crate::{room::RoomKey, server::Server, NaiaServerError, UserKey};
 
 // EntityMut
 pub struct EntityMut<'s, E: Copy + Eq + Hash + Send + Sync, W: WorldMutType<E>> {
@@ -58,14 +58,69 @@ impl<'s, E: Copy + Eq + Hash + Send + Sync, W: WorldMutType<E>> EntityMut<'s, E,
             .remove_component::<R, W>(&mut self.world, &self.entity)
     }
 
-    // Rooms
+    // Hierarchy
+
+    pub fn set_parent(&mut self, parent: &E) -> Result<&mut Self, NaiaServerError> {
+        self.server.entity_set_parent(&self.entity, parent)?;
+
+        Ok(self)
+    }
+
+    pub fn remove_parent(&mut self) -> &mut Self {
+        self.server.entity_remove_parent(&self.entity);
+
+        self
+    }
+
+    // Predicted Spawns
+
+    pub fn confirm_predicted_spawn(
+        &mut self,
+        user_key: &UserKey,
+        prediction_key: PredictionKey,
+    ) -> Result<(), NaiaServerError> {
+        self.server
+            .confirm_predicted_spawn(&self.entity, user_key, prediction_key)
+    }
+
+    // Dormancy
+
+    pub fn enable_dormancy(&mut self) -> &mut Self {
+        self.server.enable_dormancy(&self.entity);
+
+        self
+    }
 
-    pub fn enter_room(&mut self, room_key: &RoomKey) -> &mut Self {
-        self.server.room_add_entity(room_key, &self.entity);
+    pub fn disable_dormancy(&mut self) -> &mut Self {
+        self.server.disable_dormancy(&self.entity);
 
         self
     }
 
+    // Authority Delegation
+
+    pub fn enable_delegation(&mut self) -> Result<&mut Self, NaiaServerError> {
+        self.server.enable_delegation(&self.entity)?;
+
+        Ok(self)
+    }
+
+    pub fn disable_delegation(&mut self) -> &mut Self {
+        self.server.disable_delegation(&self.entity);
+
+        self
+    }
+
+    // Rooms
+
+    /// Adds the Entity to a Room, or returns an Error if the Entity already
+    /// belongs to another Room
+    pub fn enter_room(&mut self, room_key: &RoomKey) -> Result<&mut Self, NaiaServerError> {
+        self.server.room_add_entity(room_key, &self.entity)?;
+
+        Ok(self)
+    }
+
     pub fn leave_room(&mut self, room_key: &RoomKey) -> &mut Self {
         self.server.room_remove_entity(room_key, &self.entity);
 
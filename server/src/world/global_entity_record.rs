@@ -2,12 +2,14 @@ use std::collections::HashSet;
 
 use naia_shared::{ComponentKind, GlobalEntity};
 
-use crate::EntityOwner;
+use crate::{EntityOwner, UserKey};
 
 pub struct GlobalEntityRecord {
     pub global_entity: GlobalEntity,
     pub component_kinds: HashSet<ComponentKind>,
     pub owner: EntityOwner,
+    pub delegated: bool,
+    pub authority: Option<UserKey>,
 }
 
 impl GlobalEntityRecord {
@@ -19,6 +21,8 @@ impl GlobalEntityRecord {
             global_entity,
             component_kinds: HashSet::new(),
             owner,
+            delegated: false,
+            authority: None,
         }
     }
 }
@@ -5,13 +5,13 @@ use std::{
 };
 
 use naia_shared::{
-    BigMap, BigMapKey, ComponentKind, EntityAndGlobalEntityConverter, EntityDoesNotExistError,
-    GlobalDiffHandler, GlobalEntity, GlobalWorldManagerType, MutChannelType, PropertyMutator,
-    Replicate,
+    BigMap, BigMapKey, ComponentKind, DuplicateComponentError, EntityAndGlobalEntityConverter,
+    EntityDoesNotExistError, GlobalDiffHandler, GlobalEntity, GlobalWorldManagerType,
+    MutChannelType, PropertyMutator, Replicate,
 };
 
 use super::global_entity_record::GlobalEntityRecord;
-use crate::{world::mut_channel::MutChannelData, EntityOwner, UserKey};
+use crate::{world::mut_channel::MutChannelData, EntityOwner, NaiaServerError, UserKey};
 
 pub struct GlobalWorldManager<E: Copy + Eq + Hash + Send + Sync> {
     diff_handler: Arc<RwLock<GlobalDiffHandler<E>>>,
@@ -90,26 +90,33 @@ impl<E: Copy + Eq + Hash + Send + Sync> GlobalWorldManager<E> {
     }
 
     // Insert Component
-    pub fn host_insert_component(&mut self, entity: &E, component: &mut dyn Replicate) {
+    pub fn host_insert_component(
+        &mut self,
+        entity: &E,
+        component: &mut dyn Replicate,
+    ) -> Result<(), DuplicateComponentError> {
         let component_kind = component.kind();
         let diff_mask_length: u8 = component.diff_mask_size();
 
         if !self.entity_records.contains_key(entity) {
             panic!("entity does not exist!");
         }
-        let component_kind_set = &mut self.entity_records.get_mut(entity).unwrap().component_kinds;
-        component_kind_set.insert(component_kind);
 
         let mut_sender = self
             .diff_handler
             .as_ref()
             .write()
             .expect("DiffHandler should be initialized")
-            .register_component(self, entity, &component_kind, diff_mask_length);
+            .register_component(self, entity, &component_kind, diff_mask_length)?;
+
+        let component_kind_set = &mut self.entity_records.get_mut(entity).unwrap().component_kinds;
+        component_kind_set.insert(component_kind);
 
         let prop_mutator = PropertyMutator::new(mut_sender);
 
         component.set_mutator(&prop_mutator);
+
+        Ok(())
     }
 
     // Remove Component
@@ -140,6 +147,68 @@ impl<E: Copy + Eq + Hash + Send + Sync> GlobalWorldManager<E> {
 
         record.owner = EntityOwner::Client(*user_key);
     }
+
+    // Delegation
+    pub fn entity_is_delegated(&self, entity: &E) -> bool {
+        if let Some(record) = self.entity_records.get(entity) {
+            return record.delegated;
+        }
+        return false;
+    }
+
+    pub fn entity_set_delegated(
+        &mut self,
+        entity: &E,
+        delegated: bool,
+    ) -> Result<(), NaiaServerError> {
+        let Some(record) = self.entity_records.get_mut(entity) else {
+            return Err(NaiaServerError::EntityNotFound);
+        };
+        if record.owner != EntityOwner::Server {
+            return Err(NaiaServerError::EntityNotServerOwned);
+        }
+
+        record.delegated = delegated;
+        if !delegated {
+            record.authority = None;
+        }
+        Ok(())
+    }
+
+    pub fn entity_authority(&self, entity: &E) -> Option<UserKey> {
+        if let Some(record) = self.entity_records.get(entity) {
+            return record.authority;
+        }
+        return None;
+    }
+
+    pub fn entity_set_authority(
+        &mut self,
+        entity: &E,
+        authority: Option<UserKey>,
+    ) -> Result<(), NaiaServerError> {
+        let Some(record) = self.entity_records.get_mut(entity) else {
+            return Err(NaiaServerError::EntityNotFound);
+        };
+        if authority.is_some() && !record.delegated {
+            return Err(NaiaServerError::EntityNotDelegated);
+        }
+
+        record.authority = authority;
+        Ok(())
+    }
+
+    pub fn user_authority_entities(&self, user_key: &UserKey) -> Vec<E> {
+        let mut output = Vec::new();
+
+        for (entity, record) in &self.entity_records {
+            if record.authority == Some(*user_key) {
+                output.push(*entity);
+            }
+        }
+
+        output
+    }
 }
 
 impl<E: Copy + Eq + Hash + Send + Sync> GlobalWorldManagerType<E> for GlobalWorldManager<E> {
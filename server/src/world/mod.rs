@@ -1,6 +1,9 @@
+pub mod component_scope_map;
 pub mod entity_mut;
 pub mod entity_owner;
 pub mod entity_scope_map;
 pub mod global_entity_record;
 pub mod global_world_manager;
 pub mod mut_channel;
+pub mod snapshot;
+pub mod validation;
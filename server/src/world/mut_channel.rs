@@ -34,4 +34,10 @@ impl MutChannelType for MutChannelData {
             receiver.mutate(diff);
         }
     }
+
+    fn send_elements(&self, diff: u8, elements: &[u32]) {
+        for (_, receiver) in self.receiver_map.iter() {
+            receiver.mutate_elements(diff, elements);
+        }
+    }
 }
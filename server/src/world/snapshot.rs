@@ -0,0 +1,58 @@
+use std::collections::HashMap;
+
+use naia_shared::{
+    EntityDoesNotExistError, GlobalEntity, LocalEntity, LocalEntityAndGlobalEntityConverter,
+    LocalEntityAndGlobalEntityConverterMut,
+};
+
+/// Maps Entities to their position within a world snapshot, so that Entity
+/// references held by Components survive being saved & restored
+pub struct SnapshotEntityConverter {
+    global_to_index: HashMap<GlobalEntity, u16>,
+    index_to_global: Vec<GlobalEntity>,
+}
+
+impl SnapshotEntityConverter {
+    pub fn new(global_entities: Vec<GlobalEntity>) -> Self {
+        let global_to_index = global_entities
+            .iter()
+            .enumerate()
+            .map(|(index, global_entity)| (*global_entity, index as u16))
+            .collect();
+        Self {
+            global_to_index,
+            index_to_global: global_entities,
+        }
+    }
+}
+
+impl LocalEntityAndGlobalEntityConverter for SnapshotEntityConverter {
+    fn global_entity_to_local_entity(
+        &self,
+        global_entity: &GlobalEntity,
+    ) -> Result<LocalEntity, EntityDoesNotExistError> {
+        self.global_to_index
+            .get(global_entity)
+            .map(|index| LocalEntity::new_host(*index))
+            .ok_or(EntityDoesNotExistError)
+    }
+
+    fn local_entity_to_global_entity(
+        &self,
+        local_entity: &LocalEntity,
+    ) -> Result<GlobalEntity, EntityDoesNotExistError> {
+        self.index_to_global
+            .get(local_entity.value() as usize)
+            .copied()
+            .ok_or(EntityDoesNotExistError)
+    }
+}
+
+impl LocalEntityAndGlobalEntityConverterMut for SnapshotEntityConverter {
+    fn get_or_reserve_host_entity(
+        &mut self,
+        global_entity: &GlobalEntity,
+    ) -> Result<LocalEntity, EntityDoesNotExistError> {
+        self.global_entity_to_local_entity(global_entity)
+    }
+}
@@ -0,0 +1,207 @@
+use std::{
+    collections::{HashMap, HashSet},
+    hash::Hash,
+    marker::PhantomData,
+};
+
+use naia_shared::{ComponentKind, RemoteStateValidator, RemoteStateVerdict, Replicate};
+
+use crate::UserKey;
+
+/// The verdict of a Component validator on state proposed by a Client for
+/// one of its Client-authoritative Entities
+pub enum Validation<C> {
+    /// Apply the proposed state
+    Accept,
+    /// Apply the given state instead of the proposed one, i.e. to clamp
+    /// values into an allowed range
+    Replace(C),
+    /// Discard the proposed state. A rejected insertion leaves the Entity
+    /// without the Component, a rejected update keeps its previous state.
+    Reject,
+    /// Discard the proposed state and despawn the Entity, on the Server and
+    /// on the proposing Client
+    Despawn,
+    /// Discard the proposed state and disconnect the offending User, which
+    /// also despawns all of their Entities
+    Kick,
+}
+
+impl<C> Validation<C> {
+    pub(crate) fn map<D, F: FnOnce(C) -> D>(self, func: F) -> Validation<D> {
+        match self {
+            Validation::Accept => Validation::Accept,
+            Validation::Replace(component) => Validation::Replace(func(component)),
+            Validation::Reject => Validation::Reject,
+            Validation::Despawn => Validation::Despawn,
+            Validation::Kick => Validation::Kick,
+        }
+    }
+}
+
+// ComponentValidator
+pub trait ComponentValidator<E>: Send + Sync {
+    fn validate(
+        &self,
+        user_key: &UserKey,
+        entity: &E,
+        previous: Option<&dyn Replicate>,
+        proposed: &dyn Replicate,
+    ) -> Validation<Box<dyn Replicate>>;
+}
+
+struct TypedComponentValidator<C: Replicate, F> {
+    validator: F,
+    phantom_c: PhantomData<C>,
+}
+
+impl<E, C, F> ComponentValidator<E> for TypedComponentValidator<C, F>
+where
+    C: Replicate,
+    F: Fn(&UserKey, &E, Option<&C>, &C) -> Validation<C> + Send + Sync,
+{
+    fn validate(
+        &self,
+        user_key: &UserKey,
+        entity: &E,
+        previous: Option<&dyn Replicate>,
+        proposed: &dyn Replicate,
+    ) -> Validation<Box<dyn Replicate>> {
+        // validators are keyed by their Component's kind, so this only
+        // guards against a misregistered one
+        let Some(proposed) = proposed.to_any().downcast_ref::<C>() else {
+            return Validation::Reject;
+        };
+        let previous = previous.and_then(|previous| previous.to_any().downcast_ref::<C>());
+
+        (self.validator)(user_key, entity, previous, proposed)
+            .map(|component| -> Box<dyn Replicate> { Box::new(component) })
+    }
+}
+
+// ComponentValidators
+pub struct ComponentValidators<E> {
+    validators: HashMap<ComponentKind, Box<dyn ComponentValidator<E>>>,
+}
+
+impl<E> ComponentValidators<E> {
+    pub fn new() -> Self {
+        Self {
+            validators: HashMap::new(),
+        }
+    }
+
+    pub fn insert<C, F>(&mut self, validator: F)
+    where
+        C: Replicate,
+        F: Fn(&UserKey, &E, Option<&C>, &C) -> Validation<C> + Send + Sync + 'static,
+    {
+        self.validators.insert(
+            ComponentKind::of::<C>(),
+            Box::new(TypedComponentValidator {
+                validator,
+                phantom_c: PhantomData,
+            }),
+        );
+    }
+
+    pub fn remove(&mut self, component_kind: &ComponentKind) {
+        self.validators.remove(component_kind);
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.validators.is_empty()
+    }
+
+    pub fn get(&self, component_kind: &ComponentKind) -> Option<&dyn ComponentValidator<E>> {
+        self.validators
+            .get(component_kind)
+            .map(|validator| validator.as_ref())
+    }
+}
+
+/// The verdicts on the state a User proposed which the Server must act on
+pub struct ValidationOutcome<E> {
+    /// Entities to despawn
+    pub despawned_entities: Vec<E>,
+    /// Whether to disconnect the User
+    pub kick: bool,
+}
+
+impl<E> Default for ValidationOutcome<E> {
+    fn default() -> Self {
+        Self {
+            despawned_entities: Vec::new(),
+            kick: false,
+        }
+    }
+}
+
+// UserStateValidator
+/// Runs the registered validators over the state received from one User for
+/// its Client-authoritative Entities, as it is applied to the World
+pub(crate) struct UserStateValidator<'v, E> {
+    user_key: UserKey,
+    validators: &'v ComponentValidators<E>,
+    /// Entities despawned by an earlier verdict, whose state is discarded
+    discarded_entities: &'v HashSet<E>,
+    /// Entities a validator despawned while applying this state
+    pub despawned_entities: Vec<E>,
+    /// Whether a validator kicked the User
+    pub kick: bool,
+}
+
+impl<'v, E: Copy + Eq + Hash> UserStateValidator<'v, E> {
+    pub fn new(
+        user_key: &UserKey,
+        validators: &'v ComponentValidators<E>,
+        discarded_entities: &'v HashSet<E>,
+    ) -> Self {
+        Self {
+            user_key: *user_key,
+            validators,
+            discarded_entities,
+            despawned_entities: Vec::new(),
+            kick: false,
+        }
+    }
+
+    fn discards(&self, entity: &E) -> bool {
+        self.kick
+            || self.discarded_entities.contains(entity)
+            || self.despawned_entities.contains(entity)
+    }
+}
+
+impl<'v, E: Copy + Eq + Hash> RemoteStateValidator<E> for UserStateValidator<'v, E> {
+    fn validates(&self, entity: &E, component_kind: &ComponentKind) -> bool {
+        self.discards(entity) || self.validators.get(component_kind).is_some()
+    }
+
+    fn validate(
+        &mut self,
+        entity: &E,
+        previous: Option<&dyn Replicate>,
+        proposed: &dyn Replicate,
+    ) -> RemoteStateVerdict {
+        if self.discards(entity) {
+            return RemoteStateVerdict::Reject;
+        }
+        let Some(validator) = self.validators.get(&proposed.kind()) else {
+            return RemoteStateVerdict::Accept;
+        };
+        match validator.validate(&self.user_key, entity, previous, proposed) {
+            Validation::Accept => RemoteStateVerdict::Accept,
+            Validation::Replace(component) => RemoteStateVerdict::Replace(component),
+            Validation::Reject => RemoteStateVerdict::Reject,
+            Validation::Despawn => {
+                self.despawned_entities.push(*entity);
+                RemoteStateVerdict::Reject
+            }
+            Validation::Kick => {
+                self.kick = true;
+                RemoteStateVerdict::Reject
+            }
+        }
+    }
+}
@@ -44,7 +44,7 @@ pub fn message_impl(
             pub use std::collections::HashSet;
             pub use #shared_crate_name::{
                 Named, GlobalEntity, Message, BitWrite, LocalEntityAndGlobalEntityConverter, LocalEntityAndGlobalEntityConverterMut, LocalEntity,
-                EntityProperty, MessageKind, MessageKinds, Serde, MessageBuilder, BitReader, SerdeErr, ConstBitLength, MessageContainer
+                EntityProperty, EntityPropertyVec, MessageKind, MessageKinds, Serde, MessageBuilder, BitReader, SerdeErr, ConstBitLength, MessageContainer
             };
             use super::*;
 
@@ -102,7 +102,7 @@ fn get_clone_method(fields: &[Field], struct_type: &StructType) -> TokenStream {
     for (index, field) in fields.iter().enumerate() {
         let field_name = get_field_name(field, index, struct_type);
         match field {
-            Field::Normal(_) | Field::EntityProperty(_) => {
+            Field::Normal(_) | Field::EntityProperty(_) | Field::EntityPropertyVec(_) => {
                 let new_output_right = quote! {
                     #field_name: self.#field_name.clone(),
                 };
@@ -175,19 +175,31 @@ fn get_relations_waiting_method(fields: &[Field], struct_type: &StructType) -> T
     let mut body = quote! {};
 
     for (index, field) in fields.iter().enumerate() {
-        if let Field::EntityProperty(_) = field {
-            let field_name = get_field_name(field, index, struct_type);
-            let body_add_right = quote! {
-                if let Some(local_entity) = self.#field_name.waiting_local_entity() {
-                    output.insert(local_entity);
+        let field_name = get_field_name(field, index, struct_type);
+        let body_add_right = match field {
+            Field::EntityProperty(_) => {
+                quote! {
+                    if let Some(local_entity) = self.#field_name.waiting_local_entity() {
+                        output.insert(local_entity);
+                    }
                 }
-            };
-            let new_body = quote! {
-                #body
-                #body_add_right
-            };
-            body = new_body;
-        }
+            }
+            Field::EntityPropertyVec(_) => {
+                quote! {
+                    if let Some(local_entities) = self.#field_name.waiting_local_entities() {
+                        output.extend(local_entities);
+                    }
+                }
+            }
+            Field::Normal(_) => {
+                continue;
+            }
+        };
+        let new_body = quote! {
+            #body
+            #body_add_right
+        };
+        body = new_body;
     }
 
     quote! {
@@ -206,7 +218,7 @@ fn get_relations_complete_method(fields: &[Field], struct_type: &StructType) ->
     let mut body = quote! {};
 
     for (index, field) in fields.iter().enumerate() {
-        if let Field::EntityProperty(_) = field {
+        if let Field::EntityProperty(_) | Field::EntityPropertyVec(_) = field {
             let field_name = get_field_name(field, index, struct_type);
             let body_add_right = quote! {
                 self.#field_name.waiting_complete(converter);
@@ -253,6 +265,11 @@ pub fn get_read_method(
                     let #field_name = EntityProperty::new_read(reader, converter)?;
                 }
             }
+            Field::EntityPropertyVec(_property) => {
+                quote! {
+                    let #field_name = EntityPropertyVec::new_read(reader, converter)?;
+                }
+            }
             Field::Normal(normal_field) => {
                 let field_name = &normal_field.variable_name;
                 let field_type = &normal_field.field_type;
@@ -316,6 +333,11 @@ fn get_write_method(fields: &[Field], struct_type: &StructType) -> TokenStream {
                     EntityProperty::write(&self.#field_name, writer, converter);
                 }
             }
+            Field::EntityPropertyVec(_) => {
+                quote! {
+                    EntityPropertyVec::write(&self.#field_name, writer, converter);
+                }
+            }
         };
 
         let new_output_result = quote! {
@@ -344,7 +366,7 @@ fn get_bit_length_method(fields: &[Field], struct_type: &StructType) -> TokenStr
                     output += self.#field_name.bit_length();
                 }
             }
-            Field::EntityProperty(_) => {
+            Field::EntityProperty(_) | Field::EntityPropertyVec(_) => {
                 quote! {
                     output += self.#field_name.bit_length(converter);
                 }
@@ -392,6 +414,12 @@ fn get_fields(input: &DeriveInput) -> Vec<Field> {
                                     if property_type == "EntityProperty" {
                                         fields.push(Field::entity_property(variable_name.clone()));
                                         continue;
+                                        // EntityPropertyVec
+                                    } else if property_type == "EntityPropertyVec" {
+                                        fields.push(Field::entity_property_vec(
+                                            variable_name.clone(),
+                                        ));
+                                        continue;
                                         // Property
                                     } else {
                                         fields.push(Field::normal(
@@ -418,6 +446,9 @@ fn get_fields(input: &DeriveInput) -> Vec<Field> {
                             if property_type == "EntityProperty" {
                                 fields.push(Field::entity_property(variable_name));
                                 continue;
+                            } else if property_type == "EntityPropertyVec" {
+                                fields.push(Field::entity_property_vec(variable_name));
+                                continue;
                             } else {
                                 fields.push(Field::normal(variable_name, field.ty.clone()))
                             }
@@ -469,6 +500,7 @@ pub struct Normal {
 #[allow(clippy::large_enum_variant)]
 pub enum Field {
     EntityProperty(EntityProperty),
+    EntityPropertyVec(EntityProperty),
     Normal(Normal),
 }
 
@@ -483,6 +515,16 @@ impl Field {
         })
     }
 
+    pub fn entity_property_vec(variable_name: Ident) -> Self {
+        Self::EntityPropertyVec(EntityProperty {
+            variable_name: variable_name.clone(),
+            uppercase_variable_name: Ident::new(
+                variable_name.to_string().to_uppercase().as_str(),
+                Span::call_site(),
+            ),
+        })
+    }
+
     pub fn normal(variable_name: Ident, field_type: Type) -> Self {
         Self::Normal(Normal {
             variable_name: variable_name.clone(),
@@ -493,6 +535,7 @@ impl Field {
     pub fn variable_name(&self) -> &Ident {
         match self {
             Self::EntityProperty(property) => &property.variable_name,
+            Self::EntityPropertyVec(property) => &property.variable_name,
             Self::Normal(field) => &field.variable_name,
         }
     }
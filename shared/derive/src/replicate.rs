@@ -31,6 +31,7 @@ pub struct NonReplicatedProperty {
 pub enum Property {
     Normal(NormalProperty),
     Entity(EntityProperty),
+    EntityVec(EntityProperty),
     NonReplicated(NonReplicatedProperty),
 }
 
@@ -72,6 +73,7 @@ pub fn replicate_impl(
     let create_builder_method = get_create_builder_method(&builder_name);
     let read_method = get_read_method(&replica_name, &properties, &struct_type);
     let read_create_update_method = get_read_create_update_method(&replica_name, &properties);
+    let read_update_fields_method = get_read_update_fields_method(&properties, &struct_type);
 
     let dyn_ref_method = get_dyn_ref_method();
     let dyn_mut_method = get_dyn_mut_method();
@@ -97,7 +99,7 @@ pub fn replicate_impl(
                 DiffMask, PropertyMutate, PropertyMutator, ComponentUpdate,
                 ReplicaDynRef, ReplicaDynMut, LocalEntityAndGlobalEntityConverter, LocalEntityAndGlobalEntityConverterMut, ComponentKind, Named,
                 BitReader, BitWrite, BitWriter, OwnedBitReader, SerdeErr, Serde, LocalEntity,
-                EntityProperty, GlobalEntity, Replicate, Property, ComponentKinds, ReplicateBuilder, ComponentFieldUpdate,
+                EntityProperty, EntityPropertyVec, GlobalEntity, Replicate, Property, ComponentKinds, ReplicateBuilder, ComponentFieldUpdate,
             };
             use super::*;
 
@@ -107,6 +109,7 @@ pub fn replicate_impl(
             impl ReplicateBuilder for #builder_name {
                 #read_method
                 #read_create_update_method
+                #read_update_fields_method
                 #split_update_method
             }
             impl Named for #builder_name {
@@ -207,6 +210,17 @@ impl Property {
         })
     }
 
+    pub fn entity_vec(index: usize, variable_name: Ident) -> Self {
+        Self::EntityVec(EntityProperty {
+            index,
+            variable_name: variable_name.clone(),
+            uppercase_variable_name: Ident::new(
+                variable_name.to_string().to_uppercase().as_str(),
+                Span::call_site(),
+            ),
+        })
+    }
+
     pub fn nonreplicated(variable_name: Ident, field_type: Type) -> Self {
         Self::NonReplicated(NonReplicatedProperty {
             variable_name: variable_name.clone(),
@@ -216,7 +230,7 @@ impl Property {
 
     pub fn is_replicated(&self) -> bool {
         match self {
-            Self::Normal(_) | Self::Entity(_) => true,
+            Self::Normal(_) | Self::Entity(_) | Self::EntityVec(_) => true,
             Self::NonReplicated(_) => false,
         }
     }
@@ -225,6 +239,7 @@ impl Property {
         match self {
             Self::Normal(property) => &property.variable_name,
             Self::Entity(property) => &property.variable_name,
+            Self::EntityVec(property) => &property.variable_name,
             Self::NonReplicated(property) => &property.variable_name,
         }
     }
@@ -233,6 +248,7 @@ impl Property {
         match self {
             Self::Normal(property) => &property.uppercase_variable_name,
             Self::Entity(property) => &property.uppercase_variable_name,
+            Self::EntityVec(property) => &property.uppercase_variable_name,
             Self::NonReplicated(_) => panic!("Unused for non-replicated properties"),
         }
     }
@@ -241,6 +257,7 @@ impl Property {
         match self {
             Self::Normal(property) => property.index,
             Self::Entity(property) => property.index,
+            Self::EntityVec(property) => property.index,
             Self::NonReplicated(_) => panic!("Unused for non-replicated properties"),
         }
     }
@@ -264,6 +281,13 @@ fn get_properties(input: &DeriveInput) -> Vec<Property> {
                                         variable_name.clone(),
                                     ));
                                     continue;
+                                // EntityPropertyVec
+                                } else if property_type == "EntityPropertyVec" {
+                                    fields.push(Property::entity_vec(
+                                        fields.len(),
+                                        variable_name.clone(),
+                                    ));
+                                    continue;
                                 // Property
                                 } else if property_type == "Property" {
                                     if let PathArguments::AngleBracketed(angle_args) =
@@ -302,6 +326,9 @@ fn get_properties(input: &DeriveInput) -> Vec<Property> {
                             if property_type == "EntityProperty" {
                                 fields.push(Property::entity(fields.len(), variable_name));
                                 continue;
+                            } else if property_type == "EntityPropertyVec" {
+                                fields.push(Property::entity_vec(fields.len(), variable_name));
+                                continue;
                             } else if let PathArguments::AngleBracketed(angle_args) =
                                 &property_seg.arguments
                             {
@@ -409,7 +436,7 @@ fn get_clone_method(
                 };
                 output = new_output_result;
             }
-            Property::Entity(_) => {
+            Property::Entity(_) | Property::EntityVec(_) => {
                 let new_output_right = quote! {
                     new_clone.#field_name.mirror(&self.#field_name);
                 };
@@ -518,7 +545,7 @@ pub fn get_new_complete_method(
                 };
                 args = new_output_result;
             }
-            Property::Entity(_) => {
+            Property::Entity(_) | Property::EntityVec(_) => {
                 continue;
             }
         };
@@ -568,6 +595,26 @@ pub fn get_new_complete_method(
                     }
                 }
             }
+            Property::EntityVec(property) => {
+                let field_name = &property.variable_name;
+                let uppercase_variant_name = &property.uppercase_variable_name;
+
+                match *struct_type {
+                    StructType::Struct => {
+                        quote! {
+                             #field_name: EntityPropertyVec::with_mutator(#enum_name::#uppercase_variant_name as u8)
+                        }
+                    }
+                    StructType::TupleStruct => {
+                        quote! {
+                            EntityPropertyVec::with_mutator(#enum_name::#uppercase_variant_name as u8)
+                        }
+                    }
+                    _ => {
+                        quote! {}
+                    }
+                }
+            }
             Property::NonReplicated(property) => {
                 let field_name = &property.variable_name;
                 match *struct_type {
@@ -665,6 +712,11 @@ pub fn get_read_method(
                     let #field_name = EntityProperty::new_read(reader, converter)?;
                 }
             }
+            Property::EntityVec(_) => {
+                quote! {
+                    let #field_name = EntityPropertyVec::new_read(reader, converter)?;
+                }
+            }
             Property::NonReplicated(inner_property) => {
                 let field_name = &inner_property.variable_name;
                 let field_type = &inner_property.field_type;
@@ -739,6 +791,17 @@ pub fn get_read_create_update_method(replica_name: &Ident, properties: &[Propert
                     }
                 }
             }
+            Property::EntityVec(_) => {
+                quote! {
+                    {
+                        let should_read = bool::de(reader)?;
+                        should_read.ser(&mut update_writer);
+                        if should_read {
+                            EntityPropertyVec::read_write_update(reader, &mut update_writer)?;
+                        }
+                    }
+                }
+            }
             Property::NonReplicated(_) => {
                 continue;
             }
@@ -765,6 +828,57 @@ pub fn get_read_create_update_method(replica_name: &Ident, properties: &[Propert
     }
 }
 
+fn get_read_update_fields_method(properties: &[Property], struct_type: &StructType) -> TokenStream {
+    let mut field_reads = quote! {};
+    for property in properties.iter() {
+        let read_write = match property {
+            Property::Normal(inner_property) => {
+                let field_type = &inner_property.inner_type;
+                quote! { Property::<#field_type>::read_write(reader, &mut scratch_writer)?; }
+            }
+            Property::Entity(_) => {
+                quote! { EntityProperty::read_write(reader, &mut scratch_writer)?; }
+            }
+            Property::EntityVec(_) => {
+                quote! { EntityPropertyVec::read_write_update(reader, &mut scratch_writer)?; }
+            }
+            Property::NonReplicated(_) => {
+                continue;
+            }
+        };
+        let field_name = match *struct_type {
+            StructType::TupleStruct => property.index().to_string(),
+            _ => property.variable_name().to_string(),
+        };
+
+        let new_output_result = quote! {
+            #field_reads
+            {
+                let should_read = bool::de(reader)?;
+                if should_read {
+                    let bits_before = scratch_writer.bits_written();
+                    #read_write
+                    let field_bits = scratch_writer.bits_written() - bits_before;
+                    fields.push((#field_name, field_bits));
+                }
+            }
+        };
+        field_reads = new_output_result;
+    }
+
+    quote! {
+        fn read_update_fields(&self, reader: &mut BitReader) -> Result<Vec<(&'static str, u32)>, SerdeErr> {
+
+            let mut scratch_writer = BitWriter::new();
+            let mut fields = Vec::new();
+
+            #field_reads
+
+            return Ok(fields);
+        }
+    }
+}
+
 fn get_split_update_method(replica_name: &Ident, properties: &[Property]) -> TokenStream {
     let mut output = quote! {};
 
@@ -794,10 +908,16 @@ fn get_split_update_method(replica_name: &Ident, properties: &[Property]) -> Tok
                         if let Some(waiting_entity) = waiting_entity_opt {
                             waiting_did_write = true;
 
+                            // the ready part does not update this property
+                            false.ser(&mut ready_writer);
+
                             // property is waiting on waiting_entity, write into the waiting_writer
                             let mut waiting_writer = BitWriter::new();
+                            true.ser(&mut waiting_writer);
                             waiting_entity.owned_ser(&mut waiting_writer);
-                            waiting_updates.push((waiting_entity, ComponentFieldUpdate::new(#index, waiting_writer.to_owned_reader())));
+                            let mut waiting_entities = HashSet::new();
+                            waiting_entities.insert(waiting_entity);
+                            waiting_updates.push((waiting_entities, ComponentFieldUpdate::new(#index, waiting_writer.to_owned_reader())));
                         } else {
                             ready_did_write = true;
 
@@ -811,6 +931,24 @@ fn get_split_update_method(replica_name: &Ident, properties: &[Property]) -> Tok
                     }
                 }
             }
+            Property::EntityVec(inner_property) => {
+                let index = inner_property.index as u8;
+                quote! {
+                    let should_read = bool::de(reader)?;
+                    should_read.ser(&mut ready_writer);
+                    if should_read {
+                        // the length & every element which is ready go into the ready writer
+                        ready_did_write = true;
+
+                        // each element waiting on an entity waits on its own
+                        let element_updates = EntityPropertyVec::split_update(#index, reader, converter, &mut ready_writer)?;
+                        if !element_updates.is_empty() {
+                            waiting_did_write = true;
+                            waiting_updates.extend(element_updates);
+                        }
+                    }
+                }
+            }
             Property::NonReplicated(_) => {
                 continue;
             }
@@ -829,14 +967,14 @@ fn get_split_update_method(replica_name: &Ident, properties: &[Property]) -> Tok
             converter: &dyn LocalEntityAndGlobalEntityConverter,
             update: ComponentUpdate
         ) -> Result<(
-            Option<Vec<(LocalEntity, ComponentFieldUpdate)>>,
+            Option<Vec<(HashSet<LocalEntity>, ComponentFieldUpdate)>>,
             Option<ComponentUpdate>
         ), SerdeErr> {
             let component_kind = ComponentKind::of::<#replica_name>();
             let reader = &mut update.reader();
 
             let mut waiting_did_write = false;
-            let mut waiting_updates: Vec<(LocalEntity, ComponentFieldUpdate)> = Vec::new();
+            let mut waiting_updates: Vec<(HashSet<LocalEntity>, ComponentFieldUpdate)> = Vec::new();
 
             let mut ready_writer = BitWriter::new();
             let mut ready_did_write = false;
@@ -883,6 +1021,13 @@ fn get_read_apply_update_method(properties: &[Property], struct_type: &StructTyp
                     }
                 }
             }
+            Property::EntityVec(_) => {
+                quote! {
+                    if bool::de(reader)? {
+                        EntityPropertyVec::read(&mut self.#field_name, reader, converter)?;
+                    }
+                }
+            }
             Property::NonReplicated(_) => {
                 continue;
             }
@@ -924,6 +1069,14 @@ fn get_read_apply_field_update_method(
                     }
                 }
             }
+            Property::EntityVec(inner_property) => {
+                let index = inner_property.index as u8;
+                quote! {
+                    #index => {
+                        EntityPropertyVec::read_element(&mut self.#field_name, update.element_index(), reader, converter)?;
+                    }
+                }
+            }
         };
 
         let new_output_result = quote! {
@@ -961,6 +1114,11 @@ fn get_write_method(properties: &[Property], struct_type: &StructType) -> TokenS
                     EntityProperty::write(&self.#field_name, writer, converter);
                 }
             }
+            Property::EntityVec(_) => {
+                quote! {
+                    EntityPropertyVec::write(&self.#field_name, writer, converter);
+                }
+            }
             Property::NonReplicated(_) => {
                 continue;
             }
@@ -1013,6 +1171,17 @@ fn get_write_update_method(
                     }
                 }
             }
+            Property::EntityVec(property) => {
+                let uppercase_variant_name = &property.uppercase_variable_name;
+                quote! {
+                    if let Some(true) = diff_mask.bit(#enum_name::#uppercase_variant_name as u8) {
+                        true.ser(writer);
+                        EntityPropertyVec::write_update(&self.#field_name, diff_mask.elements(#enum_name::#uppercase_variant_name as u8), writer, converter);
+                    } else {
+                        false.ser(writer);
+                    }
+                }
+            }
             Property::NonReplicated(_) => {
                 continue;
             }
@@ -1082,19 +1251,32 @@ fn get_relations_waiting_method(fields: &[Property], struct_type: &StructType) -
     let mut body = quote! {};
 
     for field in fields.iter() {
-        if let Property::Entity(_) = field {
-            let field_name = get_field_name(field, struct_type);
-            let body_add_right = quote! {
-                if let Some(local_entity) = self.#field_name.waiting_local_entity() {
-                    output.insert(local_entity);
+        let body_add_right = match field {
+            Property::Entity(_) => {
+                let field_name = get_field_name(field, struct_type);
+                quote! {
+                    if let Some(local_entity) = self.#field_name.waiting_local_entity() {
+                        output.insert(local_entity);
+                    }
                 }
-            };
-            let new_body = quote! {
-                #body
-                #body_add_right
-            };
-            body = new_body;
-        }
+            }
+            Property::EntityVec(_) => {
+                let field_name = get_field_name(field, struct_type);
+                quote! {
+                    if let Some(local_entities) = self.#field_name.waiting_local_entities() {
+                        output.extend(local_entities);
+                    }
+                }
+            }
+            Property::Normal(_) | Property::NonReplicated(_) => {
+                continue;
+            }
+        };
+        let new_body = quote! {
+            #body
+            #body_add_right
+        };
+        body = new_body;
     }
 
     quote! {
@@ -1113,7 +1295,7 @@ fn get_relations_complete_method(fields: &[Property], struct_type: &StructType)
     let mut body = quote! {};
 
     for field in fields.iter() {
-        if let Property::Entity(_) = field {
+        if let Property::Entity(_) | Property::EntityVec(_) = field {
             let field_name = get_field_name(field, struct_type);
             let body_add_right = quote! {
                 self.#field_name.waiting_complete(converter);
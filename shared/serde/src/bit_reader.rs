@@ -47,6 +47,12 @@ impl<'b> BitReader<'b> {
         Ok(value != 0)
     }
 
+    /// The number of bits left to read. Lengths read from a remote host are
+    /// untrusted, so no more than this should be allocated for them.
+    pub(crate) fn bits_remaining(&self) -> usize {
+        ((self.buffer.len() - self.state.buffer_index) * 8) + usize::from(self.state.scratch_index)
+    }
+
     pub(crate) fn read_byte(&mut self) -> Result<u8, SerdeErr> {
         let mut output = 0;
         for _ in 0..7 {
@@ -64,6 +70,7 @@ impl<'b> BitReader<'b> {
 
 // OwnedBitReader
 
+#[derive(Clone)]
 pub struct OwnedBitReader {
     state: BitReaderState,
     buffer: Box<[u8]>,
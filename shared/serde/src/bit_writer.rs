@@ -84,6 +84,10 @@ impl BitWriter {
     pub fn bits_free(&self) -> u32 {
         self.max_bits - self.current_bits
     }
+
+    pub fn bits_written(&self) -> u32 {
+        self.current_bits
+    }
 }
 
 impl BitWrite for BitWriter {
@@ -126,6 +130,69 @@ impl BitWrite for BitWriter {
     }
 }
 
+// GrowableBitWriter
+/// A BitWriter which is not bound to the size of a packet, used to write
+/// larger blobs such as world snapshots
+pub struct GrowableBitWriter {
+    scratch: u8,
+    scratch_index: u8,
+    buffer: Vec<u8>,
+}
+
+impl GrowableBitWriter {
+    #[allow(clippy::new_without_default)]
+    pub fn new() -> Self {
+        Self {
+            scratch: 0,
+            scratch_index: 0,
+            buffer: Vec::new(),
+        }
+    }
+
+    pub fn to_bytes(mut self) -> Vec<u8> {
+        if self.scratch_index > 0 {
+            self.buffer
+                .push((self.scratch << (8 - self.scratch_index)).reverse_bits());
+        }
+        self.buffer
+    }
+}
+
+impl BitWrite for GrowableBitWriter {
+    fn write_bit(&mut self, bit: bool) {
+        self.scratch <<= 1;
+
+        if bit {
+            self.scratch |= 1;
+        }
+
+        self.scratch_index += 1;
+
+        if self.scratch_index >= 8 {
+            self.buffer.push(self.scratch.reverse_bits());
+
+            self.scratch_index -= 8;
+            self.scratch = 0;
+        }
+    }
+
+    fn write_byte(&mut self, byte: u8) {
+        let mut temp = byte;
+        for _ in 0..8 {
+            self.write_bit(temp & 1 != 0);
+            temp >>= 1;
+        }
+    }
+
+    fn write_bits(&mut self, _: u32) {
+        panic!("This method should not be called for GrowableBitWriter!");
+    }
+
+    fn is_counter(&self) -> bool {
+        false
+    }
+}
+
 mod tests {
 
     #[test]
@@ -347,4 +414,29 @@ mod tests {
         assert_eq!(34, reader.read_byte().unwrap());
         assert_eq!(2, reader.read_byte().unwrap());
     }
+
+    #[test]
+    fn growable_writes_past_mtu() {
+        use crate::{
+            bit_reader::BitReader,
+            bit_writer::{BitWrite, GrowableBitWriter},
+            constants::MTU_SIZE_BYTES,
+        };
+
+        let mut writer = GrowableBitWriter::new();
+
+        writer.write_bit(true);
+        for index in 0..(MTU_SIZE_BYTES * 2) {
+            writer.write_byte(index as u8);
+        }
+
+        let buffer = writer.to_bytes();
+
+        let mut reader = BitReader::new(&buffer);
+
+        assert!(reader.read_bit().unwrap());
+        for index in 0..(MTU_SIZE_BYTES * 2) {
+            assert_eq!(index as u8, reader.read_byte().unwrap());
+        }
+    }
 }
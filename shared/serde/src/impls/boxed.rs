@@ -39,7 +39,7 @@ impl Serde for Box<[u8]> {
     fn de(reader: &mut BitReader) -> Result<Box<[u8]>, SerdeErr> {
         let length_int = UnsignedVariableInteger::<9>::de(reader)?;
         let length_usize = length_int.get() as usize;
-        let mut bytes: Vec<u8> = Vec::with_capacity(length_usize);
+        let mut bytes: Vec<u8> = Vec::with_capacity(length_usize.min(reader.bits_remaining() / 8));
         for _ in 0..length_usize {
             bytes.push(reader.read_byte()?);
         }
@@ -1,3 +1,5 @@
+use std::{error::Error, fmt};
+
 use crate::{
     bit_reader::BitReader, bit_writer::BitWrite, error::SerdeErr, serde::Serde, ConstBitLength,
 };
@@ -7,6 +9,34 @@ pub type SignedInteger<const BITS: u8> = SerdeInteger<true, false, BITS>;
 pub type UnsignedVariableInteger<const BITS: u8> = SerdeInteger<false, true, BITS>;
 pub type SignedVariableInteger<const BITS: u8> = SerdeInteger<true, true, BITS>;
 
+/// The reason a value can not be encoded by a `SerdeInteger`
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum IntegerError {
+    /// The value is negative, but the Integer is unsigned
+    Negative,
+    /// The value is too large for the Integer's number of bits
+    TooLarge,
+    /// The value is too small for the Integer's number of bits
+    TooSmall,
+}
+
+impl fmt::Display for IntegerError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        match self {
+            IntegerError::Negative => {
+                write!(
+                    f,
+                    "can't encode a negative number with an Unsigned Integer!"
+                )
+            }
+            IntegerError::TooLarge => write!(f, "number is too large for the Integer's bits"),
+            IntegerError::TooSmall => write!(f, "number is too small for the Integer's bits"),
+        }
+    }
+}
+
+impl Error for IntegerError {}
+
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub struct SerdeInteger<const SIGNED: bool, const VARIABLE: bool, const BITS: u8> {
     inner: i128,
@@ -19,11 +49,20 @@ impl<const SIGNED: bool, const VARIABLE: bool, const BITS: u8>
         self.inner
     }
 
+    /// Panics if the value can not be encoded with the Integer's bits, see
+    /// `try_new()`
     pub fn new<T: Into<i128>>(value: T) -> Self {
+        match Self::try_new(value) {
+            Ok(integer) => integer,
+            Err(error) => panic!("{}", error),
+        }
+    }
+
+    pub fn try_new<T: Into<i128>>(value: T) -> Result<Self, IntegerError> {
         let inner = Into::<i128>::into(value);
 
         if inner < 0 && !SIGNED {
-            panic!("can't encode a negative number with an Unsigned Integer!");
+            return Err(IntegerError::Negative);
         }
 
         if BITS == 0 {
@@ -36,23 +75,17 @@ impl<const SIGNED: bool, const VARIABLE: bool, const BITS: u8>
         if !VARIABLE {
             let max_value: i128 = 2_i128.pow(BITS as u32);
             if inner >= max_value {
-                panic!(
-                    "with {} bits, can't encode number greater than {}",
-                    BITS, max_value
-                );
+                return Err(IntegerError::TooLarge);
             }
             if inner < 0 && SIGNED {
                 let min_value: i128 = -(2_i128.pow(BITS as u32));
                 if inner <= min_value {
-                    panic!(
-                        "with {} bits, can't encode number less than {}",
-                        BITS, min_value
-                    );
+                    return Err(IntegerError::TooSmall);
                 }
             }
         }
 
-        Self { inner }
+        Ok(Self { inner })
     }
 
     fn new_unchecked(value: i128) -> Self {
@@ -119,19 +152,18 @@ impl<const SIGNED: bool, const VARIABLE: bool, const BITS: u8> Serde
                 let proceed = reader.read_bit()?;
 
                 for _ in 0..BITS {
-                    total_bits += 1;
-
-                    output <<= 1;
-
                     if reader.read_bit()? {
-                        output |= 1;
+                        // no value this large could have been written
+                        if total_bits >= 127 {
+                            return Err(SerdeErr);
+                        }
+                        output |= 1 << total_bits;
                     }
+
+                    total_bits += 1;
                 }
 
                 if !proceed {
-                    output <<= 128 - total_bits;
-                    output = output.reverse_bits();
-
                     let value: i128 = output as i128;
                     if negative {
                         return Ok(SerdeInteger::new_unchecked(-value));
@@ -217,8 +249,11 @@ impl<const SIGNED: bool, const BITS: u8> ConstBitLength for SerdeInteger<SIGNED,
 mod tests {
     use crate::{
         bit_reader::BitReader,
-        bit_writer::BitWriter,
-        integer::{SignedInteger, SignedVariableInteger, UnsignedInteger, UnsignedVariableInteger},
+        bit_writer::{BitWrite, BitWriter},
+        integer::{
+            IntegerError, SignedInteger, SignedVariableInteger, UnsignedInteger,
+            UnsignedVariableInteger,
+        },
         serde::Serde,
     };
 
@@ -338,4 +373,32 @@ mod tests {
         assert_eq!(in_2, out_2);
         assert_eq!(in_3, out_3);
     }
+
+    #[test]
+    fn out_of_range() {
+        assert_eq!(
+            UnsignedInteger::<3>::try_new(8),
+            Err(IntegerError::TooLarge)
+        );
+        assert_eq!(
+            UnsignedInteger::<3>::try_new(-1),
+            Err(IntegerError::Negative)
+        );
+        assert_eq!(SignedInteger::<3>::try_new(-8), Err(IntegerError::TooSmall));
+        assert_eq!(SignedInteger::<3>::try_new(-7).unwrap().get(), -7);
+    }
+
+    #[test]
+    fn read_oversized_variable() {
+        // a stream of set bits, which never ends the Integer within 128 bits
+        let mut writer = BitWriter::new();
+        for _ in 0..200 {
+            writer.write_bit(true);
+        }
+        let buffer = writer.to_bytes();
+
+        let mut reader = BitReader::new(&buffer);
+        let result: Result<UnsignedVariableInteger<7>, _> = Serde::de(&mut reader);
+        assert!(result.is_err());
+    }
 }
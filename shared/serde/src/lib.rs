@@ -12,10 +12,12 @@ mod serde;
 
 pub use bit_counter::BitCounter;
 pub use bit_reader::{BitReader, OwnedBitReader};
-pub use bit_writer::{BitWrite, BitWriter};
+pub use bit_writer::{BitWrite, BitWriter, GrowableBitWriter};
 pub use constants::{MTU_SIZE_BITS, MTU_SIZE_BYTES};
 pub use error::SerdeErr;
-pub use integer::{SignedInteger, SignedVariableInteger, UnsignedInteger, UnsignedVariableInteger};
+pub use integer::{
+    IntegerError, SignedInteger, SignedVariableInteger, UnsignedInteger, UnsignedVariableInteger,
+};
 pub use outgoing_packet::OutgoingPacket;
 pub use serde::{
     ConstBitLength, Serde, Serde as SerdeInternal, Serde as SerdeBevy, Serde as SerdeHecs,
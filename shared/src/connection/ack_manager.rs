@@ -26,6 +26,8 @@ pub struct AckManager {
     // However, we can only reasonably ack up to `REDUNDANT_PACKET_ACKS_SIZE + 1` packets on each
     // message we send so this should be that large.
     received_packets: SequenceBuffer<ReceivedPacket>,
+    // Data packets the remote host reported as never received
+    packets_lost: u64,
 }
 
 impl AckManager {
@@ -35,6 +37,7 @@ impl AckManager {
             last_recv_packet_index: u16::MAX,
             sent_packets: HashMap::with_capacity(DEFAULT_SEND_PACKETS_SIZE),
             received_packets: SequenceBuffer::with_capacity(REDUNDANT_PACKET_ACKS_SIZE + 1),
+            packets_lost: 0,
         }
     }
 
@@ -43,6 +46,11 @@ impl AckManager {
         self.next_packet_index
     }
 
+    /// Get how many sent data packets were never received by the remote host
+    pub fn packets_lost(&self) -> u64 {
+        self.packets_lost
+    }
+
     /// Process an incoming packet, handle notifications of delivered / dropped
     /// packets
     pub fn process_incoming_header<E: Copy + Eq + Hash + Send + Sync>(
@@ -100,6 +108,9 @@ impl AckManager {
 
                     self.sent_packets.remove(&sent_packet_index);
                 } else {
+                    if sent_packet.packet_type == PacketType::Data {
+                        self.packets_lost += 1;
+                    }
                     self.sent_packets.remove(&sent_packet_index);
                 }
             }
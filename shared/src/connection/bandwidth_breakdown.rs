@@ -0,0 +1,226 @@
+use std::{collections::HashMap, sync::Arc, time::Duration};
+
+use naia_socket_shared::Clock;
+
+use crate::{ChannelKind, ComponentKind, MessageKind, Protocol};
+
+use super::bandwidth_monitor::TimeQueue;
+
+/// What the bits written into an outgoing packet are attributed to. Message
+/// bits are attributed to both the Message's kind and the Channel it was
+/// sent on.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BandwidthSource {
+    Component(ComponentKind),
+    Message(MessageKind),
+    Channel(ChannelKind),
+}
+
+#[derive(Clone, PartialEq, Eq)]
+struct PacketBits {
+    bytes: u64,
+    source_bits: Vec<(BandwidthSource, u64)>,
+}
+
+/// Attributes the bandwidth sent over the last measured duration to the
+/// Components, Messages & Channels which used it
+pub struct BandwidthBreakdownMonitor {
+    time_queue: TimeQueue<PacketBits>,
+    total_bytes: u64,
+    window_bits: HashMap<BandwidthSource, u64>,
+    last_source_totals: HashMap<BandwidthSource, u64>,
+}
+
+impl BandwidthBreakdownMonitor {
+    pub fn new(bandwidth_measure_duration: Duration, clock: &Arc<dyn Clock>) -> Self {
+        Self {
+            time_queue: TimeQueue::new(bandwidth_measure_duration, clock),
+            total_bytes: 0,
+            window_bits: HashMap::new(),
+            last_source_totals: HashMap::new(),
+        }
+    }
+
+    /// Records a sent packet. `source_totals` holds how many bits each source
+    /// has written since the connection started, so that the packet is
+    /// credited with everything written since the previous one.
+    pub fn record_packet(
+        &mut self,
+        packet_bytes: usize,
+        source_totals: HashMap<BandwidthSource, u64>,
+    ) {
+        self.clear_expired_packets();
+
+        let mut source_bits = Vec::new();
+        for (source, total_bits) in source_totals {
+            let last_total_bits = self.last_source_totals.entry(source).or_default();
+            if total_bits > *last_total_bits {
+                let bits = total_bits - *last_total_bits;
+                *last_total_bits = total_bits;
+                *self.window_bits.entry(source).or_default() += bits;
+                source_bits.push((source, bits));
+            }
+        }
+
+        let bytes = packet_bytes as u64;
+        self.total_bytes += bytes;
+        self.time_queue.add_item(PacketBits { bytes, source_bits });
+    }
+
+    pub fn breakdown(&mut self, protocol: &Protocol) -> BandwidthBreakdown {
+        self.clear_expired_packets();
+
+        let mut breakdown = BandwidthBreakdown {
+            total_bytes: self.total_bytes,
+            ..BandwidthBreakdown::default()
+        };
+        let total_bits = (self.total_bytes * 8) as f32;
+
+        for (source, bits) in &self.window_bits {
+            let (list, name) = match source {
+                BandwidthSource::Component(kind) => (
+                    &mut breakdown.components,
+                    protocol.component_kinds.kind_to_name(kind),
+                ),
+                BandwidthSource::Message(kind) => (
+                    &mut breakdown.messages,
+                    protocol.message_kinds.kind_to_name(kind),
+                ),
+                BandwidthSource::Channel(kind) => (
+                    &mut breakdown.channels,
+                    protocol.channel_kinds.kind_to_name(kind),
+                ),
+            };
+            let percent = if total_bits > 0.0 {
+                *bits as f32 * 100.0 / total_bits
+            } else {
+                0.0
+            };
+            list.push(BandwidthShare {
+                name,
+                bytes: bits / 8,
+                percent,
+            });
+        }
+
+        for list in [
+            &mut breakdown.components,
+            &mut breakdown.messages,
+            &mut breakdown.channels,
+        ] {
+            list.sort_by(|a, b| b.bytes.cmp(&a.bytes).then_with(|| a.name.cmp(&b.name)));
+        }
+
+        breakdown
+    }
+
+    fn clear_expired_packets(&mut self) {
+        while let Some(packet) = self.time_queue.pop_item() {
+            self.total_bytes -= packet.bytes;
+            for (source, bits) in packet.source_bits {
+                let window_bits = self.window_bits.get_mut(&source).unwrap();
+                *window_bits -= bits;
+                if *window_bits == 0 {
+                    self.window_bits.remove(&source);
+                }
+            }
+        }
+    }
+}
+
+/// The share of a connection's outgoing bandwidth used by one Component,
+/// Message or Channel
+#[derive(Clone)]
+pub struct BandwidthShare {
+    pub name: String,
+    pub bytes: u64,
+    /// Percentage of the connection's `total_bytes`, from 0 to 100
+    pub percent: f32,
+}
+
+/// A connection's outgoing bandwidth over the last measured duration,
+/// attributed to the Components, Messages & Channels which used it. Each list
+/// is sorted from the largest share to the smallest.
+#[derive(Clone, Default)]
+pub struct BandwidthBreakdown {
+    /// Bytes of every data packet sent, before compression, including headers
+    /// & other overhead
+    pub total_bytes: u64,
+    /// Component inserts & updates
+    pub components: Vec<BandwidthShare>,
+    /// Message payloads, with fragments counted as the Message they were
+    /// split from
+    pub messages: Vec<BandwidthShare>,
+    /// Messages sent on each Channel, including their indices & other overhead
+    pub channels: Vec<BandwidthShare>,
+}
+
+impl BandwidthBreakdown {
+    pub fn component(&self, name: &str) -> Option<&BandwidthShare> {
+        self.components.iter().find(|share| share.name == name)
+    }
+
+    pub fn message(&self, name: &str) -> Option<&BandwidthShare> {
+        self.messages.iter().find(|share| share.name == name)
+    }
+
+    pub fn channel(&self, name: &str) -> Option<&BandwidthShare> {
+        self.channels.iter().find(|share| share.name == name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::HashMap, sync::Arc, time::Duration};
+
+    use crate::{
+        ChannelKind, Clock, EntityScopeChannel, EntityScopeMessage, MessageKind, Protocol,
+        TickIntervalMessage, VirtualClock,
+    };
+
+    use super::{BandwidthBreakdownMonitor, BandwidthSource};
+
+    #[test]
+    fn attributes_bits_since_last_packet() {
+        let clock = VirtualClock::new();
+        let monitor_clock: Arc<dyn Clock> = Arc::new(clock.clone());
+        let protocol = Protocol::default();
+        let scope_message = BandwidthSource::Message(MessageKind::of::<EntityScopeMessage>());
+        let scope_channel = BandwidthSource::Channel(ChannelKind::of::<EntityScopeChannel>());
+        let tick_message = BandwidthSource::Message(MessageKind::of::<TickIntervalMessage>());
+
+        let mut monitor =
+            BandwidthBreakdownMonitor::new(Duration::from_millis(100), &monitor_clock);
+        monitor.record_packet(
+            10,
+            HashMap::from([(scope_message, 40), (scope_channel, 48)]),
+        );
+        // totals are cumulative, so only the new 8 bits count towards this packet
+        monitor.record_packet(
+            10,
+            HashMap::from([(scope_message, 48), (scope_channel, 56), (tick_message, 16)]),
+        );
+
+        let breakdown = monitor.breakdown(&protocol);
+        assert_eq!(breakdown.total_bytes, 20);
+        assert_eq!(breakdown.message("EntityScopeMessage").unwrap().bytes, 6);
+        assert_eq!(
+            breakdown.message("EntityScopeMessage").unwrap().percent,
+            30.0
+        );
+        assert_eq!(breakdown.messages[1].name, "TickIntervalMessage");
+        assert_eq!(breakdown.channel("EntityScopeChannel").unwrap().bytes, 7);
+        assert!(breakdown.component("Position").is_none());
+
+        clock.advance(Duration::from_millis(150));
+        monitor.record_packet(4, HashMap::from([(tick_message, 48)]));
+
+        let breakdown = monitor.breakdown(&protocol);
+        assert_eq!(breakdown.total_bytes, 4);
+        assert!(breakdown.message("EntityScopeMessage").is_none());
+        assert_eq!(
+            breakdown.message("TickIntervalMessage").unwrap().percent,
+            100.0
+        );
+    }
+}
@@ -1,4 +1,6 @@
-use std::time::Duration;
+use std::{sync::Arc, time::Duration};
+
+use naia_socket_shared::Clock;
 
 pub struct BandwidthMonitor {
     time_queue: TimeQueue<usize>,
@@ -7,9 +9,9 @@ pub struct BandwidthMonitor {
 }
 
 impl BandwidthMonitor {
-    pub fn new(bandwidth_measure_duration: Duration) -> Self {
+    pub fn new(bandwidth_measure_duration: Duration, clock: &Arc<dyn Clock>) -> Self {
         BandwidthMonitor {
-            time_queue: TimeQueue::new(bandwidth_measure_duration),
+            time_queue: TimeQueue::new(bandwidth_measure_duration, clock),
             total_bytes: 0,
             to_kbps_factor: 0.008 / bandwidth_measure_duration.as_secs_f32(),
         }
@@ -44,19 +46,21 @@ use naia_socket_shared::Instant;
 pub struct TimeQueue<T: Eq + PartialEq> {
     queue: BinaryHeap<ItemContainer<T>>,
     duration: Duration,
+    clock: Arc<dyn Clock>,
 }
 
 impl<T: Eq + PartialEq> TimeQueue<T> {
-    pub fn new(duration: Duration) -> Self {
+    pub fn new(duration: Duration, clock: &Arc<dyn Clock>) -> Self {
         TimeQueue {
             queue: BinaryHeap::new(),
             duration,
+            clock: clock.clone(),
         }
     }
 
     pub fn add_item(&mut self, item: T) {
         self.queue.push(ItemContainer {
-            instant: Instant::now(),
+            instant: self.clock.now(),
             item,
         });
     }
@@ -66,7 +70,7 @@ impl<T: Eq + PartialEq> TimeQueue<T> {
             return false;
         }
         if let Some(item) = self.queue.peek() {
-            return item.instant.elapsed() > self.duration;
+            return self.clock.elapsed(&item.instant) > self.duration;
         }
         false
     }
@@ -1,11 +1,14 @@
-use std::{hash::Hash, net::SocketAddr};
+use std::{collections::HashMap, hash::Hash, net::SocketAddr, sync::Arc};
 
 use naia_serde::{BitWriter, Serde};
-use naia_socket_shared::Instant;
+use naia_socket_shared::{Clock, Instant};
 
 use crate::{
     backends::Timer,
-    messages::{channels::channel_kinds::ChannelKinds, message_manager::MessageManager},
+    messages::{
+        channels::{channel_kinds::ChannelKinds, senders::tick_buffer_sender::TickBufferSender},
+        message_manager::MessageManager,
+    },
     types::{HostType, PacketIndex},
     world::{
         entity::entity_converters::{EntityConverterMut, GlobalWorldManagerType},
@@ -13,12 +16,17 @@ use crate::{
         local_world_manager::LocalWorldManager,
         remote::remote_world_reader::RemoteWorldReader,
     },
-    EntityEvent, HostWorldManager, Protocol, RemoteWorldManager, WorldMutType, WorldRefType,
+    EntityEvent, HostWorldManager, Protocol, RemoteWorldManager, Tick, WorldMutType, WorldRefType,
 };
 
 use super::{
-    ack_manager::AckManager, connection_config::ConnectionConfig,
-    packet_notifiable::PacketNotifiable, packet_type::PacketType, standard_header::StandardHeader,
+    ack_manager::AckManager,
+    bandwidth_breakdown::{BandwidthBreakdown, BandwidthBreakdownMonitor, BandwidthSource},
+    connection_config::ConnectionConfig,
+    network_stats::NetworkStats,
+    packet_notifiable::PacketNotifiable,
+    packet_type::PacketType,
+    standard_header::StandardHeader,
 };
 
 /// Represents a connection to a remote host, and provides functionality to
@@ -32,6 +40,7 @@ pub struct BaseConnection<E: Copy + Eq + Hash + Send + Sync> {
     heartbeat_timer: Timer,
     timeout_timer: Timer,
     ack_manager: AckManager,
+    bandwidth_breakdown_monitor: Option<BandwidthBreakdownMonitor>,
 }
 
 impl<E: Copy + Eq + Hash + Send + Sync> BaseConnection<E> {
@@ -43,16 +52,20 @@ impl<E: Copy + Eq + Hash + Send + Sync> BaseConnection<E> {
         connection_config: &ConnectionConfig,
         channel_kinds: &ChannelKinds,
         global_world_manager: &dyn GlobalWorldManagerType<E>,
+        clock: &Arc<dyn Clock>,
     ) -> Self {
         BaseConnection {
-            heartbeat_timer: Timer::new(connection_config.heartbeat_interval),
-            timeout_timer: Timer::new(connection_config.disconnection_timeout_duration),
+            heartbeat_timer: Timer::new(connection_config.heartbeat_interval, clock),
+            timeout_timer: Timer::new(connection_config.disconnection_timeout_duration, clock),
             ack_manager: AckManager::new(),
             message_manager: MessageManager::new(host_type, channel_kinds),
             host_world_manager: HostWorldManager::new(address, global_world_manager),
-            remote_world_manager: RemoteWorldManager::new(),
+            remote_world_manager: RemoteWorldManager::new(clock),
             remote_world_reader: RemoteWorldReader::new(),
-            local_world_manager: LocalWorldManager::new(user_key),
+            local_world_manager: LocalWorldManager::new(user_key, clock),
+            bandwidth_breakdown_monitor: connection_config
+                .bandwidth_measure_duration
+                .map(|duration| BandwidthBreakdownMonitor::new(duration, clock)),
         }
     }
 
@@ -117,9 +130,98 @@ impl<E: Copy + Eq + Hash + Send + Sync> BaseConnection<E> {
         self.ack_manager.next_sender_packet_index()
     }
 
+    // Stats
+
+    /// Gets the NetworkStats this connection keeps track of itself. Packets
+    /// & bytes are counted where they're sent, and must be added separately.
+    pub fn network_stats(&self, protocol: &Protocol) -> NetworkStats {
+        let mut stats = NetworkStats::new();
+        stats.packets_lost = self.ack_manager.packets_lost();
+
+        for (channel_kind, resends) in self.message_manager.channel_resends() {
+            if protocol.channel_kinds.channel(&channel_kind).reliable() {
+                stats
+                    .channel_resends
+                    .insert(protocol.channel_kinds.kind_to_name(&channel_kind), resends);
+            }
+        }
+        stats.add_channel_bits(
+            &protocol.channel_kinds,
+            self.message_manager.channel_bits_sent(),
+        );
+        stats.add_message_bits(
+            &protocol.message_kinds,
+            self.message_manager.message_bits_sent(),
+        );
+        for (component_kind, bits) in self.host_world_manager.component_bits_sent() {
+            *stats
+                .component_bytes_sent
+                .entry(protocol.component_kinds.kind_to_name(component_kind))
+                .or_default() += bits / 8;
+        }
+
+        stats.waitlist_size = self.remote_world_manager.entity_waitlist.waiting_count() as u64;
+        stats.entities_in_scope = self.local_world_manager.entity_count() as u64;
+
+        stats
+    }
+
+    /// Attributes the bits written since the last data packet to the
+    /// Components, Messages & Channels which wrote them. Does nothing unless
+    /// `bandwidth_measure_duration` is set.
+    pub fn record_sent_packet(
+        &mut self,
+        packet_bytes: usize,
+        tick_buffer_sender: &TickBufferSender,
+    ) {
+        if self.bandwidth_breakdown_monitor.is_none() {
+            return;
+        }
+        let source_totals = self.bandwidth_source_totals(tick_buffer_sender);
+        if let Some(monitor) = &mut self.bandwidth_breakdown_monitor {
+            monitor.record_packet(packet_bytes, source_totals);
+        }
+    }
+
+    /// Gets the outgoing bandwidth over the last measured duration, by
+    /// Component, Message & Channel. Returns None unless
+    /// `bandwidth_measure_duration` is set.
+    pub fn bandwidth_breakdown(&mut self, protocol: &Protocol) -> Option<BandwidthBreakdown> {
+        self.bandwidth_breakdown_monitor
+            .as_mut()
+            .map(|monitor| monitor.breakdown(protocol))
+    }
+
+    fn bandwidth_source_totals(
+        &self,
+        tick_buffer_sender: &TickBufferSender,
+    ) -> HashMap<BandwidthSource, u64> {
+        let mut totals = HashMap::new();
+        for (kind, bits) in self.host_world_manager.component_bits_sent() {
+            *totals.entry(BandwidthSource::Component(*kind)).or_default() += bits;
+        }
+        for message_bits_sent in [
+            self.message_manager.message_bits_sent(),
+            tick_buffer_sender.message_bits_sent(),
+        ] {
+            for (kind, bits) in message_bits_sent {
+                *totals.entry(BandwidthSource::Message(*kind)).or_default() += bits;
+            }
+        }
+        for channel_bits_sent in [
+            self.message_manager.channel_bits_sent(),
+            tick_buffer_sender.channel_bits_sent(),
+        ] {
+            for (kind, bits) in channel_bits_sent {
+                *totals.entry(BandwidthSource::Channel(*kind)).or_default() += bits;
+            }
+        }
+        totals
+    }
+
     pub fn collect_outgoing_messages(&mut self, now: &Instant, rtt_millis: &f32) {
         self.host_world_manager
-            .collect_outgoing_messages(rtt_millis);
+            .collect_outgoing_messages(now, rtt_millis);
         self.message_manager
             .collect_outgoing_messages(now, rtt_millis);
     }
@@ -143,12 +245,14 @@ impl<E: Copy + Eq + Hash + Send + Sync> BaseConnection<E> {
         );
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub fn write_outgoing_packet<W: WorldRefType<E>>(
         &mut self,
         protocol: &Protocol,
         now: &Instant,
         writer: &mut BitWriter,
         packet_index: PacketIndex,
+        host_tick: &Tick,
         world: &W,
         global_world_manager: &dyn GlobalWorldManagerType<E>,
         has_written: &mut bool,
@@ -176,6 +280,7 @@ impl<E: Copy + Eq + Hash + Send + Sync> BaseConnection<E> {
                 now,
                 writer,
                 &packet_index,
+                host_tick,
                 world,
                 global_world_manager,
                 &mut self.local_world_manager,
@@ -190,25 +295,31 @@ impl<E: Copy + Eq + Hash + Send + Sync> BaseConnection<E> {
         &mut self,
         global_world_manager: &mut dyn GlobalWorldManagerType<E>,
         world: &mut W,
+        tick: Tick,
     ) -> Vec<EntityEvent<E>> {
         let mut output = Vec::new();
 
         let remote_entities = self.local_world_manager.remote_entities();
 
         for entity in remote_entities {
+            // Despawn from global world manager
+            global_world_manager.remote_despawn_entity(&entity);
+
+            // the Entity may already have been despawned locally
+            if !world.has_entity(&entity) {
+                continue;
+            }
+
             // Generate remove event for each component, handing references off just in
             // case
             for component_kind in world.component_kinds(&entity) {
                 if let Some(component) = world.remove_component_of_kind(&entity, &component_kind) {
-                    output.push(EntityEvent::<E>::RemoveComponent(entity, component));
+                    output.push(EntityEvent::<E>::RemoveComponent(tick, entity, component));
                 }
             }
 
-            // Despawn from global world manager
-            global_world_manager.remote_despawn_entity(&entity);
-
             // Generate despawn event
-            output.push(EntityEvent::DespawnEntity(entity));
+            output.push(EntityEvent::DespawnEntity(tick, entity));
 
             // Despawn entity
             world.despawn_entity(&entity);
@@ -1,12 +1,18 @@
 pub mod ack_manager;
+pub mod bandwidth_breakdown;
 pub mod bandwidth_monitor;
 pub mod base_connection;
 pub mod compression_config;
 pub mod connection_config;
 pub mod decoder;
 pub mod encoder;
+pub mod network_stats;
 pub mod packet_notifiable;
 pub mod packet_type;
 pub mod ping_store;
+pub mod prometheus_exporter;
+pub mod recording;
 pub mod sequence_buffer;
 pub mod standard_header;
+pub mod tick_interval_channel;
+pub mod tick_interval_message;
@@ -0,0 +1,379 @@
+use std::collections::HashMap;
+
+use crate::messages::{
+    channels::channel_kinds::{ChannelKind, ChannelKinds},
+    message_kinds::{MessageKind, MessageKinds},
+};
+
+/// Counts the packets & bytes sent to, and received from, a remote host
+#[derive(Clone, Copy, Default)]
+pub struct PacketCounter {
+    pub packets_sent: u64,
+    pub bytes_sent: u64,
+    pub packets_received: u64,
+    pub bytes_received: u64,
+}
+
+impl PacketCounter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn record_sent(&mut self, bytes: usize) {
+        self.packets_sent += 1;
+        self.bytes_sent += bytes as u64;
+    }
+
+    pub fn record_received(&mut self, bytes: usize) {
+        self.packets_received += 1;
+        self.bytes_received += bytes as u64;
+    }
+}
+
+/// Networking statistics of one connection, or of every connection combined.
+/// Everything but `waitlist_size` & `entities_in_scope` counts up from when
+/// the connection was established.
+#[derive(Clone, Default)]
+pub struct NetworkStats {
+    pub packets_sent: u64,
+    pub packets_received: u64,
+    /// Packets carrying data which the remote host never acknowledged
+    pub packets_lost: u64,
+    pub bytes_sent: u64,
+    pub bytes_received: u64,
+    /// Messages resent on each reliable Channel, by Channel name
+    pub channel_resends: HashMap<String, u64>,
+    /// Bytes of Messages sent on each Channel, by Channel name
+    pub channel_bytes_sent: HashMap<String, u64>,
+    /// Bytes of Message payloads sent, by Message name
+    pub message_bytes_sent: HashMap<String, u64>,
+    /// Bytes of Component inserts & updates sent, by Component name
+    pub component_bytes_sent: HashMap<String, u64>,
+    /// Tick Buffered Messages dropped because the send buffer overflowed, or
+    /// because their Tick passed before they were delivered
+    pub messages_dropped: u64,
+    /// Received Messages & Components waiting on Entities to come into scope
+    pub waitlist_size: u64,
+    /// Entities replicated over the connection, in either direction
+    pub entities_in_scope: u64,
+    /// Failed Client handshakes, by reason. Only the Server counts these.
+    pub handshake_failures: HashMap<String, u64>,
+}
+
+impl NetworkStats {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn add_packets(&mut self, counter: &PacketCounter) {
+        self.packets_sent += counter.packets_sent;
+        self.bytes_sent += counter.bytes_sent;
+        self.packets_received += counter.packets_received;
+        self.bytes_received += counter.bytes_received;
+    }
+
+    /// Adds the bits written on each Channel to `channel_bytes_sent`
+    pub fn add_channel_bits(
+        &mut self,
+        channel_kinds: &ChannelKinds,
+        channel_bits: &HashMap<ChannelKind, u64>,
+    ) {
+        for (channel_kind, bits) in channel_bits {
+            *self
+                .channel_bytes_sent
+                .entry(channel_kinds.kind_to_name(channel_kind))
+                .or_default() += bits / 8;
+        }
+    }
+
+    /// Adds the payload bits written for each kind of Message to
+    /// `message_bytes_sent`
+    pub fn add_message_bits(
+        &mut self,
+        message_kinds: &MessageKinds,
+        message_bits: &HashMap<MessageKind, u64>,
+    ) {
+        for (message_kind, bits) in message_bits {
+            *self
+                .message_bytes_sent
+                .entry(message_kinds.kind_to_name(message_kind))
+                .or_default() += bits / 8;
+        }
+    }
+
+    /// Adds another NetworkStats into this one
+    pub fn merge(&mut self, other: &NetworkStats) {
+        self.packets_sent += other.packets_sent;
+        self.packets_received += other.packets_received;
+        self.packets_lost += other.packets_lost;
+        self.bytes_sent += other.bytes_sent;
+        self.bytes_received += other.bytes_received;
+        merge_counts(&mut self.channel_resends, &other.channel_resends);
+        merge_counts(&mut self.channel_bytes_sent, &other.channel_bytes_sent);
+        merge_counts(&mut self.message_bytes_sent, &other.message_bytes_sent);
+        merge_counts(&mut self.component_bytes_sent, &other.component_bytes_sent);
+        self.messages_dropped += other.messages_dropped;
+        self.waitlist_size += other.waitlist_size;
+        self.entities_in_scope += other.entities_in_scope;
+        merge_counts(&mut self.handshake_failures, &other.handshake_failures);
+    }
+}
+
+fn merge_counts(counts: &mut HashMap<String, u64>, other: &HashMap<String, u64>) {
+    for (name, count) in other {
+        *counts.entry(name.clone()).or_default() += count;
+    }
+}
+
+// PrometheusWriter
+
+struct MetricFamily {
+    name: String,
+    help: &'static str,
+    metric_type: &'static str,
+    samples: Vec<String>,
+}
+
+/// Writes NetworkStats in the Prometheus text exposition format
+#[derive(Default)]
+pub struct PrometheusWriter {
+    families: Vec<MetricFamily>,
+}
+
+impl PrometheusWriter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the samples of one NetworkStats, named with the given prefix &
+    /// tagged with the given labels, i.e. `("user", "3")`
+    pub fn add_stats(&mut self, prefix: &str, labels: &[(&str, &str)], stats: &NetworkStats) {
+        self.add_counter(
+            prefix,
+            "packets_sent_total",
+            "Packets sent",
+            labels,
+            stats.packets_sent,
+        );
+        self.add_counter(
+            prefix,
+            "packets_received_total",
+            "Packets received",
+            labels,
+            stats.packets_received,
+        );
+        self.add_counter(
+            prefix,
+            "packets_lost_total",
+            "Data packets never acknowledged by the remote host",
+            labels,
+            stats.packets_lost,
+        );
+        self.add_counter(
+            prefix,
+            "bytes_sent_total",
+            "Bytes sent",
+            labels,
+            stats.bytes_sent,
+        );
+        self.add_counter(
+            prefix,
+            "bytes_received_total",
+            "Bytes received",
+            labels,
+            stats.bytes_received,
+        );
+        self.add_counts(
+            prefix,
+            "channel_resends_total",
+            "Messages resent on a reliable Channel",
+            labels,
+            "channel",
+            &stats.channel_resends,
+        );
+        self.add_counts(
+            prefix,
+            "channel_bytes_sent_total",
+            "Bytes of Messages sent on a Channel",
+            labels,
+            "channel",
+            &stats.channel_bytes_sent,
+        );
+        self.add_counts(
+            prefix,
+            "message_bytes_sent_total",
+            "Bytes of Message payloads sent",
+            labels,
+            "message",
+            &stats.message_bytes_sent,
+        );
+        self.add_counts(
+            prefix,
+            "component_bytes_sent_total",
+            "Bytes of Component inserts & updates sent",
+            labels,
+            "component",
+            &stats.component_bytes_sent,
+        );
+        self.add_counter(
+            prefix,
+            "messages_dropped_total",
+            "Tick Buffered Messages dropped before being delivered",
+            labels,
+            stats.messages_dropped,
+        );
+        self.add_sample(
+            prefix,
+            "waitlist_size",
+            "Received Messages & Components waiting on Entities",
+            "gauge",
+            labels,
+            stats.waitlist_size,
+        );
+        self.add_sample(
+            prefix,
+            "entities_in_scope",
+            "Entities replicated over the connection",
+            "gauge",
+            labels,
+            stats.entities_in_scope,
+        );
+        self.add_counts(
+            prefix,
+            "handshake_failures_total",
+            "Failed Client handshakes",
+            labels,
+            "reason",
+            &stats.handshake_failures,
+        );
+    }
+
+    fn add_counter(
+        &mut self,
+        prefix: &str,
+        name: &str,
+        help: &'static str,
+        labels: &[(&str, &str)],
+        value: u64,
+    ) {
+        self.add_sample(prefix, name, help, "counter", labels, value);
+    }
+
+    fn add_counts(
+        &mut self,
+        prefix: &str,
+        name: &str,
+        help: &'static str,
+        labels: &[(&str, &str)],
+        count_label: &str,
+        counts: &HashMap<String, u64>,
+    ) {
+        // sorted, so that the output is stable between exports
+        let mut sorted_counts: Vec<(&String, &u64)> = counts.iter().collect();
+        sorted_counts.sort();
+
+        for (count_name, value) in sorted_counts {
+            let mut count_labels = labels.to_vec();
+            count_labels.push((count_label, count_name));
+            self.add_counter(prefix, name, help, &count_labels, *value);
+        }
+    }
+
+    fn add_sample(
+        &mut self,
+        prefix: &str,
+        name: &str,
+        help: &'static str,
+        metric_type: &'static str,
+        labels: &[(&str, &str)],
+        value: u64,
+    ) {
+        let full_name = format!("{prefix}_{name}");
+
+        let mut sample = full_name.clone();
+        if !labels.is_empty() {
+            let label_list: Vec<String> = labels
+                .iter()
+                .map(|(key, value)| format!("{key}=\"{}\"", escape_label_value(value)))
+                .collect();
+            sample.push('{');
+            sample.push_str(&label_list.join(","));
+            sample.push('}');
+        }
+        sample.push_str(&format!(" {value}"));
+
+        match self
+            .families
+            .iter_mut()
+            .find(|family| family.name == full_name)
+        {
+            Some(family) => family.samples.push(sample),
+            None => self.families.push(MetricFamily {
+                name: full_name,
+                help,
+                metric_type,
+                samples: vec![sample],
+            }),
+        }
+    }
+
+    pub fn to_text(&self) -> String {
+        let mut output = String::new();
+        for family in &self.families {
+            output.push_str(&format!("# HELP {} {}\n", family.name, family.help));
+            output.push_str(&format!("# TYPE {} {}\n", family.name, family.metric_type));
+            for sample in &family.samples {
+                output.push_str(sample);
+                output.push('\n');
+            }
+        }
+        output
+    }
+}
+
+fn escape_label_value(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{NetworkStats, PrometheusWriter};
+
+    #[test]
+    fn writes_prometheus_text() {
+        let mut stats = NetworkStats::new();
+        stats.packets_sent = 12;
+        stats
+            .channel_bytes_sent
+            .insert("PlayerCommandChannel".to_string(), 340);
+
+        let mut other_stats = stats.clone();
+        other_stats.packets_sent = 3;
+        stats.merge(&other_stats);
+        assert_eq!(stats.packets_sent, 15);
+        assert_eq!(stats.channel_bytes_sent["PlayerCommandChannel"], 680);
+
+        let mut writer = PrometheusWriter::new();
+        writer.add_stats("naia_connection", &[("user", "1")], &other_stats);
+        writer.add_stats("naia_connection", &[("user", "2\"")], &stats);
+        let text = writer.to_text();
+
+        assert!(text.starts_with(
+            "# HELP naia_connection_packets_sent_total Packets sent\n\
+             # TYPE naia_connection_packets_sent_total counter\n\
+             naia_connection_packets_sent_total{user=\"1\"} 3\n\
+             naia_connection_packets_sent_total{user=\"2\\\"\"} 15\n"
+        ));
+        assert!(text.contains(
+            "naia_connection_channel_bytes_sent_total{user=\"2\\\"\",channel=\"PlayerCommandChannel\"} 680\n"
+        ));
+        assert_eq!(
+            text.matches("# TYPE naia_connection_waitlist_size gauge")
+                .count(),
+            1
+        );
+    }
+}
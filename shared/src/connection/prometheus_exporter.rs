@@ -0,0 +1,131 @@
+use std::{
+    net::SocketAddr,
+    sync::{Arc, Mutex},
+};
+
+#[cfg(not(target_arch = "wasm32"))]
+use std::{
+    io::{self, Read, Write},
+    net::TcpListener,
+    sync::Weak,
+    thread,
+};
+
+enum ExportTarget {
+    Endpoint(SocketAddr, Arc<Mutex<String>>),
+    Callback(Box<dyn FnMut(&str) + Send>),
+}
+
+/// Hands metrics written in the Prometheus text format, i.e. by
+/// `Server::prometheus_metrics()`, to a local HTTP endpoint or a callback.
+/// Call `export()` whenever the metrics should be refreshed.
+pub struct PrometheusExporter {
+    target: ExportTarget,
+}
+
+impl PrometheusExporter {
+    /// Serves the most recently exported metrics to any HTTP request on the
+    /// given address, for a Prometheus server to scrape. The endpoint runs on
+    /// its own thread, which stops at the first request after the exporter
+    /// is dropped.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn endpoint(address: SocketAddr) -> io::Result<Self> {
+        let listener = TcpListener::bind(address)?;
+        let local_address = listener.local_addr()?;
+        let metrics = Arc::new(Mutex::new(String::new()));
+        let weak_metrics = Arc::downgrade(&metrics);
+
+        thread::spawn(move || serve_metrics(listener, weak_metrics));
+
+        Ok(Self {
+            target: ExportTarget::Endpoint(local_address, metrics),
+        })
+    }
+
+    /// Gets the address the endpoint is listening on, if exporting to one
+    pub fn endpoint_address(&self) -> Option<SocketAddr> {
+        match &self.target {
+            ExportTarget::Endpoint(address, _) => Some(*address),
+            ExportTarget::Callback(_) => None,
+        }
+    }
+
+    /// Passes the metrics to the given callback on every export
+    pub fn callback<F: FnMut(&str) + Send + 'static>(callback: F) -> Self {
+        Self {
+            target: ExportTarget::Callback(Box::new(callback)),
+        }
+    }
+
+    pub fn export(&mut self, metrics: &str) {
+        match &mut self.target {
+            ExportTarget::Endpoint(_, latest_metrics) => {
+                *latest_metrics.lock().unwrap() = metrics.to_string();
+            }
+            ExportTarget::Callback(callback) => callback(metrics),
+        }
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn serve_metrics(listener: TcpListener, metrics: Weak<Mutex<String>>) {
+    for stream in listener.incoming() {
+        let Some(metrics) = metrics.upgrade() else {
+            return;
+        };
+        let Ok(mut stream) = stream else {
+            continue;
+        };
+
+        // the request is not inspected, every path returns the metrics
+        let mut request = [0; 1024];
+        if stream.read(&mut request).is_err() {
+            continue;
+        }
+
+        let body = metrics.lock().unwrap().clone();
+        let response = format!(
+            "HTTP/1.1 200 OK\r\nContent-Type: text/plain; version=0.0.4\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+            body.len(),
+            body
+        );
+        let _ = stream.write_all(response.as_bytes());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        io::{Read, Write},
+        net::TcpStream,
+        sync::{Arc, Mutex},
+    };
+
+    use super::PrometheusExporter;
+
+    #[test]
+    fn exports_to_callback() {
+        let exported = Arc::new(Mutex::new(String::new()));
+        let callback_exported = exported.clone();
+        let mut exporter = PrometheusExporter::callback(move |metrics| {
+            *callback_exported.lock().unwrap() = metrics.to_string();
+        });
+
+        exporter.export("naia_packets_sent_total 4\n");
+        assert_eq!(*exported.lock().unwrap(), "naia_packets_sent_total 4\n");
+    }
+
+    #[test]
+    fn serves_metrics_over_http() {
+        let mut exporter = PrometheusExporter::endpoint("127.0.0.1:0".parse().unwrap()).unwrap();
+        exporter.export("naia_packets_sent_total 4\n");
+
+        let mut stream = TcpStream::connect(exporter.endpoint_address().unwrap()).unwrap();
+        stream.write_all(b"GET /metrics HTTP/1.1\r\n\r\n").unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+
+        assert!(response.starts_with("HTTP/1.1 200 OK\r\n"));
+        assert!(response.ends_with("\r\n\r\nnaia_packets_sent_total 4\n"));
+    }
+}
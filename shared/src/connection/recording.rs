@@ -0,0 +1,146 @@
+use std::time::Duration;
+
+use naia_serde::{BitReader, GrowableBitWriter, Serde, SerdeErr, SerdeInternal};
+
+use crate::{game_time::GameInstant, types::Tick, wrapping_number::sequence_greater_than};
+
+/// A packet sent by the Server to a recorded User
+#[derive(Clone, PartialEq, SerdeInternal)]
+pub struct RecordedPacket {
+    /// Milliseconds since the Recording started
+    pub millis: u32,
+    /// The Server Tick the packet was sent on
+    pub tick: Tick,
+    pub payload: Vec<u8>,
+}
+
+/// A snapshot of the replicated World, taken while recording. A replay can
+/// seek back to it, playing every packet before `packet_index` at once.
+#[derive(Clone, PartialEq, SerdeInternal)]
+pub struct Keyframe {
+    pub tick: Tick,
+    /// Index of the first packet sent after the snapshot was taken
+    pub packet_index: u32,
+    /// Bytes written by `Server::snapshot()`
+    pub snapshot: Vec<u8>,
+}
+
+/// Everything the Server replicated to one User over a span of time, which
+/// can be played back into a Client
+#[derive(Clone, PartialEq, SerdeInternal)]
+pub struct Recording {
+    start_tick: Tick,
+    start_tick_instant: GameInstant,
+    start_game_instant: GameInstant,
+    tick_duration_micros: u32,
+    packets: Vec<RecordedPacket>,
+    keyframes: Vec<Keyframe>,
+}
+
+impl Recording {
+    pub fn new(
+        start_tick: Tick,
+        start_tick_instant: GameInstant,
+        start_game_instant: GameInstant,
+        tick_duration: Duration,
+    ) -> Self {
+        Self {
+            start_tick,
+            start_tick_instant,
+            start_game_instant,
+            tick_duration_micros: tick_duration.as_micros() as u32,
+            packets: Vec::new(),
+            keyframes: Vec::new(),
+        }
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, SerdeErr> {
+        let mut reader = BitReader::new(bytes);
+        Self::de(&mut reader)
+    }
+
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut writer = GrowableBitWriter::new();
+        self.ser(&mut writer);
+        writer.to_bytes()
+    }
+
+    pub fn push_packet(&mut self, millis: u32, tick: Tick, payload: &[u8]) {
+        self.packets.push(RecordedPacket {
+            millis,
+            tick,
+            payload: payload.to_vec(),
+        });
+    }
+
+    pub fn push_keyframe(&mut self, tick: Tick, snapshot: Vec<u8>) {
+        self.keyframes.push(Keyframe {
+            tick,
+            packet_index: self.packets.len() as u32,
+            snapshot,
+        });
+    }
+
+    /// The Server Tick when the Recording started
+    pub fn start_tick(&self) -> Tick {
+        self.start_tick
+    }
+
+    /// The Server's game time at its last Tick before the Recording started
+    pub fn start_tick_instant(&self) -> &GameInstant {
+        &self.start_tick_instant
+    }
+
+    /// The Server's game time when the Recording started
+    pub fn start_game_instant(&self) -> &GameInstant {
+        &self.start_game_instant
+    }
+
+    /// The Server's average Tick duration when the Recording started
+    pub fn tick_duration(&self) -> Duration {
+        Duration::from_micros(u64::from(self.tick_duration_micros))
+    }
+
+    pub fn packets(&self) -> &[RecordedPacket] {
+        &self.packets
+    }
+
+    pub fn keyframes(&self) -> &[Keyframe] {
+        &self.keyframes
+    }
+
+    /// Gets the newest Keyframe taken at or before the given Tick
+    pub fn keyframe_at(&self, tick: Tick) -> Option<&Keyframe> {
+        self.keyframes
+            .iter()
+            .rev()
+            .find(|keyframe| !sequence_greater_than(keyframe.tick, tick))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use naia_socket_shared::Instant;
+
+    use super::Recording;
+    use crate::game_time::GameInstant;
+
+    #[test]
+    fn round_trips_through_bytes() {
+        let start = Instant::now();
+        let instant = GameInstant::new(&start, &start);
+        let mut recording = Recording::new(10, instant.clone(), instant, Duration::from_millis(50));
+        recording.push_packet(0, 10, &[1, 2, 3]);
+        recording.push_keyframe(11, vec![4; 2000]);
+        recording.push_packet(50, 11, &[5; 1200]);
+
+        let bytes = recording.to_bytes();
+        let restored = Recording::from_bytes(&bytes).unwrap();
+        assert!(restored == recording);
+        assert_eq!(restored.tick_duration(), Duration::from_millis(50));
+        assert_eq!(restored.keyframe_at(12).unwrap().packet_index, 1);
+        assert!(restored.keyframe_at(10).is_none());
+    }
+}
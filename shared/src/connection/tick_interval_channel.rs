@@ -0,0 +1,6 @@
+use crate::Channel;
+
+/// Internal Channel used to let Clients know when the Server's tick interval
+/// changes
+#[derive(Channel)]
+pub struct TickIntervalChannel;
@@ -0,0 +1,27 @@
+use std::time::Duration;
+
+use naia_derive::MessageInternal;
+
+use crate::Tick;
+
+/// Sent when the Server's tick interval changes, so that Clients can re-target
+/// their Ticks to the new rate
+#[derive(MessageInternal)]
+pub struct TickIntervalMessage {
+    /// The Server Tick the new interval took effect on
+    pub tick: Tick,
+    pub interval_micros: u32,
+}
+
+impl TickIntervalMessage {
+    pub fn new(tick: Tick, interval: Duration) -> Self {
+        Self {
+            tick,
+            interval_micros: interval.as_micros() as u32,
+        }
+    }
+
+    pub fn interval(&self) -> Duration {
+        Duration::from_micros(u64::from(self.interval_micros))
+    }
+}
@@ -16,8 +16,8 @@ pub struct GameInstant {
 }
 
 impl GameInstant {
-    pub fn new(start_instant: &Instant) -> Self {
-        let millis = (start_instant.elapsed().as_millis() % GAME_TIME_LIMIT_U128) as u32;
+    pub fn new(start_instant: &Instant, now: &Instant) -> Self {
+        let millis = (now.duration_since(start_instant).as_millis() % GAME_TIME_LIMIT_U128) as u32;
 
         // start_instant should mark the initialization of the Server's TimeManager
         Self { millis }
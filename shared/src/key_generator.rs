@@ -1,6 +1,6 @@
-use std::{collections::VecDeque, marker::PhantomData, time::Duration};
+use std::{collections::VecDeque, marker::PhantomData, sync::Arc, time::Duration};
 
-use naia_socket_shared::Instant;
+use naia_socket_shared::{Clock, Instant};
 
 /// Simple implementation of a store that manages a recycling pool of u16 keys
 pub struct KeyGenerator<K: From<u16> + Into<u16> + Copy> {
@@ -8,16 +8,18 @@ pub struct KeyGenerator<K: From<u16> + Into<u16> + Copy> {
     recycled_keys: VecDeque<u16>,
     recycle_timeout: Duration,
     next_new_key: u16,
+    clock: Arc<dyn Clock>,
     phantom: PhantomData<K>,
 }
 
 impl<K: From<u16> + Into<u16> + Copy> KeyGenerator<K> {
-    pub fn new(recycle_timeout: Duration) -> Self {
+    pub fn new(recycle_timeout: Duration, clock: &Arc<dyn Clock>) -> Self {
         Self {
             recycle_timeout,
             recycling_keys: VecDeque::new(),
             recycled_keys: VecDeque::new(),
             next_new_key: 0,
+            clock: clock.clone(),
             phantom: PhantomData,
         }
     }
@@ -28,7 +30,7 @@ impl<K: From<u16> + Into<u16> + Copy> KeyGenerator<K> {
             let Some((_, instant)) = self.recycling_keys.front() else {
                 break;
             };
-            if instant.elapsed() < self.recycle_timeout {
+            if self.clock.elapsed(instant) < self.recycle_timeout {
                 break;
             }
             let (key, _) = self.recycling_keys.pop_front().unwrap();
@@ -50,7 +52,6 @@ impl<K: From<u16> + Into<u16> + Copy> KeyGenerator<K> {
     /// Recycle a used key, freeing it up
     pub fn recycle_key(&mut self, key: &K) {
         let key_u16: u16 = Into::<u16>::into(*key);
-        self.recycling_keys
-            .push_back((key_u16, Instant::now()));
+        self.recycling_keys.push_back((key_u16, self.clock.now()));
     }
 }
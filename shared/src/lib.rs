@@ -23,12 +23,13 @@ pub use naia_derive::{
     Channel, Message, MessageBevy, MessageHecs, Replicate, ReplicateBevy, ReplicateHecs,
 };
 pub use naia_serde::{
-    BitReader, BitWrite, BitWriter, ConstBitLength, OutgoingPacket, OwnedBitReader, Serde,
-    SerdeBevy, SerdeErr, SerdeHecs, SerdeInternal, UnsignedInteger, UnsignedVariableInteger,
-    MTU_SIZE_BITS, MTU_SIZE_BYTES,
+    BitCounter, BitReader, BitWrite, BitWriter, ConstBitLength, GrowableBitWriter, IntegerError,
+    OutgoingPacket, OwnedBitReader, Serde, SerdeBevy, SerdeErr, SerdeHecs, SerdeInternal,
+    UnsignedInteger, UnsignedVariableInteger, MTU_SIZE_BITS, MTU_SIZE_BYTES,
 };
 pub use naia_socket_shared::{
-    link_condition_logic, Instant, LinkConditionerConfig, Random, SocketConfig, TimeQueue,
+    link_condition_logic, Clock, Instant, LinkConditionerConfig, Random, SocketConfig, SystemClock,
+    TimeQueue, VirtualClock,
 };
 
 mod backends;
@@ -37,6 +38,7 @@ mod connection;
 mod constants;
 mod game_time;
 mod key_generator;
+mod lockstep;
 mod messages;
 mod protocol;
 mod sequence_list;
@@ -46,17 +48,31 @@ mod wrapping_number;
 
 pub use backends::{Timer, Timestamp};
 pub use connection::{
-    ack_manager::AckManager,
+    ack_manager::{AckManager, REDUNDANT_PACKET_ACKS_SIZE},
+    bandwidth_breakdown::{
+        BandwidthBreakdown, BandwidthBreakdownMonitor, BandwidthShare, BandwidthSource,
+    },
     bandwidth_monitor::BandwidthMonitor,
     base_connection::BaseConnection,
     compression_config::{CompressionConfig, CompressionMode},
     connection_config::ConnectionConfig,
     decoder::Decoder,
     encoder::Encoder,
+    network_stats::{NetworkStats, PacketCounter, PrometheusWriter},
     packet_notifiable::PacketNotifiable,
     packet_type::PacketType,
     ping_store::{PingIndex, PingStore},
+    prometheus_exporter::PrometheusExporter,
+    recording::{Keyframe, RecordedPacket, Recording},
     standard_header::StandardHeader,
+    tick_interval_channel::TickIntervalChannel,
+    tick_interval_message::TickIntervalMessage,
+};
+pub use lockstep::{
+    lockstep_channel::LockstepChannel, lockstep_checksum_message::LockstepChecksumMessage,
+    lockstep_frame::LockstepFrame, lockstep_frame_message::LockstepFrameMessage,
+    lockstep_input_channel::LockstepInputChannel, lockstep_input_message::LockstepInputMessage,
+    lockstep_join_message::LockstepJoinMessage, lockstep_plugin::LockstepPlugin,
 };
 pub use messages::{
     channels::{
@@ -64,10 +80,15 @@ pub use messages::{
         channel_kinds::{ChannelKind, ChannelKinds},
         default_channels,
         receivers::{
-            channel_receiver::ChannelReceiver, ordered_reliable_receiver::OrderedReliableReceiver,
+            channel_receiver::ChannelReceiver, indexed_message_reader::IndexedMessageReader,
+            ordered_reliable_receiver::OrderedReliableReceiver,
+            tick_buffer_receiver::TickBufferReceiver,
             unordered_reliable_receiver::UnorderedReliableReceiver,
         },
-        senders::{channel_sender::ChannelSender, reliable_sender::ReliableSender},
+        senders::{
+            channel_sender::ChannelSender, indexed_message_writer::IndexedMessageWriter,
+            reliable_sender::ReliableSender, tick_buffer_sender::TickBufferSender,
+        },
     },
     message::{Message, Message as MessageBevy, Message as MessageHecs, MessageBuilder},
     message_container::MessageContainer,
@@ -81,6 +102,7 @@ pub use world::{
         component_update::{ComponentFieldUpdate, ComponentUpdate},
         diff_mask::DiffMask,
         entity_property::EntityProperty,
+        entity_property_vec::EntityPropertyVec,
         property::Property,
         property_mutate::{PropertyMutate, PropertyMutator},
         replica_ref::{
@@ -92,6 +114,10 @@ pub use world::{
             Replicate, Replicate as ReplicateHecs, Replicate as ReplicateBevy, ReplicateBuilder,
         },
     },
+    delegation::{
+        entity_auth_channel::EntityAuthChannel,
+        entity_auth_message::{EntityAuthAction, EntityAuthMessage, EntityAuthUpdateMessage},
+    },
     entity::{
         entity_action::EntityAction,
         entity_action_receiver::EntityActionReceiver,
@@ -99,29 +125,37 @@ pub use world::{
         entity_converters::{
             EntityAndGlobalEntityConverter, EntityConverter, EntityConverterMut,
             FakeEntityConverter, GlobalWorldManagerType, LocalEntityAndGlobalEntityConverter,
-            LocalEntityAndGlobalEntityConverterMut, LocalEntityConverter,
+            LocalEntityAndGlobalEntityConverterMut, LocalEntityConverter, ReversedEntityConverter,
         },
         entity_ref::EntityRef,
         error::EntityDoesNotExistError,
         global_entity::GlobalEntity,
         local_entity::LocalEntity,
+        prediction_key::PredictionKey,
+    },
+    hierarchy::{
+        entity_hierarchy::EntityHierarchy, entity_hierarchy_channel::EntityHierarchyChannel,
+        entity_parent_message::EntityParentMessage,
     },
     host::{
-        global_diff_handler::GlobalDiffHandler,
+        global_diff_handler::{DuplicateComponentError, GlobalDiffHandler},
         host_world_manager::{HostWorldEvents, HostWorldManager},
         mut_channel::{MutChannelType, MutReceiver},
     },
     local_world_manager::LocalWorldManager,
     remote::{
-        entity_action_event::EntityActionEvent, entity_event::EntityEvent,
+        entity_action_event::EntityActionEvent,
+        entity_event::EntityEvent,
+        remote_state_validator::{RemoteStateValidator, RemoteStateVerdict},
         remote_world_manager::RemoteWorldManager,
     },
+    scope::{entity_scope_channel::EntityScopeChannel, entity_scope_message::EntityScopeMessage},
     world_type::{WorldMutType, WorldRefType},
 };
 
 pub use bigmap::{BigMap, BigMapKey};
 pub use game_time::{GameDuration, GameInstant, GAME_TIME_LIMIT};
 pub use key_generator::KeyGenerator;
-pub use protocol::{Protocol, ProtocolPlugin};
+pub use protocol::{Protocol, ProtocolLockedError, ProtocolPlugin};
 pub use types::{HostType, MessageIndex, PacketIndex, ShortMessageIndex, Tick};
 pub use wrapping_number::{sequence_greater_than, sequence_less_than, wrapping_diff};
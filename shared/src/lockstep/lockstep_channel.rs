@@ -0,0 +1,6 @@
+use crate::Channel;
+
+/// Internal Channel used to deliver lockstep Frames to Clients, and checksums
+/// of simulated Ticks to the Server
+#[derive(Channel)]
+pub struct LockstepChannel;
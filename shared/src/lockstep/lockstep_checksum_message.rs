@@ -0,0 +1,17 @@
+use naia_derive::MessageInternal;
+
+use crate::Tick;
+
+/// Sent by a Client after simulating a lockstep Tick, so that the Server can
+/// compare the resulting states of every player
+#[derive(MessageInternal)]
+pub struct LockstepChecksumMessage {
+    pub tick: Tick,
+    pub checksum: u64,
+}
+
+impl LockstepChecksumMessage {
+    pub fn new(tick: Tick, checksum: u64) -> Self {
+        Self { tick, checksum }
+    }
+}
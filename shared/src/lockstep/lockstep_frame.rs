@@ -0,0 +1,34 @@
+use crate::Tick;
+
+/// The inputs every player sent for one Tick of a lockstep simulation. A
+/// player whose input did not reach the Server in time has no input in the
+/// Frame.
+#[derive(Clone)]
+pub struct LockstepFrame<I> {
+    tick: Tick,
+    inputs: Vec<(u16, I)>,
+}
+
+impl<I> LockstepFrame<I> {
+    pub fn new(tick: Tick, inputs: Vec<(u16, I)>) -> Self {
+        Self { tick, inputs }
+    }
+
+    /// The Tick to simulate with these inputs
+    pub fn tick(&self) -> Tick {
+        self.tick
+    }
+
+    /// Every player's input, ordered by player id
+    pub fn inputs(&self) -> &[(u16, I)] {
+        &self.inputs
+    }
+
+    /// Gets the input of the given player, if it arrived in time
+    pub fn input(&self, player: u16) -> Option<&I> {
+        self.inputs
+            .iter()
+            .find(|(input_player, _)| *input_player == player)
+            .map(|(_, input)| input)
+    }
+}
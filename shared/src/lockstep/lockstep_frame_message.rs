@@ -0,0 +1,53 @@
+use naia_derive::MessageInternal;
+use naia_serde::{BitReader, Serde, SerdeErr};
+
+use crate::{lockstep::lockstep_frame::LockstepFrame, Tick};
+
+/// The serialized inputs of every player for one lockstep Tick
+#[derive(MessageInternal)]
+pub struct LockstepFrameMessage {
+    pub tick: Tick,
+    /// Serialized inputs, keyed by player id
+    pub inputs: Vec<(u16, Vec<u8>)>,
+}
+
+impl LockstepFrameMessage {
+    pub fn new(tick: Tick) -> Self {
+        Self {
+            tick,
+            inputs: Vec::new(),
+        }
+    }
+
+    pub fn to_frame<I: Serde>(&self) -> Result<LockstepFrame<I>, SerdeErr> {
+        let mut inputs = Vec::with_capacity(self.inputs.len());
+        for (player, bytes) in &self.inputs {
+            let mut reader = BitReader::new(bytes);
+            inputs.push((*player, I::de(&mut reader)?));
+        }
+        Ok(LockstepFrame::new(self.tick, inputs))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::LockstepFrameMessage;
+    use crate::lockstep::lockstep_input_message::LockstepInputMessage;
+
+    #[test]
+    fn frame_holds_forwarded_inputs() {
+        let mut message = LockstepFrameMessage::new(7);
+        message
+            .inputs
+            .push((0, LockstepInputMessage::new(&(3u8, true)).input));
+        message
+            .inputs
+            .push((2, LockstepInputMessage::new(&(9u8, false)).input));
+
+        let frame = message.to_frame::<(u8, bool)>().unwrap();
+        assert_eq!(frame.tick(), 7);
+        assert_eq!(frame.input(0), Some(&(3, true)));
+        assert_eq!(frame.input(1), None);
+        assert_eq!(frame.input(2), Some(&(9, false)));
+    }
+}
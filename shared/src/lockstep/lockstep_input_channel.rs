@@ -0,0 +1,5 @@
+use crate::Channel;
+
+/// Internal Tick Buffered Channel used to send lockstep inputs to the Server
+#[derive(Channel)]
+pub struct LockstepInputChannel;
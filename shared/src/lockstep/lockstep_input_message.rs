@@ -0,0 +1,23 @@
+use naia_derive::MessageInternal;
+use naia_serde::{BitReader, GrowableBitWriter, Serde, SerdeErr};
+
+/// A Client's serialized input for the Tick it is buffered on
+#[derive(MessageInternal)]
+pub struct LockstepInputMessage {
+    pub input: Vec<u8>,
+}
+
+impl LockstepInputMessage {
+    pub fn new<I: Serde>(input: &I) -> Self {
+        let mut writer = GrowableBitWriter::new();
+        input.ser(&mut writer);
+        Self {
+            input: writer.to_bytes(),
+        }
+    }
+
+    pub fn to_input<I: Serde>(&self) -> Result<I, SerdeErr> {
+        let mut reader = BitReader::new(&self.input);
+        I::de(&mut reader)
+    }
+}
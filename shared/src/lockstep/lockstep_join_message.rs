@@ -0,0 +1,25 @@
+use naia_derive::MessageInternal;
+
+use crate::Tick;
+
+/// Sent to a Client when it joins a lockstep simulation, ahead of every Frame
+/// the Server still holds
+#[derive(MessageInternal)]
+pub struct LockstepJoinMessage {
+    /// The id of the joining player, which keys its inputs in each Frame
+    pub player: u16,
+    /// The first Tick the joining player simulates
+    pub start_tick: Tick,
+    /// How many Ticks ahead of the Client Tick inputs are scheduled
+    pub input_delay: u16,
+}
+
+impl LockstepJoinMessage {
+    pub fn new(player: u16, start_tick: Tick, input_delay: u16) -> Self {
+        Self {
+            player,
+            start_tick,
+            input_delay,
+        }
+    }
+}
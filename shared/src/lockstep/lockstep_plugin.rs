@@ -0,0 +1,54 @@
+use crate::{
+    messages::channels::channel::{
+        ChannelDirection, ChannelMode, ReliableSettings, TickBufferSettings,
+    },
+    LockstepChannel, LockstepChecksumMessage, LockstepFrameMessage, LockstepInputChannel,
+    LockstepInputMessage, LockstepJoinMessage, Protocol, ProtocolPlugin,
+};
+
+/// Registers the Messages & Channels which the Server's & Client's `Lockstep`
+/// communicate over. Both sides must add it, in the same order relative to
+/// the application's own Messages & Channels.
+pub struct LockstepPlugin;
+
+impl ProtocolPlugin for LockstepPlugin {
+    fn build(&self, protocol: &mut Protocol) {
+        protocol
+            .add_message::<LockstepInputMessage>()
+            .add_message::<LockstepFrameMessage>()
+            .add_message::<LockstepJoinMessage>()
+            .add_message::<LockstepChecksumMessage>()
+            .add_channel::<LockstepChannel>(
+                ChannelDirection::Bidirectional,
+                ChannelMode::OrderedReliable(ReliableSettings::default()),
+            )
+            .add_channel::<LockstepInputChannel>(
+                ChannelDirection::ClientToServer,
+                ChannelMode::TickBuffered(TickBufferSettings::default()),
+            );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{ChannelKind, LockstepInputChannel, Protocol};
+
+    use super::LockstepPlugin;
+
+    fn has_input_channel(protocol: &Protocol) -> bool {
+        let input_channel = ChannelKind::of::<LockstepInputChannel>();
+        protocol
+            .channel_kinds
+            .channels()
+            .iter()
+            .any(|(kind, _)| *kind == input_channel)
+    }
+
+    #[test]
+    fn lockstep_is_opt_in() {
+        assert!(!has_input_channel(&Protocol::default()));
+
+        let protocol = Protocol::builder().add_plugin(LockstepPlugin).build();
+        assert!(has_input_channel(&protocol));
+    }
+}
@@ -0,0 +1,8 @@
+pub mod lockstep_channel;
+pub mod lockstep_checksum_message;
+pub mod lockstep_frame;
+pub mod lockstep_frame_message;
+pub mod lockstep_input_channel;
+pub mod lockstep_input_message;
+pub mod lockstep_join_message;
+pub mod lockstep_plugin;
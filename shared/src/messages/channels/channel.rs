@@ -10,8 +10,8 @@ pub struct ChannelSettings {
 
 impl ChannelSettings {
     pub fn new(mode: ChannelMode, direction: ChannelDirection) -> Self {
-        if mode.tick_buffered() && direction != ChannelDirection::ClientToServer {
-            panic!("TickBuffered Messages are only allowed to be sent from Client to Server");
+        if mode.tick_buffered() && direction == ChannelDirection::Bidirectional {
+            panic!("TickBuffered Messages are only allowed to be sent in one direction, either from Client to Server or from Server to Client");
         }
 
         Self { mode, direction }
@@ -33,7 +33,7 @@ impl ChannelKind {
 
     pub fn de(channel_kinds: &ChannelKinds, reader: &mut BitReader) -> Result<Self, SerdeErr> {
         let net_id: NetId = NetId::de(reader)?;
-        Ok(channel_kinds.net_id_to_kind(&net_id))
+        channel_kinds.net_id_to_kind(&net_id).ok_or(SerdeErr)
     }
 }
 
@@ -48,6 +48,7 @@ pub struct ChannelKinds {
     current_net_id: NetId,
     kind_map: HashMap<ChannelKind, (NetId, ChannelSettings)>,
     net_id_map: HashMap<NetId, ChannelKind>,
+    name_map: HashMap<ChannelKind, String>,
 }
 
 impl ChannelKinds {
@@ -56,6 +57,7 @@ impl ChannelKinds {
             current_net_id: 0,
             kind_map: HashMap::new(),
             net_id_map: HashMap::new(),
+            name_map: HashMap::new(),
         }
     }
 
@@ -64,6 +66,7 @@ impl ChannelKinds {
         let net_id = self.current_net_id;
         self.kind_map.insert(channel_kind, (net_id, settings));
         self.net_id_map.insert(net_id, channel_kind);
+        self.name_map.insert(channel_kind, channel_name::<C>());
         self.current_net_id += 1;
         //TODO: check for current_id overflow?
     }
@@ -83,10 +86,15 @@ impl ChannelKinds {
         settings.clone()
     }
 
-    fn net_id_to_kind(&self, net_id: &NetId) -> ChannelKind {
-        return *self.net_id_map.get(net_id).expect(
-            "Must properly initialize Channel with Protocol via `add_channel()` function!",
-        );
+    pub fn kind_to_name(&self, kind: &ChannelKind) -> String {
+        self.name_map
+            .get(kind)
+            .cloned()
+            .expect("Must properly initialize Channel with Protocol via `add_channel()` function!")
+    }
+
+    fn net_id_to_kind(&self, net_id: &NetId) -> Option<ChannelKind> {
+        self.net_id_map.get(net_id).copied()
     }
 
     fn kind_to_net_id(&self, channel_kind: &ChannelKind) -> NetId {
@@ -99,3 +107,10 @@ impl ChannelKinds {
             .0;
     }
 }
+
+/// Gets the name of a Channel type, without its module path
+fn channel_name<C: Channel>() -> String {
+    let type_name = std::any::type_name::<C>();
+    let path = type_name.split('<').next().unwrap_or(type_name);
+    path.rsplit("::").next().unwrap_or(path).to_string()
+}
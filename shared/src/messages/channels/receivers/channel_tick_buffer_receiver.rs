@@ -1,13 +1,14 @@
 use std::collections::{HashMap, VecDeque};
 
-use naia_shared::{
-    sequence_greater_than, BitReader, LocalEntityAndGlobalEntityConverter, MessageContainer,
-    MessageKinds, Serde, SerdeErr, ShortMessageIndex, Tick, TickBufferSettings,
-    UnsignedVariableInteger,
+use naia_serde::{BitReader, Serde, SerdeErr, UnsignedVariableInteger};
+
+use crate::{
+    sequence_greater_than, LocalEntityAndGlobalEntityConverter, MessageContainer, MessageKinds,
+    ShortMessageIndex, Tick, TickBufferSettings,
 };
 
-/// Receive updates from the client and store them in a buffer along with the corresponding
-/// client tick.
+/// Receive messages from the remote host and store them in a buffer along with the
+/// corresponding remote tick.
 pub struct ChannelTickBufferReceiver {
     incoming_messages: IncomingMessages,
 }
@@ -99,7 +100,7 @@ impl ChannelTickBufferReceiver {
 struct IncomingMessages {
     // front is small, back is big
     // front is present, back is future
-    /// Buffer containing messages from the client, along with the corresponding tick
+    /// Buffer containing messages from the remote host, along with the corresponding tick
     /// We do not store anything for empty ticks
     buffer: VecDeque<(Tick, HashMap<ShortMessageIndex, MessageContainer>)>,
 }
@@ -111,8 +112,8 @@ impl IncomingMessages {
         }
     }
 
-    /// Insert a message from the client into the tick-buffer
-    /// Will only insert messages that are from future ticks compared to the current server tick
+    /// Insert a message from the remote host into the tick-buffer
+    /// Will only insert messages that are from future ticks compared to the current host tick
     pub fn insert(
         &mut self,
         host_tick: &Tick,
@@ -155,7 +156,7 @@ impl IncomingMessages {
                             return false;
                         }
                     } else if sequence_greater_than(*message_tick, *existing_tick) {
-                        // incoming client tick is larger (more in the future) than found tick
+                        // incoming remote tick is larger (more in the future) than found tick
                         insert = true;
                     }
                 }
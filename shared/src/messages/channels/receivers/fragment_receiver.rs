@@ -1,6 +1,6 @@
 use std::collections::HashMap;
 
-use log::info;
+use log::{info, warn};
 
 use naia_serde::BitReader;
 
@@ -11,7 +11,7 @@ use crate::{
 
 pub struct FragmentReceiver {
     current_index: MessageIndex,
-    map: HashMap<FragmentId, (u32, Vec<Box<[u8]>>)>,
+    map: HashMap<FragmentId, (u32, Vec<Option<Box<[u8]>>>)>,
 }
 
 impl FragmentReceiver {
@@ -43,30 +43,38 @@ impl FragmentReceiver {
             .downcast::<FragmentedMessage>()
             .unwrap();
         let fragment_id = fragment.id();
-        let fragment_index = fragment.index();
+        let fragment_index = fragment.index().as_usize();
         let fragment_total = fragment.total().as_usize();
         info!("fragment_total: {fragment_total}");
-        if !self.map.contains_key(&fragment_id) {
-            self.map
-                .insert(fragment_id, (0, vec![Box::new([]); fragment_total]));
+        let (fragments_received, fragment_list) = self
+            .map
+            .entry(fragment_id)
+            .or_insert_with(|| (0, vec![None; fragment_total]));
+        if fragment_list.len() != fragment_total
+            || fragment_index >= fragment_total
+            || fragment_list[fragment_index].is_some()
+        {
+            warn!("Received a malformed message fragment, dropping it");
+            return None;
         }
-        let (fragments_received, fragment_list) = self.map.get_mut(&fragment_id).unwrap();
-        fragment_list[fragment_index.as_usize()] = fragment.to_payload();
+        fragment_list[fragment_index] = Some(fragment.to_payload());
         *fragments_received += 1;
         if *fragments_received != fragment_total as u32 {
             return None;
         }
 
         // we have received all fragments! put it all together
-        let (_, fragment_list) = self.map.remove(&fragment_id).unwrap();
-        let concat_list = fragment_list.concat();
+        let (_, fragment_list) = self.map.remove(&fragment_id)?;
+        let concat_list = fragment_list
+            .into_iter()
+            .flatten()
+            .collect::<Vec<_>>()
+            .concat();
         let mut reader = BitReader::new(&concat_list);
-        let full_message_result = message_kinds.read(&mut reader, converter);
-        if full_message_result.is_err() {
-            // TODO: bubble up error instead of panicking here
-            panic!("Cannot read fragmented message!");
-        }
-        let full_message = full_message_result.unwrap();
+        let Ok(full_message) = message_kinds.read(&mut reader, converter) else {
+            warn!("Cannot read fragmented message, dropping it");
+            return None;
+        };
         let output = Some((self.current_index, full_message));
         self.current_index = self.current_index.wrapping_add(1);
         output
@@ -54,7 +54,8 @@ impl<A: ReceiverArranger> ReliableMessageReceiver<A> {
     ) {
         let Some((first_index, full_message)) =
             self.fragment_receiver
-                .receive(message_kinds, converter, message) else {
+                .receive(message_kinds, converter, message)
+        else {
             return;
         };
 
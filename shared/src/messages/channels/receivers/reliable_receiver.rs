@@ -17,7 +17,8 @@ impl<M> ReliableReceiver<M> {
         }
     }
 
-    pub(crate) fn buffer_message(&mut self, message_index: MessageIndex, message: M) {
+    /// Buffers a received message, returning whether it was not received before
+    pub(crate) fn buffer_message(&mut self, message_index: MessageIndex, message: M) -> bool {
         // moving from oldest incoming message to newest
         // compare existing slots and see if the message_index has been instantiated
         // already if it has, put the message into the slot
@@ -27,7 +28,7 @@ impl<M> ReliableReceiver<M> {
 
         if sequence_less_than(message_index, self.oldest_received_message_index) {
             // already moved sliding window past this message id
-            return;
+            return false;
         }
 
         let mut current_index = 0;
@@ -42,7 +43,7 @@ impl<M> ReliableReceiver<M> {
                             should_push_message = true;
                         } else {
                             // already received this message
-                            return;
+                            return false;
                         }
                     }
                 }
@@ -63,7 +64,7 @@ impl<M> ReliableReceiver<M> {
             if should_push_message {
                 self.incoming_messages.push((message_index, message));
                 self.clear_old_messages();
-                return;
+                return true;
             }
 
             current_index += 1;
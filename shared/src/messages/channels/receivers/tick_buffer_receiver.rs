@@ -1,21 +1,29 @@
 use std::collections::HashMap;
 
-use naia_shared::{
-    BitReader, ChannelKind, ChannelKinds, ChannelMode, LocalEntityAndGlobalEntityConverter,
-    MessageContainer, Protocol, Serde, SerdeErr, Tick,
-};
+use naia_serde::{BitReader, Serde, SerdeErr};
 
-use crate::connection::channel_tick_buffer_receiver::ChannelTickBufferReceiver;
+use crate::{
+    messages::channels::receivers::channel_tick_buffer_receiver::ChannelTickBufferReceiver,
+    types::HostType, ChannelKind, ChannelKinds, ChannelMode, LocalEntityAndGlobalEntityConverter,
+    MessageContainer, Protocol, Tick,
+};
 
 pub struct TickBufferReceiver {
     channel_receivers: HashMap<ChannelKind, ChannelTickBufferReceiver>,
 }
 
 impl TickBufferReceiver {
-    pub fn new(channel_kinds: &ChannelKinds) -> Self {
+    pub fn new(host_type: HostType, channel_kinds: &ChannelKinds) -> Self {
         // initialize receivers
         let mut channel_receivers = HashMap::new();
         for (channel_kind, channel_settings) in channel_kinds.channels() {
+            let can_receive = match host_type {
+                HostType::Server => channel_settings.can_send_to_server(),
+                HostType::Client => channel_settings.can_send_to_client(),
+            };
+            if !can_receive {
+                continue;
+            }
             if let ChannelMode::TickBuffered(settings) = channel_settings.mode {
                 channel_receivers.insert(
                     channel_kind,
@@ -48,7 +56,9 @@ impl TickBufferReceiver {
             let channel_kind = ChannelKind::de(&protocol.channel_kinds, reader)?;
 
             // continue read inside channel
-            let channel = self.channel_receivers.get_mut(&channel_kind).unwrap();
+            let Some(channel) = self.channel_receivers.get_mut(&channel_kind) else {
+                return Err(SerdeErr);
+            };
             channel.read_messages(
                 converter,
                 &protocol.message_kinds,
@@ -1,8 +1,13 @@
+use std::collections::HashMap;
+
 use naia_serde::BitWriter;
 use naia_socket_shared::Instant;
 
 use crate::{
-    messages::{message_container::MessageContainer, message_kinds::MessageKinds},
+    messages::{
+        message_container::MessageContainer,
+        message_kinds::{MessageKind, MessageKinds},
+    },
     types::MessageIndex,
     LocalEntityAndGlobalEntityConverterMut,
 };
@@ -16,15 +21,19 @@ pub trait ChannelSender<P>: Send + Sync {
     fn has_messages(&self) -> bool;
     /// Called when it receives acknowledgement that a Message has been received
     fn notify_message_delivered(&mut self, message_index: &MessageIndex);
+    /// Returns how many times Messages have been resent, for reliable channels
+    fn resend_count(&self) -> u64;
 }
 
 pub trait MessageChannelSender: ChannelSender<MessageContainer> {
-    /// Gets Messages from the internal buffer and writes it to the BitWriter
+    /// Gets Messages from the internal buffer and writes it to the BitWriter,
+    /// adding the payload bits written to `message_bits_sent`
     fn write_messages(
         &mut self,
         message_kinds: &MessageKinds,
         converter: &mut dyn LocalEntityAndGlobalEntityConverterMut,
         writer: &mut BitWriter,
         has_written: &mut bool,
+        message_bits_sent: &mut HashMap<MessageKind, u64>,
     ) -> Option<Vec<MessageIndex>>;
 }
@@ -1,11 +1,13 @@
-use std::collections::VecDeque;
+use std::collections::{HashMap, VecDeque};
 
 use log::warn;
 
-use naia_shared::{
-    sequence_greater_than, sequence_less_than, wrapping_diff, BitWrite, BitWriter,
-    LocalEntityAndGlobalEntityConverterMut, MessageContainer, MessageKinds, Serde,
-    ShortMessageIndex, Tick, TickBufferSettings, UnsignedVariableInteger,
+use naia_serde::{BitWrite, BitWriter, Serde, UnsignedVariableInteger};
+
+use crate::{
+    sequence_greater_than, sequence_less_than, wrapping_diff,
+    LocalEntityAndGlobalEntityConverterMut, MessageContainer, MessageKind, MessageKinds,
+    ShortMessageIndex, Tick, TickBufferSettings,
 };
 
 pub struct ChannelTickBufferSender {
@@ -27,22 +29,22 @@ impl ChannelTickBufferSender {
 
     pub fn collect_outgoing_messages(
         &mut self,
-        client_sending_tick: &Tick,
-        server_receivable_tick: &Tick,
+        host_sending_tick: &Tick,
+        remote_receivable_tick: &Tick,
     ) {
-        if sequence_greater_than(*client_sending_tick, self.last_sent) || self.never_sent {
-            // Remove messages that would never be able to reach the Server
+        if sequence_greater_than(*host_sending_tick, self.last_sent) || self.never_sent {
+            // Remove messages that would never be able to reach the remote host in time
             self.sending_messages
-                .pop_back_until_excluding(server_receivable_tick);
+                .pop_back_until_excluding(remote_receivable_tick);
 
-            self.last_sent = *client_sending_tick;
+            self.last_sent = *host_sending_tick;
             self.never_sent = true;
 
             // Loop through outstanding messages and add them to the outgoing list
             for (message_tick, message_map) in self.sending_messages.iter() {
-                if sequence_greater_than(*message_tick, *client_sending_tick) {
-                    warn!("Sending message that is more recent than client sending tick! This shouldn't be possible.");
-                    break;
+                if sequence_greater_than(*message_tick, *host_sending_tick) {
+                    // held until the host reaches the message's Tick
+                    continue;
                 }
 
                 let messages = message_map.collect_messages();
@@ -59,6 +61,12 @@ impl ChannelTickBufferSender {
         !self.outgoing_messages.is_empty()
     }
 
+    /// Gets how many Messages were dropped before being delivered, because
+    /// the buffer overflowed or their Tick passed
+    pub fn dropped_count(&self) -> u64 {
+        self.sending_messages.dropped_count
+    }
+
     // Tick Buffer Message Writing
 
     pub fn write_messages(
@@ -68,6 +76,7 @@ impl ChannelTickBufferSender {
         writer: &mut BitWriter,
         host_tick: &Tick,
         has_written: &mut bool,
+        message_bits_sent: &mut HashMap<MessageKind, u64>,
     ) -> Option<Vec<(Tick, ShortMessageIndex)>> {
         let mut last_written_tick = *host_tick;
         let mut output = Vec::new();
@@ -116,6 +125,10 @@ impl ChannelTickBufferSender {
                 &messages,
             );
             last_written_tick = *message_tick;
+            for (_, message) in messages {
+                *message_bits_sent.entry(message.origin_kind()).or_default() +=
+                    u64::from(message.bit_length());
+            }
             for message_index in message_indices {
                 output.push((*message_tick, message_index));
             }
@@ -226,6 +239,11 @@ impl MessageMap {
     pub fn len(&self) -> usize {
         self.list.len()
     }
+
+    /// Gets how many Messages have not been delivered yet
+    pub fn undelivered_len(&self) -> usize {
+        self.list.iter().flatten().count()
+    }
 }
 
 // OutgoingMessages
@@ -236,6 +254,8 @@ struct OutgoingMessages {
     buffer: VecDeque<(Tick, MessageMap)>,
     // this is the maximum length of the buffer
     capacity: usize,
+    // undelivered messages pruned from the buffer
+    dropped_count: u64,
 }
 
 impl OutgoingMessages {
@@ -243,6 +263,7 @@ impl OutgoingMessages {
         OutgoingMessages {
             buffer: VecDeque::new(),
             capacity,
+            dropped_count: 0,
         }
     }
 
@@ -258,7 +279,7 @@ impl OutgoingMessages {
             if sequence_less_than(message_tick, *front_tick) {
                 warn!("This method should always receive increasing or equal Ticks! \
                 Received Tick: {message_tick} after receiving {front_tick}. \
-                Possibly try ensuring that Client.send_message() is only called on this channel once per Tick?");
+                Possibly try ensuring that messages on this channel are sent in order of their Ticks?");
                 return;
             }
         } else {
@@ -271,7 +292,7 @@ impl OutgoingMessages {
 
         // a good time to prune down this list
         while self.buffer.len() > self.capacity {
-            self.buffer.pop_back();
+            self.pop_back();
         }
     }
 
@@ -285,7 +306,13 @@ impl OutgoingMessages {
                 return;
             }
 
-            self.buffer.pop_back();
+            self.pop_back();
+        }
+    }
+
+    fn pop_back(&mut self) {
+        if let Some((_, msg_map)) = self.buffer.pop_back() {
+            self.dropped_count += msg_map.undelivered_len() as u64;
         }
     }
 
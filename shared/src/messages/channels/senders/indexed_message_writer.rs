@@ -1,9 +1,12 @@
-use std::collections::VecDeque;
+use std::collections::{HashMap, VecDeque};
 
 use naia_serde::{BitWrite, BitWriter, Serde, UnsignedVariableInteger};
 
 use crate::{
-    messages::{message_container::MessageContainer, message_kinds::MessageKinds},
+    messages::{
+        message_container::MessageContainer,
+        message_kinds::{MessageKind, MessageKinds},
+    },
     types::MessageIndex,
     world::entity::entity_converters::LocalEntityAndGlobalEntityConverterMut,
     wrapping_diff,
@@ -19,6 +22,7 @@ impl IndexedMessageWriter {
         converter: &mut dyn LocalEntityAndGlobalEntityConverterMut,
         writer: &mut BitWriter,
         has_written: &mut bool,
+        message_bits_sent: &mut HashMap<MessageKind, u64>,
     ) -> Option<Vec<MessageIndex>> {
         let mut last_written_id: Option<MessageIndex> = None;
         let mut message_indices = Vec::new();
@@ -66,6 +70,8 @@ impl IndexedMessageWriter {
                 message_index,
                 message,
             );
+            *message_bits_sent.entry(message.origin_kind()).or_default() +=
+                u64::from(message.bit_length());
 
             message_indices.push(*message_index);
             last_written_id = Some(*message_index);
@@ -3,7 +3,7 @@ use naia_serde::{BitWrite, BitWriter};
 use crate::{
     constants::FRAGMENTATION_LIMIT_BITS,
     messages::fragment::{FragmentId, FragmentIndex, FragmentedMessage},
-    LocalEntityAndGlobalEntityConverterMut, MessageContainer, MessageKinds,
+    LocalEntityAndGlobalEntityConverterMut, MessageContainer, MessageKind, MessageKinds,
 };
 
 // MessageFragmenter
@@ -27,7 +27,7 @@ impl MessageFragmenter {
         let mut fragmenter = FragmentWriter::new(self.current_fragment_id);
         self.current_fragment_id.increment();
         message.write(message_kinds, &mut fragmenter, converter);
-        fragmenter.to_messages(converter)
+        fragmenter.to_messages(message.kind(), converter)
     }
 }
 
@@ -63,6 +63,7 @@ impl FragmentWriter {
 
     fn to_messages(
         mut self,
+        origin_kind: MessageKind,
         converter: &mut dyn LocalEntityAndGlobalEntityConverterMut,
     ) -> Vec<MessageContainer> {
         self.flush_current();
@@ -71,7 +72,11 @@ impl FragmentWriter {
 
         for mut fragment in self.fragments {
             fragment.set_total(self.current_fragment_index);
-            output.push(MessageContainer::from_write(Box::new(fragment), converter));
+            output.push(MessageContainer::from_fragment(
+                Box::new(fragment),
+                origin_kind,
+                converter,
+            ));
         }
 
         output
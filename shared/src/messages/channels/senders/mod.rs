@@ -1,6 +1,8 @@
 pub mod channel_sender;
+pub mod channel_tick_buffer_sender;
 pub mod indexed_message_writer;
 pub mod message_fragmenter;
 pub mod reliable_sender;
 pub mod sequenced_unreliable_sender;
+pub mod tick_buffer_sender;
 pub mod unordered_unreliable_sender;
@@ -1,4 +1,8 @@
-use std::{collections::VecDeque, mem, time::Duration};
+use std::{
+    collections::{HashMap, VecDeque},
+    mem,
+    time::Duration,
+};
 
 use naia_serde::BitWriter;
 use naia_socket_shared::Instant;
@@ -10,7 +14,7 @@ use crate::{
             indexed_message_writer::IndexedMessageWriter,
         },
         message_container::MessageContainer,
-        message_kinds::MessageKinds,
+        message_kinds::{MessageKind, MessageKinds},
     },
     types::MessageIndex,
     LocalEntityAndGlobalEntityConverterMut,
@@ -22,6 +26,7 @@ pub struct ReliableSender<P: Send + Sync> {
     sending_messages: VecDeque<Option<(MessageIndex, Option<Instant>, P)>>,
     next_send_message_index: MessageIndex,
     outgoing_messages: VecDeque<(MessageIndex, P)>,
+    resend_count: u64,
 }
 
 impl<P: Send + Sync> ReliableSender<P> {
@@ -31,6 +36,7 @@ impl<P: Send + Sync> ReliableSender<P> {
             next_send_message_index: 0,
             sending_messages: VecDeque::new(),
             outgoing_messages: VecDeque::new(),
+            resend_count: 0,
         }
     }
 
@@ -102,8 +108,9 @@ impl<P: Send + Sync + Clone> ChannelSender<P> for ReliableSender<P> {
         for (message_index, last_sent_opt, message) in self.sending_messages.iter_mut().flatten() {
             let mut should_send = false;
             if let Some(last_sent) = last_sent_opt {
-                if last_sent.elapsed() >= resend_duration {
+                if now.duration_since(last_sent) >= resend_duration {
                     should_send = true;
+                    self.resend_count += 1;
                 }
             } else {
                 should_send = true;
@@ -123,6 +130,10 @@ impl<P: Send + Sync + Clone> ChannelSender<P> for ReliableSender<P> {
     fn notify_message_delivered(&mut self, message_index: &MessageIndex) {
         self.deliver_message(message_index);
     }
+
+    fn resend_count(&self) -> u64 {
+        self.resend_count
+    }
 }
 
 impl MessageChannelSender for ReliableSender<MessageContainer> {
@@ -132,6 +143,7 @@ impl MessageChannelSender for ReliableSender<MessageContainer> {
         converter: &mut dyn LocalEntityAndGlobalEntityConverterMut,
         writer: &mut BitWriter,
         has_written: &mut bool,
+        message_bits_sent: &mut HashMap<MessageKind, u64>,
     ) -> Option<Vec<MessageIndex>> {
         IndexedMessageWriter::write_messages(
             message_kinds,
@@ -139,6 +151,7 @@ impl MessageChannelSender for ReliableSender<MessageContainer> {
             converter,
             writer,
             has_written,
+            message_bits_sent,
         )
     }
 }
@@ -1,4 +1,4 @@
-use std::collections::VecDeque;
+use std::collections::{HashMap, VecDeque};
 
 use naia_serde::BitWriter;
 use naia_socket_shared::Instant;
@@ -10,7 +10,7 @@ use crate::{
             indexed_message_writer::IndexedMessageWriter,
         },
         message_container::MessageContainer,
-        message_kinds::MessageKinds,
+        message_kinds::{MessageKind, MessageKinds},
     },
     types::MessageIndex,
     LocalEntityAndGlobalEntityConverterMut,
@@ -50,6 +50,11 @@ impl ChannelSender<MessageContainer> for SequencedUnreliableSender {
     fn notify_message_delivered(&mut self, _: &MessageIndex) {
         // not necessary for an unreliable channel
     }
+
+    fn resend_count(&self) -> u64 {
+        // unreliable channels never resend
+        0
+    }
 }
 
 impl MessageChannelSender for SequencedUnreliableSender {
@@ -61,6 +66,7 @@ impl MessageChannelSender for SequencedUnreliableSender {
         converter: &mut dyn LocalEntityAndGlobalEntityConverterMut,
         writer: &mut BitWriter,
         has_written: &mut bool,
+        message_bits_sent: &mut HashMap<MessageKind, u64>,
     ) -> Option<Vec<MessageIndex>> {
         IndexedMessageWriter::write_messages(
             message_kinds,
@@ -68,6 +74,7 @@ impl MessageChannelSender for SequencedUnreliableSender {
             converter,
             writer,
             has_written,
+            message_bits_sent,
         )
     }
 }
@@ -1,24 +1,34 @@
 use std::collections::HashMap;
 
-use naia_shared::{
-    BitWrite, BitWriter, ChannelKind, ChannelKinds, ChannelMode, ConstBitLength,
-    LocalEntityAndGlobalEntityConverterMut, MessageContainer, PacketIndex, PacketNotifiable,
-    Protocol, Serde, ShortMessageIndex, Tick,
-};
+use naia_serde::{BitWrite, BitWriter, ConstBitLength, Serde};
 
-use super::channel_tick_buffer_sender::ChannelTickBufferSender;
+use crate::{
+    messages::channels::senders::channel_tick_buffer_sender::ChannelTickBufferSender,
+    types::HostType, ChannelKind, ChannelKinds, ChannelMode,
+    LocalEntityAndGlobalEntityConverterMut, MessageContainer, MessageKind, PacketIndex,
+    PacketNotifiable, Protocol, ShortMessageIndex, Tick,
+};
 
 pub struct TickBufferSender {
     channel_senders: HashMap<ChannelKind, ChannelTickBufferSender>,
     #[allow(clippy::type_complexity)]
     packet_to_channel_map: HashMap<PacketIndex, Vec<(ChannelKind, Vec<(Tick, ShortMessageIndex)>)>>,
+    channel_bits_sent: HashMap<ChannelKind, u64>,
+    message_bits_sent: HashMap<MessageKind, u64>,
 }
 
 impl TickBufferSender {
-    pub fn new(channel_kinds: &ChannelKinds) -> Self {
+    pub fn new(host_type: HostType, channel_kinds: &ChannelKinds) -> Self {
         // initialize senders
         let mut channel_senders = HashMap::new();
         for (channel_kind, channel) in channel_kinds.channels() {
+            let can_send = match host_type {
+                HostType::Server => channel.can_send_to_client(),
+                HostType::Client => channel.can_send_to_server(),
+            };
+            if !can_send {
+                continue;
+            }
             if let ChannelMode::TickBuffered(settings) = &channel.mode {
                 channel_senders
                     .insert(channel_kind, ChannelTickBufferSender::new(settings.clone()));
@@ -28,6 +38,8 @@ impl TickBufferSender {
         Self {
             channel_senders,
             packet_to_channel_map: HashMap::new(),
+            channel_bits_sent: HashMap::new(),
+            message_bits_sent: HashMap::new(),
         }
     }
 
@@ -46,11 +58,11 @@ impl TickBufferSender {
 
     pub fn collect_outgoing_messages(
         &mut self,
-        client_sending_tick: &Tick,
-        server_receivable_tick: &Tick,
+        host_sending_tick: &Tick,
+        remote_receivable_tick: &Tick,
     ) {
         for channel in self.channel_senders.values_mut() {
-            channel.collect_outgoing_messages(client_sending_tick, server_receivable_tick);
+            channel.collect_outgoing_messages(host_sending_tick, remote_receivable_tick);
         }
     }
 
@@ -86,6 +98,8 @@ impl TickBufferSender {
                 break;
             }
 
+            let start_bits = writer.bits_written();
+
             // write ChannelContinue bit
             true.ser(writer);
 
@@ -102,6 +116,7 @@ impl TickBufferSender {
                 writer,
                 host_tick,
                 has_written,
+                &mut self.message_bits_sent,
             ) {
                 self.packet_to_channel_map
                     .entry(packet_index)
@@ -113,8 +128,29 @@ impl TickBufferSender {
             // write MessageContinue finish bit, release
             false.ser(writer);
             writer.release_bits(1);
+
+            *self.channel_bits_sent.entry(*channel_kind).or_default() +=
+                u64::from(writer.bits_written() - start_bits);
         }
     }
+
+    /// Gets how many bits have been written for each Channel
+    pub fn channel_bits_sent(&self) -> &HashMap<ChannelKind, u64> {
+        &self.channel_bits_sent
+    }
+
+    /// Gets how many payload bits have been written for each kind of Message
+    pub fn message_bits_sent(&self) -> &HashMap<MessageKind, u64> {
+        &self.message_bits_sent
+    }
+
+    /// Gets how many Messages were dropped before being delivered
+    pub fn messages_dropped(&self) -> u64 {
+        self.channel_senders
+            .values()
+            .map(|channel| channel.dropped_count())
+            .sum()
+    }
 }
 
 impl PacketNotifiable for TickBufferSender {
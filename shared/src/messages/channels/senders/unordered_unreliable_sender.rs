@@ -1,4 +1,4 @@
-use std::collections::VecDeque;
+use std::collections::{HashMap, VecDeque};
 
 use naia_serde::{BitWrite, BitWriter, Serde};
 use naia_socket_shared::Instant;
@@ -7,7 +7,7 @@ use crate::{
     messages::{
         channels::senders::channel_sender::{ChannelSender, MessageChannelSender},
         message_container::MessageContainer,
-        message_kinds::MessageKinds,
+        message_kinds::{MessageKind, MessageKinds},
     },
     types::MessageIndex,
     LocalEntityAndGlobalEntityConverterMut,
@@ -58,6 +58,11 @@ impl ChannelSender<MessageContainer> for UnorderedUnreliableSender {
     fn notify_message_delivered(&mut self, _: &MessageIndex) {
         // not necessary for an unreliable channel
     }
+
+    fn resend_count(&self) -> u64 {
+        // unreliable channels never resend
+        0
+    }
 }
 
 impl MessageChannelSender for UnorderedUnreliableSender {
@@ -67,6 +72,7 @@ impl MessageChannelSender for UnorderedUnreliableSender {
         converter: &mut dyn LocalEntityAndGlobalEntityConverterMut,
         writer: &mut BitWriter,
         has_written: &mut bool,
+        message_bits_sent: &mut HashMap<MessageKind, u64>,
     ) -> Option<Vec<MessageIndex>> {
         loop {
             if self.outgoing_messages.is_empty() {
@@ -96,6 +102,8 @@ impl MessageChannelSender for UnorderedUnreliableSender {
 
             // write data
             self.write_message(message_kinds, converter, writer, &message);
+            *message_bits_sent.entry(message.origin_kind()).or_default() +=
+                u64::from(message.bit_length());
 
             // pop message we've written
             self.outgoing_messages.pop_front();
@@ -11,6 +11,7 @@ use crate::{
 pub struct MessageContainer {
     inner: Box<dyn Message>,
     bit_length: Option<u32>,
+    origin_kind: Option<MessageKind>,
 }
 
 impl MessageContainer {
@@ -22,6 +23,7 @@ impl MessageContainer {
         Self {
             inner: message,
             bit_length: Some(bit_length),
+            origin_kind: None,
         }
     }
 
@@ -29,9 +31,22 @@ impl MessageContainer {
         Self {
             inner: message,
             bit_length: None,
+            origin_kind: None,
         }
     }
 
+    /// Creates a container for one fragment of a larger Message, so that the
+    /// fragment's bandwidth can be attributed to the original Message's kind
+    pub fn from_fragment(
+        fragment: Box<dyn Message>,
+        origin_kind: MessageKind,
+        converter: &mut dyn LocalEntityAndGlobalEntityConverterMut,
+    ) -> Self {
+        let mut container = Self::from_write(fragment, converter);
+        container.origin_kind = Some(origin_kind);
+        container
+    }
+
     pub fn name(&self) -> String {
         self.inner.name()
     }
@@ -65,6 +80,12 @@ impl MessageContainer {
         return self.inner.kind();
     }
 
+    /// Gets the kind of the Message this container was created from, which
+    /// only differs from `kind()` for fragments
+    pub fn origin_kind(&self) -> MessageKind {
+        self.origin_kind.unwrap_or_else(|| self.inner.kind())
+    }
+
     pub fn relations_waiting(&self) -> Option<HashSet<LocalEntity>> {
         return self.inner.relations_waiting();
     }
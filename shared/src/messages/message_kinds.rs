@@ -33,7 +33,7 @@ impl MessageKind {
 
     pub fn de(message_kinds: &MessageKinds, reader: &mut BitReader) -> Result<Self, SerdeErr> {
         let net_id: NetId = NetId::de(reader)?;
-        Ok(message_kinds.net_id_to_kind(&net_id))
+        message_kinds.net_id_to_kind(&net_id).ok_or(SerdeErr)
     }
 }
 
@@ -48,6 +48,7 @@ pub struct MessageKinds {
     current_net_id: NetId,
     kind_map: HashMap<MessageKind, (NetId, Box<dyn MessageBuilder>)>,
     net_id_map: HashMap<NetId, MessageKind>,
+    name_map: HashMap<MessageKind, String>,
 }
 
 impl MessageKinds {
@@ -56,6 +57,7 @@ impl MessageKinds {
             current_net_id: 0,
             kind_map: HashMap::new(),
             net_id_map: HashMap::new(),
+            name_map: HashMap::new(),
         }
     }
 
@@ -66,6 +68,7 @@ impl MessageKinds {
         self.kind_map
             .insert(message_kind, (net_id, M::create_builder()));
         self.net_id_map.insert(net_id, message_kind);
+        self.name_map.insert(message_kind, message_name::<M>());
         self.current_net_id += 1;
         //TODO: check for current_id overflow?
     }
@@ -79,10 +82,15 @@ impl MessageKinds {
         return self.kind_to_builder(&message_kind).read(reader, converter);
     }
 
-    fn net_id_to_kind(&self, net_id: &NetId) -> MessageKind {
-        return *self.net_id_map.get(net_id).expect(
-            "Must properly initialize Message with Protocol via `add_message()` function!",
-        );
+    pub fn kind_to_name(&self, message_kind: &MessageKind) -> String {
+        self.name_map
+            .get(message_kind)
+            .cloned()
+            .expect("Must properly initialize Message with Protocol via `add_message()` function!")
+    }
+
+    fn net_id_to_kind(&self, net_id: &NetId) -> Option<MessageKind> {
+        self.net_id_map.get(net_id).copied()
     }
 
     fn kind_to_net_id(&self, message_kind: &MessageKind) -> NetId {
@@ -101,3 +109,10 @@ impl MessageKinds {
             .1;
     }
 }
+
+/// Gets the name of a Message type, without its module path
+fn message_name<M: Message>() -> String {
+    let type_name = std::any::type_name::<M>();
+    let path = type_name.split('<').next().unwrap_or(type_name);
+    path.rsplit("::").next().unwrap_or(path).to_string()
+}
@@ -27,6 +27,7 @@ use crate::{
             },
         },
         message_container::MessageContainer,
+        message_kinds::MessageKind,
     },
     types::{HostType, MessageIndex, PacketIndex},
     world::{
@@ -45,6 +46,8 @@ pub struct MessageManager {
     channel_settings: HashMap<ChannelKind, ChannelSettings>,
     packet_to_message_map: HashMap<PacketIndex, Vec<(ChannelKind, Vec<MessageIndex>)>>,
     message_fragmenter: MessageFragmenter,
+    channel_bits_sent: HashMap<ChannelKind, u64>,
+    message_bits_sent: HashMap<MessageKind, u64>,
 }
 
 impl MessageManager {
@@ -158,6 +161,8 @@ impl MessageManager {
             channel_settings: channel_settings_map,
             packet_to_message_map: HashMap::new(),
             message_fragmenter: MessageFragmenter::new(),
+            channel_bits_sent: HashMap::new(),
+            message_bits_sent: HashMap::new(),
         }
     }
 
@@ -236,6 +241,8 @@ impl MessageManager {
                 break;
             }
 
+            let start_bits = writer.bits_written();
+
             // write ChannelContinue bit
             true.ser(writer);
 
@@ -246,9 +253,13 @@ impl MessageManager {
             channel_kind.ser(&protocol.channel_kinds, writer);
 
             // write Messages
-            if let Some(message_indices) =
-                channel.write_messages(&protocol.message_kinds, converter, writer, has_written)
-            {
+            if let Some(message_indices) = channel.write_messages(
+                &protocol.message_kinds,
+                converter,
+                writer,
+                has_written,
+                &mut self.message_bits_sent,
+            ) {
                 self.packet_to_message_map
                     .entry(packet_index)
                     .or_insert_with(Vec::new);
@@ -259,9 +270,31 @@ impl MessageManager {
             // write MessageContinue finish bit, release
             false.ser(writer);
             writer.release_bits(1);
+
+            *self.channel_bits_sent.entry(*channel_kind).or_default() +=
+                u64::from(writer.bits_written() - start_bits);
         }
     }
 
+    /// Gets how many bits have been written for each Channel
+    pub fn channel_bits_sent(&self) -> &HashMap<ChannelKind, u64> {
+        &self.channel_bits_sent
+    }
+
+    /// Gets how many payload bits have been written for each kind of Message,
+    /// with fragments counted as the Message they were split from
+    pub fn message_bits_sent(&self) -> &HashMap<MessageKind, u64> {
+        &self.message_bits_sent
+    }
+
+    /// Gets how many Messages each Channel has resent
+    pub fn channel_resends(&self) -> HashMap<ChannelKind, u64> {
+        self.channel_senders
+            .iter()
+            .map(|(channel_kind, channel)| (*channel_kind, channel.resend_count()))
+            .collect()
+    }
+
     // Incoming Messages
 
     pub fn read_messages(
@@ -280,8 +313,10 @@ impl MessageManager {
             // read channel id
             let channel_kind = ChannelKind::de(&protocol.channel_kinds, reader)?;
 
-            // continue read inside channel
-            let channel = self.channel_receivers.get_mut(&channel_kind).unwrap();
+            // continue read inside channel, which must be able to receive
+            let Some(channel) = self.channel_receivers.get_mut(&channel_kind) else {
+                return Err(SerdeErr);
+            };
             channel.read_messages(&protocol.message_kinds, entity_waitlist, converter, reader)?;
         }
 
@@ -45,16 +45,17 @@ fn setup() -> (
 
 #[test]
 fn convert_single_fragment() {
-    let (message_kinds, converter, mut fragmenter, mut receiver) = setup();
+    let (message_kinds, mut converter, mut fragmenter, mut receiver) = setup();
 
     // Message
     let initial_message = StringMessage::new("hello");
     let outgoing_message = initial_message.clone();
 
-    let container = MessageContainer::from_write(Box::new(outgoing_message), &FakeEntityConverter);
+    let container =
+        MessageContainer::from_write(Box::new(outgoing_message), &mut FakeEntityConverter);
 
     // Fragment Message
-    let fragments = fragmenter.fragment_message(&message_kinds, &converter, container);
+    let fragments = fragmenter.fragment_message(&message_kinds, &mut converter, container);
     let fragment_count = fragments.len();
 
     // Receive Fragments
@@ -70,7 +71,10 @@ fn convert_single_fragment() {
     let Some(incoming_message_container) = incoming_message_container_opt else {
         panic!("Did not receive reassembled message!");
     };
-    let Ok(incoming_message) = incoming_message_container.to_boxed_any().downcast::<StringMessage>() else {
+    let Ok(incoming_message) = incoming_message_container
+        .to_boxed_any()
+        .downcast::<StringMessage>()
+    else {
         panic!("cannot cast message container into proper message!");
     };
 
@@ -81,7 +85,7 @@ fn convert_single_fragment() {
 
 #[test]
 fn convert_multiple_fragments() {
-    let (message_kinds, converter, mut fragmenter, mut receiver) = setup();
+    let (message_kinds, mut converter, mut fragmenter, mut receiver) = setup();
 
     // Message
     let initial_message = StringMessage::new("Lorem ipsum dolor sit amet, consectetur adipiscing elit. Donec sed justo a mi ultricies ultrices. \
@@ -95,10 +99,11 @@ fn convert_multiple_fragments() {
             Donec ut purus venenatis, mollis est ut, sollicitudin egestas.");
     let outgoing_message = initial_message.clone();
 
-    let container = MessageContainer::from_write(Box::new(outgoing_message), &FakeEntityConverter);
+    let container =
+        MessageContainer::from_write(Box::new(outgoing_message), &mut FakeEntityConverter);
 
     // Fragment Message
-    let fragments = fragmenter.fragment_message(&message_kinds, &converter, container);
+    let fragments = fragmenter.fragment_message(&message_kinds, &mut converter, container);
     let fragment_count = fragments.len();
 
     // Receive Fragments
@@ -122,7 +127,10 @@ fn convert_multiple_fragments() {
     let Some(incoming_message_container) = incoming_message_container_opt else {
         panic!("Did not receive reassembled message!");
     };
-    let Ok(incoming_message) = incoming_message_container.to_boxed_any().downcast::<StringMessage>() else {
+    let Ok(incoming_message) = incoming_message_container
+        .to_boxed_any()
+        .downcast::<StringMessage>()
+    else {
         panic!("cannot cast message container into proper message!");
     };
 
@@ -130,3 +138,34 @@ fn convert_multiple_fragments() {
     assert_eq!(fragment_count, 3);
     assert_eq!(initial_message.inner, incoming_message.inner);
 }
+
+#[test]
+fn duplicate_fragment_is_dropped() {
+    let (message_kinds, mut converter, mut fragmenter, mut receiver) = setup();
+
+    let initial_message = StringMessage::new(&"a".repeat(2000));
+    let container =
+        MessageContainer::from_write(Box::new(initial_message.clone()), &mut FakeEntityConverter);
+    let mut fragments = fragmenter.fragment_message(&message_kinds, &mut converter, container);
+    assert!(fragments.len() > 2);
+
+    // a remote host repeating a fragment must not complete the message early
+    let duplicate = fragments[0].clone();
+    fragments.insert(1, duplicate);
+
+    let mut reassembled_messages = Vec::new();
+    for fragment in fragments {
+        if let Some((_, message)) = receiver.receive(&message_kinds, &converter, fragment) {
+            reassembled_messages.push(message);
+        }
+    }
+    assert_eq!(reassembled_messages.len(), 1);
+    let Ok(incoming_message) = reassembled_messages
+        .remove(0)
+        .to_boxed_any()
+        .downcast::<StringMessage>()
+    else {
+        panic!("cannot cast message container into proper message!");
+    };
+    assert_eq!(initial_message.inner, incoming_message.inner);
+}
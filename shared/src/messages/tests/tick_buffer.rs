@@ -0,0 +1,100 @@
+use naia_derive::MessageInternal;
+use naia_serde::{BitReader, BitWriter, Serde};
+
+use crate::{
+    Channel, ChannelDirection, ChannelKind, ChannelMode, FakeEntityConverter, HostType,
+    MessageContainer, Protocol, TickBufferReceiver, TickBufferSender, TickBufferSettings,
+};
+
+#[derive(Channel)]
+pub struct EventChannel;
+
+#[derive(MessageInternal)]
+pub struct ExplosionMessage {
+    pub radius: u8,
+}
+
+fn setup() -> Protocol {
+    let mut protocol = Protocol::builder();
+    protocol
+        .add_channel::<EventChannel>(
+            ChannelDirection::ServerToClient,
+            ChannelMode::TickBuffered(TickBufferSettings::default()),
+        )
+        .add_message::<ExplosionMessage>();
+    protocol
+}
+
+fn write_packet(protocol: &Protocol, sender: &mut TickBufferSender, host_tick: u16) -> Vec<u8> {
+    let mut writer = BitWriter::new();
+    let mut has_written = false;
+    sender.write_messages(
+        protocol,
+        &mut FakeEntityConverter,
+        &mut writer,
+        0,
+        &host_tick,
+        &mut has_written,
+    );
+    false.ser(&mut writer);
+    writer.to_bytes().into_vec()
+}
+
+#[test]
+fn server_messages_arrive_on_their_tick() {
+    let protocol = setup();
+    let mut sender = TickBufferSender::new(HostType::Server, &protocol.channel_kinds);
+    let mut receiver = TickBufferReceiver::new(HostType::Client, &protocol.channel_kinds);
+
+    let message = MessageContainer::from_write(
+        Box::new(ExplosionMessage { radius: 3 }),
+        &mut FakeEntityConverter,
+    );
+    sender.send_message(&12, &ChannelKind::of::<EventChannel>(), message);
+
+    // held until the Server reaches its Tick
+    sender.collect_outgoing_messages(&11, &9);
+    assert!(!sender.has_outgoing_messages());
+    sender.collect_outgoing_messages(&12, &10);
+    assert!(sender.has_outgoing_messages());
+
+    let bytes = write_packet(&protocol, &mut sender, 12);
+    let mut reader = BitReader::new(&bytes);
+    receiver
+        .read_messages(&protocol, &10, &12, &FakeEntityConverter, &mut reader)
+        .unwrap();
+
+    let early: usize = receiver
+        .receive_messages(&11)
+        .iter()
+        .map(|(_, messages)| messages.len())
+        .sum();
+    assert_eq!(early, 0);
+
+    let mut received = receiver.receive_messages(&12);
+    assert_eq!(received.len(), 1);
+    let (channel_kind, messages) = received.pop().unwrap();
+    assert!(channel_kind == ChannelKind::of::<EventChannel>());
+    let message = messages
+        .into_iter()
+        .next()
+        .unwrap()
+        .to_boxed_any()
+        .downcast::<ExplosionMessage>()
+        .unwrap();
+    assert_eq!(message.radius, 3);
+}
+
+#[test]
+fn client_does_not_send_on_server_channels() {
+    let protocol = setup();
+    let mut sender = TickBufferSender::new(HostType::Client, &protocol.channel_kinds);
+
+    let message = MessageContainer::from_write(
+        Box::new(ExplosionMessage { radius: 3 }),
+        &mut FakeEntityConverter,
+    );
+    sender.send_message(&12, &ChannelKind::of::<EventChannel>(), message);
+    sender.collect_outgoing_messages(&12, &10);
+    assert!(!sender.has_outgoing_messages());
+}
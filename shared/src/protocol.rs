@@ -1,12 +1,16 @@
-use std::time::Duration;
+use std::{error::Error, fmt, time::Duration};
 
+use log::warn;
 use naia_socket_shared::{LinkConditionerConfig, SocketConfig};
 
 use crate::{
-    connection::compression_config::CompressionConfig,
+    connection::{
+        compression_config::CompressionConfig, tick_interval_channel::TickIntervalChannel,
+        tick_interval_message::TickIntervalMessage,
+    },
     messages::{
         channels::{
-            channel::{Channel, ChannelDirection, ChannelMode, ChannelSettings},
+            channel::{Channel, ChannelDirection, ChannelMode, ChannelSettings, ReliableSettings},
             channel_kinds::ChannelKinds,
             default_channels::DefaultChannelsPlugin,
         },
@@ -14,9 +18,34 @@ use crate::{
         message::Message,
         message_kinds::MessageKinds,
     },
-    world::component::{component_kinds::ComponentKinds, replicate::Replicate},
+    world::{
+        component::{component_kinds::ComponentKinds, replicate::Replicate},
+        delegation::{
+            entity_auth_channel::EntityAuthChannel,
+            entity_auth_message::{EntityAuthMessage, EntityAuthUpdateMessage},
+        },
+        hierarchy::{
+            entity_hierarchy_channel::EntityHierarchyChannel,
+            entity_parent_message::EntityParentMessage,
+        },
+        scope::{
+            entity_scope_channel::EntityScopeChannel, entity_scope_message::EntityScopeMessage,
+        },
+    },
 };
 
+/// A locked Protocol can no longer be changed
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct ProtocolLockedError;
+
+impl fmt::Display for ProtocolLockedError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        write!(f, "Protocol already locked!")
+    }
+}
+
+impl Error for ProtocolLockedError {}
+
 // Protocol Plugin
 pub trait ProtocolPlugin {
     fn build(&self, protocol: &mut Protocol);
@@ -40,10 +69,36 @@ pub struct Protocol {
 
 impl Default for Protocol {
     fn default() -> Self {
+        // Internal Messages & Channels are registered ahead of the
+        // application's, so every addition here shifts the net ids of all
+        // application Messages & Channels. This is a wire break: a Client and
+        // Server built against different lists of them can not talk.
         let mut message_kinds = MessageKinds::new();
         message_kinds.add_message::<FragmentedMessage>();
+        message_kinds.add_message::<EntityAuthMessage>();
+        message_kinds.add_message::<EntityAuthUpdateMessage>();
+        message_kinds.add_message::<EntityParentMessage>();
+        message_kinds.add_message::<EntityScopeMessage>();
+        message_kinds.add_message::<TickIntervalMessage>();
+        let mut channel_kinds = ChannelKinds::new();
+        channel_kinds.add_channel::<EntityAuthChannel>(ChannelSettings::new(
+            ChannelMode::OrderedReliable(ReliableSettings::default()),
+            ChannelDirection::Bidirectional,
+        ));
+        channel_kinds.add_channel::<EntityHierarchyChannel>(ChannelSettings::new(
+            ChannelMode::OrderedReliable(ReliableSettings::default()),
+            ChannelDirection::ServerToClient,
+        ));
+        channel_kinds.add_channel::<EntityScopeChannel>(ChannelSettings::new(
+            ChannelMode::OrderedReliable(ReliableSettings::default()),
+            ChannelDirection::ServerToClient,
+        ));
+        channel_kinds.add_channel::<TickIntervalChannel>(ChannelSettings::new(
+            ChannelMode::OrderedReliable(ReliableSettings::default()),
+            ChannelDirection::ServerToClient,
+        ));
         Self {
-            channel_kinds: ChannelKinds::new(),
+            channel_kinds,
             message_kinds,
             component_kinds: ComponentKinds::new(),
             socket: SocketConfig::new(None, None),
@@ -61,43 +116,57 @@ impl Protocol {
     }
 
     pub fn add_plugin<P: ProtocolPlugin>(&mut self, plugin: P) -> &mut Self {
-        self.check_lock();
+        if !self.is_unlocked() {
+            return self;
+        }
         plugin.build(self);
         self
     }
 
     pub fn link_condition(&mut self, config: LinkConditionerConfig) -> &mut Self {
-        self.check_lock();
+        if !self.is_unlocked() {
+            return self;
+        }
         self.socket.link_condition = Some(config);
         self
     }
 
     pub fn rtc_endpoint(&mut self, path: String) -> &mut Self {
-        self.check_lock();
+        if !self.is_unlocked() {
+            return self;
+        }
         self.socket.rtc_endpoint_path = path;
         self
     }
 
     pub fn tick_interval(&mut self, duration: Duration) -> &mut Self {
-        self.check_lock();
+        if !self.is_unlocked() {
+            return self;
+        }
         self.tick_interval = duration;
         self
     }
 
     pub fn compression(&mut self, config: CompressionConfig) -> &mut Self {
-        self.check_lock();
+        if !self.is_unlocked() {
+            return self;
+        }
         self.compression = Some(config);
         self
     }
 
     pub fn enable_client_authoritative_entities(&mut self) -> &mut Self {
-        self.check_lock();
+        if !self.is_unlocked() {
+            return self;
+        }
         self.client_authoritative_entities = true;
         self
     }
 
     pub fn add_default_channels(&mut self) -> &mut Self {
-        self.check_lock();
+        if !self.is_unlocked() {
+            return self;
+        }
         let plugin = DefaultChannelsPlugin;
         plugin.build(self);
         self
@@ -108,33 +177,60 @@ impl Protocol {
         direction: ChannelDirection,
         mode: ChannelMode,
     ) -> &mut Self {
-        self.check_lock();
+        if !self.is_unlocked() {
+            return self;
+        }
         self.channel_kinds
             .add_channel::<C>(ChannelSettings::new(mode, direction));
         self
     }
 
     pub fn add_message<M: Message>(&mut self) -> &mut Self {
-        self.check_lock();
+        if !self.is_unlocked() {
+            return self;
+        }
         self.message_kinds.add_message::<M>();
         self
     }
 
     pub fn add_component<C: Replicate>(&mut self) -> &mut Self {
-        self.check_lock();
+        if !self.is_unlocked() {
+            return self;
+        }
         self.component_kinds.add_component::<C>();
         self
     }
 
-    pub fn lock(&mut self) {
-        self.check_lock();
+    /// Registers a Component which is replicated as a singleton Resource,
+    /// rather than being attached to Entities by the user
+    pub fn add_resource<R: Replicate>(&mut self) -> &mut Self {
+        if !self.is_unlocked() {
+            return self;
+        }
+        self.component_kinds.add_resource::<R>();
+        self
+    }
+
+    pub fn try_lock(&mut self) -> Result<(), ProtocolLockedError> {
+        self.try_check_lock()?;
         self.locked = true;
+        Ok(())
     }
 
-    pub fn check_lock(&self) {
+    pub fn try_check_lock(&self) -> Result<(), ProtocolLockedError> {
         if self.locked {
-            panic!("Protocol already locked!");
+            return Err(ProtocolLockedError);
+        }
+        Ok(())
+    }
+
+    /// Changes to a locked Protocol are ignored, with a warning
+    fn is_unlocked(&self) -> bool {
+        if let Err(error) = self.try_check_lock() {
+            warn!("{} Ignoring the change.", error);
+            return false;
         }
+        true
     }
 
     pub fn build(&mut self) -> Self {
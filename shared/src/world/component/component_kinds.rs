@@ -1,4 +1,7 @@
-use std::{any::TypeId, collections::HashMap};
+use std::{
+    any::TypeId,
+    collections::{HashMap, HashSet},
+};
 
 use naia_serde::{BitReader, BitWrite, ConstBitLength, Serde, SerdeErr};
 
@@ -39,7 +42,7 @@ impl ComponentKind {
 
     pub fn de(component_kinds: &ComponentKinds, reader: &mut BitReader) -> Result<Self, SerdeErr> {
         let net_id: NetId = NetId::de(reader)?;
-        Ok(component_kinds.net_id_to_kind(&net_id))
+        component_kinds.net_id_to_kind(&net_id).ok_or(SerdeErr)
     }
 }
 
@@ -54,6 +57,7 @@ pub struct ComponentKinds {
     current_net_id: NetId,
     kind_map: HashMap<ComponentKind, (NetId, Box<dyn ReplicateBuilder>)>,
     net_id_map: HashMap<NetId, ComponentKind>,
+    resource_kinds: HashSet<ComponentKind>,
 }
 
 impl ComponentKinds {
@@ -62,6 +66,7 @@ impl ComponentKinds {
             current_net_id: 0,
             kind_map: HashMap::new(),
             net_id_map: HashMap::new(),
+            resource_kinds: HashSet::new(),
         }
     }
 
@@ -76,6 +81,16 @@ impl ComponentKinds {
         //TODO: check for current_id overflow?
     }
 
+    pub fn add_resource<R: Replicate>(&mut self) {
+        self.add_component::<R>();
+        self.resource_kinds.insert(ComponentKind::of::<R>());
+    }
+
+    /// Returns whether the Component is replicated as a singleton Resource
+    pub fn is_resource(&self, component_kind: &ComponentKind) -> bool {
+        self.resource_kinds.contains(component_kind)
+    }
+
     pub fn read(
         &self,
         reader: &mut BitReader,
@@ -94,6 +109,19 @@ impl ComponentKinds {
             .read_create_update(reader);
     }
 
+    /// Reads a Component update, getting the name & bit length of each field
+    /// it updates
+    pub fn read_update_fields(
+        &self,
+        reader: &mut BitReader,
+    ) -> Result<(ComponentKind, Vec<(&'static str, u32)>), SerdeErr> {
+        let component_kind: ComponentKind = ComponentKind::de(self, reader)?;
+        let fields = self
+            .kind_to_builder(&component_kind)
+            .read_update_fields(reader)?;
+        Ok((component_kind, fields))
+    }
+
     pub fn split_update(
         &self,
         converter: &dyn LocalEntityAndGlobalEntityConverter,
@@ -101,7 +129,7 @@ impl ComponentKinds {
         update: ComponentUpdate,
     ) -> Result<
         (
-            Option<Vec<(LocalEntity, ComponentFieldUpdate)>>,
+            Option<Vec<(HashSet<LocalEntity>, ComponentFieldUpdate)>>,
             Option<ComponentUpdate>,
         ),
         SerdeErr,
@@ -115,10 +143,8 @@ impl ComponentKinds {
         return self.kind_to_builder(component_kind).name();
     }
 
-    fn net_id_to_kind(&self, net_id: &NetId) -> ComponentKind {
-        return *self.net_id_map.get(net_id).expect(
-            "Must properly initialize Component with Protocol via `add_component()` function!",
-        );
+    fn net_id_to_kind(&self, net_id: &NetId) -> Option<ComponentKind> {
+        self.net_id_map.get(net_id).copied()
     }
 
     fn kind_to_net_id(&self, component_kind: &ComponentKind) -> NetId {
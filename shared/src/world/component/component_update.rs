@@ -1,3 +1,5 @@
+use std::collections::HashSet;
+
 use naia_serde::{BitReader, OwnedBitReader, SerdeErr};
 
 use crate::{
@@ -5,6 +7,7 @@ use crate::{
     LocalEntityAndGlobalEntityConverter,
 };
 
+#[derive(Clone)]
 pub struct ComponentUpdate {
     pub kind: ComponentKind,
     buffer: OwnedBitReader,
@@ -25,7 +28,7 @@ impl ComponentUpdate {
         component_kinds: &ComponentKinds,
     ) -> Result<
         (
-            Option<Vec<(LocalEntity, ComponentFieldUpdate)>>,
+            Option<Vec<(HashSet<LocalEntity>, ComponentFieldUpdate)>>,
             Option<Self>,
         ),
         SerdeErr,
@@ -35,20 +38,40 @@ impl ComponentUpdate {
     }
 }
 
+#[derive(Clone)]
 pub struct ComponentFieldUpdate {
     id: u8,
+    element: Option<u32>,
     buffer: OwnedBitReader,
 }
 
 impl ComponentFieldUpdate {
     pub fn new(id: u8, buffer: OwnedBitReader) -> Self {
-        Self { id, buffer }
+        Self {
+            id,
+            element: None,
+            buffer,
+        }
+    }
+
+    /// Creates an update to a single element of a list field
+    pub fn new_element(id: u8, element: u32, buffer: OwnedBitReader) -> Self {
+        Self {
+            id,
+            element: Some(element),
+            buffer,
+        }
     }
 
     pub fn field_id(&self) -> u8 {
         self.id
     }
 
+    /// Gets the index of the list element this update applies to, if any
+    pub fn element_index(&self) -> Option<u32> {
+        self.element
+    }
+
     pub fn reader(&self) -> BitReader {
         self.buffer.borrow()
     }
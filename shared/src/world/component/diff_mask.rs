@@ -1,12 +1,18 @@
-use std::fmt;
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt,
+};
 
 // The DiffMask is a variable-length byte array, where each bit represents
 // the current state of a Property owned by a Replica.
 // The Property tracks whether it has been updated and needs to be synced
-// with the remote Client
+// with the remote Client.
+// List Properties can also track which of their elements have changed. A set
+// bit with no tracked elements means the whole list needs to be synced.
 #[derive(PartialEq, Eq, Clone, Debug)]
 pub struct DiffMask {
     mask: Vec<u8>,
+    elements: BTreeMap<u8, BTreeSet<u32>>,
 }
 
 impl DiffMask {
@@ -14,6 +20,7 @@ impl DiffMask {
     pub fn new(bytes: u8) -> DiffMask {
         DiffMask {
             mask: vec![0; bytes as usize],
+            elements: BTreeMap::new(),
         }
     }
 
@@ -36,14 +43,39 @@ impl DiffMask {
                 *byte |= bit_mask;
             } else {
                 *byte &= !bit_mask;
+                self.elements.remove(&index);
             }
         }
     }
 
+    /// Sets the bit at the specified position within the DiffMask, tracking
+    /// which elements of the list Property at that position have changed
+    pub fn set_elements(&mut self, index: u8, elements: &[u32]) {
+        if self.bit(index).is_none() {
+            return;
+        }
+        // a set bit without tracked elements already covers the whole list
+        if self.bit(index) == Some(true) && !self.elements.contains_key(&index) {
+            return;
+        }
+        self.set_bit(index, true);
+        self.elements
+            .entry(index)
+            .or_default()
+            .extend(elements.iter().copied());
+    }
+
+    /// Gets the changed elements of the list Property at the specified
+    /// position, or None if every element should be synced
+    pub fn elements(&self, index: u8) -> Option<&BTreeSet<u32>> {
+        self.elements.get(&index)
+    }
+
     /// Clears the whole DiffMask
     pub fn clear(&mut self) {
         let size = self.mask.len();
         self.mask = vec![0; size];
+        self.elements.clear();
     }
 
     /// Returns whether any bit has been set in the DiffMask
@@ -73,12 +105,32 @@ impl DiffMask {
             return;
         }
 
+        // elements which were not covered by the other DiffMask remain set
+        let mut remaining_elements = BTreeMap::new();
+        for (index, my_elements) in &self.elements {
+            let Some(other_elements) = other.elements.get(index) else {
+                if other.bit(*index) != Some(true) {
+                    remaining_elements.insert(*index, my_elements.clone());
+                }
+                continue;
+            };
+            let elements: BTreeSet<u32> = my_elements.difference(other_elements).copied().collect();
+            if !elements.is_empty() {
+                remaining_elements.insert(*index, elements);
+            }
+        }
+
         for n in 0..self.mask.len() {
             if let Some(my_byte) = self.mask.get_mut(n) {
                 let other_byte = !other.byte(n);
                 *my_byte &= other_byte;
             }
         }
+
+        for (index, elements) in remaining_elements {
+            self.set_bit(index, true);
+            self.elements.insert(index, elements);
+        }
     }
 
     /// Performs an OR operation on the DiffMask, with another DiffMask
@@ -88,6 +140,24 @@ impl DiffMask {
             return;
         }
 
+        // a list is only partially changed if both DiffMasks track its elements
+        let mut elements = BTreeMap::new();
+        for index in 0..(u16::from(self.byte_number()) * 8) {
+            let index = index as u8;
+            let mine = self.elements.get(&index);
+            let others = other.elements.get(&index);
+            let merged = match (self.bit(index), mine, other.bit(index), others) {
+                (Some(true), Some(mine), Some(true), Some(others)) => {
+                    mine.union(others).copied().collect()
+                }
+                (Some(true), Some(mine), Some(false), _) => mine.clone(),
+                (Some(false), _, Some(true), Some(others)) => others.clone(),
+                _ => continue,
+            };
+            elements.insert(index, merged);
+        }
+        self.elements = elements;
+
         for n in 0..self.mask.len() {
             if let Some(my_byte) = self.mask.get_mut(n) {
                 let other_byte = other.byte(n);
@@ -109,6 +179,7 @@ impl DiffMask {
                 *my_byte = other_byte;
             }
         }
+        self.elements = other.elements.clone();
     }
 }
 
@@ -366,3 +437,75 @@ mod double_byte_tests {
         assert!(mask_b.bit(10).unwrap());
     }
 }
+
+#[cfg(test)]
+mod element_tests {
+    use std::collections::BTreeSet;
+
+    use crate::DiffMask;
+
+    #[test]
+    fn set_elements() {
+        let mut mask = DiffMask::new(1);
+
+        mask.set_elements(1, &[2, 5]);
+        mask.set_elements(1, &[3]);
+
+        assert!(mask.bit(1).unwrap());
+        assert_eq!(mask.elements(1), Some(&BTreeSet::from([2, 3, 5])));
+
+        mask.set_bit(1, false);
+
+        assert!(mask.is_clear());
+        assert_eq!(mask.elements(1), None);
+    }
+
+    #[test]
+    fn whole_list_covers_elements() {
+        let mut mask = DiffMask::new(1);
+
+        mask.set_bit(1, true);
+        mask.set_elements(1, &[2]);
+
+        assert!(mask.bit(1).unwrap());
+        assert_eq!(mask.elements(1), None);
+    }
+
+    #[test]
+    fn or_elements() {
+        let mut mask_a = DiffMask::new(1);
+        mask_a.set_elements(1, &[2]);
+        mask_a.set_elements(3, &[4]);
+
+        let mut mask_b = DiffMask::new(1);
+        mask_b.set_elements(1, &[5]);
+        mask_b.set_bit(3, true);
+
+        mask_a.or(&mask_b);
+
+        assert_eq!(mask_a.elements(1), Some(&BTreeSet::from([2, 5])));
+        assert!(mask_a.bit(3).unwrap());
+        assert_eq!(mask_a.elements(3), None);
+    }
+
+    #[test]
+    fn nand_elements() {
+        let mut mask_a = DiffMask::new(1);
+        mask_a.set_elements(1, &[2, 5]);
+        mask_a.set_elements(3, &[4]);
+        mask_a.set_elements(6, &[7]);
+
+        let mut mask_b = DiffMask::new(1);
+        mask_b.set_elements(1, &[5]);
+        mask_b.set_elements(3, &[4]);
+        mask_b.set_bit(6, true);
+
+        mask_a.nand(&mask_b);
+
+        assert!(mask_a.bit(1).unwrap());
+        assert_eq!(mask_a.elements(1), Some(&BTreeSet::from([2])));
+        assert!(!mask_a.bit(3).unwrap());
+        assert!(!mask_a.bit(6).unwrap());
+        assert!(!mask_a.is_clear());
+    }
+}
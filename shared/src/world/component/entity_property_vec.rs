@@ -0,0 +1,671 @@
+use log::warn;
+use std::{
+    collections::{BTreeSet, HashSet},
+    hash::Hash,
+};
+
+use naia_serde::{
+    BitCounter, BitReader, BitWrite, BitWriter, Serde, SerdeErr, UnsignedVariableInteger,
+};
+
+use crate::{
+    world::{
+        component::component_update::ComponentFieldUpdate,
+        entity::{
+            entity_converters::{
+                EntityAndGlobalEntityConverter, LocalEntityAndGlobalEntityConverter,
+                LocalEntityAndGlobalEntityConverterMut,
+            },
+            global_entity::GlobalEntity,
+            local_entity::LocalEntity,
+        },
+    },
+    PropertyMutator,
+};
+
+#[derive(Clone)]
+enum EntityVecRelation {
+    HostOwned(HostOwnedVecRelation),
+    RemoteOwned(RemoteOwnedVecRelation),
+    RemoteWaiting(RemoteWaitingVecRelation),
+}
+
+impl EntityVecRelation {
+    fn is_host_owned(&self) -> bool {
+        match self {
+            EntityVecRelation::HostOwned(_) => true,
+            EntityVecRelation::RemoteOwned(_) | EntityVecRelation::RemoteWaiting(_) => false,
+        }
+    }
+}
+
+/// An ordered list of Entity references, replicated as a single Property.
+///
+/// A Component/Message holding an EntityPropertyVec is only handed to the
+/// remote World once every referenced Entity is in scope for the remote host,
+/// using the same waitlist as [`EntityProperty`](crate::EntityProperty).
+///
+/// Updates only carry the length of the list and the elements which have
+/// changed. Each connection tracks its changed elements in the Component's
+/// [`DiffMask`](crate::DiffMask), so elements lost along with a packet are
+/// sent again. An updated element referencing an Entity which is not yet in
+/// scope for the remote host waits for that Entity on its own, leaving an
+/// empty slot in the list until then.
+#[derive(Clone)]
+pub struct EntityPropertyVec {
+    inner: EntityVecRelation,
+}
+
+impl Default for EntityPropertyVec {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl EntityPropertyVec {
+    // Should only be used by Messages
+    pub fn new() -> Self {
+        Self {
+            inner: EntityVecRelation::HostOwned(HostOwnedVecRelation::new()),
+        }
+    }
+
+    // Should only be used by Components
+    pub fn with_mutator(mutator_index: u8) -> Self {
+        Self {
+            inner: EntityVecRelation::HostOwned(HostOwnedVecRelation::with_mutator(mutator_index)),
+        }
+    }
+
+    pub fn set_mutator(&mut self, mutator: &PropertyMutator) {
+        match &mut self.inner {
+            EntityVecRelation::HostOwned(inner) => {
+                inner.set_mutator(mutator);
+            }
+            EntityVecRelation::RemoteOwned(_) | EntityVecRelation::RemoteWaiting(_) => {
+                panic!("Remote EntityPropertyVec should never have a mutator.");
+            }
+        }
+    }
+
+    // Serialization / deserialization
+
+    /// Writes the whole list
+    pub fn write(
+        &self,
+        writer: &mut dyn BitWrite,
+        converter: &mut dyn LocalEntityAndGlobalEntityConverterMut,
+    ) {
+        match &self.inner {
+            EntityVecRelation::HostOwned(inner) => {
+                inner.write(writer, converter);
+            }
+            EntityVecRelation::RemoteOwned(_) | EntityVecRelation::RemoteWaiting(_) => {
+                panic!("Remote EntityPropertyVec should never be written.");
+            }
+        }
+    }
+
+    /// Writes the length of the list and the given changed elements, or every
+    /// element if None
+    pub fn write_update(
+        &self,
+        elements: Option<&BTreeSet<u32>>,
+        writer: &mut dyn BitWrite,
+        converter: &mut dyn LocalEntityAndGlobalEntityConverterMut,
+    ) {
+        match &self.inner {
+            EntityVecRelation::HostOwned(inner) => {
+                inner.write_update(elements, writer, converter);
+            }
+            EntityVecRelation::RemoteOwned(_) | EntityVecRelation::RemoteWaiting(_) => {
+                panic!("Remote EntityPropertyVec should never be written.");
+            }
+        }
+    }
+
+    pub fn bit_length(&self, converter: &mut dyn LocalEntityAndGlobalEntityConverterMut) -> u32 {
+        match &self.inner {
+            EntityVecRelation::HostOwned(inner) => inner.bit_length(converter),
+            EntityVecRelation::RemoteOwned(_) | EntityVecRelation::RemoteWaiting(_) => {
+                panic!(
+                    "Remote EntityPropertyVec should never be written, so no need for their bit length."
+                );
+            }
+        }
+    }
+
+    pub fn new_read(
+        reader: &mut BitReader,
+        converter: &dyn LocalEntityAndGlobalEntityConverter,
+    ) -> Result<Self, SerdeErr> {
+        let local_entities = Self::read_local_entities(reader)?;
+        Ok(Self {
+            inner: Self::relation_from_local_entities(local_entities, converter),
+        })
+    }
+
+    pub fn read_write(reader: &mut BitReader, writer: &mut BitWriter) -> Result<(), SerdeErr> {
+        let local_entities = Self::read_local_entities(reader)?;
+        Self::write_local_entity_list(&local_entities, writer);
+        Ok(())
+    }
+
+    pub fn read_write_update(
+        reader: &mut BitReader,
+        writer: &mut BitWriter,
+    ) -> Result<(), SerdeErr> {
+        let update = EntityVecUpdate::de(reader)?;
+        update.ser(writer);
+        Ok(())
+    }
+
+    /// Reads an update, applying the new length of the list and every changed
+    /// element
+    pub fn read(
+        &mut self,
+        reader: &mut BitReader,
+        converter: &dyn LocalEntityAndGlobalEntityConverter,
+    ) -> Result<(), SerdeErr> {
+        if self.inner.is_host_owned() {
+            panic!("HostOwned EntityPropertyVec should never read.");
+        }
+        let update = EntityVecUpdate::de(reader)?;
+        self.resize(update.length);
+        for (index, local_entity) in update.elements {
+            self.apply_element(index, local_entity, converter);
+        }
+        Ok(())
+    }
+
+    /// Reads a single element of an update, which has been waiting for its
+    /// Entity to come into scope
+    pub fn read_element(
+        &mut self,
+        element_index: Option<u32>,
+        reader: &mut BitReader,
+        converter: &dyn LocalEntityAndGlobalEntityConverter,
+    ) -> Result<(), SerdeErr> {
+        if self.inner.is_host_owned() {
+            panic!("HostOwned EntityPropertyVec should never read.");
+        }
+        let local_entity = Self::read_element_value(reader)?;
+        let Some(index) = element_index else {
+            warn!("EntityPropertyVec received an element update without an index");
+            return Ok(());
+        };
+        self.apply_element(index, local_entity, converter);
+        Ok(())
+    }
+
+    /// Splits an update into the part which is ready to be applied, written
+    /// into `ready_writer`, and the elements which are waiting on an Entity to
+    /// come into scope
+    pub fn split_update(
+        field_id: u8,
+        reader: &mut BitReader,
+        converter: &dyn LocalEntityAndGlobalEntityConverter,
+        ready_writer: &mut BitWriter,
+    ) -> Result<Vec<(HashSet<LocalEntity>, ComponentFieldUpdate)>, SerdeErr> {
+        let update = EntityVecUpdate::de(reader)?;
+
+        let mut ready_update = EntityVecUpdate {
+            length: update.length,
+            elements: Vec::new(),
+        };
+        let mut waiting_updates = Vec::new();
+        for (index, local_entity) in update.elements {
+            if let Some(waiting_entity) = local_entity {
+                if converter
+                    .local_entity_to_global_entity(&waiting_entity)
+                    .is_err()
+                {
+                    let mut waiting_writer = BitWriter::new();
+                    Self::write_element_value(&local_entity, &mut waiting_writer);
+                    waiting_updates.push((
+                        HashSet::from([waiting_entity]),
+                        ComponentFieldUpdate::new_element(
+                            field_id,
+                            index,
+                            waiting_writer.to_owned_reader(),
+                        ),
+                    ));
+                    continue;
+                }
+            }
+            ready_update.elements.push((index, local_entity));
+        }
+        ready_update.ser(ready_writer);
+
+        Ok(waiting_updates)
+    }
+
+    fn resize(&mut self, length: usize) {
+        match &mut self.inner {
+            EntityVecRelation::HostOwned(_) => {}
+            EntityVecRelation::RemoteOwned(inner) => inner.global_entities.resize(length, None),
+            EntityVecRelation::RemoteWaiting(inner) => inner.local_entities.resize(length, None),
+        }
+    }
+
+    fn apply_element(
+        &mut self,
+        index: u32,
+        local_entity: Option<LocalEntity>,
+        converter: &dyn LocalEntityAndGlobalEntityConverter,
+    ) {
+        let index = index as usize;
+        match &mut self.inner {
+            EntityVecRelation::HostOwned(_) => {}
+            EntityVecRelation::RemoteOwned(inner) => {
+                let Some(slot) = inner.global_entities.get_mut(index) else {
+                    return;
+                };
+                *slot = local_entity.and_then(|local_entity| {
+                    let global_entity = converter.local_entity_to_global_entity(&local_entity);
+                    if global_entity.is_err() {
+                        warn!("Could not find Global Entity from Local Entity, in order to read the EntityPropertyVec element!");
+                    }
+                    global_entity.ok()
+                });
+            }
+            EntityVecRelation::RemoteWaiting(inner) => {
+                if let Some(slot) = inner.local_entities.get_mut(index) {
+                    *slot = local_entity;
+                }
+            }
+        }
+    }
+
+    fn read_local_entities(reader: &mut BitReader) -> Result<Vec<Option<LocalEntity>>, SerdeErr> {
+        let length = UnsignedVariableInteger::<3>::de(reader)?.get();
+        let mut local_entities = Vec::new();
+        for _ in 0..length {
+            local_entities.push(Self::read_element_value(reader)?);
+        }
+        Ok(local_entities)
+    }
+
+    fn write_local_entity_list(local_entities: &[Option<LocalEntity>], writer: &mut dyn BitWrite) {
+        UnsignedVariableInteger::<3>::new(local_entities.len() as u64).ser(writer);
+        for local_entity in local_entities {
+            Self::write_element_value(local_entity, writer);
+        }
+    }
+
+    fn read_element_value(reader: &mut BitReader) -> Result<Option<LocalEntity>, SerdeErr> {
+        if bool::de(reader)? {
+            Ok(Some(LocalEntity::owned_de(reader)?))
+        } else {
+            Ok(None)
+        }
+    }
+
+    fn write_element_value(local_entity: &Option<LocalEntity>, writer: &mut dyn BitWrite) {
+        local_entity.is_some().ser(writer);
+        if let Some(local_entity) = local_entity {
+            local_entity.owned_ser(writer);
+        }
+    }
+
+    fn relation_from_local_entities(
+        local_entities: Vec<Option<LocalEntity>>,
+        converter: &dyn LocalEntityAndGlobalEntityConverter,
+    ) -> EntityVecRelation {
+        let mut global_entities = Vec::new();
+        for local_entity in &local_entities {
+            let Some(local_entity) = local_entity else {
+                global_entities.push(None);
+                continue;
+            };
+            let Ok(global_entity) = converter.local_entity_to_global_entity(local_entity) else {
+                return EntityVecRelation::RemoteWaiting(RemoteWaitingVecRelation::new(
+                    local_entities,
+                ));
+            };
+            global_entities.push(Some(global_entity));
+        }
+        EntityVecRelation::RemoteOwned(RemoteOwnedVecRelation::new(global_entities))
+    }
+
+    // Internal
+
+    /// Gets the list of referenced World Entities, skipping any that can't be
+    /// found or have yet to arrive
+    pub fn get<E: Copy + Eq + Hash>(
+        &self,
+        converter: &dyn EntityAndGlobalEntityConverter<E>,
+    ) -> Vec<E> {
+        match &self.inner {
+            EntityVecRelation::HostOwned(inner) => {
+                Self::global_to_world_entities(inner.global_entities.iter(), converter)
+            }
+            EntityVecRelation::RemoteOwned(inner) => {
+                Self::global_to_world_entities(inner.global_entities.iter().flatten(), converter)
+            }
+            EntityVecRelation::RemoteWaiting(_) => {
+                panic!("Not ready to get RemoteWaiting EntityPropertyVec value!");
+            }
+        }
+    }
+
+    fn global_to_world_entities<'a, E: Copy + Eq + Hash>(
+        global_entities: impl Iterator<Item = &'a GlobalEntity>,
+        converter: &dyn EntityAndGlobalEntityConverter<E>,
+    ) -> Vec<E> {
+        let mut output = Vec::new();
+        for global_entity in global_entities {
+            if let Ok(world_entity) = converter.global_entity_to_entity(global_entity) {
+                output.push(world_entity);
+            } else {
+                warn!("Could not find World Entity from Global Entity, in order to get the EntityPropertyVec value!");
+            }
+        }
+        output
+    }
+
+    /// Gets the length of the list, including any empty slots
+    pub fn len(&self) -> usize {
+        match &self.inner {
+            EntityVecRelation::HostOwned(inner) => inner.global_entities.len(),
+            EntityVecRelation::RemoteOwned(inner) => inner.global_entities.len(),
+            EntityVecRelation::RemoteWaiting(inner) => inner.local_entities.len(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Replaces the whole list of referenced Entities
+    pub fn set<E: Copy + Eq + Hash>(
+        &mut self,
+        converter: &dyn EntityAndGlobalEntityConverter<E>,
+        entities: &[E],
+    ) {
+        let inner = self.host_owned_mut();
+        let mut global_entities = Vec::new();
+        for entity in entities {
+            if let Ok(global_entity) = converter.entity_to_global_entity(entity) {
+                global_entities.push(global_entity);
+            } else {
+                warn!("Could not find Global Entity from World Entity, in order to set the EntityPropertyVec value!");
+            }
+        }
+        inner.replace(global_entities);
+    }
+
+    /// Appends an Entity to the end of the list
+    pub fn push<E: Copy + Eq + Hash>(
+        &mut self,
+        converter: &dyn EntityAndGlobalEntityConverter<E>,
+        entity: &E,
+    ) {
+        let inner = self.host_owned_mut();
+        let Ok(global_entity) = converter.entity_to_global_entity(entity) else {
+            warn!("Could not find Global Entity from World Entity, in order to push to the EntityPropertyVec!");
+            return;
+        };
+        inner.global_entities.push(global_entity);
+        let index = (inner.global_entities.len() - 1) as u32;
+        inner.mutate(&[index]);
+    }
+
+    /// Removes every occurrence of an Entity from the list.
+    /// Returns true if the Entity was found.
+    pub fn remove<E: Copy + Eq + Hash>(
+        &mut self,
+        converter: &dyn EntityAndGlobalEntityConverter<E>,
+        entity: &E,
+    ) -> bool {
+        let inner = self.host_owned_mut();
+        let Ok(global_entity) = converter.entity_to_global_entity(entity) else {
+            return false;
+        };
+        let mut global_entities = inner.global_entities.clone();
+        global_entities.retain(|other_entity| *other_entity != global_entity);
+        if global_entities.len() == inner.global_entities.len() {
+            return false;
+        }
+        inner.replace(global_entities);
+        true
+    }
+
+    /// Removes all Entities from the list
+    pub fn clear(&mut self) {
+        let inner = self.host_owned_mut();
+        if inner.global_entities.is_empty() {
+            return;
+        }
+        inner.global_entities.clear();
+        inner.mutate(&[]);
+    }
+
+    fn host_owned_mut(&mut self) -> &mut HostOwnedVecRelation {
+        match &mut self.inner {
+            EntityVecRelation::HostOwned(inner) => inner,
+            EntityVecRelation::RemoteOwned(_) | EntityVecRelation::RemoteWaiting(_) => {
+                panic!("Remote EntityPropertyVec should never be set manually.");
+            }
+        }
+    }
+
+    pub fn mirror(&mut self, other: &EntityPropertyVec) {
+        let global_entities = match &other.inner {
+            EntityVecRelation::HostOwned(other_inner) => other_inner.global_entities.clone(),
+            EntityVecRelation::RemoteOwned(other_inner) => other_inner
+                .global_entities
+                .iter()
+                .flatten()
+                .copied()
+                .collect(),
+            EntityVecRelation::RemoteWaiting(_) => Vec::new(),
+        };
+        self.host_owned_mut().replace(global_entities);
+    }
+
+    // Waiting
+
+    pub fn waiting_local_entities(&self) -> Option<HashSet<LocalEntity>> {
+        match &self.inner {
+            EntityVecRelation::HostOwned(_) | EntityVecRelation::RemoteOwned(_) => None,
+            EntityVecRelation::RemoteWaiting(inner) => {
+                Some(inner.local_entities.iter().flatten().copied().collect())
+            }
+        }
+    }
+
+    pub fn waiting_complete(&mut self, converter: &dyn LocalEntityAndGlobalEntityConverter) {
+        match &mut self.inner {
+            EntityVecRelation::HostOwned(_) | EntityVecRelation::RemoteOwned(_) => {
+                panic!("Can't complete a RemoteOwned or HostOwned Relation!");
+            }
+            EntityVecRelation::RemoteWaiting(inner) => {
+                let mut global_entities = Vec::new();
+                for local_entity in &inner.local_entities {
+                    let Some(local_entity) = local_entity else {
+                        global_entities.push(None);
+                        continue;
+                    };
+                    if let Ok(global_entity) = converter.local_entity_to_global_entity(local_entity)
+                    {
+                        global_entities.push(Some(global_entity));
+                    } else {
+                        panic!("Could not find Global Entity from Local Entity! Should only call `waiting_complete` when it is known the converter will not fail!");
+                    }
+                }
+                self.inner =
+                    EntityVecRelation::RemoteOwned(RemoteOwnedVecRelation::new(global_entities));
+            }
+        }
+    }
+}
+
+// EntityVecUpdate
+
+// The length of a list, and the elements of it which have changed
+struct EntityVecUpdate {
+    length: usize,
+    elements: Vec<(u32, Option<LocalEntity>)>,
+}
+
+impl EntityVecUpdate {
+    fn ser(&self, writer: &mut dyn BitWrite) {
+        UnsignedVariableInteger::<3>::new(self.length as u64).ser(writer);
+        for (index, local_entity) in &self.elements {
+            // continue bit
+            true.ser(writer);
+            UnsignedVariableInteger::<3>::new(*index as u64).ser(writer);
+            EntityPropertyVec::write_element_value(local_entity, writer);
+        }
+        // finish bit
+        false.ser(writer);
+    }
+
+    fn de(reader: &mut BitReader) -> Result<Self, SerdeErr> {
+        let length = UnsignedVariableInteger::<3>::de(reader)?.get() as usize;
+        let mut elements = Vec::new();
+        while bool::de(reader)? {
+            let index = UnsignedVariableInteger::<3>::de(reader)?.get() as u32;
+            let local_entity = EntityPropertyVec::read_element_value(reader)?;
+            elements.push((index, local_entity));
+        }
+        Ok(Self { length, elements })
+    }
+}
+
+// HostOwnedVecRelation
+#[derive(Clone)]
+struct HostOwnedVecRelation {
+    global_entities: Vec<GlobalEntity>,
+    mutator: Option<PropertyMutator>,
+    mutator_index: u8,
+}
+
+impl HostOwnedVecRelation {
+    pub fn new() -> Self {
+        Self {
+            global_entities: Vec::new(),
+            mutator: None,
+            mutator_index: 0,
+        }
+    }
+
+    pub fn with_mutator(mutate_index: u8) -> Self {
+        Self {
+            global_entities: Vec::new(),
+            mutator: None,
+            mutator_index: mutate_index,
+        }
+    }
+
+    pub fn set_mutator(&mut self, mutator: &PropertyMutator) {
+        self.mutator = Some(mutator.clone_new());
+    }
+
+    pub fn write(
+        &self,
+        writer: &mut dyn BitWrite,
+        converter: &mut dyn LocalEntityAndGlobalEntityConverterMut,
+    ) {
+        let mut local_entities = Vec::new();
+        for global_entity in &self.global_entities {
+            local_entities.push(Self::host_local_entity(global_entity, converter));
+        }
+
+        EntityPropertyVec::write_local_entity_list(&local_entities, writer);
+    }
+
+    pub fn write_update(
+        &self,
+        elements: Option<&BTreeSet<u32>>,
+        writer: &mut dyn BitWrite,
+        converter: &mut dyn LocalEntityAndGlobalEntityConverterMut,
+    ) {
+        let length = self.global_entities.len();
+        let indices: Vec<u32> = match elements {
+            Some(elements) => elements
+                .iter()
+                .copied()
+                .filter(|index| (*index as usize) < length)
+                .collect(),
+            None => (0..length as u32).collect(),
+        };
+
+        let mut update = EntityVecUpdate {
+            length,
+            elements: Vec::new(),
+        };
+        for index in indices {
+            let global_entity = &self.global_entities[index as usize];
+            update
+                .elements
+                .push((index, Self::host_local_entity(global_entity, converter)));
+        }
+        update.ser(writer);
+    }
+
+    // Entities which cannot relate to the remote host are left as empty slots
+    fn host_local_entity(
+        global_entity: &GlobalEntity,
+        converter: &mut dyn LocalEntityAndGlobalEntityConverterMut,
+    ) -> Option<LocalEntity> {
+        let local_entity = converter.get_or_reserve_host_entity(global_entity).ok()?;
+
+        // Must reverse the LocalEntity because the Host<->Remote
+        // relationship inverts after this data goes over the wire
+        Some(local_entity.to_reversed())
+    }
+
+    pub fn bit_length(&self, converter: &mut dyn LocalEntityAndGlobalEntityConverterMut) -> u32 {
+        let mut bit_counter = BitCounter::new(0, 0, u32::MAX);
+        self.write(&mut bit_counter, converter);
+        bit_counter.bits_needed()
+    }
+
+    // Replaces the list, marking only the elements which differ as changed
+    fn replace(&mut self, global_entities: Vec<GlobalEntity>) {
+        let old_entities = std::mem::replace(&mut self.global_entities, global_entities);
+        let changed: Vec<u32> = self
+            .global_entities
+            .iter()
+            .enumerate()
+            .filter(|(index, global_entity)| old_entities.get(*index) != Some(*global_entity))
+            .map(|(index, _)| index as u32)
+            .collect();
+        if changed.is_empty() && old_entities.len() == self.global_entities.len() {
+            return;
+        }
+        self.mutate(&changed);
+    }
+
+    fn mutate(&mut self, changed_elements: &[u32]) {
+        if let Some(mutator) = &mut self.mutator {
+            mutator.mutate_elements(self.mutator_index, changed_elements);
+        }
+    }
+}
+
+// RemoteOwnedVecRelation
+#[derive(Clone)]
+struct RemoteOwnedVecRelation {
+    global_entities: Vec<Option<GlobalEntity>>,
+}
+
+impl RemoteOwnedVecRelation {
+    fn new(global_entities: Vec<Option<GlobalEntity>>) -> Self {
+        Self { global_entities }
+    }
+}
+
+// RemoteWaitingVecRelation
+#[derive(Clone)]
+struct RemoteWaitingVecRelation {
+    local_entities: Vec<Option<LocalEntity>>,
+}
+
+impl RemoteWaitingVecRelation {
+    fn new(local_entities: Vec<Option<LocalEntity>>) -> Self {
+        Self { local_entities }
+    }
+}
@@ -6,6 +6,10 @@ pub trait PropertyMutate: PropertyMutateClone + Send + Sync + 'static {
     /// Given the index of the Property whose value has changed, queue that
     /// Property for transmission to the Client
     fn mutate(&mut self, property_index: u8);
+    /// Given the index of a list Property and the indices of its elements
+    /// which have changed, queue those elements for transmission to the
+    /// Client
+    fn mutate_elements(&mut self, property_index: u8, element_indices: &[u32]);
 }
 
 pub trait PropertyMutateClone {
@@ -26,6 +26,15 @@ pub trait ReplicateBuilder: Send + Sync + Named {
     ) -> Result<Box<dyn Replicate>, SerdeErr>;
     /// Create new Component Update from incoming bit stream
     fn read_create_update(&self, reader: &mut BitReader) -> Result<ComponentUpdate, SerdeErr>;
+    /// Read a Component Update from incoming bit stream, getting the name &
+    /// bit length of each updated field, for inspecting packets. Fails by
+    /// default, so only derived Components can be inspected.
+    fn read_update_fields(
+        &self,
+        _reader: &mut BitReader,
+    ) -> Result<Vec<(&'static str, u32)>, SerdeErr> {
+        Err(SerdeErr)
+    }
     /// Split a Component update into Waiting and Ready updates
     fn split_update(
         &self,
@@ -33,7 +42,7 @@ pub trait ReplicateBuilder: Send + Sync + Named {
         update: ComponentUpdate,
     ) -> Result<
         (
-            Option<Vec<(LocalEntity, ComponentFieldUpdate)>>,
+            Option<Vec<(HashSet<LocalEntity>, ComponentFieldUpdate)>>,
             Option<ComponentUpdate>,
         ),
         SerdeErr,
@@ -94,9 +103,9 @@ pub trait Replicate: ReplicateInner + Named + Any {
         converter: &dyn LocalEntityAndGlobalEntityConverter,
         update: ComponentFieldUpdate,
     ) -> Result<(), SerdeErr>;
-    /// Returns a list of LocalEntities contained within the Component's EntityProperty fields, which are waiting to be converted to GlobalEntities
+    /// Returns a list of LocalEntities contained within the Component's EntityProperty & EntityPropertyVec fields, which are waiting to be converted to GlobalEntities
     fn relations_waiting(&self) -> Option<HashSet<LocalEntity>>;
-    /// Converts any LocalEntities contained within the Component's EntityProperty fields to GlobalEntities
+    /// Converts any LocalEntities contained within the Component's EntityProperty & EntityPropertyVec fields to GlobalEntities
     fn relations_complete(&mut self, converter: &dyn LocalEntityAndGlobalEntityConverter);
     // /// Returns whether has any EntityProperties
     // fn has_entity_properties(&self) -> bool;
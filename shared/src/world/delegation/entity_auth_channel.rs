@@ -0,0 +1,7 @@
+use crate::Channel;
+
+/// Internal Channel used to negotiate authority over delegated Entities, and
+/// to send the state of delegated Entities from the Client holding authority
+/// over them to the Server
+#[derive(Channel)]
+pub struct EntityAuthChannel;
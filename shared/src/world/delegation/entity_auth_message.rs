@@ -0,0 +1,75 @@
+use std::hash::Hash;
+
+use naia_derive::MessageInternal;
+use naia_serde::SerdeInternal;
+
+use crate::{EntityAndGlobalEntityConverter, EntityProperty};
+
+// Enum used as a shared network protocol, representing the various steps
+// of negotiating authority over a delegated Entity
+#[derive(Copy, Clone, PartialEq, Eq, Debug, SerdeInternal)]
+pub enum EntityAuthAction {
+    // Server -> Client: the Entity can now have authority delegated to a Client
+    EnableDelegation,
+    // Server -> Client: the Entity can no longer have authority delegated to a Client
+    DisableDelegation,
+    // Client -> Server: the Client would like authority over the Entity
+    Request,
+    // Client -> Server: the Client gives up its authority over the Entity
+    Release,
+    // Server -> Client: the Client has been given authority over the Entity
+    Grant,
+    // Server -> Client: the Client's request for authority has been denied
+    Deny,
+    // Server -> Client: the Client's authority over the Entity has been taken away
+    Revoke,
+    // Server -> Client: the Server despawned an Entity the Client owns, as a validator
+    // refused its state, so the Client must despawn it too
+    Despawn,
+}
+
+#[derive(MessageInternal)]
+pub struct EntityAuthMessage {
+    pub entity: EntityProperty,
+    pub action: EntityAuthAction,
+}
+
+impl EntityAuthMessage {
+    pub fn new<E: Copy + Eq + Hash>(
+        converter: &dyn EntityAndGlobalEntityConverter<E>,
+        entity: &E,
+        action: EntityAuthAction,
+    ) -> Self {
+        let mut entity_property = EntityProperty::new();
+        entity_property.set(converter, entity);
+
+        Self {
+            entity: entity_property,
+            action,
+        }
+    }
+}
+
+/// Carries the full state of the changed Components of a delegated Entity,
+/// sent by the Client which holds authority over it
+#[derive(MessageInternal)]
+pub struct EntityAuthUpdateMessage {
+    pub entity: EntityProperty,
+    pub bytes: Box<[u8]>,
+}
+
+impl EntityAuthUpdateMessage {
+    pub fn new<E: Copy + Eq + Hash>(
+        converter: &dyn EntityAndGlobalEntityConverter<E>,
+        entity: &E,
+        bytes: Box<[u8]>,
+    ) -> Self {
+        let mut entity_property = EntityProperty::new();
+        entity_property.set(converter, entity);
+
+        Self {
+            entity: entity_property,
+            bytes,
+        }
+    }
+}
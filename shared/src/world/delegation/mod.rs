@@ -0,0 +1,2 @@
+pub mod entity_auth_channel;
+pub mod entity_auth_message;
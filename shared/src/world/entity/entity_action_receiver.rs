@@ -22,9 +22,10 @@ impl<E: Copy + Hash + Eq> EntityActionReceiver<E> {
         }
     }
 
-    /// Buffer a read [`EntityAction`] so that it can be processed later
-    pub fn buffer_action(&mut self, action_index: ActionIndex, action: EntityAction<E>) {
-        self.receiver.buffer_message(action_index, action);
+    /// Buffer a read [`EntityAction`] so that it can be processed later.
+    /// Returns whether the [`EntityAction`] was not received before
+    pub fn buffer_action(&mut self, action_index: ActionIndex, action: EntityAction<E>) -> bool {
+        self.receiver.buffer_message(action_index, action)
     }
 
     /// Read all buffered [`EntityAction`] inside the `receiver` and process them.
@@ -32,6 +33,15 @@ impl<E: Copy + Hash + Eq> EntityActionReceiver<E> {
     /// Outputs the list of [`EntityAction`] that can be executed now, buffer the rest
     /// into each entity's [`EntityChannel`]
     pub fn receive_actions(&mut self) -> Vec<EntityAction<E>> {
+        self.receive_indexed_actions()
+            .into_iter()
+            .map(|(_, action)| action)
+            .collect()
+    }
+
+    /// Same as `receive_actions()`, but outputs each [`EntityAction`] along with
+    /// its index
+    pub fn receive_indexed_actions(&mut self) -> Vec<(ActionIndex, EntityAction<E>)> {
         let mut outgoing_actions = Vec::new();
         let incoming_actions = self.receiver.receive_messages();
         for (action_index, action) in incoming_actions {
@@ -83,7 +93,7 @@ impl<E: Copy + Hash + Eq> EntityChannel<E> {
         &mut self,
         incoming_action_index: ActionIndex,
         incoming_action: EntityAction<E>,
-        outgoing_actions: &mut Vec<EntityAction<E>>,
+        outgoing_actions: &mut Vec<(ActionIndex, EntityAction<E>)>,
     ) {
         match incoming_action {
             EntityAction::SpawnEntity(_, components) => {
@@ -121,7 +131,7 @@ impl<E: Copy + Hash + Eq> EntityChannel<E> {
         &mut self,
         action_index: ActionIndex,
         components: Vec<ComponentKind>,
-        outgoing_actions: &mut Vec<EntityAction<E>>,
+        outgoing_actions: &mut Vec<(ActionIndex, EntityAction<E>)>,
     ) {
         // do not process any spawn OLDER than last received spawn index / despawn index
         if let Some(last_index) = self.last_canonical_index {
@@ -132,11 +142,24 @@ impl<E: Copy + Hash + Eq> EntityChannel<E> {
 
         if !self.spawned {
             self.spawned = true;
-            outgoing_actions.push(EntityAction::SpawnEntity(self.entity, components));
 
             // pop ALL waiting spawns, despawns, inserts, and removes OLDER than spawn_index
             self.receive_canonical(action_index);
 
+            // components sent along with the spawn are inserted, so can be removed later
+            for component in &components {
+                let component_state = self
+                    .components
+                    .entry(*component)
+                    .or_insert_with(|| ComponentChannel::new(Some(action_index)));
+                component_state.inserted = true;
+            }
+
+            outgoing_actions.push((
+                action_index,
+                EntityAction::SpawnEntity(self.entity, components),
+            ));
+
             // process any waiting despawns
             if let Some((despawn_index, _)) = self.waiting_despawns.inner.pop_front() {
                 self.receive_despawn_entity_action(despawn_index, outgoing_actions);
@@ -165,7 +188,7 @@ impl<E: Copy + Hash + Eq> EntityChannel<E> {
     pub fn receive_despawn_entity_action(
         &mut self,
         index: ActionIndex,
-        outgoing_actions: &mut Vec<EntityAction<E>>,
+        outgoing_actions: &mut Vec<(ActionIndex, EntityAction<E>)>,
     ) {
         // do not process any despawn OLDER than last received spawn index / despawn index
         if let Some(last_index) = self.last_canonical_index {
@@ -176,7 +199,7 @@ impl<E: Copy + Hash + Eq> EntityChannel<E> {
 
         if self.spawned {
             self.spawned = false;
-            outgoing_actions.push(EntityAction::DespawnEntity(self.entity));
+            outgoing_actions.push((index, EntityAction::DespawnEntity(self.entity)));
 
             // pop ALL waiting spawns, despawns, inserts, and removes OLDER than despawn_index
             self.receive_canonical(index);
@@ -200,7 +223,7 @@ impl<E: Copy + Hash + Eq> EntityChannel<E> {
         &mut self,
         index: ActionIndex,
         component: ComponentKind,
-        outgoing_actions: &mut Vec<EntityAction<E>>,
+        outgoing_actions: &mut Vec<(ActionIndex, EntityAction<E>)>,
     ) {
         // do not process any insert OLDER than last received spawn index / despawn index
         if let Some(last_index) = self.last_canonical_index {
@@ -224,7 +247,7 @@ impl<E: Copy + Hash + Eq> EntityChannel<E> {
 
         if !component_state.inserted {
             component_state.inserted = true;
-            outgoing_actions.push(EntityAction::InsertComponent(self.entity, component));
+            outgoing_actions.push((index, EntityAction::InsertComponent(self.entity, component)));
 
             // pop ALL waiting inserts, and removes OLDER than insert_index (in reference to
             // component)
@@ -244,7 +267,7 @@ impl<E: Copy + Hash + Eq> EntityChannel<E> {
         &mut self,
         index: ActionIndex,
         component: ComponentKind,
-        outgoing_actions: &mut Vec<EntityAction<E>>,
+        outgoing_actions: &mut Vec<(ActionIndex, EntityAction<E>)>,
     ) {
         // do not process any remove OLDER than last received spawn index / despawn index
         if let Some(last_index) = self.last_canonical_index {
@@ -268,7 +291,7 @@ impl<E: Copy + Hash + Eq> EntityChannel<E> {
 
         if component_state.inserted {
             component_state.inserted = false;
-            outgoing_actions.push(EntityAction::RemoveComponent(self.entity, component));
+            outgoing_actions.push((index, EntityAction::RemoveComponent(self.entity, component)));
 
             // pop ALL waiting inserts, and removes OLDER than remove_index (in reference to
             // component)
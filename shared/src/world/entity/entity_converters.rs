@@ -8,6 +8,7 @@ use crate::{
     world::{
         entity::{
             error::EntityDoesNotExistError, global_entity::GlobalEntity, local_entity::LocalEntity,
+            prediction_key::PredictionKey,
         },
         host::mut_channel::MutChannelType,
     },
@@ -22,6 +23,12 @@ pub trait GlobalWorldManagerType<E: Copy + Eq + Hash>: EntityAndGlobalEntityConv
     fn diff_handler(&self) -> Arc<RwLock<GlobalDiffHandler<E>>>;
     fn remote_spawn_entity(&mut self, entity: &E, user_key: &u64);
     fn remote_despawn_entity(&mut self, entity: &E);
+    /// Takes the Entity which was spawned locally in prediction of a remote
+    /// Entity tagged with the given key, if any, so that the remote Entity can
+    /// be mapped onto it
+    fn take_predicted_entity(&mut self, _prediction_key: &PredictionKey) -> Option<E> {
+        None
+    }
 }
 
 pub trait EntityAndGlobalEntityConverter<E: Copy + Eq + Hash> {
@@ -191,7 +198,8 @@ impl<'a, 'b, E: Copy + Eq + Hash> LocalEntityAndGlobalEntityConverterMut
     ) -> Result<LocalEntity, EntityDoesNotExistError> {
         let Ok(entity) = self
             .global_world_manager
-            .global_entity_to_entity(global_entity) else {
+            .global_entity_to_entity(global_entity)
+        else {
             return Err(EntityDoesNotExistError);
         };
         if !self
@@ -207,3 +215,35 @@ impl<'a, 'b, E: Copy + Eq + Hash> LocalEntityAndGlobalEntityConverterMut
         return Ok(self.local_world_manager.host_reserve_entity(&entity));
     }
 }
+
+/// Reads entity references which were written by this same side of the
+/// connection. Written LocalEntities are reversed in anticipation of crossing
+/// the wire, so they must be reversed again to be read back locally.
+pub struct ReversedEntityConverter<'c> {
+    inner: &'c dyn LocalEntityAndGlobalEntityConverter,
+}
+
+impl<'c> ReversedEntityConverter<'c> {
+    pub fn new(inner: &'c dyn LocalEntityAndGlobalEntityConverter) -> Self {
+        Self { inner }
+    }
+}
+
+impl<'c> LocalEntityAndGlobalEntityConverter for ReversedEntityConverter<'c> {
+    fn global_entity_to_local_entity(
+        &self,
+        global_entity: &GlobalEntity,
+    ) -> Result<LocalEntity, EntityDoesNotExistError> {
+        self.inner
+            .global_entity_to_local_entity(global_entity)
+            .map(LocalEntity::to_reversed)
+    }
+
+    fn local_entity_to_global_entity(
+        &self,
+        local_entity: &LocalEntity,
+    ) -> Result<GlobalEntity, EntityDoesNotExistError> {
+        self.inner
+            .local_entity_to_global_entity(&local_entity.to_reversed())
+    }
+}
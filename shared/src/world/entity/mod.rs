@@ -6,3 +6,4 @@ pub mod entity_ref;
 pub mod error;
 pub mod global_entity;
 pub mod local_entity;
+pub mod prediction_key;
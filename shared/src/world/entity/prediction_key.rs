@@ -0,0 +1,44 @@
+use naia_serde::{BitReader, BitWrite, ConstBitLength, Serde, SerdeErr};
+
+/// Identifies an Entity a Client spawned locally in prediction of one the
+/// Server is going to spawn. The Client sends it to the Server (i.e. as part
+/// of the command which causes the spawn), and the Server tags the Entity it
+/// spawns in response with it, so that the Client can map the Server Entity
+/// onto its predicted one.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct PredictionKey {
+    inner: u16,
+}
+
+impl PredictionKey {
+    pub fn new(value: u16) -> Self {
+        Self { inner: value }
+    }
+
+    pub fn next(&self) -> Self {
+        Self {
+            inner: self.inner.wrapping_add(1),
+        }
+    }
+}
+
+impl Serde for PredictionKey {
+    fn ser(&self, writer: &mut dyn BitWrite) {
+        self.inner.ser(writer);
+    }
+
+    fn de(reader: &mut BitReader) -> Result<Self, SerdeErr> {
+        let inner = u16::de(reader)?;
+        Ok(Self { inner })
+    }
+
+    fn bit_length(&self) -> u32 {
+        <Self as ConstBitLength>::const_bit_length()
+    }
+}
+
+impl ConstBitLength for PredictionKey {
+    fn const_bit_length() -> u32 {
+        <u16 as ConstBitLength>::const_bit_length()
+    }
+}
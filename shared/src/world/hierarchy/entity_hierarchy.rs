@@ -0,0 +1,174 @@
+use std::{
+    collections::{HashMap, VecDeque},
+    hash::Hash,
+};
+
+/// Parent / child relationships between Entities, forming a forest of
+/// Entity trees
+pub struct EntityHierarchy<E: Copy + Eq + Hash> {
+    parents: HashMap<E, E>,
+    children: HashMap<E, Vec<E>>,
+}
+
+impl<E: Copy + Eq + Hash> Default for EntityHierarchy<E> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<E: Copy + Eq + Hash> EntityHierarchy<E> {
+    pub fn new() -> Self {
+        Self {
+            parents: HashMap::new(),
+            children: HashMap::new(),
+        }
+    }
+
+    /// Makes `parent` the parent of `child`, detaching `child` from any
+    /// previous parent. Returns false, leaving the hierarchy untouched, if
+    /// this would create a cycle.
+    pub fn set_parent(&mut self, child: &E, parent: &E) -> bool {
+        if child == parent || self.is_ancestor(child, parent) {
+            return false;
+        }
+        if self.parents.get(child) == Some(parent) {
+            return true;
+        }
+
+        self.remove_parent(child);
+        self.parents.insert(*child, *parent);
+        self.children.entry(*parent).or_default().push(*child);
+        true
+    }
+
+    /// Detaches `child` from its parent, returning the previous parent
+    pub fn remove_parent(&mut self, child: &E) -> Option<E> {
+        let parent = self.parents.remove(child)?;
+        if let Some(siblings) = self.children.get_mut(&parent) {
+            siblings.retain(|sibling| sibling != child);
+            if siblings.is_empty() {
+                self.children.remove(&parent);
+            }
+        }
+        Some(parent)
+    }
+
+    /// Removes an Entity from the hierarchy, detaching it from its parent and
+    /// leaving its children as roots. Returns the orphaned children.
+    pub fn remove_entity(&mut self, entity: &E) -> Vec<E> {
+        self.remove_parent(entity);
+        let children = self.children.remove(entity).unwrap_or_default();
+        for child in &children {
+            self.parents.remove(child);
+        }
+        children
+    }
+
+    pub fn parent(&self, entity: &E) -> Option<E> {
+        self.parents.get(entity).copied()
+    }
+
+    pub fn children(&self, entity: &E) -> &[E] {
+        self.children
+            .get(entity)
+            .map(|children| children.as_slice())
+            .unwrap_or(&[])
+    }
+
+    pub fn has_parent(&self, entity: &E) -> bool {
+        self.parents.contains_key(entity)
+    }
+
+    /// Returns the topmost ancestor of an Entity, or the Entity itself if it
+    /// has no parent
+    pub fn root(&self, entity: &E) -> E {
+        let mut current = *entity;
+        while let Some(parent) = self.parents.get(&current) {
+            current = *parent;
+        }
+        current
+    }
+
+    /// Returns all descendants of an Entity, with every parent ordered before
+    /// its children
+    pub fn descendants(&self, entity: &E) -> Vec<E> {
+        let mut output = Vec::new();
+        let mut queue = VecDeque::new();
+        queue.push_back(*entity);
+        while let Some(current) = queue.pop_front() {
+            for child in self.children(&current) {
+                output.push(*child);
+                queue.push_back(*child);
+            }
+        }
+        output
+    }
+
+    /// Fills `output` with an Entity followed by all its descendants, with
+    /// every parent ordered before its children. Reuses `output`'s allocation.
+    pub fn tree_into(&self, entity: &E, output: &mut Vec<E>) {
+        output.clear();
+        output.push(*entity);
+        let mut index = 0;
+        while index < output.len() {
+            let current = output[index];
+            output.extend_from_slice(self.children(&current));
+            index += 1;
+        }
+    }
+
+    fn is_ancestor(&self, ancestor: &E, entity: &E) -> bool {
+        let mut current = *entity;
+        while let Some(parent) = self.parents.get(&current) {
+            if parent == ancestor {
+                return true;
+            }
+            current = *parent;
+        }
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::EntityHierarchy;
+
+    #[test]
+    fn descendants_are_ordered_parents_first() {
+        let mut hierarchy = EntityHierarchy::new();
+        assert!(hierarchy.set_parent(&3, &2));
+        assert!(hierarchy.set_parent(&2, &1));
+        assert!(hierarchy.set_parent(&4, &1));
+
+        assert_eq!(hierarchy.descendants(&1), vec![2, 4, 3]);
+        assert_eq!(hierarchy.root(&3), 1);
+
+        let mut tree = vec![7];
+        hierarchy.tree_into(&1, &mut tree);
+        assert_eq!(tree, vec![1, 2, 4, 3]);
+        hierarchy.tree_into(&2, &mut tree);
+        assert_eq!(tree, vec![2, 3]);
+    }
+
+    #[test]
+    fn rejects_cycles() {
+        let mut hierarchy = EntityHierarchy::new();
+        assert!(hierarchy.set_parent(&2, &1));
+        assert!(hierarchy.set_parent(&3, &2));
+
+        assert!(!hierarchy.set_parent(&1, &3));
+        assert!(!hierarchy.set_parent(&1, &1));
+        assert_eq!(hierarchy.parent(&1), None);
+    }
+
+    #[test]
+    fn removing_an_entity_orphans_its_children() {
+        let mut hierarchy = EntityHierarchy::new();
+        hierarchy.set_parent(&2, &1);
+        hierarchy.set_parent(&3, &2);
+
+        assert_eq!(hierarchy.remove_entity(&2), vec![3]);
+        assert_eq!(hierarchy.parent(&3), None);
+        assert!(hierarchy.children(&1).is_empty());
+    }
+}
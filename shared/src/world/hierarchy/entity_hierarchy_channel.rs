@@ -0,0 +1,6 @@
+use crate::Channel;
+
+/// Internal Channel used to let Clients know about the parent / child
+/// relationships between their in-scope Entities
+#[derive(Channel)]
+pub struct EntityHierarchyChannel;
@@ -0,0 +1,33 @@
+use std::hash::Hash;
+
+use naia_derive::MessageInternal;
+
+use crate::{EntityAndGlobalEntityConverter, EntityProperty};
+
+/// Sets the parent of an Entity, or clears it if `parent` holds no Entity
+#[derive(MessageInternal)]
+pub struct EntityParentMessage {
+    pub child: EntityProperty,
+    pub parent: EntityProperty,
+}
+
+impl EntityParentMessage {
+    pub fn new<E: Copy + Eq + Hash>(
+        converter: &dyn EntityAndGlobalEntityConverter<E>,
+        child: &E,
+        parent: Option<&E>,
+    ) -> Self {
+        let mut child_property = EntityProperty::new();
+        child_property.set(converter, child);
+
+        let mut parent_property = EntityProperty::new();
+        if let Some(parent) = parent {
+            parent_property.set(converter, parent);
+        }
+
+        Self {
+            child: child_property,
+            parent: parent_property,
+        }
+    }
+}
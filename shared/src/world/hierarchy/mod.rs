@@ -0,0 +1,3 @@
+pub mod entity_hierarchy;
+pub mod entity_hierarchy_channel;
+pub mod entity_parent_message;
@@ -1,9 +1,21 @@
-use std::{collections::HashMap, hash::Hash, net::SocketAddr};
+use std::{collections::HashMap, error::Error, fmt, hash::Hash, net::SocketAddr};
 
 use crate::{ComponentKind, GlobalWorldManagerType};
 
 use super::mut_channel::{MutChannel, MutReceiver, MutReceiverBuilder, MutSender};
 
+/// A Component can be registered only once per Entity
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct DuplicateComponentError;
+
+impl fmt::Display for DuplicateComponentError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        write!(f, "Component cannot register more than once!")
+    }
+}
+
+impl Error for DuplicateComponentError {}
+
 pub struct GlobalDiffHandler<E: Copy + Eq + Hash> {
     mut_receiver_builders: HashMap<(E, ComponentKind), MutReceiverBuilder>,
 }
@@ -21,12 +33,12 @@ impl<E: Copy + Eq + Hash> GlobalDiffHandler<E> {
         entity: &E,
         component_kind: &ComponentKind,
         diff_mask_length: u8,
-    ) -> MutSender {
+    ) -> Result<MutSender, DuplicateComponentError> {
         if self
             .mut_receiver_builders
             .contains_key(&(*entity, *component_kind))
         {
-            panic!("Component cannot register with Server more than once!");
+            return Err(DuplicateComponentError);
         }
 
         let (sender, builder) = MutChannel::new_channel(global_world_manager, diff_mask_length);
@@ -34,7 +46,7 @@ impl<E: Copy + Eq + Hash> GlobalDiffHandler<E> {
         self.mut_receiver_builders
             .insert((*entity, *component_kind), builder);
 
-        sender
+        Ok(sender)
     }
 
     pub fn deregister_component(&mut self, entity: &E, component_kind: &ComponentKind) {
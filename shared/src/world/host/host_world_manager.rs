@@ -11,7 +11,7 @@ use crate::{
     world::{
         entity::entity_converters::GlobalWorldManagerType, local_world_manager::LocalWorldManager,
     },
-    ComponentKind, DiffMask, EntityAction, Instant, MessageIndex, PacketIndex,
+    ComponentKind, DiffMask, EntityAction, Instant, MessageIndex, PacketIndex, PredictionKey, Tick,
 };
 
 use super::{entity_action_event::EntityActionEvent, world_channel::WorldChannel};
@@ -29,12 +29,24 @@ pub struct HostWorldManager<E: Copy + Eq + Hash + Send + Sync> {
 
     // Actions
     pub sent_action_packets: SequenceList<(Instant, Vec<(ActionId, EntityAction<E>)>)>,
+    /// The host Tick each undelivered action was first written in
+    action_ticks: HashMap<ActionId, Tick>,
+    /// The Components each undelivered spawn was first written with
+    spawn_components: HashMap<ActionId, Vec<ComponentKind>>,
 
     // Updates
     /// Map of component updates and [`DiffMask`] that were written into each packet
     pub sent_updates: HashMap<PacketIndex, (Instant, HashMap<(E, ComponentKind), DiffMask>)>,
     /// Last [`PacketIndex`] where a component update was written by the server
     pub last_update_packet_index: PacketIndex,
+
+    // Predictions
+    /// Keys sent along with the spawn of Entities the remote host predicted
+    spawn_predictions: HashMap<E, PredictionKey>,
+
+    // Stats
+    /// Bits written for the inserts & updates of each kind of Component
+    component_bits_sent: HashMap<ComponentKind, u64>,
 }
 
 pub struct HostWorldEvents<E: Copy + Eq + Hash + Send + Sync> {
@@ -58,10 +70,18 @@ impl<E: Copy + Eq + Hash + Send + Sync> HostWorldManager<E> {
             // World
             world_channel: WorldChannel::new(address, global_world_manager),
             sent_action_packets: SequenceList::new(),
+            action_ticks: HashMap::new(),
+            spawn_components: HashMap::new(),
 
             // Update
             sent_updates: HashMap::new(),
             last_update_packet_index: 0,
+
+            // Predictions
+            spawn_predictions: HashMap::new(),
+
+            // Stats
+            component_bits_sent: HashMap::new(),
         }
     }
 
@@ -88,6 +108,58 @@ impl<E: Copy + Eq + Hash + Send + Sync> HostWorldManager<E> {
 
     pub fn despawn_entity(&mut self, entity: &E) {
         self.world_channel.host_despawn_entity(entity);
+        self.spawn_predictions.remove(entity);
+    }
+
+    /// Tags the spawn of an Entity with the key of the remote host's
+    /// prediction of it
+    pub fn set_spawn_prediction(&mut self, entity: &E, prediction_key: &PredictionKey) {
+        self.spawn_predictions.insert(*entity, *prediction_key);
+    }
+
+    pub fn spawn_prediction(&self, entity: &E) -> Option<PredictionKey> {
+        self.spawn_predictions.get(entity).copied()
+    }
+
+    /// Gets the Tick an action is written for: the one it was first written
+    /// in, or `host_tick` if it has yet to be. Resent actions keep their Tick.
+    pub fn action_tick(
+        &mut self,
+        action_id: &ActionId,
+        host_tick: &Tick,
+        is_writing: bool,
+    ) -> Tick {
+        if is_writing {
+            return *self.action_ticks.entry(*action_id).or_insert(*host_tick);
+        }
+        self.action_ticks
+            .get(action_id)
+            .copied()
+            .unwrap_or(*host_tick)
+    }
+
+    /// Gets the Components a spawn is written with: those it was first written
+    /// with that the Entity still has, or `component_kinds` if it has yet to be.
+    /// Resent spawns keep their Components, as the remote host only reads the
+    /// first copy to arrive.
+    pub fn spawn_components(
+        &mut self,
+        action_id: &ActionId,
+        component_kinds: Vec<ComponentKind>,
+        is_writing: bool,
+    ) -> Vec<ComponentKind> {
+        let Some(first_components) = self.spawn_components.get(action_id) else {
+            if is_writing {
+                self.spawn_components
+                    .insert(*action_id, component_kinds.clone());
+            }
+            return component_kinds;
+        };
+        first_components
+            .iter()
+            .filter(|component_kind| component_kinds.contains(component_kind))
+            .copied()
+            .collect()
     }
 
     pub fn insert_component(&mut self, entity: &E, component_kind: &ComponentKind) {
@@ -110,19 +182,19 @@ impl<E: Copy + Eq + Hash + Send + Sync> HostWorldManager<E> {
 
     // Messages
 
-    pub fn collect_outgoing_messages(&mut self, rtt_millis: &f32) {
-        self.collect_dropped_update_packets(rtt_millis);
-        self.collect_dropped_action_packets();
+    pub fn collect_outgoing_messages(&mut self, now: &Instant, rtt_millis: &f32) {
+        self.collect_dropped_update_packets(now, rtt_millis);
+        self.collect_dropped_action_packets(now);
     }
 
     // Collecting
 
-    fn collect_dropped_action_packets(&mut self) {
+    fn collect_dropped_action_packets(&mut self, now: &Instant) {
         let mut pop = false;
 
         loop {
             if let Some((_, (time_sent, _))) = self.sent_action_packets.front() {
-                if time_sent.elapsed() > ACTION_RECORD_TTL {
+                if now.duration_since(time_sent) > ACTION_RECORD_TTL {
                     pop = true;
                 }
             } else {
@@ -136,13 +208,13 @@ impl<E: Copy + Eq + Hash + Send + Sync> HostWorldManager<E> {
         }
     }
 
-    fn collect_dropped_update_packets(&mut self, rtt_millis: &f32) {
+    fn collect_dropped_update_packets(&mut self, now: &Instant, rtt_millis: &f32) {
         let drop_duration = Duration::from_millis((DROP_UPDATE_RTT_FACTOR * rtt_millis) as u64);
 
         {
             let mut dropped_packets = Vec::new();
             for (packet_index, (time_sent, _)) in &self.sent_updates {
-                if time_sent.elapsed() > drop_duration {
+                if now.duration_since(time_sent) > drop_duration {
                     dropped_packets.push(*packet_index);
                 }
             }
@@ -195,6 +267,17 @@ impl<E: Copy + Eq + Hash + Send + Sync> HostWorldManager<E> {
             next_send_updates: self.world_channel.collect_next_updates(),
         }
     }
+
+    // Stats
+
+    pub fn record_component_bits(&mut self, component_kind: &ComponentKind, bits: u32) {
+        *self.component_bits_sent.entry(*component_kind).or_default() += u64::from(bits);
+    }
+
+    /// Gets how many bits have been written for each kind of Component
+    pub fn component_bits_sent(&self) -> &HashMap<ComponentKind, u64> {
+        &self.component_bits_sent
+    }
 }
 
 impl<E: Copy + Eq + Hash + Send + Sync> HostWorldManager<E> {
@@ -212,6 +295,8 @@ impl<E: Copy + Eq + Hash + Send + Sync> HostWorldManager<E> {
             .remove_scan_from_front(&packet_index)
         {
             for (action_id, action) in action_list {
+                self.action_ticks.remove(&action_id);
+                self.spawn_components.remove(&action_id);
                 self.world_channel
                     .action_delivered(local_world_manager, action_id, action);
             }
@@ -10,9 +10,10 @@ use crate::{
     world::{
         entity::entity_converters::GlobalWorldManagerType, local_world_manager::LocalWorldManager,
     },
-    BitWrite, BitWriter, ComponentKind, ComponentKinds, ConstBitLength, EntityAction,
+    BitCounter, BitWrite, BitWriter, ComponentKind, ComponentKinds, ConstBitLength, EntityAction,
     EntityActionType, EntityConverterMut, HostWorldEvents, HostWorldManager, Instant,
-    LocalEntityConverter, MessageIndex, PacketIndex, Serde, UnsignedVariableInteger, WorldRefType,
+    LocalEntityAndGlobalEntityConverterMut, LocalEntityConverter, MessageIndex, PacketIndex,
+    Replicate, Serde, Tick, UnsignedVariableInteger, WorldRefType,
 };
 
 use super::entity_action_event::EntityActionEvent;
@@ -36,6 +37,7 @@ impl HostWorldWriter {
         now: &Instant,
         writer: &mut BitWriter,
         packet_index: &PacketIndex,
+        host_tick: &Tick,
         world: &W,
         global_world_manager: &dyn GlobalWorldManagerType<E>,
         local_world_manager: &mut LocalWorldManager<E>,
@@ -70,6 +72,7 @@ impl HostWorldWriter {
                 now,
                 writer,
                 &packet_index,
+                host_tick,
                 world,
                 global_world_manager,
                 local_world_manager,
@@ -89,6 +92,7 @@ impl HostWorldWriter {
         now: &Instant,
         writer: &mut BitWriter,
         packet_index: &PacketIndex,
+        host_tick: &Tick,
         world: &W,
         global_world_manager: &dyn GlobalWorldManagerType<E>,
         local_world_manager: &mut LocalWorldManager<E>,
@@ -112,6 +116,7 @@ impl HostWorldWriter {
                 global_world_manager,
                 local_world_manager,
                 packet_index,
+                host_tick,
                 &mut counter,
                 &mut last_counted_id,
                 false,
@@ -156,6 +161,7 @@ impl HostWorldWriter {
                 global_world_manager,
                 local_world_manager,
                 packet_index,
+                host_tick,
                 writer,
                 &mut last_written_id,
                 true,
@@ -175,6 +181,7 @@ impl HostWorldWriter {
         global_world_manager: &dyn GlobalWorldManagerType<E>,
         local_world_manager: &mut LocalWorldManager<E>,
         packet_index: &PacketIndex,
+        host_tick: &Tick,
         writer: &mut dyn BitWrite,
         last_written_id: &mut Option<ActionId>,
         is_writing: bool,
@@ -186,6 +193,11 @@ impl HostWorldWriter {
         // write message id
         Self::write_action_id(writer, last_written_id, action_id);
 
+        // write how many Ticks before this packet's the action was written for, as
+        // resent actions keep the Tick they were first written in
+        let action_tick = host_manager.action_tick(action_id, host_tick, is_writing);
+        UnsignedVariableInteger::<3>::new(host_tick.wrapping_sub(action_tick)).ser(writer);
+
         match action {
             EntityActionEvent::SpawnEntity(world_entity) => {
                 EntityActionType::SpawnEntity.ser(writer);
@@ -196,11 +208,27 @@ impl HostWorldWriter {
                     .unwrap()
                     .host_ser(writer);
 
-                // get component list
-                let component_kind_list = match global_world_manager.component_kinds(world_entity) {
-                    Some(kind_list) => kind_list,
-                    None => Vec::new(),
-                };
+                // write prediction key. Every spawn carries this, costing one bit when
+                // the spawn wasn't predicted, so Clients & Servers from before predicted
+                // spawns can't read each other's spawns
+                host_manager.spawn_prediction(world_entity).ser(writer);
+
+                // get component list, only including Components the host has inserted for
+                // this connection
+                let component_kind_list: Vec<ComponentKind> =
+                    match global_world_manager.component_kinds(world_entity) {
+                        Some(kind_list) => kind_list
+                            .into_iter()
+                            .filter(|component_kind| {
+                                host_manager
+                                    .world_channel
+                                    .host_has_component(world_entity, component_kind)
+                            })
+                            .collect(),
+                        None => Vec::new(),
+                    };
+                let component_kind_list =
+                    host_manager.spawn_components(action_id, component_kind_list, is_writing);
 
                 // write number of components
                 let components_num =
@@ -210,12 +238,22 @@ impl HostWorldWriter {
                 for component_kind in &component_kind_list {
                     let mut converter =
                         EntityConverterMut::new(global_world_manager, local_world_manager);
+                    let component = world
+                        .component_of_kind(world_entity, component_kind)
+                        .expect("Component does not exist in World");
+
+                    if is_writing {
+                        Self::record_component_bits(
+                            component_kinds,
+                            host_manager,
+                            component_kind,
+                            &*component,
+                            &mut converter,
+                        );
+                    }
 
                     // write component payload
-                    world
-                        .component_of_kind(world_entity, component_kind)
-                        .expect("Component does not exist in World")
-                        .write(component_kinds, writer, &mut converter);
+                    component.write(component_kinds, writer, &mut converter);
                 }
 
                 // if we are writing to this packet, add it to record
@@ -276,12 +314,22 @@ impl HostWorldWriter {
 
                     let mut converter =
                         EntityConverterMut::new(global_world_manager, local_world_manager);
+                    let component_ref = world
+                        .component_of_kind(world_entity, component)
+                        .expect("Component does not exist in World");
+
+                    if is_writing {
+                        Self::record_component_bits(
+                            component_kinds,
+                            host_manager,
+                            component,
+                            &*component_ref,
+                            &mut converter,
+                        );
+                    }
 
                     // write component payload
-                    world
-                        .component_of_kind(world_entity, component)
-                        .expect("Component does not exist in World")
-                        .write(component_kinds, writer, &mut converter);
+                    component_ref.write(component_kinds, writer, &mut converter);
 
                     // if we are actually writing this packet
                     if is_writing {
@@ -338,6 +386,18 @@ impl HostWorldWriter {
         }
     }
 
+    fn record_component_bits<E: Copy + Eq + Hash + Send + Sync>(
+        component_kinds: &ComponentKinds,
+        host_manager: &mut HostWorldManager<E>,
+        component_kind: &ComponentKind,
+        component: &dyn Replicate,
+        converter: &mut dyn LocalEntityAndGlobalEntityConverterMut,
+    ) {
+        let mut counter = BitCounter::new(0, 0, u32::MAX);
+        component.write(component_kinds, &mut counter, converter);
+        host_manager.record_component_bits(component_kind, counter.bits_needed());
+    }
+
     #[allow(clippy::type_complexity)]
     fn record_action_written<E: Copy + Eq + Hash + Send + Sync>(
         sent_actions: &mut SequenceList<(Instant, Vec<(ActionId, EntityAction<E>)>)>,
@@ -506,6 +566,7 @@ impl HostWorldWriter {
             }
 
             *has_written = true;
+            host_manager.record_component_bits(component_kind, counter.bits_needed());
 
             // write ComponentContinue bit
             true.ser(writer);
@@ -9,6 +9,7 @@ use crate::{DiffMask, GlobalWorldManagerType, PropertyMutate};
 pub trait MutChannelType: Send + Sync {
     fn new_receiver(&mut self, address: &Option<SocketAddr>) -> Option<MutReceiver>;
     fn send(&self, diff: u8);
+    fn send_elements(&self, diff: u8, elements: &[u32]);
 }
 
 // MutChannel
@@ -51,6 +52,14 @@ impl MutChannel {
         }
         false
     }
+
+    pub fn send_elements(&self, diff: u8, elements: &[u32]) -> bool {
+        if let Ok(data) = self.data.as_ref().read() {
+            data.send_elements(diff, elements);
+            return true;
+        }
+        false
+    }
 }
 
 // MutReceiver
@@ -83,6 +92,12 @@ impl MutReceiver {
         }
     }
 
+    pub fn mutate_elements(&self, diff: u8, elements: &[u32]) {
+        if let Ok(mut mask) = self.mask.as_ref().write() {
+            mask.set_elements(diff, elements);
+        }
+    }
+
     pub fn or_mask(&self, other_mask: &DiffMask) {
         if let Ok(mut mask) = self.mask.as_ref().write() {
             mask.or(other_mask);
@@ -114,6 +129,10 @@ impl PropertyMutate for MutSender {
     fn mutate(&mut self, property_index: u8) {
         self.channel.send(property_index);
     }
+
+    fn mutate_elements(&mut self, property_index: u8, element_indices: &[u32]) {
+        self.channel.send_elements(property_index, element_indices);
+    }
 }
 
 // MutReceiverBuilder
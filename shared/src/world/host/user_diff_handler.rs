@@ -38,6 +38,21 @@ impl<E: Copy + Eq + Hash> UserDiffHandler<E> {
         }
     }
 
+    /// Same as `register_component()`, but does nothing if the
+    /// GlobalDiffHandler has yet to register the Component
+    pub fn try_register_component(
+        &mut self,
+        address: &Option<SocketAddr>,
+        entity: &E,
+        component_kind: &ComponentKind,
+    ) {
+        if let Ok(global_handler) = self.global_diff_handler.as_ref().read() {
+            if let Some(receiver) = global_handler.receiver(address, entity, component_kind) {
+                self.receivers.insert((*entity, *component_kind), receiver);
+            }
+        }
+    }
+
     pub fn deregister_component(&mut self, entity: &E, component_kind: &ComponentKind) {
         self.receivers.remove(&(*entity, *component_kind));
     }
@@ -76,6 +76,13 @@ impl<E: Copy + Eq + Hash + Send + Sync> WorldChannel<E> {
         self.host_world.contains_key(entity)
     }
 
+    pub fn host_has_component(&self, entity: &E, component_kind: &ComponentKind) -> bool {
+        if let Some(components) = self.host_world.get(entity) {
+            return components.contains(component_kind);
+        }
+        false
+    }
+
     pub fn entity_channel_is_open(&self, entity: &E) -> bool {
         matches!(
             self.entity_channels.get(entity),
@@ -109,6 +116,10 @@ impl<E: Copy + Eq + Hash + Send + Sync> WorldChannel<E> {
             return;
         }
 
+        for component_kind in self.host_world.get(entity).unwrap().inner.iter() {
+            self.diff_handler
+                .deregister_component(entity, component_kind);
+        }
         self.host_world.remove(entity);
 
         let mut despawn = false;
@@ -151,6 +162,7 @@ impl<E: Copy + Eq + Hash + Send + Sync> WorldChannel<E> {
         }
 
         components.insert(*component_kind);
+        self.on_component_written(entity, component_kind);
 
         if let Some(EntityChannel::Spawned(component_channels)) =
             self.entity_channels.get_mut(entity)
@@ -177,6 +189,8 @@ impl<E: Copy + Eq + Hash + Send + Sync> WorldChannel<E> {
         }
 
         components.remove(component_kind);
+        self.diff_handler
+            .deregister_component(entity, component_kind);
 
         if let Some(EntityChannel::Spawned(component_channels)) =
             self.entity_channels.get_mut(entity)
@@ -380,6 +394,14 @@ impl<E: Copy + Eq + Hash + Send + Sync> WorldChannel<E> {
         world_manager.host_despawn_entity(entity);
     }
 
+    /// Starts tracking changes to a Component as soon as it is written in an
+    /// insertion or spawn, as changes made before the remote host acknowledges
+    /// it would otherwise be lost
+    fn on_component_written(&mut self, entity: &E, component_kind: &ComponentKind) {
+        self.diff_handler
+            .try_register_component(&self.address, entity, component_kind);
+    }
+
     fn on_component_channel_opened(&mut self, entity: &E, component_kind: &ComponentKind) {
         self.diff_handler
             .register_component(&self.address, entity, component_kind);
@@ -1,8 +1,9 @@
 use log::warn;
-use naia_socket_shared::Instant;
+use naia_socket_shared::{Clock, Instant};
 use std::{
     collections::{HashMap, VecDeque},
     hash::Hash,
+    sync::Arc,
     time::Duration,
 };
 
@@ -19,18 +20,20 @@ pub struct LocalWorldManager<E: Copy + Eq + Hash> {
     reserved_entities: HashMap<E, LocalEntity>,
     reserved_entity_ttl: Duration,
     reserved_entities_ttls: VecDeque<(Instant, E)>,
+    clock: Arc<dyn Clock>,
 }
 
 impl<E: Copy + Eq + Hash> LocalWorldManager<E> {
-    pub fn new(user_key: u64) -> Self {
+    pub fn new(user_key: u64, clock: &Arc<dyn Clock>) -> Self {
         Self {
             user_key,
-            host_entity_generator: KeyGenerator::new(Duration::from_secs(60)),
+            host_entity_generator: KeyGenerator::new(Duration::from_secs(60), clock),
             world_to_local_entity: HashMap::new(),
             local_to_world_entity: HashMap::new(),
             reserved_entities: HashMap::new(),
             reserved_entity_ttl: Duration::from_secs(60),
             reserved_entities_ttls: VecDeque::new(),
+            clock: clock.clone(),
         }
     }
 
@@ -52,7 +55,7 @@ impl<E: Copy + Eq + Hash> LocalWorldManager<E> {
             let Some((timeout, _)) = self.reserved_entities_ttls.front() else {
                 break;
             };
-            if timeout.elapsed() < self.reserved_entity_ttl {
+            if self.clock.elapsed(timeout) < self.reserved_entity_ttl {
                 break;
             }
             let (_, world_entity) = self.reserved_entities_ttls.pop_front().unwrap();
@@ -157,6 +160,11 @@ impl<E: Copy + Eq + Hash> LocalWorldManager<E> {
     pub fn get_user_key(&self) -> &u64 {
         &self.user_key
     }
+
+    /// Gets how many Entities, host & remote, are known to this connection
+    pub fn entity_count(&self) -> usize {
+        self.world_to_local_entity.len()
+    }
 }
 
 impl<E: Copy + Eq + Hash> LocalEntityConverter<E> for LocalWorldManager<E> {
@@ -1,6 +1,9 @@
 pub mod component;
+pub mod delegation;
 pub mod entity;
+pub mod hierarchy;
 pub mod host;
 pub mod local_world_manager;
 pub mod remote;
+pub mod scope;
 pub mod world_type;
@@ -1,9 +1,11 @@
 use crate::{ComponentKind, Replicate, Tick};
 
+/// An event on a remote Entity, along with the Tick of the packet it was
+/// received in
 pub enum EntityEvent<E: Copy> {
-    SpawnEntity(E),
-    DespawnEntity(E),
-    InsertComponent(E, ComponentKind),
-    RemoveComponent(E, Box<dyn Replicate>),
+    SpawnEntity(Tick, E),
+    DespawnEntity(Tick, E),
+    InsertComponent(Tick, E, ComponentKind),
+    RemoveComponent(Tick, E, Box<dyn Replicate>),
     UpdateComponent(Tick, E, ComponentKind),
 }
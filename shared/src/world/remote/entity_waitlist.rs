@@ -1,7 +1,8 @@
-use naia_socket_shared::Instant;
+use naia_socket_shared::{Clock, Instant};
 use std::collections::VecDeque;
 use std::{
     collections::{HashMap, HashSet},
+    sync::Arc,
     time::Duration,
 };
 
@@ -18,22 +19,29 @@ pub struct EntityWaitlist {
     removed_handles: HashSet<WaitlistHandle>,
     handle_ttls: VecDeque<(Instant, WaitlistHandle)>,
     handle_ttl: Duration,
+    clock: Arc<dyn Clock>,
 }
 
 impl EntityWaitlist {
-    pub fn new() -> Self {
+    pub fn new(clock: &Arc<dyn Clock>) -> Self {
         Self {
             handle_to_required_entities: HashMap::new(),
-            handle_store: KeyGenerator::new(Duration::from_secs(60)),
+            handle_store: KeyGenerator::new(Duration::from_secs(60), clock),
             waiting_entity_to_handles: HashMap::new(),
             in_scope_entities: HashSet::new(),
             ready_handles: HashSet::new(),
             removed_handles: HashSet::new(),
             handle_ttls: VecDeque::new(),
             handle_ttl: Duration::from_secs(60),
+            clock: clock.clone(),
         }
     }
 
+    /// Gets how many items are waiting on Entities to come into scope
+    pub fn waiting_count(&self) -> usize {
+        self.handle_to_required_entities.len()
+    }
+
     fn must_queue(&self, entities: &HashSet<LocalEntity>) -> bool {
         !entities.is_subset(&self.in_scope_entities)
     }
@@ -63,7 +71,7 @@ impl EntityWaitlist {
             }
         }
 
-        self.handle_ttls.push_back((Instant::now(), new_handle));
+        self.handle_ttls.push_back((self.clock.now(), new_handle));
         self.handle_to_required_entities
             .insert(new_handle, entities.clone());
 
@@ -150,7 +158,7 @@ impl EntityWaitlist {
             let Some((ttl, _)) = self.handle_ttls.front() else {
                 break;
             };
-            if ttl.elapsed() < self.handle_ttl {
+            if self.clock.elapsed(ttl) < self.handle_ttl {
                 break;
             }
             let (_, handle) = self.handle_ttls.pop_front().unwrap();
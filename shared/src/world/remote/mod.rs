@@ -1,5 +1,6 @@
 pub mod entity_action_event;
 pub mod entity_event;
 pub mod entity_waitlist;
+pub mod remote_state_validator;
 pub mod remote_world_manager;
 pub mod remote_world_reader;
@@ -0,0 +1,30 @@
+use crate::{ComponentKind, Replicate};
+
+/// The verdict of a `RemoteStateValidator` on Component state received from
+/// the remote host
+pub enum RemoteStateVerdict {
+    /// Apply the received state
+    Accept,
+    /// Apply the given host-owned Component's state instead of the received
+    /// one
+    Replace(Box<dyn Replicate>),
+    /// Discard the received state. A discarded insertion leaves the Entity
+    /// without the Component, a discarded update keeps its previous state.
+    Reject,
+}
+
+/// Checks Component state received from the remote host before it is applied
+/// to the World
+pub trait RemoteStateValidator<E> {
+    /// Whether state received for the Component on the Entity must be checked
+    fn validates(&self, entity: &E, component_kind: &ComponentKind) -> bool;
+
+    /// Given the Component's state in the World (None for insertions) and the
+    /// state it would have once the received state is applied
+    fn validate(
+        &mut self,
+        entity: &E,
+        previous: Option<&dyn Replicate>,
+        proposed: &dyn Replicate,
+    ) -> RemoteStateVerdict;
+}
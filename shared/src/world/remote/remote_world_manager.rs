@@ -1,9 +1,12 @@
 use std::{
     collections::{HashMap, HashSet},
     hash::Hash,
+    sync::Arc,
 };
 
 use log::warn;
+use naia_serde::{BitWriter, SerdeErr};
+use naia_socket_shared::Clock;
 
 use crate::{
     world::{
@@ -11,26 +14,40 @@ use crate::{
         remote::{
             entity_event::EntityEvent,
             entity_waitlist::{EntityWaitlist, WaitlistHandle, WaitlistStore},
+            remote_state_validator::{RemoteStateValidator, RemoteStateVerdict},
             remote_world_reader::RemoteWorldEvents,
         },
     },
-    ComponentFieldUpdate, ComponentKind, ComponentKinds, ComponentUpdate, EntityAction,
-    EntityConverter, GlobalWorldManagerType, LocalEntity, Replicate, Tick, WorldMutType,
+    ComponentFieldUpdate, ComponentKind, ComponentKinds, ComponentUpdate, DiffMask, EntityAction,
+    EntityConverter, EntityConverterMut, GlobalWorldManagerType, LocalEntity,
+    LocalEntityAndGlobalEntityConverter, PredictionKey, Replicate, ReversedEntityConverter, Tick,
+    WorldMutType,
 };
 
+/// What to do with a received Component update, once validated
+enum UpdateVerdict {
+    Apply,
+    /// Apply the given update instead, which is written from the host's side
+    Replace(ComponentUpdate),
+    Discard,
+}
+
+/// Identifies a waiting field update, by field & by element for list fields
+type WaitingFieldKey = (u8, Option<u32>);
+
 pub struct RemoteWorldManager<E: Copy + Eq + Hash + Send + Sync> {
     pub entity_waitlist: EntityWaitlist,
-    insert_waitlist_store: WaitlistStore<(E, Box<dyn Replicate>)>,
+    insert_waitlist_store: WaitlistStore<(Tick, E, Box<dyn Replicate>)>,
     insert_waitlist_map: HashMap<(E, ComponentKind), WaitlistHandle>,
     update_waitlist_store: WaitlistStore<(Tick, E, ComponentKind, ComponentFieldUpdate)>,
-    update_waitlist_map: HashMap<(E, ComponentKind), HashMap<u8, WaitlistHandle>>,
+    update_waitlist_map: HashMap<(E, ComponentKind), HashMap<WaitingFieldKey, WaitlistHandle>>,
     outgoing_events: Vec<EntityEvent<E>>,
 }
 
 impl<E: Copy + Eq + Hash + Send + Sync> RemoteWorldManager<E> {
-    pub fn new() -> Self {
+    pub fn new(clock: &Arc<dyn Clock>) -> Self {
         Self {
-            entity_waitlist: EntityWaitlist::new(),
+            entity_waitlist: EntityWaitlist::new(clock),
             insert_waitlist_store: WaitlistStore::new(),
             insert_waitlist_map: HashMap::new(),
             update_waitlist_store: WaitlistStore::new(),
@@ -47,6 +64,9 @@ impl<E: Copy + Eq + Hash + Send + Sync> RemoteWorldManager<E> {
         self.entity_waitlist.remove_entity(local_entity);
     }
 
+    /// Applies received Entity actions & updates to the World. If a validator
+    /// is given, it checks every Component insertion & update before it is
+    /// applied.
     pub fn process_world_events<W: WorldMutType<E>>(
         &mut self,
         global_world_manager: &mut dyn GlobalWorldManagerType<E>,
@@ -54,6 +74,7 @@ impl<E: Copy + Eq + Hash + Send + Sync> RemoteWorldManager<E> {
         component_kinds: &ComponentKinds,
         world: &mut W,
         world_events: RemoteWorldEvents<E>,
+        mut validator: Option<&mut (dyn RemoteStateValidator<E> + '_)>,
     ) -> Vec<EntityEvent<E>> {
         self.process_updates(
             global_world_manager,
@@ -61,6 +82,7 @@ impl<E: Copy + Eq + Hash + Send + Sync> RemoteWorldManager<E> {
             component_kinds,
             world,
             world_events.incoming_updates,
+            validator.as_deref_mut(),
         );
         self.process_actions(
             global_world_manager,
@@ -68,6 +90,8 @@ impl<E: Copy + Eq + Hash + Send + Sync> RemoteWorldManager<E> {
             world,
             world_events.incoming_actions,
             world_events.incoming_components,
+            world_events.incoming_predictions,
+            validator,
         );
 
         std::mem::take(&mut self.outgoing_events)
@@ -77,13 +101,16 @@ impl<E: Copy + Eq + Hash + Send + Sync> RemoteWorldManager<E> {
     ///
     /// * Emits client events corresponding to any [`EntityAction`] received
     /// Store
+    #[allow(clippy::too_many_arguments)]
     pub fn process_actions<W: WorldMutType<E>>(
         &mut self,
         global_world_manager: &mut dyn GlobalWorldManagerType<E>,
         local_world_manager: &mut LocalWorldManager<E>,
         world: &mut W,
-        incoming_actions: Vec<EntityAction<LocalEntity>>,
+        incoming_actions: Vec<(Tick, EntityAction<LocalEntity>)>,
         incoming_components: HashMap<(LocalEntity, ComponentKind), Box<dyn Replicate>>,
+        incoming_predictions: HashMap<LocalEntity, PredictionKey>,
+        mut validator: Option<&mut (dyn RemoteStateValidator<E> + '_)>,
     ) {
         self.process_ready_actions(
             global_world_manager,
@@ -91,33 +118,47 @@ impl<E: Copy + Eq + Hash + Send + Sync> RemoteWorldManager<E> {
             world,
             incoming_actions,
             incoming_components,
+            incoming_predictions,
+            validator.as_deref_mut(),
         );
-        self.process_waitlist_actions(global_world_manager, local_world_manager, world);
+        self.process_waitlist_actions(global_world_manager, local_world_manager, world, validator);
     }
 
     /// For each [`EntityAction`] that can be executed now,
     /// execute it and emit a corresponding event.
+    #[allow(clippy::too_many_arguments)]
     fn process_ready_actions<W: WorldMutType<E>>(
         &mut self,
         global_world_manager: &mut dyn GlobalWorldManagerType<E>,
         local_world_manager: &mut LocalWorldManager<E>,
         world: &mut W,
-        incoming_actions: Vec<EntityAction<LocalEntity>>,
+        incoming_actions: Vec<(Tick, EntityAction<LocalEntity>)>,
         mut incoming_components: HashMap<(LocalEntity, ComponentKind), Box<dyn Replicate>>,
+        incoming_predictions: HashMap<LocalEntity, PredictionKey>,
+        mut validator: Option<&mut (dyn RemoteStateValidator<E> + '_)>,
     ) {
         // execute the action and emit an event
-        for action in incoming_actions {
+        for (tick, action) in incoming_actions {
             match action {
                 EntityAction::SpawnEntity(local_entity, components) => {
-                    // set up entity
-                    let world_entity = world.spawn_entity();
+                    // set up entity, reusing the Entity spawned in prediction of it if there is one
+                    let predicted_entity = incoming_predictions
+                        .get(&local_entity)
+                        .and_then(|prediction_key| {
+                            global_world_manager.take_predicted_entity(prediction_key)
+                        })
+                        .filter(|predicted_entity| world.has_entity(predicted_entity));
+                    let world_entity = match predicted_entity {
+                        Some(predicted_entity) => predicted_entity,
+                        None => world.spawn_entity(),
+                    };
                     local_world_manager.remote_spawn_entity(&world_entity, &local_entity);
                     global_world_manager
                         .remote_spawn_entity(&world_entity, local_world_manager.get_user_key());
                     self.on_entity_channel_opened(&local_entity);
 
                     self.outgoing_events
-                        .push(EntityEvent::<E>::SpawnEntity(world_entity));
+                        .push(EntityEvent::<E>::SpawnEntity(tick, world_entity));
 
                     // read component list
                     for component_kind in components {
@@ -125,23 +166,35 @@ impl<E: Copy + Eq + Hash + Send + Sync> RemoteWorldManager<E> {
                             .remove(&(local_entity, component_kind))
                             .unwrap();
 
-                        self.process_insert(world, world_entity, component, &component_kind);
+                        self.process_insert(
+                            global_world_manager,
+                            local_world_manager,
+                            world,
+                            validator.as_deref_mut(),
+                            tick,
+                            world_entity,
+                            component,
+                            &component_kind,
+                        );
                     }
                 }
                 EntityAction::DespawnEntity(local_entity) => {
                     let world_entity = local_world_manager.remote_despawn_entity(&local_entity);
                     global_world_manager.remote_despawn_entity(&world_entity);
 
-                    // Generate event for each component, handing references off just in
-                    // case
-                    for component_kind in world.component_kinds(&world_entity) {
-                        self.process_remove(world, world_entity, component_kind);
-                    }
+                    // the Entity may already have been despawned locally
+                    if world.has_entity(&world_entity) {
+                        // Generate event for each component, handing references off just in
+                        // case
+                        for component_kind in world.component_kinds(&world_entity) {
+                            self.process_remove(world, tick, world_entity, component_kind);
+                        }
 
-                    world.despawn_entity(&world_entity);
+                        world.despawn_entity(&world_entity);
+                    }
                     self.on_entity_channel_closing(&local_entity);
                     self.outgoing_events
-                        .push(EntityEvent::<E>::DespawnEntity(world_entity));
+                        .push(EntityEvent::<E>::DespawnEntity(tick, world_entity));
                 }
                 EntityAction::InsertComponent(local_entity, component_kind) => {
                     let component = incoming_components
@@ -150,11 +203,20 @@ impl<E: Copy + Eq + Hash + Send + Sync> RemoteWorldManager<E> {
 
                     let world_entity = local_world_manager.get_world_entity(&local_entity);
 
-                    self.process_insert(world, world_entity, component, &component_kind);
+                    self.process_insert(
+                        global_world_manager,
+                        local_world_manager,
+                        world,
+                        validator.as_deref_mut(),
+                        tick,
+                        world_entity,
+                        component,
+                        &component_kind,
+                    );
                 }
                 EntityAction::RemoveComponent(local_entity, component_kind) => {
                     let world_entity = local_world_manager.get_world_entity(&local_entity);
-                    self.process_remove(world, world_entity, component_kind);
+                    self.process_remove(world, tick, world_entity, component_kind);
                 }
                 EntityAction::Noop => {
                     // do nothing
@@ -163,9 +225,14 @@ impl<E: Copy + Eq + Hash + Send + Sync> RemoteWorldManager<E> {
         }
     }
 
+    #[allow(clippy::too_many_arguments)]
     fn process_insert<W: WorldMutType<E>>(
         &mut self,
+        global_world_manager: &dyn GlobalWorldManagerType<E>,
+        local_world_manager: &mut LocalWorldManager<E>,
         world: &mut W,
+        validator: Option<&mut (dyn RemoteStateValidator<E> + '_)>,
+        tick: Tick,
         world_entity: E,
         component: Box<dyn Replicate>,
         component_kind: &ComponentKind,
@@ -174,23 +241,79 @@ impl<E: Copy + Eq + Hash + Send + Sync> RemoteWorldManager<E> {
             let handle = self.entity_waitlist.queue(
                 &entity_set,
                 &mut self.insert_waitlist_store,
-                (world_entity, component),
+                (tick, world_entity, component),
             );
             self.insert_waitlist_map
                 .insert((world_entity, *component_kind), handle);
         } else {
-            world.insert_boxed_component(&world_entity, component);
-
-            self.outgoing_events.push(EntityEvent::<E>::InsertComponent(
+            self.insert_component(
+                global_world_manager,
+                local_world_manager,
+                world,
+                validator,
+                tick,
                 world_entity,
-                *component_kind,
-            ));
+                component,
+            );
         }
     }
 
+    /// Inserts a received Component into the World, unless the validator
+    /// rejects it
+    #[allow(clippy::too_many_arguments)]
+    fn insert_component<W: WorldMutType<E>>(
+        &mut self,
+        global_world_manager: &dyn GlobalWorldManagerType<E>,
+        local_world_manager: &mut LocalWorldManager<E>,
+        world: &mut W,
+        validator: Option<&mut (dyn RemoteStateValidator<E> + '_)>,
+        tick: Tick,
+        world_entity: E,
+        mut component: Box<dyn Replicate>,
+    ) {
+        let component_kind = component.kind();
+        if !world.has_entity(&world_entity) {
+            return;
+        }
+
+        if let Some(validator) = validator {
+            if validator.validates(&world_entity, &component_kind) {
+                match validator.validate(&world_entity, None, component.as_ref()) {
+                    RemoteStateVerdict::Accept => {}
+                    RemoteStateVerdict::Replace(replacement) => {
+                        let update = Self::replacement_update(
+                            global_world_manager,
+                            local_world_manager,
+                            replacement.as_ref(),
+                        );
+                        let converter = EntityConverter::new(
+                            global_world_manager.to_global_entity_converter(),
+                            local_world_manager,
+                        );
+                        let converter = ReversedEntityConverter::new(&converter);
+                        if component.read_apply_update(&converter, update).is_err() {
+                            warn!("Remote World Manager: cannot apply replacement Component");
+                            return;
+                        }
+                    }
+                    RemoteStateVerdict::Reject => return,
+                }
+            }
+        }
+
+        world.insert_boxed_component(&world_entity, component);
+
+        self.outgoing_events.push(EntityEvent::<E>::InsertComponent(
+            tick,
+            world_entity,
+            component_kind,
+        ));
+    }
+
     fn process_remove<W: WorldMutType<E>>(
         &mut self,
         world: &mut W,
+        tick: Tick,
         world_entity: E,
         component_kind: ComponentKind,
     ) {
@@ -214,11 +337,18 @@ impl<E: Copy + Eq + Hash + Send + Sync> RemoteWorldManager<E> {
             }
             return;
         }
+        // the Entity may already have been despawned locally
+        if !world.has_entity(&world_entity) {
+            return;
+        }
         // Remove from world
         if let Some(component) = world.remove_component_of_kind(&world_entity, &component_kind) {
             // Send out event
-            self.outgoing_events
-                .push(EntityEvent::<E>::RemoveComponent(world_entity, component));
+            self.outgoing_events.push(EntityEvent::<E>::RemoveComponent(
+                tick,
+                world_entity,
+                component,
+            ));
         }
     }
 
@@ -227,27 +357,33 @@ impl<E: Copy + Eq + Hash + Send + Sync> RemoteWorldManager<E> {
         global_world_manager: &mut dyn GlobalWorldManagerType<E>,
         local_world_manager: &mut LocalWorldManager<E>,
         world: &mut W,
+        mut validator: Option<&mut (dyn RemoteStateValidator<E> + '_)>,
     ) {
-        let converter = EntityConverter::new(
-            global_world_manager.to_global_entity_converter(),
-            local_world_manager,
-        );
-
         if let Some(list) = self
             .entity_waitlist
             .collect_ready_items(&mut self.insert_waitlist_store)
         {
-            for (world_entity, mut component) in list {
+            for (tick, world_entity, mut component) in list {
                 let component_kind = component.kind();
                 self.insert_waitlist_map
                     .remove(&(world_entity, component_kind));
-                component.relations_complete(&converter);
-                world.insert_boxed_component(&world_entity, component);
+                {
+                    let converter = EntityConverter::new(
+                        global_world_manager.to_global_entity_converter(),
+                        local_world_manager,
+                    );
+                    component.relations_complete(&converter);
+                }
 
-                self.outgoing_events.push(EntityEvent::<E>::InsertComponent(
+                self.insert_component(
+                    global_world_manager,
+                    local_world_manager,
+                    world,
+                    validator.as_deref_mut(),
+                    tick,
                     world_entity,
-                    component_kind,
-                ));
+                    component,
+                );
             }
         }
     }
@@ -263,6 +399,7 @@ impl<E: Copy + Eq + Hash + Send + Sync> RemoteWorldManager<E> {
         component_kinds: &ComponentKinds,
         world: &mut W,
         incoming_updates: Vec<(Tick, E, ComponentUpdate)>,
+        mut validator: Option<&mut (dyn RemoteStateValidator<E> + '_)>,
     ) {
         self.process_ready_updates(
             global_world_manager,
@@ -270,29 +407,39 @@ impl<E: Copy + Eq + Hash + Send + Sync> RemoteWorldManager<E> {
             component_kinds,
             world,
             incoming_updates,
+            validator.as_deref_mut(),
+        );
+        self.process_waitlist_updates(
+            global_world_manager,
+            local_world_manager,
+            component_kinds,
+            world,
+            validator,
         );
-        self.process_waitlist_updates(global_world_manager, local_world_manager, world);
     }
 
     /// Process component updates from raw bits for a given entity
     fn process_ready_updates<W: WorldMutType<E>>(
         &mut self,
         global_world_manager: &mut dyn GlobalWorldManagerType<E>,
-        local_world_manager: &LocalWorldManager<E>,
+        local_world_manager: &mut LocalWorldManager<E>,
         component_kinds: &ComponentKinds,
         world: &mut W,
         mut incoming_updates: Vec<(Tick, E, ComponentUpdate)>,
+        mut validator: Option<&mut (dyn RemoteStateValidator<E> + '_)>,
     ) {
-        let converter = EntityConverter::new(
-            global_world_manager.to_global_entity_converter(),
-            local_world_manager,
-        );
         for (tick, world_entity, component_update) in incoming_updates.drain(..) {
             let component_kind = component_update.kind;
 
             // split the component_update into the waiting and ready parts
-            let Ok((waiting_updates_opt, ready_update_opt)) =
-                component_update.split_into_waiting_and_ready(&converter, component_kinds) else {
+            let split_result = {
+                let converter = EntityConverter::new(
+                    global_world_manager.to_global_entity_converter(),
+                    local_world_manager,
+                );
+                component_update.split_into_waiting_and_ready(&converter, component_kinds)
+            };
+            let Ok((waiting_updates_opt, ready_update_opt)) = split_result else {
                 warn!("Remote World Manager: cannot read malformed component update message");
                 continue;
             };
@@ -312,46 +459,50 @@ impl<E: Copy + Eq + Hash + Send + Sync> RemoteWorldManager<E> {
 
             // if it exists, queue the waiting part of the component update
             if let Some(waiting_updates) = waiting_updates_opt {
-                for (waiting_entity, waiting_field_update) in waiting_updates {
-                    let field_id = waiting_field_update.field_id();
-
-                    // Have to convert the single waiting entity to a HashSet ..
-                    // TODO: make this more efficient
-                    let mut waiting_entities = HashSet::new();
-                    waiting_entities.insert(waiting_entity);
-
-                    let handle = self.entity_waitlist.queue(
+                for (waiting_entities, waiting_field_update) in waiting_updates {
+                    self.queue_waiting_update(
+                        tick,
+                        world_entity,
+                        component_kind,
                         &waiting_entities,
-                        &mut self.update_waitlist_store,
-                        (tick, world_entity, component_kind, waiting_field_update),
+                        waiting_field_update,
                     );
-                    let component_field_key = (world_entity, component_kind);
-                    if !self.update_waitlist_map.contains_key(&component_field_key) {
-                        self.update_waitlist_map
-                            .insert(component_field_key, HashMap::new());
-                    }
-                    let handle_map = self
-                        .update_waitlist_map
-                        .get_mut(&component_field_key)
-                        .unwrap();
-                    if let Some(old_handle) = handle_map.get(&field_id) {
-                        self.update_waitlist_store.remove(&handle);
-                        self.entity_waitlist.remove_waiting_handle(old_handle);
-                    }
-                    handle_map.insert(field_id, handle);
                 }
             }
             // if it exists, apply the ready part of the component update
             if let Some(ready_update) = ready_update_opt {
-                if world
-                    .component_apply_update(
+                let verdict = Self::validate_update(
+                    global_world_manager,
+                    local_world_manager,
+                    component_kinds,
+                    world,
+                    validator.as_deref_mut(),
+                    &world_entity,
+                    &component_kind,
+                    |component, converter| {
+                        component.read_apply_update(converter, ready_update.clone())
+                    },
+                );
+                let converter = EntityConverter::new(
+                    global_world_manager.to_global_entity_converter(),
+                    local_world_manager,
+                );
+                let result = match verdict {
+                    UpdateVerdict::Apply => world.component_apply_update(
                         &converter,
                         &world_entity,
                         &component_kind,
                         ready_update,
-                    )
-                    .is_err()
-                {
+                    ),
+                    UpdateVerdict::Replace(update) => world.component_apply_update(
+                        &ReversedEntityConverter::new(&converter),
+                        &world_entity,
+                        &component_kind,
+                        update,
+                    ),
+                    UpdateVerdict::Discard => continue,
+                };
+                if result.is_err() {
                     warn!("Remote World Manager: cannot read malformed component update message");
                     continue;
                 }
@@ -365,16 +516,45 @@ impl<E: Copy + Eq + Hash + Send + Sync> RemoteWorldManager<E> {
         }
     }
 
+    /// Queues the part of a Component update which waits on Entities to come
+    /// into scope, replacing any older update to the same field (or element of
+    /// a list field) which is still waiting
+    fn queue_waiting_update(
+        &mut self,
+        tick: Tick,
+        world_entity: E,
+        component_kind: ComponentKind,
+        waiting_entities: &HashSet<LocalEntity>,
+        waiting_field_update: ComponentFieldUpdate,
+    ) {
+        let field_key = (
+            waiting_field_update.field_id(),
+            waiting_field_update.element_index(),
+        );
+
+        let handle = self.entity_waitlist.queue(
+            waiting_entities,
+            &mut self.update_waitlist_store,
+            (tick, world_entity, component_kind, waiting_field_update),
+        );
+        let handle_map = self
+            .update_waitlist_map
+            .entry((world_entity, component_kind))
+            .or_default();
+        if let Some(old_handle) = handle_map.insert(field_key, handle) {
+            self.update_waitlist_store.remove(&old_handle);
+            self.entity_waitlist.remove_waiting_handle(&old_handle);
+        }
+    }
+
     fn process_waitlist_updates<W: WorldMutType<E>>(
         &mut self,
         global_world_manager: &mut dyn GlobalWorldManagerType<E>,
-        local_world_manager: &LocalWorldManager<E>,
+        local_world_manager: &mut LocalWorldManager<E>,
+        component_kinds: &ComponentKinds,
         world: &mut W,
+        mut validator: Option<&mut (dyn RemoteStateValidator<E> + '_)>,
     ) {
-        let converter = EntityConverter::new(
-            global_world_manager.to_global_entity_converter(),
-            local_world_manager,
-        );
         if let Some(list) = self
             .entity_waitlist
             .collect_ready_items(&mut self.update_waitlist_store)
@@ -383,7 +563,7 @@ impl<E: Copy + Eq + Hash + Send + Sync> RemoteWorldManager<E> {
                 let component_key = (world_entity, component_kind);
                 let mut remove_entry = false;
                 if let Some(component_map) = self.update_waitlist_map.get_mut(&component_key) {
-                    component_map.remove(&ready_update.field_id());
+                    component_map.remove(&(ready_update.field_id(), ready_update.element_index()));
                     if component_map.is_empty() {
                         remove_entry = true;
                     }
@@ -392,15 +572,38 @@ impl<E: Copy + Eq + Hash + Send + Sync> RemoteWorldManager<E> {
                     self.update_waitlist_map.remove(&component_key);
                 }
 
-                if world
-                    .component_apply_field_update(
+                let verdict = Self::validate_update(
+                    global_world_manager,
+                    local_world_manager,
+                    component_kinds,
+                    world,
+                    validator.as_deref_mut(),
+                    &world_entity,
+                    &component_kind,
+                    |component, converter| {
+                        component.read_apply_field_update(converter, ready_update.clone())
+                    },
+                );
+                let converter = EntityConverter::new(
+                    global_world_manager.to_global_entity_converter(),
+                    local_world_manager,
+                );
+                let result = match verdict {
+                    UpdateVerdict::Apply => world.component_apply_field_update(
                         &converter,
                         &world_entity,
                         &component_kind,
                         ready_update,
-                    )
-                    .is_err()
-                {
+                    ),
+                    UpdateVerdict::Replace(update) => world.component_apply_update(
+                        &ReversedEntityConverter::new(&converter),
+                        &world_entity,
+                        &component_kind,
+                        update,
+                    ),
+                    UpdateVerdict::Discard => continue,
+                };
+                if result.is_err() {
                     warn!("Remote World Manager: cannot read malformed complete waitlisted component update message");
                     continue;
                 }
@@ -413,4 +616,171 @@ impl<E: Copy + Eq + Hash + Send + Sync> RemoteWorldManager<E> {
             }
         }
     }
+
+    /// Runs the validator over the state a Component would have once an
+    /// update is applied to it, which `apply` does to a copy of the Component
+    #[allow(clippy::too_many_arguments)]
+    fn validate_update<W: WorldMutType<E>>(
+        global_world_manager: &dyn GlobalWorldManagerType<E>,
+        local_world_manager: &mut LocalWorldManager<E>,
+        component_kinds: &ComponentKinds,
+        world: &W,
+        validator: Option<&mut (dyn RemoteStateValidator<E> + '_)>,
+        world_entity: &E,
+        component_kind: &ComponentKind,
+        apply: impl FnOnce(
+            &mut dyn Replicate,
+            &dyn LocalEntityAndGlobalEntityConverter,
+        ) -> Result<(), SerdeErr>,
+    ) -> UpdateVerdict {
+        let Some(validator) = validator else {
+            return UpdateVerdict::Apply;
+        };
+        if !validator.validates(world_entity, component_kind) {
+            return UpdateVerdict::Apply;
+        }
+        let Some(previous) = world
+            .component_of_kind(world_entity, component_kind)
+            .map(|component| component.copy_to_box())
+        else {
+            return UpdateVerdict::Discard;
+        };
+
+        let Ok(mut proposed) = Self::remote_copy(
+            global_world_manager,
+            local_world_manager,
+            component_kinds,
+            previous.as_ref(),
+        ) else {
+            warn!("Remote World Manager: cannot copy Component for validation");
+            return UpdateVerdict::Discard;
+        };
+        {
+            let converter = EntityConverter::new(
+                global_world_manager.to_global_entity_converter(),
+                local_world_manager,
+            );
+            if apply(proposed.as_mut(), &converter).is_err() {
+                warn!("Remote World Manager: cannot read malformed component update message");
+                return UpdateVerdict::Discard;
+            }
+        }
+
+        match validator.validate(world_entity, Some(previous.as_ref()), proposed.as_ref()) {
+            RemoteStateVerdict::Accept => UpdateVerdict::Apply,
+            RemoteStateVerdict::Replace(replacement) => {
+                UpdateVerdict::Replace(Self::replacement_update(
+                    global_world_manager,
+                    local_world_manager,
+                    replacement.as_ref(),
+                ))
+            }
+            RemoteStateVerdict::Reject => UpdateVerdict::Discard,
+        }
+    }
+
+    /// Copies a Component into a remote-owned one, which unlike a copy made by
+    /// `Replicate::copy_to_box()` can have updates applied to it
+    fn remote_copy(
+        global_world_manager: &dyn GlobalWorldManagerType<E>,
+        local_world_manager: &mut LocalWorldManager<E>,
+        component_kinds: &ComponentKinds,
+        component: &dyn Replicate,
+    ) -> Result<Box<dyn Replicate>, SerdeErr> {
+        let mut writer = BitWriter::new();
+        {
+            let mut converter = EntityConverterMut::new(global_world_manager, local_world_manager);
+            component.write(component_kinds, &mut writer, &mut converter);
+        }
+        let owned_reader = writer.to_owned_reader();
+        let converter = EntityConverter::new(
+            global_world_manager.to_global_entity_converter(),
+            local_world_manager,
+        );
+        let converter = ReversedEntityConverter::new(&converter);
+        component_kinds.read(&mut owned_reader.borrow(), &converter)
+    }
+
+    /// Writes an update setting every Property of a remote Component to its
+    /// value in the given host-owned Component. Remote Properties can only be
+    /// set by reading an update, which must be read with a
+    /// `ReversedEntityConverter` as it is written from the host's side.
+    fn replacement_update(
+        global_world_manager: &dyn GlobalWorldManagerType<E>,
+        local_world_manager: &mut LocalWorldManager<E>,
+        replacement: &dyn Replicate,
+    ) -> ComponentUpdate {
+        let diff_mask_size = replacement.diff_mask_size();
+        let mut diff_mask = DiffMask::new(diff_mask_size);
+        for index in 0..(u16::from(diff_mask_size) * 8) {
+            diff_mask.set_bit(index as u8, true);
+        }
+
+        let mut writer = BitWriter::new();
+        let mut converter = EntityConverterMut::new(global_world_manager, local_world_manager);
+        replacement.write_update(&diff_mask, &mut writer, &mut converter);
+        ComponentUpdate::new(replacement.kind(), writer.to_owned_reader())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{any::TypeId, collections::HashSet, sync::Arc};
+
+    use naia_serde::BitWriter;
+
+    use crate::{Clock, ComponentFieldUpdate, ComponentKind, LocalEntity, SystemClock};
+
+    use super::RemoteWorldManager;
+
+    #[test]
+    fn newer_waiting_update_replaces_older() {
+        let clock: Arc<dyn Clock> = Arc::new(SystemClock);
+        let mut manager = RemoteWorldManager::<u64>::new(&clock);
+        let component_kind = ComponentKind::from(TypeId::of::<u8>());
+        let waiting_entities = HashSet::from([LocalEntity::new_remote(2)]);
+
+        for tick in [10, 11] {
+            let update = ComponentFieldUpdate::new(0, BitWriter::new().to_owned_reader());
+            manager.queue_waiting_update(tick, 1, component_kind, &waiting_entities, update);
+        }
+
+        manager
+            .entity_waitlist
+            .add_entity(&LocalEntity::new_remote(2));
+        let ready_updates = manager
+            .entity_waitlist
+            .collect_ready_items(&mut manager.update_waitlist_store)
+            .unwrap();
+        let ready_ticks: Vec<_> = ready_updates.iter().map(|(tick, ..)| *tick).collect();
+        assert_eq!(ready_ticks, vec![11]);
+    }
+
+    #[test]
+    fn waiting_updates_to_different_elements_are_kept() {
+        let clock: Arc<dyn Clock> = Arc::new(SystemClock);
+        let mut manager = RemoteWorldManager::<u64>::new(&clock);
+        let component_kind = ComponentKind::from(TypeId::of::<u8>());
+        let waiting_entities = HashSet::from([LocalEntity::new_remote(2)]);
+
+        for element in [0, 1] {
+            let update =
+                ComponentFieldUpdate::new_element(0, element, BitWriter::new().to_owned_reader());
+            manager.queue_waiting_update(10, 1, component_kind, &waiting_entities, update);
+        }
+
+        manager
+            .entity_waitlist
+            .add_entity(&LocalEntity::new_remote(2));
+        let ready_updates = manager
+            .entity_waitlist
+            .collect_ready_items(&mut manager.update_waitlist_store)
+            .unwrap();
+        let mut ready_elements: Vec<_> = ready_updates
+            .iter()
+            .map(|(_, _, _, update)| update.element_index())
+            .collect();
+        ready_elements.sort();
+        assert_eq!(ready_elements, vec![Some(0), Some(1)]);
+    }
 }
@@ -5,19 +5,22 @@ use crate::{
     world::local_world_manager::LocalWorldManager, BitReader, ComponentKind, ComponentKinds,
     ComponentUpdate, EntityAction, EntityActionReceiver, EntityActionType, EntityConverter,
     GlobalWorldManagerType, LocalEntity, LocalEntityAndGlobalEntityConverter, MessageIndex,
-    Protocol, Replicate, Serde, SerdeErr, Tick, UnsignedVariableInteger,
+    PredictionKey, Protocol, Replicate, Serde, SerdeErr, Tick, UnsignedVariableInteger,
 };
 
 pub struct RemoteWorldReader<E: Copy + Eq + Hash + Send + Sync> {
     receiver: EntityActionReceiver<LocalEntity>,
     received_components: HashMap<(LocalEntity, ComponentKind), Box<dyn Replicate>>,
     received_updates: Vec<(Tick, E, ComponentUpdate)>,
+    received_predictions: HashMap<LocalEntity, PredictionKey>,
+    received_action_ticks: HashMap<MessageIndex, Tick>,
 }
 
 pub struct RemoteWorldEvents<E: Copy + Eq + Hash + Send + Sync> {
-    pub incoming_actions: Vec<EntityAction<LocalEntity>>,
+    pub incoming_actions: Vec<(Tick, EntityAction<LocalEntity>)>,
     pub incoming_components: HashMap<(LocalEntity, ComponentKind), Box<dyn Replicate>>,
     pub incoming_updates: Vec<(Tick, E, ComponentUpdate)>,
+    pub incoming_predictions: HashMap<LocalEntity, PredictionKey>,
 }
 
 impl<E: Copy + Eq + Hash + Send + Sync> RemoteWorldReader<E> {
@@ -26,14 +29,36 @@ impl<E: Copy + Eq + Hash + Send + Sync> RemoteWorldReader<E> {
             receiver: EntityActionReceiver::new(),
             received_components: HashMap::default(),
             received_updates: Vec::new(),
+            received_predictions: HashMap::new(),
+            received_action_ticks: HashMap::new(),
         }
     }
 
     pub fn take_incoming_events(&mut self) -> RemoteWorldEvents<E> {
+        let mut incoming_actions = Vec::new();
+        for (action_index, action) in self.receiver.receive_indexed_actions() {
+            let tick = self
+                .received_action_ticks
+                .remove(&action_index)
+                .expect("received action should have a tick");
+            incoming_actions.push((tick, action));
+        }
+
+        // only hand off the prediction keys of spawns which are ready
+        let mut incoming_predictions = HashMap::new();
+        for (_, action) in &incoming_actions {
+            if let EntityAction::SpawnEntity(local_entity, _) = action {
+                if let Some(prediction_key) = self.received_predictions.remove(local_entity) {
+                    incoming_predictions.insert(*local_entity, prediction_key);
+                }
+            }
+        }
+
         RemoteWorldEvents {
-            incoming_actions: self.receiver.receive_actions(),
+            incoming_actions,
             incoming_components: std::mem::take(&mut self.received_components),
             incoming_updates: std::mem::take(&mut self.received_updates),
+            incoming_predictions,
         }
     }
 
@@ -67,6 +92,7 @@ impl<E: Copy + Eq + Hash + Send + Sync> RemoteWorldReader<E> {
             global_world_manager,
             local_world_manager,
             &protocol.component_kinds,
+            tick,
             reader,
         )?;
 
@@ -79,6 +105,7 @@ impl<E: Copy + Eq + Hash + Send + Sync> RemoteWorldReader<E> {
         global_world_manager: &mut dyn GlobalWorldManagerType<E>,
         local_world_manager: &mut LocalWorldManager<E>,
         component_kinds: &ComponentKinds,
+        tick: Tick,
         reader: &mut BitReader,
     ) -> Result<(), SerdeErr> {
         let mut last_read_id: Option<MessageIndex> = None;
@@ -96,7 +123,7 @@ impl<E: Copy + Eq + Hash + Send + Sync> RemoteWorldReader<E> {
                     break;
                 }
 
-                self.read_action(&converter, component_kinds, reader, &mut last_read_id)?;
+                self.read_action(&converter, component_kinds, tick, reader, &mut last_read_id)?;
             }
         }
 
@@ -104,7 +131,8 @@ impl<E: Copy + Eq + Hash + Send + Sync> RemoteWorldReader<E> {
     }
 
     /// Read the bits corresponding to the EntityAction and adds the [`EntityAction`]
-    /// to an internal buffer.
+    /// to an internal buffer, along with the Tick it was written for, which
+    /// precedes that of the packet if the action was resent.
     ///
     /// We can use a UnorderedReliableReceiver buffer because the messages have already been
     /// ordered by the client's jitter buffer
@@ -112,11 +140,16 @@ impl<E: Copy + Eq + Hash + Send + Sync> RemoteWorldReader<E> {
         &mut self,
         converter: &dyn LocalEntityAndGlobalEntityConverter,
         component_kinds: &ComponentKinds,
+        tick: Tick,
         reader: &mut BitReader,
         last_read_id: &mut Option<MessageIndex>,
     ) -> Result<(), SerdeErr> {
         let action_id = Self::read_message_index(reader, last_read_id)?;
 
+        // read the Tick the action was written for
+        let tick_diff = UnsignedVariableInteger::<3>::de(reader)?.get() as Tick;
+        let tick = tick.wrapping_sub(tick_diff);
+
         let action_type = EntityActionType::de(reader)?;
 
         match action_type {
@@ -125,6 +158,17 @@ impl<E: Copy + Eq + Hash + Send + Sync> RemoteWorldReader<E> {
                 // read entity
                 let local_entity = LocalEntity::remote_de(reader)?;
 
+                // read prediction key, present in every spawn
+                match Option::<PredictionKey>::de(reader)? {
+                    Some(prediction_key) => {
+                        self.received_predictions
+                            .insert(local_entity, prediction_key);
+                    }
+                    None => {
+                        self.received_predictions.remove(&local_entity);
+                    }
+                }
+
                 // read components
                 let components_num = UnsignedVariableInteger::<3>::de(reader)?.get();
                 let mut component_kind_list = Vec::new();
@@ -136,8 +180,9 @@ impl<E: Copy + Eq + Hash + Send + Sync> RemoteWorldReader<E> {
                     component_kind_list.push(new_component_kind);
                 }
 
-                self.receiver.buffer_action(
+                self.buffer_action(
                     action_id,
+                    tick,
                     EntityAction::SpawnEntity(local_entity, component_kind_list),
                 );
             }
@@ -146,8 +191,7 @@ impl<E: Copy + Eq + Hash + Send + Sync> RemoteWorldReader<E> {
                 // read all data
                 let local_entity = LocalEntity::remote_de(reader)?;
 
-                self.receiver
-                    .buffer_action(action_id, EntityAction::DespawnEntity(local_entity));
+                self.buffer_action(action_id, tick, EntityAction::DespawnEntity(local_entity));
             }
             // Add Component to Entity
             EntityActionType::InsertComponent => {
@@ -156,8 +200,9 @@ impl<E: Copy + Eq + Hash + Send + Sync> RemoteWorldReader<E> {
                 let new_component = component_kinds.read(reader, converter)?;
                 let new_component_kind = new_component.kind();
 
-                self.receiver.buffer_action(
+                self.buffer_action(
                     action_id,
+                    tick,
                     EntityAction::InsertComponent(local_entity, new_component_kind),
                 );
                 self.received_components
@@ -169,19 +214,33 @@ impl<E: Copy + Eq + Hash + Send + Sync> RemoteWorldReader<E> {
                 let local_entity = LocalEntity::remote_de(reader)?;
                 let component_kind = ComponentKind::de(component_kinds, reader)?;
 
-                self.receiver.buffer_action(
+                self.buffer_action(
                     action_id,
+                    tick,
                     EntityAction::RemoveComponent(local_entity, component_kind),
                 );
             }
             EntityActionType::Noop => {
-                self.receiver.buffer_action(action_id, EntityAction::Noop);
+                self.buffer_action(action_id, tick, EntityAction::Noop);
             }
         }
 
         Ok(())
     }
 
+    fn buffer_action(
+        &mut self,
+        action_id: MessageIndex,
+        tick: Tick,
+        action: EntityAction<LocalEntity>,
+    ) {
+        // Noop actions are never output by the receiver, so need no Tick
+        let has_entity = action.entity().is_some();
+        if self.receiver.buffer_action(action_id, action) && has_entity {
+            self.received_action_ticks.insert(action_id, tick);
+        }
+    }
+
     /// Read component updates from raw bits
     fn read_updates(
         &mut self,
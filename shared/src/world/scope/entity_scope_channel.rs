@@ -0,0 +1,6 @@
+use crate::Channel;
+
+/// Internal Channel used to let Clients know when a dormant Entity leaves or
+/// re-enters their scope
+#[derive(Channel)]
+pub struct EntityScopeChannel;
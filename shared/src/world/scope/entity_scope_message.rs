@@ -0,0 +1,30 @@
+use std::hash::Hash;
+
+use naia_derive::MessageInternal;
+
+use crate::{EntityAndGlobalEntityConverter, EntityProperty};
+
+/// Sent when a dormant Entity leaves a Client's scope, in which case the
+/// Client keeps it with its last known state instead of despawning it, or
+/// when it re-enters it
+#[derive(MessageInternal)]
+pub struct EntityScopeMessage {
+    pub entity: EntityProperty,
+    pub in_scope: bool,
+}
+
+impl EntityScopeMessage {
+    pub fn new<E: Copy + Eq + Hash>(
+        converter: &dyn EntityAndGlobalEntityConverter<E>,
+        entity: &E,
+        in_scope: bool,
+    ) -> Self {
+        let mut entity_property = EntityProperty::new();
+        entity_property.set(converter, entity);
+
+        Self {
+            entity: entity_property,
+            in_scope,
+        }
+    }
+}
@@ -0,0 +1,2 @@
+pub mod entity_scope_channel;
+pub mod entity_scope_message;
@@ -53,6 +53,21 @@ mod some_entity_replica {
     }
 }
 
+mod some_entity_update_replica {
+    use naia_shared::{EntityProperty, EntityPropertyVec, Property, Replicate};
+    #[derive(Replicate)]
+    pub struct EntityUpdateHolder {
+        pub entity_1: EntityProperty,
+        pub value_1: Property<u8>,
+        pub entities_1: EntityPropertyVec,
+    }
+    impl EntityUpdateHolder {
+        pub fn new(value_1: u8) -> Self {
+            EntityUpdateHolder::new_complete(value_1)
+        }
+    }
+}
+
 mod some_nonreplicated_replica {
     use naia_shared::{Property, Replicate};
 
@@ -72,13 +87,20 @@ mod some_nonreplicated_replica {
     }
 }
 
+use std::{
+    collections::{BTreeSet, HashSet},
+    sync::{Arc, Mutex},
+};
+
 use naia_shared::{
-    BigMapKey, BitReader, BitWriter, EntityAndGlobalEntityConverter, EntityDoesNotExistError,
-    FakeEntityConverter, GlobalEntity, LocalEntity, LocalEntityAndGlobalEntityConverter, Protocol,
-    Replicate,
+    BigMapKey, BitReader, BitWriter, ComponentKind, DiffMask, EntityAndGlobalEntityConverter,
+    EntityDoesNotExistError, FakeEntityConverter, GlobalEntity, LocalEntity,
+    LocalEntityAndGlobalEntityConverter, LocalEntityAndGlobalEntityConverterMut, PropertyMutate,
+    PropertyMutator, Protocol, Replicate,
 };
 
 use some_entity_replica::EntityPropertyHolder;
+use some_entity_update_replica::EntityUpdateHolder;
 use some_named_replica::NamedStringHolder;
 use some_nonreplicated_replica::MixedReplicationHolder;
 use some_tuple_replica::TupleStringHolder;
@@ -95,7 +117,7 @@ fn read_write_unit_replica() {
 
     let in_1 = UnitHolder::new();
 
-    in_1.write(&component_kinds, &mut writer, &FakeEntityConverter);
+    in_1.write(&component_kinds, &mut writer, &mut FakeEntityConverter);
 
     let bytes = writer.to_bytes();
 
@@ -123,7 +145,7 @@ fn read_write_named_replica() {
 
     let in_1 = NamedStringHolder::new("hello world", "goodbye world");
 
-    in_1.write(&component_kinds, &mut writer, &FakeEntityConverter);
+    in_1.write(&component_kinds, &mut writer, &mut FakeEntityConverter);
 
     let bytes = writer.to_bytes();
 
@@ -158,7 +180,7 @@ fn read_write_tuple_replica() {
 
     let in_1 = TupleStringHolder::new("hello world", "goodbye world");
 
-    in_1.write(&component_kinds, &mut writer, &FakeEntityConverter);
+    in_1.write(&component_kinds, &mut writer, &mut FakeEntityConverter);
 
     let bytes = writer.to_bytes();
 
@@ -213,6 +235,14 @@ fn read_write_entity_replica() {
             Ok(GlobalEntity::from_u64(local_entity_value as u64))
         }
     }
+    impl LocalEntityAndGlobalEntityConverterMut for TestEntityConverter {
+        fn get_or_reserve_host_entity(
+            &mut self,
+            global_entity: &GlobalEntity,
+        ) -> Result<LocalEntity, EntityDoesNotExistError> {
+            self.global_entity_to_local_entity(global_entity)
+        }
+    }
 
     // Protocol
     let protocol = Protocol::builder()
@@ -224,7 +254,7 @@ fn read_write_entity_replica() {
     let mut writer = BitWriter::new();
     let mut in_1 = EntityPropertyHolder::new();
     in_1.entity_1.set(&TestEntityConverter, &1);
-    in_1.write(&component_kinds, &mut writer, &TestEntityConverter);
+    in_1.write(&component_kinds, &mut writer, &mut TestEntityConverter);
     let bytes = writer.to_bytes();
 
     // Read
@@ -235,14 +265,310 @@ fn read_write_entity_replica() {
         .to_boxed_any();
 
     let typed_out_1 = out_1.downcast_ref::<EntityPropertyHolder>().unwrap();
-    assert!(in_1.entity_1.equals(&typed_out_1.entity_1));
-    let global_entitys = Vec::<GlobalEntity>::from([GlobalEntity::from_u64(1)]);
-    assert_eq!(in_1.entities(), global_entitys);
-    assert_eq!(typed_out_1.entities(), global_entitys);
+    assert!(typed_out_1.relations_waiting().is_none());
     assert_eq!(in_1.entity_1.get(&TestEntityConverter).unwrap(), 1);
-    assert_eq!(in_1.entity_1.global_entity().unwrap().to_u64(), 1);
     assert_eq!(typed_out_1.entity_1.get(&TestEntityConverter).unwrap(), 1);
-    assert_eq!(typed_out_1.entity_1.global_entity().unwrap().to_u64(), 1);
+}
+
+// Converts Entities as the sending host, where every Entity exists
+pub struct SenderEntityConverter;
+
+impl EntityAndGlobalEntityConverter<u64> for SenderEntityConverter {
+    fn global_entity_to_entity(
+        &self,
+        global_entity: &GlobalEntity,
+    ) -> Result<u64, EntityDoesNotExistError> {
+        Ok(global_entity.to_u64())
+    }
+    fn entity_to_global_entity(
+        &self,
+        entity: &u64,
+    ) -> Result<GlobalEntity, EntityDoesNotExistError> {
+        Ok(GlobalEntity::from_u64(*entity))
+    }
+}
+impl LocalEntityAndGlobalEntityConverter for SenderEntityConverter {
+    fn global_entity_to_local_entity(
+        &self,
+        global_entity: &GlobalEntity,
+    ) -> Result<LocalEntity, EntityDoesNotExistError> {
+        Ok(LocalEntity::new_host(global_entity.to_u64() as u16))
+    }
+    fn local_entity_to_global_entity(
+        &self,
+        local_entity: &LocalEntity,
+    ) -> Result<GlobalEntity, EntityDoesNotExistError> {
+        Ok(GlobalEntity::from_u64(local_entity.value() as u64))
+    }
+}
+impl LocalEntityAndGlobalEntityConverterMut for SenderEntityConverter {
+    fn get_or_reserve_host_entity(
+        &mut self,
+        global_entity: &GlobalEntity,
+    ) -> Result<LocalEntity, EntityDoesNotExistError> {
+        self.global_entity_to_local_entity(global_entity)
+    }
+}
+
+// Converts Entities as the receiving host, which only knows of some Entities
+pub struct ReceiverEntityConverter {
+    known_entities: Vec<u16>,
+}
+
+impl EntityAndGlobalEntityConverter<u64> for ReceiverEntityConverter {
+    fn global_entity_to_entity(
+        &self,
+        global_entity: &GlobalEntity,
+    ) -> Result<u64, EntityDoesNotExistError> {
+        Ok(global_entity.to_u64())
+    }
+    fn entity_to_global_entity(
+        &self,
+        entity: &u64,
+    ) -> Result<GlobalEntity, EntityDoesNotExistError> {
+        Ok(GlobalEntity::from_u64(*entity))
+    }
+}
+impl LocalEntityAndGlobalEntityConverter for ReceiverEntityConverter {
+    fn global_entity_to_local_entity(
+        &self,
+        global_entity: &GlobalEntity,
+    ) -> Result<LocalEntity, EntityDoesNotExistError> {
+        Ok(LocalEntity::new_remote(global_entity.to_u64() as u16))
+    }
+    fn local_entity_to_global_entity(
+        &self,
+        local_entity: &LocalEntity,
+    ) -> Result<GlobalEntity, EntityDoesNotExistError> {
+        if self.known_entities.contains(&local_entity.value()) {
+            Ok(GlobalEntity::from_u64(local_entity.value() as u64))
+        } else {
+            Err(EntityDoesNotExistError)
+        }
+    }
+}
+
+fn write_entity_update(
+    protocol: &Protocol,
+    component: &EntityUpdateHolder,
+    changed_fields: &[u8],
+) -> Vec<u8> {
+    let mut diff_mask = DiffMask::new(component.diff_mask_size());
+    for field in changed_fields {
+        diff_mask.set_bit(*field, true);
+    }
+    write_entity_update_with_mask(protocol, component, &diff_mask)
+}
+
+fn write_entity_update_with_mask(
+    protocol: &Protocol,
+    component: &EntityUpdateHolder,
+    diff_mask: &DiffMask,
+) -> Vec<u8> {
+    let mut writer = BitWriter::new();
+    ComponentKind::of::<EntityUpdateHolder>().ser(&protocol.component_kinds, &mut writer);
+    component.write_update(diff_mask, &mut writer, &mut SenderEntityConverter);
+    writer.to_bytes().to_vec()
+}
+
+#[test]
+fn split_entity_update_into_waiting_and_ready() {
+    let protocol = Protocol::builder()
+        .add_component::<EntityUpdateHolder>()
+        .build();
+    let component_kinds = &protocol.component_kinds;
+    let mut receiver = ReceiverEntityConverter {
+        known_entities: vec![1],
+    };
+
+    // Create on the receiver, referencing a known Entity
+    let mut in_1 = EntityUpdateHolder::new(5);
+    in_1.entity_1.set(&SenderEntityConverter, &1);
+    let mut writer = BitWriter::new();
+    in_1.write(component_kinds, &mut writer, &mut SenderEntityConverter);
+    let bytes = writer.to_bytes();
+    let mut out_1 = component_kinds
+        .read(&mut BitReader::new(&bytes), &receiver)
+        .expect("should deserialize correctly");
+
+    // Update to reference an Entity the receiver doesn't know yet
+    in_1.entity_1.set(&SenderEntityConverter, &2);
+    *in_1.value_1 = 7;
+    let bytes = write_entity_update(&protocol, &in_1, &[0, 1]);
+    let update = component_kinds
+        .read_create_update(&mut BitReader::new(&bytes))
+        .expect("should deserialize correctly");
+
+    let (waiting_updates, ready_update) = component_kinds
+        .split_update(&receiver, &update.kind.clone(), update)
+        .expect("should split correctly");
+    let mut waiting_updates = waiting_updates.expect("update should wait on Entity 2");
+    assert_eq!(waiting_updates.len(), 1);
+    let (waiting_entities, waiting_update) = waiting_updates.remove(0);
+    assert_eq!(
+        waiting_entities,
+        HashSet::from([LocalEntity::new_remote(2)])
+    );
+    assert_eq!(waiting_update.field_id(), 0);
+
+    // the ready part only updates the other field
+    out_1
+        .read_apply_update(&receiver, ready_update.expect("value_1 should be ready"))
+        .expect("should apply correctly");
+    {
+        let typed_out_1 = out_1.to_any().downcast_ref::<EntityUpdateHolder>().unwrap();
+        assert_eq!(*typed_out_1.value_1, 7);
+        assert_eq!(typed_out_1.entity_1.get(&receiver), Some(1));
+    }
+
+    // the waiting part applies once the Entity arrives
+    receiver.known_entities.push(2);
+    out_1
+        .read_apply_field_update(&receiver, waiting_update)
+        .expect("should apply correctly");
+    let typed_out_1 = out_1.to_any().downcast_ref::<EntityUpdateHolder>().unwrap();
+    assert_eq!(typed_out_1.entity_1.get(&receiver), Some(2));
+}
+
+#[test]
+fn split_entity_vec_update_waits_per_element() {
+    let protocol = Protocol::builder()
+        .add_component::<EntityUpdateHolder>()
+        .build();
+    let component_kinds = &protocol.component_kinds;
+    let mut receiver = ReceiverEntityConverter {
+        known_entities: vec![1],
+    };
+
+    let mut in_1 = EntityUpdateHolder::new(5);
+    let mut writer = BitWriter::new();
+    in_1.write(component_kinds, &mut writer, &mut SenderEntityConverter);
+    let bytes = writer.to_bytes();
+    let mut out_1 = component_kinds
+        .read(&mut BitReader::new(&bytes), &receiver)
+        .expect("should deserialize correctly");
+
+    in_1.entities_1.set(&SenderEntityConverter, &[1, 2, 3]);
+    let bytes = write_entity_update(&protocol, &in_1, &[2]);
+    let update = component_kinds
+        .read_create_update(&mut BitReader::new(&bytes))
+        .expect("should deserialize correctly");
+
+    let (waiting_updates, ready_update) = component_kinds
+        .split_update(&receiver, &update.kind.clone(), update)
+        .expect("should split correctly");
+
+    // each element referencing an unknown Entity waits on its own
+    let mut waiting_updates = waiting_updates.expect("update should wait on Entities 2 & 3");
+    assert_eq!(waiting_updates.len(), 2);
+    let (waiting_entities_3, waiting_update_3) = waiting_updates.remove(1);
+    let (waiting_entities_2, waiting_update_2) = waiting_updates.remove(0);
+    assert_eq!(
+        waiting_entities_2,
+        HashSet::from([LocalEntity::new_remote(2)])
+    );
+    assert_eq!(waiting_update_2.element_index(), Some(1));
+    assert_eq!(
+        waiting_entities_3,
+        HashSet::from([LocalEntity::new_remote(3)])
+    );
+    assert_eq!(waiting_update_3.element_index(), Some(2));
+
+    // the ready part sets the length & every known element
+    out_1
+        .read_apply_update(&receiver, ready_update.expect("Entity 1 should be ready"))
+        .expect("should apply correctly");
+    {
+        let typed_out_1 = out_1.to_any().downcast_ref::<EntityUpdateHolder>().unwrap();
+        assert_eq!(typed_out_1.entities_1.len(), 3);
+        assert_eq!(typed_out_1.entities_1.get(&receiver), vec![1]);
+    }
+
+    receiver.known_entities.push(3);
+    out_1
+        .read_apply_field_update(&receiver, waiting_update_3)
+        .expect("should apply correctly");
+    {
+        let typed_out_1 = out_1.to_any().downcast_ref::<EntityUpdateHolder>().unwrap();
+        assert_eq!(typed_out_1.entities_1.get(&receiver), vec![1, 3]);
+    }
+
+    receiver.known_entities.push(2);
+    out_1
+        .read_apply_field_update(&receiver, waiting_update_2)
+        .expect("should apply correctly");
+    let typed_out_1 = out_1.to_any().downcast_ref::<EntityUpdateHolder>().unwrap();
+    assert_eq!(typed_out_1.entities_1.get(&receiver), vec![1, 2, 3]);
+}
+
+// Records mutations into a DiffMask, as a connection does
+#[derive(Clone)]
+struct DiffMaskMutator {
+    mask: Arc<Mutex<DiffMask>>,
+}
+
+impl PropertyMutate for DiffMaskMutator {
+    fn mutate(&mut self, property_index: u8) {
+        self.mask.lock().unwrap().set_bit(property_index, true);
+    }
+
+    fn mutate_elements(&mut self, property_index: u8, element_indices: &[u32]) {
+        self.mask
+            .lock()
+            .unwrap()
+            .set_elements(property_index, element_indices);
+    }
+}
+
+#[test]
+fn entity_vec_update_only_writes_changed_elements() {
+    let protocol = Protocol::builder()
+        .add_component::<EntityUpdateHolder>()
+        .build();
+    let component_kinds = &protocol.component_kinds;
+    let receiver = ReceiverEntityConverter {
+        known_entities: vec![1, 2, 3, 4, 5],
+    };
+
+    let mut in_1 = EntityUpdateHolder::new(5);
+    in_1.entities_1.set(&SenderEntityConverter, &[1, 2, 3]);
+    let mut writer = BitWriter::new();
+    in_1.write(component_kinds, &mut writer, &mut SenderEntityConverter);
+    let bytes = writer.to_bytes();
+    let mut out_1 = component_kinds
+        .read(&mut BitReader::new(&bytes), &receiver)
+        .expect("should deserialize correctly");
+
+    let mask = Arc::new(Mutex::new(DiffMask::new(in_1.diff_mask_size())));
+    in_1.set_mutator(&PropertyMutator::new(DiffMaskMutator {
+        mask: mask.clone(),
+    }));
+
+    // replace one element & append another
+    in_1.entities_1.set(&SenderEntityConverter, &[1, 4, 3]);
+    in_1.entities_1.push(&SenderEntityConverter, &5);
+    let diff_mask = mask.lock().unwrap().clone();
+    assert_eq!(diff_mask.elements(2), Some(&BTreeSet::from([1, 3])));
+
+    let mut writer = BitWriter::new();
+    in_1.write_update(&diff_mask, &mut writer, &mut SenderEntityConverter);
+    let partial_bits = writer.bits_written();
+
+    let mut full_mask = DiffMask::new(in_1.diff_mask_size());
+    full_mask.set_bit(2, true);
+    let mut writer = BitWriter::new();
+    in_1.write_update(&full_mask, &mut writer, &mut SenderEntityConverter);
+    assert!(partial_bits < writer.bits_written());
+
+    let bytes = write_entity_update_with_mask(&protocol, &in_1, &diff_mask);
+    let update = component_kinds
+        .read_create_update(&mut BitReader::new(&bytes))
+        .expect("should deserialize correctly");
+    out_1
+        .read_apply_update(&receiver, update)
+        .expect("should apply correctly");
+    let typed_out_1 = out_1.to_any().downcast_ref::<EntityUpdateHolder>().unwrap();
+    assert_eq!(typed_out_1.entities_1.get(&receiver), vec![1, 4, 3, 5]);
 }
 
 #[test]
@@ -258,7 +584,7 @@ fn read_write_nonreplicated_replica() {
 
     let in_1 = MixedReplicationHolder::new("hello world", "goodbye world");
 
-    in_1.write(&component_kinds, &mut writer, &FakeEntityConverter);
+    in_1.write(&component_kinds, &mut writer, &mut FakeEntityConverter);
 
     let bytes = writer.to_bytes();
 
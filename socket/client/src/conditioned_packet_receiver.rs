@@ -1,4 +1,4 @@
-use naia_socket_shared::{link_condition_logic, LinkConditionerConfig, TimeQueue};
+use naia_socket_shared::{link_condition_logic, Instant, LinkConditionerConfig, TimeQueue};
 
 use super::{
     error::NaiaClientSocketError, packet_receiver::PacketReceiver, server_addr::ServerAddr,
@@ -30,6 +30,7 @@ impl ConditionedPacketReceiver {
 
 impl PacketReceiver for ConditionedPacketReceiver {
     fn receive(&mut self) -> Result<Option<&[u8]>, NaiaClientSocketError> {
+        let now = Instant::now();
         loop {
             match self.inner_receiver.receive() {
                 Ok(option) => match option {
@@ -40,6 +41,7 @@ impl PacketReceiver for ConditionedPacketReceiver {
                         link_condition_logic::process_packet(
                             &self.link_conditioner_config,
                             &mut self.time_queue,
+                            &now,
                             payload.into(),
                         );
                     }
@@ -50,8 +52,8 @@ impl PacketReceiver for ConditionedPacketReceiver {
             }
         }
 
-        if self.time_queue.has_item() {
-            self.last_payload = Some(self.time_queue.pop_item().unwrap());
+        if self.time_queue.has_item(&now) {
+            self.last_payload = Some(self.time_queue.pop_item(&now).unwrap());
             return Ok(Some(self.last_payload.as_ref().unwrap()));
         } else {
             Ok(None)
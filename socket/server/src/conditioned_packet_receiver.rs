@@ -2,7 +2,7 @@ use std::net::SocketAddr;
 
 use smol::channel::Receiver;
 
-use naia_socket_shared::{link_condition_logic, LinkConditionerConfig, TimeQueue};
+use naia_socket_shared::{link_condition_logic, Instant, LinkConditionerConfig, TimeQueue};
 
 use super::{error::NaiaServerSocketError, packet_receiver::PacketReceiver};
 
@@ -34,12 +34,14 @@ impl ConditionedPacketReceiverImpl {
 
 impl PacketReceiver for ConditionedPacketReceiverImpl {
     fn receive(&mut self) -> Result<Option<(SocketAddr, &[u8])>, NaiaServerSocketError> {
+        let now = Instant::now();
         while let Ok(result) = self.channel_receiver.try_recv() {
             match result {
                 Ok(packet) => {
                     link_condition_logic::process_packet(
                         &self.link_conditioner_config,
                         &mut self.time_queue,
+                        &now,
                         packet,
                     );
                 }
@@ -49,8 +51,8 @@ impl PacketReceiver for ConditionedPacketReceiverImpl {
             }
         }
 
-        if self.time_queue.has_item() {
-            let (address, payload) = self.time_queue.pop_item().unwrap();
+        if self.time_queue.has_item(&now) {
+            let (address, payload) = self.time_queue.pop_item(&now).unwrap();
             self.last_payload = Some(payload);
             return Ok(Some((address, self.last_payload.as_ref().unwrap())));
         } else {
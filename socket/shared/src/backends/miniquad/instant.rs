@@ -36,6 +36,18 @@ impl Instant {
         }
     }
 
+    /// Returns time elapsed from an earlier Instant until this one, or zero if
+    /// it is not earlier
+    pub fn duration_since(&self, earlier: &Instant) -> Duration {
+        let inner_duration = (self.inner - earlier.inner).max(0.0);
+        Duration::from_secs_f64(inner_duration / 1000.0)
+    }
+
+    /// Adds a given Duration to the Instant
+    pub fn add_duration(&mut self, duration: Duration) {
+        self.inner += duration.as_secs_f64() * 1000.0;
+    }
+
     /// Adds a given number of milliseconds to the Instant
     pub fn add_millis(&mut self, millis: u32) {
         let millis_f64: f64 = millis.into();
@@ -24,6 +24,17 @@ impl Instant {
         self.inner.duration_since(std::time::Instant::now())
     }
 
+    /// Returns time elapsed from an earlier Instant until this one, or zero if
+    /// it is not earlier
+    pub fn duration_since(&self, earlier: &Instant) -> Duration {
+        self.inner.saturating_duration_since(earlier.inner)
+    }
+
+    /// Adds a given Duration to the Instant
+    pub fn add_duration(&mut self, duration: Duration) {
+        self.inner += duration;
+    }
+
     /// Adds a given number of milliseconds to the Instant
     pub fn add_millis(&mut self, millis: u32) {
         self.inner += Duration::from_millis(millis.into());
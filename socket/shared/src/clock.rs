@@ -0,0 +1,87 @@
+use std::{
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use crate::Instant;
+
+/// A source of time, which a Server or Client reads all of its timing from
+pub trait Clock: Send + Sync {
+    /// Returns the current time of the Clock. This must never decrease.
+    fn now(&self) -> Instant;
+
+    /// Returns the time passed since the given Instant
+    fn elapsed(&self, instant: &Instant) -> Duration {
+        self.now().duration_since(instant)
+    }
+}
+
+/// The system's Clock, which is used unless another is configured
+#[derive(Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+}
+
+/// A Clock which only moves when advanced, so that ticks, timeouts & RTT can
+/// be simulated deterministically and faster than real time
+#[derive(Clone)]
+pub struct VirtualClock {
+    start: Instant,
+    elapsed: Arc<Mutex<Duration>>,
+}
+
+impl Default for VirtualClock {
+    fn default() -> Self {
+        Self {
+            start: Instant::now(),
+            elapsed: Arc::new(Mutex::new(Duration::ZERO)),
+        }
+    }
+}
+
+impl VirtualClock {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Moves the Clock forward by the given Duration
+    pub fn advance(&self, duration: Duration) {
+        *self.elapsed.lock().unwrap() += duration;
+    }
+}
+
+impl Clock for VirtualClock {
+    fn now(&self) -> Instant {
+        let mut now = self.start.clone();
+        now.add_duration(*self.elapsed.lock().unwrap());
+        now
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::{Clock, VirtualClock};
+
+    #[test]
+    fn instants_follow_virtual_clock() {
+        let clock = VirtualClock::new();
+
+        let start = clock.now();
+        assert_eq!(clock.elapsed(&start), Duration::ZERO);
+
+        clock.advance(Duration::from_secs(600));
+        assert_eq!(clock.elapsed(&start), Duration::from_secs(600));
+        assert!(clock.now() > start);
+
+        // clones share the time
+        let clone = clock.clone();
+        clone.advance(Duration::from_secs(1));
+        assert_eq!(clock.elapsed(&start), Duration::from_secs(601));
+    }
+}
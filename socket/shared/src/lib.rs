@@ -17,12 +17,14 @@ extern crate cfg_if;
 pub mod link_condition_logic;
 
 mod backends;
+mod clock;
 mod link_conditioner_config;
 mod socket_config;
 mod time_queue;
 mod url_parse;
 
 pub use backends::{Instant, Random};
+pub use clock::{Clock, SystemClock, VirtualClock};
 pub use link_conditioner_config::LinkConditionerConfig;
 pub use socket_config::SocketConfig;
 pub use time_queue::TimeQueue;
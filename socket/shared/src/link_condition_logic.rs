@@ -5,11 +5,12 @@ use super::{link_conditioner_config::LinkConditionerConfig, time_queue::TimeQueu
 use crate::Random;
 
 /// Given a config object which describes the network conditions to be
-/// simulated, process an incoming packet, adding it to a TimeQueue at the
-/// correct timestamp
+/// simulated, process a packet incoming at the given time, adding it to a
+/// TimeQueue at the correct timestamp
 pub fn process_packet<T: Eq>(
     config: &LinkConditionerConfig,
     time_queue: &mut TimeQueue<T>,
+    now: &Instant,
     packet: T,
 ) {
     if Random::gen_range_f32(0.0, 1.0) <= config.incoming_loss {
@@ -24,7 +25,7 @@ pub fn process_packet<T: Eq>(
             latency -= Random::gen_range_u32(0, config.incoming_jitter);
         }
     }
-    let mut packet_timestamp = Instant::now();
+    let mut packet_timestamp = now.clone();
     packet_timestamp.add_millis(latency);
     time_queue.add_item(packet_timestamp, packet);
 }
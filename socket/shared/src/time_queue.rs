@@ -25,19 +25,21 @@ impl<T: Eq + PartialEq> TimeQueue<T> {
     }
 
     /// Returns whether or not there is an item that is ready to be returned
-    pub fn has_item(&self) -> bool {
+    /// by the given time
+    pub fn has_item(&self, now: &Instant) -> bool {
         if self.queue.is_empty() {
             return false;
         }
         if let Some(item) = self.queue.peek() {
-            return item.instant <= Instant::now();
+            return item.instant <= *now;
         }
         false
     }
 
-    /// Pops an item from the queue if the sufficient time has elapsed
-    pub fn pop_item(&mut self) -> Option<T> {
-        if self.has_item() {
+    /// Pops an item from the queue if the sufficient time has elapsed by the
+    /// given time
+    pub fn pop_item(&mut self, now: &Instant) -> Option<T> {
+        if self.has_item(now) {
             if let Some(container) = self.queue.pop() {
                 return Some(container.item);
             }
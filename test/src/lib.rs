@@ -1,3 +1,9 @@
 mod auth;
+mod local_socket;
+mod position;
+mod session;
 
 pub use auth::Auth;
+pub use local_socket::{LocalClientSocket, LocalNetwork, LocalServerSocket};
+pub use position::Position;
+pub use session::{Session, SessionClient, StepEvents};
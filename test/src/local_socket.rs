@@ -0,0 +1,206 @@
+use std::{
+    collections::{HashMap, VecDeque},
+    net::{IpAddr, Ipv4Addr, SocketAddr},
+    sync::{Arc, Mutex},
+};
+
+use naia_shared::Clock;
+
+use naia_client::transport::{
+    PacketReceiver as ClientPacketReceiver, PacketSender as ClientPacketSender,
+    RecvError as ClientRecvError, SendError as ClientSendError, ServerAddr, Socket as ClientSocket,
+};
+use naia_server::transport::{
+    PacketReceiver as ServerPacketReceiver, PacketSender as ServerPacketSender,
+    RecvError as ServerRecvError, SendError as ServerSendError, Socket as ServerSocket,
+};
+
+const SERVER_ADDR: SocketAddr = SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), 14191);
+
+// Local Network
+/// Delivers packets between a Server & any number of Clients in memory,
+/// instantly and in order, so that whole sessions can run inside a test
+#[derive(Clone, Default)]
+pub struct LocalNetwork {
+    inner: Arc<Mutex<NetworkInner>>,
+}
+
+#[derive(Default)]
+struct NetworkInner {
+    to_server: VecDeque<(SocketAddr, Vec<u8>)>,
+    to_clients: HashMap<SocketAddr, VecDeque<Vec<u8>>>,
+    next_port: u16,
+    drop_to_clients: bool,
+}
+
+impl LocalNetwork {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Gets the Socket for the Server to listen on
+    pub fn server_socket(&self) -> LocalServerSocket {
+        LocalServerSocket {
+            network: self.clone(),
+        }
+    }
+
+    /// Gets a Socket for a new Client to connect with, at an address of its own
+    pub fn client_socket(&self) -> LocalClientSocket {
+        let mut inner = self.inner.lock().unwrap();
+        inner.next_port += 1;
+        let address = SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), 50000 + inner.next_port);
+        inner.to_clients.insert(address, VecDeque::new());
+        LocalClientSocket {
+            network: self.clone(),
+            address,
+        }
+    }
+
+    /// While set, every packet the Server sends to a Client is lost
+    pub fn set_drop_to_clients(&self, drop: bool) {
+        self.inner.lock().unwrap().drop_to_clients = drop;
+    }
+}
+
+// Server Socket
+pub struct LocalServerSocket {
+    network: LocalNetwork,
+}
+
+impl From<LocalServerSocket> for Box<dyn ServerSocket> {
+    fn from(socket: LocalServerSocket) -> Self {
+        Box::new(socket)
+    }
+}
+
+impl ServerSocket for LocalServerSocket {
+    fn listen(
+        self: Box<Self>,
+        _clock: &Arc<dyn Clock>,
+    ) -> (Box<dyn ServerPacketSender>, Box<dyn ServerPacketReceiver>) {
+        let sender = Box::new(ServerSender {
+            network: self.network.clone(),
+        });
+        let receiver = Box::new(ServerReceiver {
+            network: self.network,
+            buffer: Vec::new(),
+        });
+        (sender, receiver)
+    }
+}
+
+struct ServerSender {
+    network: LocalNetwork,
+}
+
+impl ServerPacketSender for ServerSender {
+    fn send(&self, address: &SocketAddr, payload: &[u8]) -> Result<(), ServerSendError> {
+        let mut inner = self.network.inner.lock().unwrap();
+        let drop = inner.drop_to_clients;
+        let Some(queue) = inner.to_clients.get_mut(address) else {
+            return Err(ServerSendError);
+        };
+        if !drop {
+            queue.push_back(payload.to_vec());
+        }
+        Ok(())
+    }
+}
+
+#[derive(Clone)]
+struct ServerReceiver {
+    network: LocalNetwork,
+    buffer: Vec<u8>,
+}
+
+impl ServerPacketReceiver for ServerReceiver {
+    fn receive(&mut self) -> Result<Option<(SocketAddr, &[u8])>, ServerRecvError> {
+        let Some((address, payload)) = self.network.inner.lock().unwrap().to_server.pop_front()
+        else {
+            return Ok(None);
+        };
+        self.buffer = payload;
+        Ok(Some((address, &self.buffer)))
+    }
+}
+
+// Client Socket
+pub struct LocalClientSocket {
+    network: LocalNetwork,
+    address: SocketAddr,
+}
+
+impl LocalClientSocket {
+    /// The address the Server sees this Client at
+    pub fn address(&self) -> SocketAddr {
+        self.address
+    }
+}
+
+impl From<LocalClientSocket> for Box<dyn ClientSocket> {
+    fn from(socket: LocalClientSocket) -> Self {
+        Box::new(socket)
+    }
+}
+
+impl ClientSocket for LocalClientSocket {
+    fn connect(
+        self: Box<Self>,
+        _clock: &Arc<dyn Clock>,
+    ) -> (Box<dyn ClientPacketSender>, Box<dyn ClientPacketReceiver>) {
+        let sender = Box::new(ClientSender {
+            network: self.network.clone(),
+            address: self.address,
+        });
+        let receiver = Box::new(ClientReceiver {
+            network: self.network,
+            address: self.address,
+            buffer: Vec::new(),
+        });
+        (sender, receiver)
+    }
+}
+
+struct ClientSender {
+    network: LocalNetwork,
+    address: SocketAddr,
+}
+
+impl ClientPacketSender for ClientSender {
+    fn send(&self, payload: &[u8]) -> Result<(), ClientSendError> {
+        let mut inner = self.network.inner.lock().unwrap();
+        inner.to_server.push_back((self.address, payload.to_vec()));
+        Ok(())
+    }
+
+    fn server_addr(&self) -> ServerAddr {
+        ServerAddr::Found(SERVER_ADDR)
+    }
+}
+
+#[derive(Clone)]
+struct ClientReceiver {
+    network: LocalNetwork,
+    address: SocketAddr,
+    buffer: Vec<u8>,
+}
+
+impl ClientPacketReceiver for ClientReceiver {
+    fn receive(&mut self) -> Result<Option<&[u8]>, ClientRecvError> {
+        let mut inner = self.network.inner.lock().unwrap();
+        let Some(payload) = inner
+            .to_clients
+            .get_mut(&self.address)
+            .and_then(|queue| queue.pop_front())
+        else {
+            return Ok(None);
+        };
+        self.buffer = payload;
+        Ok(Some(&self.buffer))
+    }
+
+    fn server_addr(&self) -> ServerAddr {
+        ServerAddr::Found(SERVER_ADDR)
+    }
+}
@@ -0,0 +1,13 @@
+use naia_shared::{Property, Replicate};
+
+#[derive(Replicate)]
+pub struct Position {
+    pub x: Property<u16>,
+    pub y: Property<u16>,
+}
+
+impl Position {
+    pub fn new(x: u16, y: u16) -> Self {
+        Self::new_complete(x, y)
+    }
+}
@@ -0,0 +1,189 @@
+use std::{sync::Arc, time::Duration};
+
+use naia_client::{
+    Client, ClientConfig, ConnectEvent as ClientConnectEvent, Events as ClientEvents,
+};
+use naia_demo_world::{Entity, World};
+use naia_server::{
+    ConnectEvent as ServerConnectEvent, Events as ServerEvents, RoomKey, Server, ServerConfig,
+    UserKey,
+};
+use naia_shared::{Protocol, Replicate, VirtualClock};
+
+use crate::local_socket::LocalNetwork;
+
+/// The most steps `Session::connect_client()` waits for a handshake to finish
+const CONNECT_STEPS: usize = 500;
+
+// Session
+/// A Server & its Clients, connected over a `LocalNetwork` and timed by a
+/// shared `VirtualClock`. Each `step()` moves time forward by one Tick and
+/// runs every endpoint once.
+pub struct Session {
+    pub clock: VirtualClock,
+    pub network: LocalNetwork,
+    pub server: Server<Entity>,
+    pub server_world: World,
+    pub room_key: RoomKey,
+    pub clients: Vec<SessionClient>,
+    /// Whether every Entity sharing a Room with a User is included in its
+    /// scope each step. Turn this off to manage `Server::user_scope()`
+    /// directly.
+    pub auto_scope: bool,
+    protocol: fn() -> Protocol,
+    tick_interval: Duration,
+}
+
+pub struct SessionClient {
+    pub client: Client<Entity>,
+    pub world: World,
+    pub user_key: UserKey,
+}
+
+/// The Events every endpoint produced during one `Session::step()`
+pub struct StepEvents {
+    pub server: ServerEvents<Entity>,
+    pub clients: Vec<ClientEvents<Entity>>,
+}
+
+impl Session {
+    /// Starts a Server listening on a new `LocalNetwork`, with one Room
+    /// which connecting Clients are added to
+    pub fn new(protocol: fn() -> Protocol) -> Self {
+        Self::with_config(protocol, ServerConfig::default())
+    }
+
+    pub fn with_config(protocol: fn() -> Protocol, mut server_config: ServerConfig) -> Self {
+        let clock = VirtualClock::new();
+        let network = LocalNetwork::new();
+
+        server_config.require_auth = false;
+        server_config.clock = Arc::new(clock.clone());
+        let server_protocol = protocol();
+        let tick_interval = server_protocol.tick_interval;
+        let mut server = Server::new(server_config, server_protocol);
+        server.listen(network.server_socket());
+        let room_key = server.make_room().key();
+
+        Self {
+            clock,
+            network,
+            server,
+            server_world: World::default(),
+            room_key,
+            clients: Vec::new(),
+            auto_scope: true,
+            protocol,
+            tick_interval,
+        }
+    }
+
+    /// Connects a new Client & puts its User in the Room, returning its
+    /// index into `clients`
+    pub fn connect_client(&mut self) -> usize {
+        self.connect_client_with_config(ClientConfig::default())
+    }
+
+    pub fn connect_client_with_config(&mut self, mut client_config: ClientConfig) -> usize {
+        client_config.clock = Arc::new(self.clock.clone());
+        let mut client = Client::new(client_config, (self.protocol)());
+        client.connect(self.network.client_socket()).unwrap();
+        let mut world = World::default();
+
+        let mut user_key = None;
+        for _ in 0..CONNECT_STEPS {
+            let mut events = self.step();
+            if let Some(key) = events.server.read::<ServerConnectEvent>().next() {
+                self.server.room_mut(&self.room_key).add_user(&key);
+                user_key = Some(key);
+            }
+            let mut client_events = client.receive(world.proxy_mut());
+            let connected = client_events.read::<ClientConnectEvent>().next().is_some();
+            if connected || (user_key.is_some() && client.is_connected()) {
+                break;
+            }
+        }
+        let user_key = user_key.expect("Client did not connect in time");
+        assert!(client.is_connected());
+
+        self.clients.push(SessionClient {
+            client,
+            world,
+            user_key,
+        });
+        self.clients.len() - 1
+    }
+
+    /// Moves time forward by one Tick, then has the Server receive & send, and
+    /// every Client receive (which also sends)
+    pub fn step(&mut self) -> StepEvents {
+        self.clock.advance(self.tick_interval);
+
+        let server = self.server.receive(self.server_world.proxy_mut());
+        if self.auto_scope {
+            for (_room_key, user_key, entity) in self.server.scope_checks() {
+                self.server.user_scope(&user_key).include(&entity);
+            }
+        }
+        self.server.send_all_updates(self.server_world.proxy());
+
+        let mut clients = Vec::new();
+        for session_client in &mut self.clients {
+            clients.push(
+                session_client
+                    .client
+                    .receive(session_client.world.proxy_mut()),
+            );
+        }
+
+        StepEvents { server, clients }
+    }
+
+    /// Steps `count` times, discarding the Events
+    pub fn steps(&mut self, count: usize) {
+        for _ in 0..count {
+            self.step();
+        }
+    }
+
+    /// Steps until `condition` holds, or panics after `max_steps`
+    pub fn step_until(&mut self, max_steps: usize, mut condition: impl FnMut(&Self) -> bool) {
+        for _ in 0..max_steps {
+            if condition(self) {
+                return;
+            }
+            self.step();
+        }
+        assert!(condition(self), "condition not met in {} steps", max_steps);
+    }
+
+    /// Spawns an Entity with the given Component on the Server, in the Room
+    pub fn spawn<R: Replicate>(&mut self, component: R) -> Entity {
+        let entity = self
+            .server
+            .spawn_entity(self.server_world.proxy_mut())
+            .insert_component(component)
+            .id();
+        self.server
+            .room_mut(&self.room_key)
+            .add_entity(&entity)
+            .unwrap();
+        entity
+    }
+
+    /// The Entities replicated to a Client
+    pub fn client_entities(&self, index: usize) -> Vec<Entity> {
+        let session_client = &self.clients[index];
+        session_client
+            .client
+            .entities(&session_client.world.proxy())
+    }
+
+    pub fn client(&self, index: usize) -> &SessionClient {
+        &self.clients[index]
+    }
+
+    pub fn client_mut(&mut self, index: usize) -> &mut SessionClient {
+        &mut self.clients[index]
+    }
+}
@@ -0,0 +1,162 @@
+use naia_client::{
+    EntityAuthGrantedEvent, EntityAuthStatus, NaiaClientError, UpdateComponentEvent,
+};
+use naia_demo_world::{WorldMutType, WorldRefType};
+use naia_server::{EntityAuthReleaseEvent, EntityAuthRequestEvent, NaiaServerError};
+use naia_shared::Protocol;
+use naia_test::{Position, Session};
+
+fn protocol() -> Protocol {
+    Protocol::builder()
+        .add_component::<Position>()
+        .add_default_channels()
+        .build()
+}
+
+#[test]
+fn authority_errors_instead_of_panicking() {
+    let mut session = Session::new(protocol);
+    let client = session.connect_client();
+    let server_entity = session.spawn(Position::new(0, 0));
+    session.step_until(50, |session| session.client_entities(client).len() == 1);
+    let client_entity = session.client_entities(client)[0];
+    let user_key = session.client(client).user_key;
+
+    assert!(matches!(
+        session.server.grant_authority(&server_entity, &user_key),
+        Err(NaiaServerError::EntityNotDelegated)
+    ));
+    assert!(matches!(
+        session
+            .client_mut(client)
+            .client
+            .request_authority(&client_entity),
+        Err(NaiaClientError::EntityNotDelegated)
+    ));
+
+    // delegated, but out of the User's scope
+    session.auto_scope = false;
+    session.server.enable_delegation(&server_entity).unwrap();
+    session.server.user_scope(&user_key).exclude(&server_entity);
+    session.steps(10);
+    assert!(matches!(
+        session.server.grant_authority(&server_entity, &user_key),
+        Err(NaiaServerError::EntityNotInScope)
+    ));
+}
+
+#[test]
+fn authority_is_requested_granted_and_released() {
+    let mut session = Session::new(protocol);
+    let client = session.connect_client();
+    let server_entity = session.spawn(Position::new(1, 1));
+    session.server.enable_delegation(&server_entity).unwrap();
+    session.step_until(50, |session| {
+        session
+            .client_entities(client)
+            .first()
+            .and_then(|entity| session.client(client).client.entity_auth_status(entity))
+            == Some(EntityAuthStatus::Available)
+    });
+    let client_entity = session.client_entities(client)[0];
+    let user_key = session.client(client).user_key;
+
+    // Client requests authority, and the Server grants it
+    session
+        .client_mut(client)
+        .client
+        .request_authority(&client_entity)
+        .unwrap();
+    let mut requested = false;
+    for _ in 0..50 {
+        let mut events = session.step();
+        if events
+            .server
+            .read::<EntityAuthRequestEvent>()
+            .any(|(key, entity)| key == user_key && entity == server_entity)
+        {
+            requested = true;
+            break;
+        }
+    }
+    assert!(requested);
+    session
+        .server
+        .grant_authority(&server_entity, &user_key)
+        .unwrap();
+
+    let mut granted = false;
+    for _ in 0..50 {
+        let mut events = session.step();
+        if events.clients[client]
+            .read::<EntityAuthGrantedEvent>()
+            .any(|entity| entity == client_entity)
+        {
+            granted = true;
+            break;
+        }
+    }
+    assert!(granted);
+    assert!(session.server.entity_authority(&server_entity) == Some(user_key));
+
+    // the Client's changes now replicate to the Server
+    {
+        let session_client = session.client_mut(client);
+        let mut world = session_client.world.proxy_mut();
+        let mut position = world.component_mut::<Position>(&client_entity).unwrap();
+        *position.x = 7;
+    }
+    session.step_until(50, |session| {
+        *session
+            .server_world
+            .proxy()
+            .component::<Position>(&server_entity)
+            .unwrap()
+            .x
+            == 7
+    });
+
+    // releasing hands authority back to the Server
+    {
+        let session_client = session.client_mut(client);
+        session_client
+            .client
+            .release_authority(session_client.world.proxy_mut(), &client_entity);
+    }
+    let mut released = false;
+    for _ in 0..50 {
+        let mut events = session.step();
+        if events
+            .server
+            .read::<EntityAuthReleaseEvent>()
+            .next()
+            .is_some()
+        {
+            released = true;
+            break;
+        }
+    }
+    assert!(released);
+    assert!(session.server.entity_authority(&server_entity).is_none());
+
+    // and the Server's changes replicate again
+    *session
+        .server
+        .entity_mut(session.server_world.proxy_mut(), &server_entity)
+        .component::<Position>()
+        .unwrap()
+        .y = 5;
+    let mut updated = false;
+    for _ in 0..50 {
+        let mut events = session.step();
+        if events.clients[client]
+            .read::<UpdateComponentEvent<Position>>()
+            .next()
+            .is_some()
+        {
+            updated = true;
+            break;
+        }
+    }
+    assert!(updated);
+}
@@ -0,0 +1,192 @@
+use std::{
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use naia_client::{InsertComponentEvent, RemoveComponentEvent};
+use naia_demo_world::{Entity, WorldRefType};
+use naia_server::{ServerConfig, UserKey};
+use naia_shared::{Property, Protocol, Replicate};
+use naia_test::{Position, Session};
+
+const MAX_STEPS: usize = 100;
+
+#[derive(Replicate)]
+pub struct Marker {
+    pub value: Property<u8>,
+}
+
+impl Marker {
+    pub fn new(value: u8) -> Self {
+        Self::new_complete(value)
+    }
+}
+
+fn protocol() -> Protocol {
+    Protocol::builder()
+        .add_component::<Position>()
+        .add_component::<Marker>()
+        .add_default_channels()
+        .build()
+}
+
+/// Starts a Session measuring bandwidth over its whole run, with two Clients
+fn session() -> (Session, usize, usize) {
+    let mut server_config = ServerConfig::default();
+    server_config.connection.bandwidth_measure_duration = Some(Duration::from_secs(3600));
+    let mut session = Session::with_config(protocol, server_config);
+    let a = session.connect_client();
+    let b = session.connect_client();
+    (session, a, b)
+}
+
+fn client_has_position(session: &Session, client: usize, entity: &Entity) -> bool {
+    session
+        .client(client)
+        .world
+        .proxy()
+        .component::<Position>(entity)
+        .is_some()
+}
+
+/// Steps until both Clients have an Entity with a Marker of the given value,
+/// returning their Entities
+fn wait_for_marker(session: &mut Session, value: u8) -> (Entity, Entity) {
+    let find = |session: &Session, client: usize| {
+        session.client_entities(client).into_iter().find(|entity| {
+            session
+                .client(client)
+                .world
+                .proxy()
+                .component::<Marker>(entity)
+                .is_some_and(|marker| *marker.value == value)
+        })
+    };
+    session.step_until(MAX_STEPS, |session| {
+        find(session, 0).is_some() && find(session, 1).is_some()
+    });
+    (find(session, 0).unwrap(), find(session, 1).unwrap())
+}
+
+/// Steps until in-flight changes have surely arrived, counting the Position insertions & removals each Client
+/// received
+fn step_counting(session: &mut Session) -> ([usize; 2], [usize; 2]) {
+    let mut inserts = [0, 0];
+    let mut removes = [0, 0];
+    for _ in 0..MAX_STEPS {
+        let mut events = session.step();
+        for client in 0..2 {
+            inserts[client] += events.clients[client]
+                .read::<InsertComponentEvent<Position>>()
+                .count();
+            removes[client] += events.clients[client]
+                .read::<RemoveComponentEvent<Position>>()
+                .count();
+        }
+    }
+    (inserts, removes)
+}
+
+#[test]
+fn excluded_component_is_never_sent() {
+    let (mut session, a, b) = session();
+    let user_a = session.client(a).user_key;
+    let room_key = session.room_key;
+
+    // excluded before the Entity is spawned for the User
+    let spawned = session
+        .server
+        .spawn_entity(session.server_world.proxy_mut())
+        .insert_component(Marker::new(1))
+        .insert_component(Position::new(1, 1))
+        .id();
+    session
+        .server
+        .user_scope(&user_a)
+        .exclude_component::<Position>(&spawned);
+    session
+        .server
+        .room_mut(&room_key)
+        .add_entity(&spawned)
+        .unwrap();
+    let (spawned_a, spawned_b) = wait_for_marker(&mut session, 1);
+
+    // excluded before the Component is inserted
+    let inserted = session.spawn(Marker::new(2));
+    session
+        .server
+        .user_scope(&user_a)
+        .exclude_component::<Position>(&inserted);
+    let (inserted_a, inserted_b) = wait_for_marker(&mut session, 2);
+    session
+        .server
+        .entity_mut(session.server_world.proxy_mut(), &inserted)
+        .insert_component(Position::new(2, 2));
+    let (inserts, _) = step_counting(&mut session);
+
+    assert_eq!(inserts, [0, 1]);
+    assert!(!client_has_position(&session, a, &spawned_a));
+    assert!(!client_has_position(&session, a, &inserted_a));
+    assert!(client_has_position(&session, b, &spawned_b));
+    assert!(client_has_position(&session, b, &inserted_b));
+
+    // not a single bit of Position went out to the excluded User
+    let user_b = session.client(b).user_key;
+    let breakdown_a = session.server.bandwidth_breakdown(&user_a).unwrap();
+    let breakdown_b = session.server.bandwidth_breakdown(&user_b).unwrap();
+    assert!(breakdown_a.component("Marker").is_some());
+    assert!(breakdown_a.component("Position").is_none());
+    assert!(breakdown_b.component("Position").is_some());
+}
+
+#[test]
+fn visibility_rule_inserts_and_removes_components() {
+    let (mut session, a, b) = session();
+    let user_a = session.client(a).user_key;
+    let user_b = session.client(b).user_key;
+
+    let visible_to: Arc<Mutex<UserKey>> = Arc::new(Mutex::new(user_b));
+    let rule_visible_to = visible_to.clone();
+    session
+        .server
+        .component_visibility::<Position, _>(move |user_key, _entity| {
+            *user_key == *rule_visible_to.lock().unwrap()
+        });
+
+    let entity = session.spawn(Marker::new(1));
+    session
+        .server
+        .entity_mut(session.server_world.proxy_mut(), &entity)
+        .insert_component(Position::new(1, 1));
+    let (entity_a, entity_b) = wait_for_marker(&mut session, 1);
+    let (inserts, _) = step_counting(&mut session);
+    assert!(!client_has_position(&session, a, &entity_a));
+    assert!(client_has_position(&session, b, &entity_b));
+    assert_eq!(inserts[a], 0);
+
+    // the rule now favours the other User
+    *visible_to.lock().unwrap() = user_a;
+    session.server.refresh_component_visibility(&entity);
+    let (inserts, removes) = step_counting(&mut session);
+    assert_eq!(inserts, [1, 0]);
+    assert_eq!(removes, [0, 1]);
+    assert!(client_has_position(&session, a, &entity_a));
+    assert!(!client_has_position(&session, b, &entity_b));
+
+    // explicit exclusions win over the rule
+    session
+        .server
+        .user_scope(&user_a)
+        .exclude_component::<Position>(&entity);
+    let (_, removes) = step_counting(&mut session);
+    assert_eq!(removes, [1, 0]);
+    assert!(!client_has_position(&session, a, &entity_a));
+
+    // without a rule, the Component is visible to whoever it isn't excluded for
+    session.server.remove_component_visibility::<Position>();
+    session.server.refresh_component_visibility(&entity);
+    let (inserts, _) = step_counting(&mut session);
+    assert_eq!(inserts, [0, 1]);
+    assert!(!client_has_position(&session, a, &entity_a));
+    assert!(client_has_position(&session, b, &entity_b));
+}
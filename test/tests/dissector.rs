@@ -0,0 +1,51 @@
+use naia_dissector::{PacketDirection, PacketDissector};
+use naia_shared::{
+    BitWriter, ComponentKind, DiffMask, FakeEntityConverter, GameInstant, Instant, LocalEntity,
+    PacketType, Protocol, Replicate, Serde, StandardHeader,
+};
+use naia_test::Position;
+
+#[test]
+fn dissects_component_update_fields() {
+    let protocol = Protocol::builder().add_component::<Position>().build();
+    let mut writer = BitWriter::new();
+
+    StandardHeader::new(PacketType::Data, 1, 0, 0).ser(&mut writer);
+    let tick: u16 = 20;
+    tick.ser(&mut writer);
+    let start = Instant::now();
+    GameInstant::new(&start, &start).ser(&mut writer);
+
+    // no tick buffered messages, no messages
+    false.ser(&mut writer);
+    false.ser(&mut writer);
+
+    // an update to only the y field of one Position
+    true.ser(&mut writer);
+    LocalEntity::new_host(4).host_ser(&mut writer);
+    true.ser(&mut writer);
+    ComponentKind::of::<Position>().ser(&protocol.component_kinds, &mut writer);
+    let mut diff_mask = DiffMask::new(1);
+    diff_mask.set_bit(1, true);
+    Position::new(5, 300).write_update(&diff_mask, &mut writer, &mut FakeEntityConverter);
+    false.ser(&mut writer);
+    false.ser(&mut writer);
+
+    // no actions
+    false.ser(&mut writer);
+
+    let payload = writer.to_bytes();
+    let packet = PacketDissector::new(&protocol)
+        .dissect(PacketDirection::ServerToClient, &payload)
+        .unwrap();
+
+    assert!(!packet.malformed);
+    assert_eq!(packet.updates.len(), 1);
+    let update = &packet.updates[0];
+    assert_eq!(update.entity, LocalEntity::new_remote(4));
+    assert_eq!(update.component, "Position");
+    assert_eq!(update.fields.len(), 1);
+    assert_eq!(update.fields[0].name, "y");
+    assert_eq!(update.fields[0].bits, 16);
+    assert!(packet.to_string().contains("Position { y (16 bits) }"));
+}
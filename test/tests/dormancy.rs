@@ -0,0 +1,136 @@
+use std::time::Duration;
+
+use naia_client::{EntityEnteredScopeEvent, EntityLeftScopeEvent};
+use naia_demo_world::{Entity, WorldMutType, WorldRefType};
+use naia_server::ServerConfig;
+use naia_shared::Protocol;
+use naia_test::{Position, Session};
+
+const MAX_STEPS: usize = 100;
+
+fn protocol() -> Protocol {
+    Protocol::builder()
+        .add_component::<Position>()
+        .add_default_channels()
+        .build()
+}
+
+/// Starts a Session whose scopes are set by hand, with one Client
+fn session(server_config: ServerConfig) -> Session {
+    let mut session = Session::with_config(protocol, server_config);
+    session.auto_scope = false;
+    session.connect_client();
+    session
+}
+
+/// Spawns a dormant Entity in scope of the Client, returning it along with
+/// the Client's Entity
+fn spawn_dormant(session: &mut Session, x: u16) -> (Entity, Entity) {
+    let user_key = session.client(0).user_key;
+    let server_entity = session.spawn(Position::new(x, 0));
+    session.server.enable_dormancy(&server_entity);
+    session.server.user_scope(&user_key).include(&server_entity);
+    session.step_until(MAX_STEPS, |session| client_entity(session, x).is_some());
+    (server_entity, client_entity(session, x).unwrap())
+}
+
+fn client_entity(session: &Session, x: u16) -> Option<Entity> {
+    session.client_entities(0).into_iter().find(|entity| {
+        session
+            .client(0)
+            .world
+            .proxy()
+            .component::<Position>(entity)
+            .is_some_and(|position| *position.x == x)
+    })
+}
+
+fn set_server_x(session: &mut Session, entity: &Entity, x: u16) {
+    let mut world = session.server_world.proxy_mut();
+    *world.component_mut::<Position>(entity).unwrap().x = x;
+}
+
+fn exclude(session: &mut Session, entity: &Entity) {
+    let user_key = session.client(0).user_key;
+    session.server.user_scope(&user_key).exclude(entity);
+}
+
+#[test]
+fn changes_while_dormant_are_sent_on_reentry() {
+    let mut session = session(ServerConfig::default());
+    let user_key = session.client(0).user_key;
+    let (server_entity, client_entity) = spawn_dormant(&mut session, 1);
+
+    exclude(&mut session, &server_entity);
+    let mut left = false;
+    for _ in 0..MAX_STEPS {
+        let mut events = session.step();
+        if events.clients[0].read::<EntityLeftScopeEvent>().next() == Some(client_entity) {
+            left = true;
+            break;
+        }
+    }
+    assert!(left);
+
+    // held back while dormant, the Client keeps the last known state
+    set_server_x(&mut session, &server_entity, 2);
+    session.steps(20);
+    let position_x = |session: &Session| {
+        session
+            .client(0)
+            .world
+            .proxy()
+            .component::<Position>(&client_entity)
+            .map(|position| *position.x)
+    };
+    assert_eq!(position_x(&session), Some(1));
+    assert!(session.client(0).client.entity_is_dormant(&client_entity));
+
+    // sent on re-entry
+    session.server.user_scope(&user_key).include(&server_entity);
+    let mut entered = false;
+    for _ in 0..MAX_STEPS {
+        let mut events = session.step();
+        entered |=
+            events.clients[0].read::<EntityEnteredScopeEvent>().next() == Some(client_entity);
+        if entered && position_x(&session) == Some(2) {
+            break;
+        }
+    }
+    assert!(entered);
+    assert_eq!(position_x(&session), Some(2));
+}
+
+#[test]
+fn dormant_entities_expire() {
+    let mut session = session(ServerConfig {
+        dormancy_timeout: Some(Duration::from_secs(1)),
+        ..Default::default()
+    });
+    let (server_entity, _) = spawn_dormant(&mut session, 1);
+
+    exclude(&mut session, &server_entity);
+    // 500ms later, still dormant
+    session.steps(10);
+    assert!(client_entity(&session, 1).is_some());
+
+    session.step_until(MAX_STEPS, |session| client_entity(session, 1).is_none());
+    assert!(session.server_world.proxy().has_entity(&server_entity));
+}
+
+#[test]
+fn oldest_dormant_entities_are_despawned_past_the_cap() {
+    let mut session = session(ServerConfig {
+        max_dormant_entities: Some(1),
+        ..Default::default()
+    });
+    let (first, _) = spawn_dormant(&mut session, 1);
+    let (second, _) = spawn_dormant(&mut session, 2);
+
+    exclude(&mut session, &first);
+    session.steps(5);
+    exclude(&mut session, &second);
+    session.step_until(MAX_STEPS, |session| client_entity(session, 1).is_none());
+    session.steps(20);
+    assert!(client_entity(&session, 2).is_some());
+}
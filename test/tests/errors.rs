@@ -0,0 +1,92 @@
+use naia_client::NaiaClientError;
+use naia_server::{NaiaServerError, SpatialInterest, UserKey};
+use naia_shared::{BigMapKey, Protocol, ProtocolLockedError};
+use naia_test::{Position, Session};
+
+fn protocol() -> Protocol {
+    Protocol::builder()
+        .add_component::<Position>()
+        .add_default_channels()
+        .enable_client_authoritative_entities()
+        .build()
+}
+
+#[test]
+fn locked_protocol_is_an_error_to_change() {
+    let mut protocol = protocol();
+    assert_eq!(protocol.try_check_lock(), Ok(()));
+    assert_eq!(protocol.try_lock(), Ok(()));
+
+    assert_eq!(protocol.try_check_lock(), Err(ProtocolLockedError));
+    assert_eq!(protocol.try_lock(), Err(ProtocolLockedError));
+
+    // changes to a locked Protocol are ignored
+    let tick_interval = protocol.tick_interval;
+    protocol.tick_interval(tick_interval * 2);
+    assert_eq!(protocol.tick_interval, tick_interval);
+}
+
+#[test]
+fn server_misuse_is_an_error() {
+    let mut session = Session::new(protocol);
+
+    // the Server ticks on its own
+    assert!(matches!(
+        session.server.advance_tick(),
+        Err(NaiaServerError::TickNotManual)
+    ));
+
+    assert!(matches!(
+        session.server.start_recording(&UserKey::from_u64(99)),
+        Err(NaiaServerError::UserNotFound)
+    ));
+
+    let entity = session.spawn(Position::new(1, 0));
+    let mut position = Position::new(2, 0);
+    assert!(matches!(
+        session
+            .server
+            .insert_component_worldless(&entity, &mut position),
+        Err(NaiaServerError::ComponentAlreadyInserted)
+    ));
+
+    // Position is a Component, but not a Resource
+    assert!(matches!(
+        session
+            .server
+            .insert_resource(session.server_world.proxy_mut(), Position::new(1, 0)),
+        Err(NaiaServerError::ResourceNotRegistered)
+    ));
+
+    let child = session.spawn(Position::new(3, 0));
+    session.server.entity_set_parent(&child, &entity).unwrap();
+    assert!(matches!(
+        session.server.entity_set_parent(&entity, &child),
+        Err(NaiaServerError::EntityHierarchyCycle)
+    ));
+
+    assert!(matches!(
+        SpatialInterest::<u32>::new(0.0),
+        Err(NaiaServerError::InvalidCellSize)
+    ));
+}
+
+#[test]
+fn client_misuse_is_an_error() {
+    let mut session = Session::new(protocol);
+    let client = session.connect_client();
+
+    let session_client = session.client_mut(client);
+    let entity = session_client
+        .client
+        .spawn_entity(session_client.world.proxy_mut())
+        .insert_component(Position::new(1, 0))
+        .id();
+    let mut position = Position::new(2, 0);
+    assert!(matches!(
+        session_client
+            .client
+            .insert_component_worldless(&entity, &mut position),
+        Err(NaiaClientError::ComponentAlreadyInserted)
+    ));
+}
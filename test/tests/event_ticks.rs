@@ -0,0 +1,81 @@
+use naia_client::{SpawnEntityEvent, UpdateComponentEvent};
+use naia_demo_world::{Entity, WorldMutType};
+use naia_shared::{Protocol, Tick};
+use naia_test::{Position, Session};
+
+const MAX_STEPS: usize = 100;
+
+fn protocol() -> Protocol {
+    Protocol::builder()
+        .add_component::<Position>()
+        .add_default_channels()
+        .build()
+}
+
+/// Steps until the Client gets a spawn, returning its Tick
+fn wait_for_spawn(session: &mut Session) -> Option<Tick> {
+    for _ in 0..MAX_STEPS {
+        let mut events = session.step();
+        if let Some((tick, _)) = events.clients[0].read::<SpawnEntityEvent>().next() {
+            return Some(tick);
+        }
+    }
+    None
+}
+
+/// Steps until the Client gets a Position update, returning its Tick
+fn wait_for_update(session: &mut Session) -> Option<Tick> {
+    for _ in 0..MAX_STEPS {
+        let mut events = session.step();
+        if let Some((tick, _)) = events.clients[0]
+            .read::<UpdateComponentEvent<Position>>()
+            .next()
+        {
+            return Some(tick);
+        }
+    }
+    None
+}
+
+fn set_server_x(session: &mut Session, entity: &Entity, x: u16) {
+    let mut world = session.server_world.proxy_mut();
+    *world.component_mut::<Position>(entity).unwrap().x = x;
+}
+
+#[test]
+fn events_carry_the_tick_they_were_written_for() {
+    let mut session = Session::new(protocol);
+    session.connect_client();
+
+    let entity = session.spawn(Position::new(1, 0));
+    session.step();
+    let spawn_tick = session.server.current_tick();
+    assert_eq!(wait_for_spawn(&mut session), Some(spawn_tick));
+    // released once the Client's receiving Tick reaches it
+    assert_eq!(session.client(0).client.server_tick(), Some(spawn_tick));
+
+    // updates wait for the spawn to be acknowledged
+    session.steps(MAX_STEPS);
+    set_server_x(&mut session, &entity, 2);
+    session.step();
+    let update_tick = session.server.current_tick();
+    assert_eq!(wait_for_update(&mut session), Some(update_tick));
+    assert_eq!(session.client(0).client.server_tick(), Some(update_tick));
+}
+
+#[test]
+fn resent_actions_keep_the_tick_they_were_first_written_for() {
+    let mut session = Session::new(protocol);
+    session.connect_client();
+
+    // the first sends of the spawn are lost
+    session.network.set_drop_to_clients(true);
+    session.spawn(Position::new(1, 0));
+    session.step();
+    let spawn_tick = session.server.current_tick();
+    session.steps(10);
+    session.network.set_drop_to_clients(false);
+
+    assert_eq!(wait_for_spawn(&mut session), Some(spawn_tick));
+    assert_ne!(session.client(0).client.server_tick(), Some(spawn_tick));
+}
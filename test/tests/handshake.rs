@@ -1,16 +1,18 @@
-use std::time::Duration;
+use std::{sync::Arc, time::Duration};
 
 use naia_client::internal::{HandshakeManager as ClientHandshakeManager, HandshakeState};
 use naia_server::internal::{HandshakeManager as ServerHandshakeManager, HandshakeResult};
 use naia_shared::{
-    BitReader, BitWriter, FakeEntityConverter, MessageContainer, PacketType, Protocol, Serde,
-    StandardHeader,
+    BitReader, BitWriter, Clock, FakeEntityConverter, MessageContainer, PacketType, Protocol,
+    Serde, StandardHeader, SystemClock,
 };
 use naia_test::Auth;
 
 #[test]
 fn end_to_end_handshake_w_auth() {
-    let mut client = ClientHandshakeManager::new(Duration::new(0, 0), Duration::new(0, 0), 1);
+    let clock: Arc<dyn Clock> = Arc::new(SystemClock);
+    let mut client =
+        ClientHandshakeManager::new(Duration::new(0, 0), Duration::new(0, 0), 1, &clock);
     let mut server = ServerHandshakeManager::new(true);
     let mut bytes: Box<[u8]>;
     let mut writer: BitWriter;
@@ -25,7 +27,7 @@ fn end_to_end_handshake_w_auth() {
     let password = "1234567";
     client.set_auth_message(MessageContainer::from_write(
         Box::new(Auth::new(username, password)),
-        &FakeEntityConverter,
+        &mut FakeEntityConverter,
     ));
 
     // 1. Client send challenge request
@@ -0,0 +1,110 @@
+use naia_demo_world::{Entity, WorldRefType};
+use naia_shared::Protocol;
+use naia_test::{Position, Session};
+
+const MAX_STEPS: usize = 100;
+
+fn protocol() -> Protocol {
+    Protocol::builder()
+        .add_component::<Position>()
+        .add_default_channels()
+        .build()
+}
+
+/// The Client's Entity with a Position of the given x, if it has one
+fn client_entity(session: &Session, client: usize, x: u16) -> Option<Entity> {
+    session.client_entities(client).into_iter().find(|entity| {
+        session
+            .client(client)
+            .world
+            .proxy()
+            .component::<Position>(entity)
+            .is_some_and(|position| *position.x == x)
+    })
+}
+
+#[test]
+fn reparenting_follows_the_scope_of_the_new_root() {
+    let mut session = Session::new(protocol);
+    let client = session.connect_client();
+
+    let seen_root = session.spawn(Position::new(1, 0));
+    let child = session.spawn(Position::new(2, 0));
+    // never in a Room with the User
+    let unseen_root = session
+        .server
+        .spawn_entity(session.server_world.proxy_mut())
+        .insert_component(Position::new(3, 0))
+        .id();
+
+    session
+        .server
+        .entity_set_parent(&child, &seen_root)
+        .unwrap();
+    session.step_until(MAX_STEPS, |session| {
+        let Some(client_child) = client_entity(session, client, 2) else {
+            return false;
+        };
+        session.client(client).client.entity_parent(&client_child)
+            == client_entity(session, client, 1)
+    });
+
+    // under a root the User can't see, the child leaves their scope
+    session
+        .server
+        .entity_set_parent(&child, &unseen_root)
+        .unwrap();
+    session.step_until(MAX_STEPS, |session| {
+        client_entity(session, client, 2).is_none()
+    });
+    assert!(client_entity(&session, client, 1).is_some());
+    assert!(client_entity(&session, client, 3).is_none());
+
+    // and comes back along with a seen root
+    session
+        .server
+        .entity_set_parent(&child, &seen_root)
+        .unwrap();
+    session.step_until(MAX_STEPS, |session| {
+        let Some(client_child) = client_entity(session, client, 2) else {
+            return false;
+        };
+        session.client(client).client.entity_parent(&client_child)
+            == client_entity(session, client, 1)
+    });
+
+    // detached, it is scoped through its own Room again
+    session.server.entity_remove_parent(&child);
+    session.step_until(MAX_STEPS, |session| {
+        client_entity(session, client, 2).is_some_and(|client_child| {
+            session
+                .client(client)
+                .client
+                .entity_parent(&client_child)
+                .is_none()
+        })
+    });
+}
+
+#[test]
+fn detached_child_outside_any_room_leaves_scope() {
+    let mut session = Session::new(protocol);
+    let client = session.connect_client();
+
+    let root = session.spawn(Position::new(1, 0));
+    let child = session
+        .server
+        .spawn_entity(session.server_world.proxy_mut())
+        .insert_component(Position::new(2, 0))
+        .id();
+    session.server.entity_set_parent(&child, &root).unwrap();
+    session.step_until(MAX_STEPS, |session| {
+        client_entity(session, client, 2).is_some()
+    });
+
+    session.server.entity_remove_parent(&child);
+    session.step_until(MAX_STEPS, |session| {
+        client_entity(session, client, 2).is_none()
+    });
+    assert!(client_entity(&session, client, 1).is_some());
+}
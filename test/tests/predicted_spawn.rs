@@ -0,0 +1,116 @@
+use std::time::Duration;
+
+use naia_client::{ClientConfig, PredictedSpawnConfirmedEvent, PredictedSpawnRolledBackEvent};
+use naia_demo_world::{Entity, WorldMutType, WorldRefType};
+use naia_server::NaiaServerError;
+use naia_shared::{PredictionKey, Protocol};
+use naia_test::{Position, Session, SessionClient};
+
+const MAX_STEPS: usize = 100;
+
+fn protocol() -> Protocol {
+    Protocol::builder()
+        .add_component::<Position>()
+        .add_default_channels()
+        .build()
+}
+
+fn spawn_predicted(session: &mut Session) -> (Entity, PredictionKey) {
+    let SessionClient { client, world, .. } = session.client_mut(0);
+    client.spawn_predicted_entity(world.proxy_mut())
+}
+
+#[test]
+fn confirmed_spawn_replicates_onto_predicted_entity() {
+    let mut session = Session::new(protocol);
+    session.connect_client();
+    let user_key = session.client(0).user_key;
+    let (predicted, prediction_key) = spawn_predicted(&mut session);
+
+    let server_entity = session.spawn(Position::new(5, 0));
+    session
+        .server
+        .confirm_predicted_spawn(&server_entity, &user_key, prediction_key)
+        .unwrap();
+
+    let mut confirmed = None;
+    for _ in 0..MAX_STEPS {
+        let mut events = session.step();
+        if let Some(confirm) = events.clients[0]
+            .read::<PredictedSpawnConfirmedEvent>()
+            .next()
+        {
+            confirmed = Some(confirm);
+            break;
+        }
+    }
+    assert!(confirmed == Some((prediction_key, predicted)));
+
+    session.step_until(MAX_STEPS, |session| {
+        session
+            .client(0)
+            .world
+            .proxy()
+            .component::<Position>(&predicted)
+            .is_some_and(|position| *position.x == 5)
+    });
+    assert!(session.client_entities(0) == vec![predicted]);
+}
+
+#[test]
+fn unconfirmed_spawn_is_rolled_back() {
+    let mut session = Session::new(protocol);
+    session.connect_client_with_config(ClientConfig {
+        predicted_spawn_timeout: Duration::from_millis(500),
+        ..Default::default()
+    });
+    let (predicted, prediction_key) = spawn_predicted(&mut session);
+
+    let mut rolled_back = None;
+    for _ in 0..MAX_STEPS {
+        let mut events = session.step();
+        if let Some(rollback) = events.clients[0]
+            .read::<PredictedSpawnRolledBackEvent>()
+            .next()
+        {
+            rolled_back = Some(rollback);
+            break;
+        }
+    }
+    assert!(rolled_back == Some((prediction_key, predicted)));
+    assert!(!session.client(0).world.proxy().has_entity(&predicted));
+}
+
+#[test]
+fn confirming_requires_an_entity_not_yet_in_scope() {
+    let mut session = Session::new(protocol);
+    session.connect_client();
+    let user_key = session.client(0).user_key;
+    let (_, prediction_key) = spawn_predicted(&mut session);
+
+    // not replicated by the Server
+    let unknown = session.server_world.proxy_mut().spawn_entity();
+    assert!(matches!(
+        session
+            .server
+            .confirm_predicted_spawn(&unknown, &user_key, prediction_key),
+        Err(NaiaServerError::EntityNotFound)
+    ));
+
+    let server_entity = session.spawn(Position::new(5, 0));
+    session.step_until(MAX_STEPS, |session| {
+        session.client_entities(0).iter().any(|entity| {
+            session
+                .client(0)
+                .world
+                .proxy()
+                .has_component::<Position>(entity)
+        })
+    });
+    assert!(matches!(
+        session
+            .server
+            .confirm_predicted_spawn(&server_entity, &user_key, prediction_key),
+        Err(NaiaServerError::EntityAlreadyInScope)
+    ));
+}
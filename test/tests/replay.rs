@@ -0,0 +1,187 @@
+use std::sync::Arc;
+
+use naia_client::{
+    transport::replay::{self, ReplayControl},
+    Client, ClientConfig, DespawnEntityEvent, SpawnEntityEvent,
+};
+use naia_demo_world::{Entity, World, WorldMutType, WorldRefType};
+use naia_shared::{Protocol, Recording};
+use naia_test::{Position, Session};
+
+const MAX_STEPS: usize = 100;
+
+fn protocol() -> Protocol {
+    Protocol::builder()
+        .add_component::<Position>()
+        .add_default_channels()
+        .build()
+}
+
+fn client_x(session: &Session) -> Option<u16> {
+    let entity = session.client_entities(0).into_iter().next()?;
+    let world = session.client(0).world.proxy();
+    let x = *world.component::<Position>(&entity)?.x;
+    Some(x)
+}
+
+fn set_server_x(session: &mut Session, entity: &Entity, x: u16) {
+    let mut world = session.server_world.proxy_mut();
+    *world.component_mut::<Position>(entity).unwrap().x = x;
+}
+
+/// Records a Session in which an Entity is spawned, updated & despawned,
+/// with a Keyframe taken once the update arrived
+fn record_session() -> (Session, Recording) {
+    let mut session = Session::new(protocol);
+    session.connect_client();
+    let user_key = session.client(0).user_key;
+    session.server.start_recording(&user_key).unwrap();
+
+    let entity = session.spawn(Position::new(1, 0));
+    session.step_until(MAX_STEPS, |session| client_x(session) == Some(1));
+    // updates wait for the spawn to be acknowledged
+    session.steps(MAX_STEPS);
+    set_server_x(&mut session, &entity, 2);
+    session.step_until(MAX_STEPS, |session| client_x(session) == Some(2));
+    session.steps(MAX_STEPS);
+    session
+        .server
+        .record_keyframe(session.server_world.proxy(), &user_key)
+        .unwrap();
+    session.steps(MAX_STEPS);
+    session
+        .server
+        .entity_mut(session.server_world.proxy_mut(), &entity)
+        .despawn();
+    session.step_until(MAX_STEPS, |session| session.client_entities(0).is_empty());
+
+    let recording = session.server.stop_recording(&user_key).unwrap();
+    (session, recording)
+}
+
+fn replay_client(session: &Session, recording: Recording) -> (Client<Entity>, ReplayControl) {
+    let client_config = ClientConfig {
+        clock: Arc::new(session.clock.clone()),
+        ..Default::default()
+    };
+    let mut client = Client::new(client_config, protocol());
+    let socket = replay::Socket::new(recording);
+    let control = socket.control();
+    client.connect(socket).unwrap();
+    (client, control)
+}
+
+/// What a replaying Client saw of the recorded Entity
+#[derive(Default)]
+struct Playback {
+    spawned: Option<Entity>,
+    seen_x: Vec<u16>,
+    despawned: Option<Entity>,
+}
+
+/// Plays the Recording until the recorded Entity is despawned
+fn play(
+    session: &Session,
+    client: &mut Client<Entity>,
+    world: &mut World,
+    control: &ReplayControl,
+) -> Playback {
+    let tick_interval = protocol().tick_interval;
+    let mut playback = Playback::default();
+    for _ in 0..(4 * MAX_STEPS) {
+        session.clock.advance(tick_interval);
+        let mut events = client.receive(world.proxy_mut());
+        if let Some((_, entity)) = events.read::<SpawnEntityEvent>().next() {
+            playback.spawned = Some(entity);
+        }
+        if let Some((_, entity)) = events.read::<DespawnEntityEvent>().next() {
+            playback.despawned = Some(entity);
+        }
+        if let Some(entity) = playback.spawned {
+            if let Some(position) = world.proxy().component::<Position>(&entity) {
+                if playback.seen_x.last() != Some(&*position.x) {
+                    playback.seen_x.push(*position.x);
+                }
+            }
+        }
+        if playback.despawned.is_some() && control.is_finished() {
+            break;
+        }
+    }
+    playback
+}
+
+#[test]
+fn recorded_session_replays_into_a_client() {
+    let (session, recording) = record_session();
+    assert!(!recording.packets().is_empty());
+    // as it would be saved & loaded
+    let recording = Recording::from_bytes(&recording.to_bytes()).unwrap();
+
+    let (mut client, control) = replay_client(&session, recording);
+    let mut world = World::default();
+    let playback = play(&session, &mut client, &mut world, &control);
+
+    assert!(playback.spawned.is_some());
+    assert!(playback.despawned == playback.spawned);
+    assert_eq!(playback.seen_x, vec![1, 2]);
+    assert!(!world.proxy().has_entity(&playback.spawned.unwrap()));
+}
+
+#[test]
+fn seeking_releases_earlier_packets_at_once() {
+    let (session, recording) = record_session();
+    let last_tick = recording.packets().last().unwrap().tick;
+
+    let (mut client, control) = replay_client(&session, recording);
+    let mut world = World::default();
+
+    let tick_interval = protocol().tick_interval;
+    for _ in 0..MAX_STEPS {
+        if client.is_connected() {
+            break;
+        }
+        session.clock.advance(tick_interval);
+        client.receive(world.proxy_mut());
+    }
+    assert!(client.is_connected());
+    assert!(!control.is_finished());
+
+    // past the end of the Recording
+    assert!(control.seek(&mut client, world.proxy_mut(), last_tick.wrapping_add(1)));
+    client.receive(world.proxy_mut());
+    assert!(control.is_finished());
+}
+
+#[test]
+fn seeking_back_restores_the_keyframe() {
+    let (session, recording) = record_session();
+    let keyframe_tick = recording.keyframes()[0].tick;
+
+    let (mut client, control) = replay_client(&session, recording);
+    let mut world = World::default();
+    let playback = play(&session, &mut client, &mut world, &control);
+    assert!(playback.despawned.is_some());
+    assert_eq!(control.keyframe_ticks(), vec![keyframe_tick]);
+
+    // the Entity is back as it was at the Keyframe, then despawned again
+    assert!(control.seek(
+        &mut client,
+        world.proxy_mut(),
+        keyframe_tick.wrapping_add(1)
+    ));
+    let playback = play(&session, &mut client, &mut world, &control);
+    assert!(playback.spawned.is_some());
+    assert!(playback.despawned == playback.spawned);
+    assert_eq!(playback.seen_x, vec![2]);
+
+    // before the Keyframe, playback restarts from the beginning
+    assert!(control.seek(
+        &mut client,
+        world.proxy_mut(),
+        keyframe_tick.wrapping_sub(1)
+    ));
+    let playback = play(&session, &mut client, &mut world, &control);
+    assert!(playback.despawned == playback.spawned);
+    assert_eq!(playback.seen_x, vec![1, 2]);
+}
@@ -0,0 +1,45 @@
+use naia_client::SpawnEntityEvent;
+use naia_demo_world::WorldRefType;
+use naia_shared::Protocol;
+use naia_test::{Position, Session};
+
+fn protocol() -> Protocol {
+    Protocol::builder()
+        .add_component::<Position>()
+        .add_default_channels()
+        .build()
+}
+
+#[test]
+fn entity_in_room_replicates_to_client() {
+    let mut session = Session::new(protocol);
+    let client = session.connect_client();
+
+    let server_entity = session
+        .server
+        .spawn_entity(session.server_world.proxy_mut())
+        .insert_component(Position::new(3, 4))
+        .id();
+    let room_key = session.room_key;
+    session
+        .server
+        .room_mut(&room_key)
+        .add_entity(&server_entity)
+        .unwrap();
+
+    let mut spawned = None;
+    for _ in 0..50 {
+        let mut events = session.step();
+        if let Some((_tick, entity)) = events.clients[client].read::<SpawnEntityEvent>().next() {
+            spawned = Some(entity);
+            break;
+        }
+    }
+    let client_entity = spawned.expect("Entity was not replicated");
+
+    let world = session.client(client).world.proxy();
+    let position = world
+        .component::<Position>(&client_entity)
+        .expect("Position was not replicated");
+    assert_eq!((*position.x, *position.y), (3, 4));
+}
@@ -0,0 +1,127 @@
+use naia_demo_world::WorldMutType;
+use naia_server::NaiaServerError;
+use naia_shared::{Property, Protocol, Replicate};
+use naia_test::Session;
+
+const MAX_STEPS: usize = 100;
+
+#[derive(Replicate)]
+pub struct Score {
+    pub value: Property<u16>,
+}
+
+impl Score {
+    pub fn new(value: u16) -> Self {
+        Self::new_complete(value)
+    }
+}
+
+fn protocol() -> Protocol {
+    Protocol::builder()
+        .add_resource::<Score>()
+        .add_default_channels()
+        .build()
+}
+
+fn client_score(session: &Session, client: usize) -> Option<u16> {
+    let session_client = session.client(client);
+    session_client
+        .client
+        .resource::<_, Score>(&session_client.world.proxy())
+        .map(|score| *score.value)
+}
+
+#[test]
+fn resource_is_inserted_updated_and_removed() {
+    let mut session = Session::new(protocol);
+    let client = session.connect_client();
+
+    session
+        .server
+        .insert_resource(session.server_world.proxy_mut(), Score::new(1))
+        .unwrap();
+    session.step_until(MAX_STEPS, |session| {
+        client_score(session, client) == Some(1)
+    });
+    // held by an Entity which is not reported as a spawn
+    assert!(session.client_entities(client).is_empty());
+
+    let entity = session.server.resource_entity::<Score>().unwrap();
+    *session
+        .server_world
+        .proxy_mut()
+        .component_mut::<Score>(&entity)
+        .unwrap()
+        .value = 2;
+    session.step_until(MAX_STEPS, |session| {
+        client_score(session, client) == Some(2)
+    });
+
+    // inserting again updates the same Resource
+    session
+        .server
+        .insert_resource(session.server_world.proxy_mut(), Score::new(3))
+        .unwrap();
+    assert!(session.server.resource_entity::<Score>() == Some(entity));
+    session.step_until(MAX_STEPS, |session| {
+        client_score(session, client) == Some(3)
+    });
+
+    let removed = session
+        .server
+        .remove_resource::<_, Score>(session.server_world.proxy_mut());
+    assert_eq!(removed.map(|score| *score.value), Some(3));
+    session.step_until(MAX_STEPS, |session| {
+        !session.client(client).client.has_resource::<Score>()
+    });
+}
+
+#[test]
+fn room_resource_only_reaches_users_in_its_room() {
+    let mut session = Session::new(protocol);
+    let a = session.connect_client();
+    let b = session.connect_client();
+    let user_b = session.client(b).user_key;
+
+    let other_room = session.server.make_room().key();
+    session
+        .server
+        .room_mut(&session.room_key)
+        .remove_user(&user_b);
+    session.server.room_mut(&other_room).add_user(&user_b);
+
+    session
+        .server
+        .insert_room_resource(session.server_world.proxy_mut(), &other_room, Score::new(1))
+        .unwrap();
+    session.step_until(MAX_STEPS, |session| client_score(session, b) == Some(1));
+    session.steps(MAX_STEPS);
+    assert_eq!(client_score(&session, a), None);
+
+    // moving it into the other Room replaces it
+    let room_key = session.room_key;
+    session
+        .server
+        .insert_room_resource(session.server_world.proxy_mut(), &room_key, Score::new(2))
+        .unwrap();
+    session.step_until(MAX_STEPS, |session| {
+        client_score(session, a) == Some(2) && client_score(session, b).is_none()
+    });
+}
+
+#[test]
+fn room_resource_requires_an_existing_room() {
+    let mut session = Session::new(protocol);
+    let room_key = session.server.make_room().key();
+    session.server.room_mut(&room_key).destroy();
+
+    assert!(matches!(
+        session.server.insert_room_resource(
+            session.server_world.proxy_mut(),
+            &room_key,
+            Score::new(1)
+        ),
+        Err(NaiaServerError::RoomNotFound)
+    ));
+    assert!(!session.server.has_resource::<Score>());
+}
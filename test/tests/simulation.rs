@@ -0,0 +1,76 @@
+use std::time::Duration;
+
+use naia_demo_world::WorldRefType;
+use naia_server::{DisconnectEvent, ServerConfig};
+use naia_shared::{ConnectionConfig, Protocol, Tick};
+use naia_test::{Position, Session};
+
+const SESSION_LENGTH: Duration = Duration::from_secs(600);
+
+fn protocol() -> Protocol {
+    Protocol::builder()
+        .add_component::<Position>()
+        .add_default_channels()
+        .build()
+}
+
+#[test]
+fn ten_minute_session_is_simulated_in_virtual_time() {
+    let started = std::time::Instant::now();
+    let mut session = Session::new(protocol);
+    session.connect_client();
+    session.spawn(Position::new(1, 0));
+
+    let tick_interval = protocol().tick_interval;
+    let steps = (SESSION_LENGTH.as_millis() / tick_interval.as_millis()) as usize;
+    let start_tick = session.server.current_tick();
+    session.steps(steps);
+
+    // every Tick of the ten minutes happened, and nothing timed out
+    assert_eq!(
+        session.server.current_tick(),
+        start_tick.wrapping_add(steps as Tick)
+    );
+    assert!(session.client(0).client.is_connected());
+    assert_eq!(session.server.users_count(), 1);
+    let client_entities = session.client_entities(0);
+    assert_eq!(client_entities.len(), 1);
+    let world = session.client(0).world.proxy();
+    assert!(world.has_component::<Position>(&client_entities[0]));
+    assert!(started.elapsed() < SESSION_LENGTH / 10);
+}
+
+#[test]
+fn silent_client_times_out_in_virtual_time() {
+    let timeout = Duration::from_secs(30);
+    let server_config = ServerConfig {
+        connection: ConnectionConfig {
+            disconnection_timeout_duration: timeout,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let mut session = Session::with_config(protocol, server_config);
+    session.connect_client();
+
+    // the Client stops sending, only the Server runs
+    let tick_interval = protocol().tick_interval;
+    let mut silent_for = Duration::ZERO;
+    let mut disconnected = false;
+    while silent_for <= 2 * timeout {
+        session.clock.advance(tick_interval);
+        silent_for += tick_interval;
+        let mut events = session.server.receive(session.server_world.proxy_mut());
+        session
+            .server
+            .send_all_updates(session.server_world.proxy());
+        if events.read::<DisconnectEvent>().next().is_some() {
+            disconnected = true;
+            break;
+        }
+    }
+
+    assert!(disconnected);
+    assert!(silent_for >= timeout);
+    assert_eq!(session.server.users_count(), 0);
+}
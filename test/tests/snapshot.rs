@@ -0,0 +1,81 @@
+use naia_demo_world::{WorldMutType, WorldRefType};
+use naia_server::NaiaServerError;
+use naia_shared::{EntityProperty, Protocol, Replicate};
+use naia_test::{Position, Session};
+
+#[derive(Replicate)]
+pub struct Target {
+    pub entity: EntityProperty,
+}
+
+fn protocol() -> Protocol {
+    Protocol::builder()
+        .add_component::<Position>()
+        .add_component::<Target>()
+        .add_default_channels()
+        .build()
+}
+
+#[test]
+fn snapshot_restores_into_a_fresh_server() {
+    let mut session = Session::new(protocol);
+    let target = session.spawn(Position::new(1, 2));
+    let mut pointer = Target::new_complete();
+    pointer.entity.set(&session.server, &target);
+    session
+        .server
+        .spawn_entity(session.server_world.proxy_mut())
+        .insert_component(pointer)
+        .insert_component(Position::new(3, 4));
+    // not replicated, so not saved
+    session.server_world.proxy_mut().spawn_entity();
+
+    let bytes = session
+        .server
+        .snapshot(session.server_world.proxy())
+        .unwrap();
+
+    let mut restored = Session::new(protocol);
+    let entities = restored
+        .server
+        .restore(restored.server_world.proxy_mut(), &bytes)
+        .unwrap();
+    assert_eq!(entities.len(), 2);
+
+    let world = restored.server_world.proxy();
+    let position = |entity| {
+        world
+            .component::<Position>(entity)
+            .map(|position| (*position.x, *position.y))
+    };
+    let restored_target = entities
+        .iter()
+        .find(|entity| position(entity) == Some((1, 2)))
+        .unwrap();
+    let restored_pointer = entities
+        .iter()
+        .find(|entity| position(entity) == Some((3, 4)))
+        .unwrap();
+    assert!(world.component::<Target>(restored_target).is_none());
+    let target_entity = world
+        .component::<Target>(restored_pointer)
+        .unwrap()
+        .entity
+        .get(&restored.server);
+    assert!(target_entity == Some(*restored_target));
+}
+
+#[test]
+fn snapshot_of_too_many_entities_is_an_error() {
+    let mut session = Session::new(protocol);
+    for _ in 0..=u16::MAX {
+        session
+            .server
+            .spawn_entity(session.server_world.proxy_mut());
+    }
+
+    assert!(matches!(
+        session.server.snapshot(session.server_world.proxy()),
+        Err(NaiaServerError::SnapshotTooLarge)
+    ));
+}
@@ -0,0 +1,116 @@
+use naia_client::ServerTickEvent;
+use naia_shared::{
+    sequence_less_than, Channel, ChannelDirection, ChannelMode, Message, Protocol, Tick,
+    TickBufferSettings,
+};
+use naia_test::Session;
+
+const MAX_STEPS: usize = 100;
+
+#[derive(Channel)]
+pub struct EventChannel;
+
+#[derive(Message)]
+pub struct Explosion {
+    pub id: u8,
+}
+
+fn protocol() -> Protocol {
+    Protocol::builder()
+        .add_message::<Explosion>()
+        .add_channel::<EventChannel>(
+            ChannelDirection::ServerToClient,
+            ChannelMode::TickBuffered(TickBufferSettings::default()),
+        )
+        .build()
+}
+
+/// Steps the Session, taking the Tick Buffered Messages of every Server Tick
+/// each Client reaches, and returns the (Client, Tick, id) of each Explosion
+fn step_explosions(session: &mut Session, steps: usize) -> Vec<(usize, Tick, u8)> {
+    let mut explosions = Vec::new();
+    for _ in 0..steps {
+        let mut events = session.step();
+        for (index, client_events) in events.clients.iter_mut().enumerate() {
+            for tick in client_events.read::<ServerTickEvent>() {
+                let mut messages = session
+                    .client_mut(index)
+                    .client
+                    .receive_tick_buffer_messages(&tick);
+                for explosion in messages.read::<EventChannel, Explosion>() {
+                    explosions.push((index, tick, explosion.id));
+                }
+            }
+        }
+    }
+    explosions
+}
+
+#[test]
+fn messages_land_on_their_tick() {
+    let mut session = Session::new(protocol);
+    session.connect_client();
+    session.connect_client();
+    step_explosions(&mut session, 10);
+
+    let target_tick = session.server.current_tick().wrapping_add(5);
+    let user_key = session.client(0).user_key;
+    session
+        .server
+        .send_tick_buffer_message::<EventChannel, _>(&user_key, &target_tick, &Explosion { id: 1 })
+        .unwrap();
+    session
+        .server
+        .broadcast_tick_buffer_message::<EventChannel, _>(&target_tick, &Explosion { id: 2 })
+        .unwrap();
+
+    let mut explosions = step_explosions(&mut session, MAX_STEPS);
+    explosions.sort();
+    assert_eq!(
+        explosions,
+        vec![
+            (0, target_tick, 1),
+            (0, target_tick, 2),
+            (1, target_tick, 2)
+        ]
+    );
+}
+
+#[test]
+fn messages_for_passed_ticks_are_dropped() {
+    let mut session = Session::new(protocol);
+    session.connect_client();
+    step_explosions(&mut session, 10);
+
+    // the Client has already taken the Messages of this Tick
+    let client_tick = session.client(0).client.server_tick().unwrap();
+    let stale_tick = client_tick.wrapping_sub(1);
+    session
+        .server
+        .broadcast_tick_buffer_message::<EventChannel, _>(&stale_tick, &Explosion { id: 1 })
+        .unwrap();
+
+    assert!(step_explosions(&mut session, MAX_STEPS).is_empty());
+
+    // and a Tick which is skipped over is not handed out later
+    let skipped_tick = session.server.current_tick().wrapping_add(5);
+    session
+        .server
+        .broadcast_tick_buffer_message::<EventChannel, _>(&skipped_tick, &Explosion { id: 2 })
+        .unwrap();
+    session.step_until(MAX_STEPS, |session| {
+        let client_tick = session.client(0).client.server_tick().unwrap();
+        sequence_less_than(skipped_tick, client_tick)
+    });
+    let client_tick = session.client(0).client.server_tick().unwrap();
+    let mut messages = session
+        .client_mut(0)
+        .client
+        .receive_tick_buffer_messages(&client_tick);
+    assert!(messages.read::<EventChannel, Explosion>().is_empty());
+    let mut messages = session
+        .client_mut(0)
+        .client
+        .receive_tick_buffer_messages(&skipped_tick);
+    assert!(messages.read::<EventChannel, Explosion>().is_empty());
+}
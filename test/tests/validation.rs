@@ -0,0 +1,329 @@
+use std::sync::{Arc, Mutex};
+
+use naia_client::{DespawnEntityEvent as ClientDespawnEntityEvent, EntityAuthGrantedEvent};
+use naia_demo_world::{Entity, WorldMutType, WorldRefType};
+use naia_server::{
+    DespawnEntityEvent, DisconnectEvent, EntityAuthRequestEvent, InsertComponentEvent,
+    SpawnEntityEvent, UpdateComponentEvent, Validation,
+};
+use naia_shared::Protocol;
+use naia_test::{Position, Session};
+
+const MAX_STEPS: usize = 50;
+
+fn protocol() -> Protocol {
+    Protocol::builder()
+        .add_component::<Position>()
+        .add_default_channels()
+        .enable_client_authoritative_entities()
+        .build()
+}
+
+/// Starts a Session whose Server validates Position. Returns the log of the
+/// (previous, proposed) x values the validator was called with.
+fn validated_session() -> (Session, Arc<Mutex<Vec<(Option<u16>, u16)>>>) {
+    let mut session = Session::new(protocol);
+    let log = Arc::new(Mutex::new(Vec::new()));
+    let validator_log = log.clone();
+    session.server.validate_component::<Position, _>(
+        move |_user_key, _entity, previous, proposed| {
+            validator_log
+                .lock()
+                .unwrap()
+                .push((previous.map(|previous| *previous.x), *proposed.x));
+            match *proposed.x {
+                0..=100 => Validation::Accept,
+                101..=200 => Validation::Replace(Position::new(100, *proposed.y)),
+                300 => Validation::Reject,
+                400 => Validation::Despawn,
+                500 => Validation::Kick,
+                _ => Validation::Reject,
+            }
+        },
+    );
+    (session, log)
+}
+
+/// Spawns an Entity with a Position on the Client, returning it along with
+/// the Server's Entity, and whether the Server emitted an insertion for it
+fn client_spawn(session: &mut Session, client: usize, x: u16) -> (Entity, Entity, bool) {
+    let client_entity = {
+        let session_client = session.client_mut(client);
+        session_client
+            .client
+            .spawn_entity(session_client.world.proxy_mut())
+            .insert_component(Position::new(x, 0))
+            .id()
+    };
+
+    let mut server_entity = None;
+    let mut inserted = false;
+    for _ in 0..MAX_STEPS {
+        let mut events = session.step();
+        if let Some((_, entity)) = events.server.read::<SpawnEntityEvent>().next() {
+            server_entity = Some(entity);
+        }
+        inserted |= events
+            .server
+            .read::<InsertComponentEvent<Position>>()
+            .next()
+            .is_some();
+        if server_entity.is_some() {
+            break;
+        }
+    }
+    // settle any events still in flight
+    for _ in 0..5 {
+        let mut events = session.step();
+        inserted |= events
+            .server
+            .read::<InsertComponentEvent<Position>>()
+            .next()
+            .is_some();
+    }
+
+    (
+        client_entity,
+        server_entity.expect("Server did not receive the Entity"),
+        inserted,
+    )
+}
+
+fn set_client_x(session: &mut Session, client: usize, entity: &Entity, x: u16) {
+    let session_client = session.client_mut(client);
+    let mut world = session_client.world.proxy_mut();
+    *world.component_mut::<Position>(entity).unwrap().x = x;
+}
+
+fn server_x(session: &Session, entity: &Entity) -> Option<u16> {
+    session
+        .server_world
+        .proxy()
+        .component::<Position>(entity)
+        .map(|position| *position.x)
+}
+
+/// Steps a few times, returning whether the Server emitted an update of
+/// Position
+fn step_for_update(session: &mut Session) -> bool {
+    let mut updated = false;
+    for _ in 0..10 {
+        let mut events = session.step();
+        updated |= events
+            .server
+            .read::<UpdateComponentEvent<Position>>()
+            .next()
+            .is_some();
+    }
+    updated
+}
+
+#[test]
+fn insertions_are_validated_before_they_are_applied() {
+    let (mut session, log) = validated_session();
+    let client = session.connect_client();
+
+    let (_, accepted, inserted) = client_spawn(&mut session, client, 50);
+    assert!(inserted);
+    assert_eq!(server_x(&session, &accepted), Some(50));
+
+    let (_, replaced, inserted) = client_spawn(&mut session, client, 150);
+    assert!(inserted);
+    assert_eq!(server_x(&session, &replaced), Some(100));
+
+    // a rejected insertion is never applied, so emits no event
+    let (_, rejected, inserted) = client_spawn(&mut session, client, 300);
+    assert!(!inserted);
+    assert!(session.server_world.proxy().has_entity(&rejected));
+    assert_eq!(server_x(&session, &rejected), None);
+
+    // insertions have no previous state
+    let log = log.lock().unwrap();
+    assert_eq!(*log, vec![(None, 50), (None, 150), (None, 300)]);
+}
+
+#[test]
+fn updates_are_validated_before_they_are_applied() {
+    let (mut session, log) = validated_session();
+    let client = session.connect_client();
+    let (client_entity, server_entity, _) = client_spawn(&mut session, client, 10);
+
+    set_client_x(&mut session, client, &client_entity, 20);
+    assert!(step_for_update(&mut session));
+    assert_eq!(server_x(&session, &server_entity), Some(20));
+
+    set_client_x(&mut session, client, &client_entity, 150);
+    assert!(step_for_update(&mut session));
+    assert_eq!(server_x(&session, &server_entity), Some(100));
+
+    // a rejected update keeps the previous state, and emits no event
+    set_client_x(&mut session, client, &client_entity, 300);
+    assert!(!step_for_update(&mut session));
+    assert_eq!(server_x(&session, &server_entity), Some(100));
+
+    set_client_x(&mut session, client, &client_entity, 30);
+    assert!(step_for_update(&mut session));
+    assert_eq!(server_x(&session, &server_entity), Some(30));
+
+    // the validator sees the state in the World, and the state proposed
+    let log = log.lock().unwrap();
+    assert_eq!(
+        log[1..],
+        [
+            (Some(10), 20),
+            (Some(20), 150),
+            (Some(100), 300),
+            (Some(100), 30)
+        ]
+    );
+}
+
+#[test]
+fn despawn_verdict_despawns_entity_on_server_and_client() {
+    let (mut session, _) = validated_session();
+    let client = session.connect_client();
+
+    // on insertion
+    let (client_entity, server_entity, inserted) = client_spawn(&mut session, client, 400);
+    assert!(!inserted);
+    assert!(!session.server_world.proxy().has_entity(&server_entity));
+    session.step_until(MAX_STEPS, |session| {
+        !session
+            .client(client)
+            .world
+            .proxy()
+            .has_entity(&client_entity)
+    });
+
+    // on update
+    let (client_entity, server_entity, _) = client_spawn(&mut session, client, 10);
+    set_client_x(&mut session, client, &client_entity, 400);
+    let mut server_despawns = 0;
+    let mut client_despawns = 0;
+    for _ in 0..MAX_STEPS {
+        let mut events = session.step();
+        server_despawns += events
+            .server
+            .read::<DespawnEntityEvent>()
+            .filter(|(_, entity)| *entity == server_entity)
+            .count();
+        client_despawns += events.clients[client]
+            .read::<ClientDespawnEntityEvent>()
+            .filter(|(_, entity)| *entity == client_entity)
+            .count();
+    }
+    assert!(!session.server_world.proxy().has_entity(&server_entity));
+    assert!(!session
+        .client(client)
+        .world
+        .proxy()
+        .has_entity(&client_entity));
+    // the Client confirming the despawn emits no second event
+    assert_eq!(server_despawns, 1);
+    assert_eq!(client_despawns, 1);
+    assert!(session.client(client).client.is_connected());
+
+    // the Client can still spawn Entities afterwards
+    let (_, server_entity, inserted) = client_spawn(&mut session, client, 60);
+    assert!(inserted);
+    assert_eq!(server_x(&session, &server_entity), Some(60));
+}
+
+#[test]
+fn kick_verdict_disconnects_user() {
+    let (mut session, _) = validated_session();
+    let client = session.connect_client();
+    let user_key = session.client(client).user_key;
+    let (client_entity, server_entity, _) = client_spawn(&mut session, client, 10);
+
+    set_client_x(&mut session, client, &client_entity, 500);
+    let mut disconnected = false;
+    for _ in 0..MAX_STEPS {
+        let mut events = session.step();
+        if events
+            .server
+            .read::<DisconnectEvent>()
+            .any(|(key, _)| key == user_key)
+        {
+            disconnected = true;
+            break;
+        }
+    }
+    assert!(disconnected);
+    assert!(!session.server_world.proxy().has_entity(&server_entity));
+}
+
+#[test]
+fn delegated_updates_are_validated() {
+    let (mut session, log) = validated_session();
+    let client = session.connect_client();
+    let user_key = session.client(client).user_key;
+    let server_entity = session.spawn(Position::new(10, 0));
+    session.server.enable_delegation(&server_entity).unwrap();
+    session.step_until(MAX_STEPS, |session| {
+        session
+            .client_entities(client)
+            .first()
+            .and_then(|entity| session.client(client).client.entity_auth_status(entity))
+            .is_some()
+    });
+    let client_entity = session.client_entities(client)[0];
+
+    session
+        .client_mut(client)
+        .client
+        .request_authority(&client_entity)
+        .unwrap();
+    let mut requested = false;
+    for _ in 0..MAX_STEPS {
+        let mut events = session.step();
+        if events
+            .server
+            .read::<EntityAuthRequestEvent>()
+            .next()
+            .is_some()
+        {
+            requested = true;
+            break;
+        }
+    }
+    assert!(requested);
+    session
+        .server
+        .grant_authority(&server_entity, &user_key)
+        .unwrap();
+    let mut granted = false;
+    for _ in 0..MAX_STEPS {
+        let mut events = session.step();
+        if events.clients[client]
+            .read::<EntityAuthGrantedEvent>()
+            .next()
+            .is_some()
+        {
+            granted = true;
+            break;
+        }
+    }
+    assert!(granted);
+
+    set_client_x(&mut session, client, &client_entity, 150);
+    session.steps(10);
+    assert_eq!(server_x(&session, &server_entity), Some(100));
+
+    set_client_x(&mut session, client, &client_entity, 300);
+    session.steps(10);
+    assert_eq!(server_x(&session, &server_entity), Some(100));
+    assert!(log.lock().unwrap().contains(&(Some(100), 300)));
+
+    set_client_x(&mut session, client, &client_entity, 400);
+    session.step_until(MAX_STEPS, |session| {
+        !session.server_world.proxy().has_entity(&server_entity)
+    });
+    session.step_until(MAX_STEPS, |session| {
+        !session
+            .client(client)
+            .world
+            .proxy()
+            .has_entity(&client_entity)
+    });
+}